@@ -5,10 +5,16 @@
 #
 # Triggers: Before context compaction (auto or manual)
 # Purpose: Re-inject protocol context that will survive compaction summary
+# Installed by: asimov hooks install --ai {{AI}}
 # ═══════════════════════════════════════════════════════════════════════════════
 
 set -e
 
+# v12.4.0: Tell `asimov --supervise` to re-inject context once the CLI is idle
+if [ -n "${ASIMOV_SUPERVISOR_EVENTS:-}" ]; then
+    echo compact >> "$ASIMOV_SUPERVISOR_EVENTS"
+fi
+
 cat << 'EOF'
 ROYALBIT ASIMOV REFRESH (Pre-Compaction)
 
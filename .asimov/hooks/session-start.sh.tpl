@@ -5,12 +5,24 @@
 #
 # Triggers: startup, resume, clear
 # Purpose: Auto-initialize RoyalBit Asimov on every session start
+# Installed by: asimov hooks install --ai {{AI}}
 #
 # When exit code is 0, stdout is injected into Claude's context.
 # ═══════════════════════════════════════════════════════════════════════════════
 
 set -e
 
+# v12.4.0: Tell `asimov --supervise` to re-inject context once the CLI is idle
+# (not on startup: the launcher already passed the warmup as the first prompt)
+if [ -n "${ASIMOV_SUPERVISOR_EVENTS:-}" ]; then
+    input=""
+    [ -t 0 ] || input=$(cat)
+    case "$input" in
+        *'"source":"startup"'* | *'"source": "startup"'*) ;;
+        *) echo session-start >> "$ASIMOV_SUPERVISOR_EVENTS" ;;
+    esac
+fi
+
 # Check if asimov is available
 if ! command -v asimov &> /dev/null; then
     cat << 'EOF'
@@ -0,0 +1,235 @@
+//! CHANGELOG.md in the Keep a Changelog format (v12.4.0)
+//!
+//! `asimov changelog` drafts the entry of a released `current` milestone;
+//! `asimov lint-docs --semantic` checks released roadmap versions and the
+//! package version against the entries.
+
+use crate::commit_policy::CommitMessage;
+use regex::Regex;
+use std::sync::LazyLock;
+
+pub const CHANGELOG_FILE: &str = "CHANGELOG.md";
+
+/// Preamble of a new CHANGELOG.md
+pub const CHANGELOG_HEADER: &str = "# Changelog
+
+All notable changes to this project will be documented in this file.
+
+The format is based on [Keep a Changelog](https://keepachangelog.com/en/1.0.0/),
+and this project adheres to [Semantic Versioning](https://semver.org/spec/v2.0.0.html).
+";
+
+/// Keep a Changelog sections, in the order they are written
+pub const SECTIONS: [&str; 6] = [
+    "Added",
+    "Changed",
+    "Deprecated",
+    "Removed",
+    "Fixed",
+    "Security",
+];
+
+/// `## [1.2.0] - 2026-01-03` (brackets, `v` and the date are optional;
+/// `## [Unreleased]` is not a version)
+static HEADING: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"^##\s+\[?v?(\d[^\]\s]*)\]?(?:\s+-\s+(\d{4}-\d{2}-\d{2}))?").unwrap()
+});
+
+/// A released version of the changelog
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChangelogEntry {
+    pub version: String,
+    pub date: Option<String>,
+    /// 1-based line of the heading
+    pub line: usize,
+    pub heading: String,
+}
+
+/// Version headings, in file order (newest first by convention)
+pub fn parse_changelog(content: &str) -> Vec<ChangelogEntry> {
+    content
+        .lines()
+        .enumerate()
+        .filter_map(|(i, line)| {
+            let caps = HEADING.captures(line)?;
+            Some(ChangelogEntry {
+                version: caps[1].to_string(),
+                date: caps.get(2).map(|d| d.as_str().to_string()),
+                line: i + 1,
+                heading: line.trim().to_string(),
+            })
+        })
+        .collect()
+}
+
+/// Section a commit belongs in; None for chores, tests, CI and docs
+pub fn changelog_section(message: &CommitMessage) -> Option<&'static str> {
+    if let Some(kind) = &message.kind {
+        return match kind.as_str() {
+            "feat" => Some("Added"),
+            "fix" => Some("Fixed"),
+            "perf" | "refactor" | "revert" => Some("Changed"),
+            "deprecate" => Some("Deprecated"),
+            "remove" => Some("Removed"),
+            "security" | "sec" => Some("Security"),
+            _ => None,
+        };
+    }
+    let description = message.description().to_lowercase();
+    let first = description.split_whitespace().next().unwrap_or_default();
+    Some(match first {
+        "add" | "adds" | "added" | "implement" | "introduce" | "support" => "Added",
+        "fix" | "fixes" | "fixed" | "resolve" | "correct" => "Fixed",
+        "remove" | "removes" | "removed" | "delete" | "drop" => "Removed",
+        "deprecate" | "deprecates" => "Deprecated",
+        "security" => "Security",
+        _ => "Changed",
+    })
+}
+
+/// A drafted release entry
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ReleaseNotes {
+    pub version: String,
+    pub date: String,
+    pub summary: Option<String>,
+    /// Bullets per section; continuation lines start with two spaces
+    pub sections: Vec<(&'static str, Vec<String>)>,
+}
+
+impl ReleaseNotes {
+    pub fn push(&mut self, section: &'static str, line: String) {
+        match self.sections.iter_mut().find(|(name, _)| *name == section) {
+            Some((_, lines)) => lines.push(line),
+            None => {
+                self.sections.push((section, vec![line]));
+                let order = |name: &str| SECTIONS.iter().position(|s| *s == name);
+                self.sections.sort_by_key(|(name, _)| order(name));
+            }
+        }
+    }
+
+    /// The entry as markdown; `separator` closes it with `---`
+    pub fn render(&self, separator: bool) -> String {
+        let mut out = format!("## [{}] - {}\n\n", self.version, self.date);
+        if let Some(summary) = &self.summary {
+            out.push_str(&format!("{}\n\n", summary.trim()));
+        }
+        for (section, lines) in &self.sections {
+            out.push_str(&format!("### {}\n\n", section));
+            for line in lines {
+                out.push_str(line);
+                out.push('\n');
+            }
+            out.push('\n');
+        }
+        if separator {
+            out.push_str("---\n\n");
+        }
+        out
+    }
+}
+
+/// Insert a rendered entry above the newest version (below the preamble
+/// and `## [Unreleased]`), or append it when there is none
+pub fn insert_entry(content: &str, entry: &str) -> String {
+    match parse_changelog(content).first() {
+        Some(newest) => {
+            let mut lines: Vec<&str> = content.lines().collect();
+            lines.insert(newest.line - 1, entry.trim_end_matches('\n'));
+            let mut out = lines.join("\n");
+            // The separator or section end needs its blank line back
+            out = out.replacen(
+                &format!("{}\n{}", entry.trim_end_matches('\n'), newest.heading),
+                &format!("{}\n\n{}", entry.trim_end_matches('\n'), newest.heading),
+                1,
+            );
+            out.push('\n');
+            out
+        }
+        None => {
+            let mut out = content.trim_end().to_string();
+            out.push_str("\n\n");
+            out.push_str(entry.trim_end_matches('\n'));
+            out.push('\n');
+            out
+        }
+    }
+}
+
+/// Does the changelog close entries with `---` lines?
+pub fn uses_separators(content: &str) -> bool {
+    content.lines().any(|line| line.trim() == "---")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CHANGELOG: &str = "# Changelog
+
+## [Unreleased]
+
+## [1.1.0]
+
+### Added
+
+- Thing
+
+---
+
+## [v1.0.0] - 2026-01-13
+
+- First
+";
+
+    #[test]
+    fn test_parse_changelog() {
+        let entries = parse_changelog(CHANGELOG);
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].version, "1.1.0");
+        assert_eq!(entries[0].date, None);
+        assert_eq!(entries[0].line, 5);
+        assert_eq!(entries[1].version, "1.0.0");
+        assert_eq!(entries[1].date.as_deref(), Some("2026-01-13"));
+        assert!(uses_separators(CHANGELOG));
+    }
+
+    #[test]
+    fn test_changelog_section() {
+        let section = |subject: &str| changelog_section(&CommitMessage::parse(subject));
+        assert_eq!(section("feat: [x] hooks"), Some("Added"));
+        assert_eq!(section("fix(cli): crash"), Some("Fixed"));
+        assert_eq!(section("chore: bump"), None);
+        assert_eq!(section("[x] Add hook manager"), Some("Added"));
+        assert_eq!(section("Remove old flag"), Some("Removed"));
+        assert_eq!(section("Tidy output"), Some("Changed"));
+    }
+
+    #[test]
+    fn test_render_and_insert_entry() {
+        let mut notes = ReleaseNotes {
+            version: "1.2.0".to_string(),
+            date: "2026-02-01".to_string(),
+            summary: Some("Hooks".to_string()),
+            sections: Vec::new(),
+        };
+        notes.push("Fixed", "- Crash".to_string());
+        notes.push("Added", "- **Hook manager**".to_string());
+        notes.push("Added", "  - Chain hooks".to_string());
+        let entry = notes.render(true);
+        assert_eq!(
+            entry,
+            "## [1.2.0] - 2026-02-01\n\nHooks\n\n### Added\n\n- **Hook manager**\n  - Chain hooks\n\n### Fixed\n\n- Crash\n\n---\n\n"
+        );
+
+        let updated = insert_entry(CHANGELOG, &entry);
+        assert!(updated.contains("## [Unreleased]\n\n## [1.2.0] - 2026-02-01\n"));
+        assert!(updated.contains("---\n\n## [1.1.0]\n"));
+        assert_eq!(parse_changelog(&updated)[0].version, "1.2.0");
+
+        let fresh = insert_entry(CHANGELOG_HEADER, &notes.render(false));
+        assert!(fresh.starts_with("# Changelog"));
+        assert!(fresh.ends_with("- Crash\n"));
+    }
+}
@@ -0,0 +1,273 @@
+//! Changelog command (v12.4.0)
+//!
+//! `asimov changelog` drafts the CHANGELOG.md entry of the `current`
+//! milestone once roadmap.yaml marks it released: the summary, its done
+//! deliverables (with the commits that referenced them as `[id]`) and the
+//! other commits since the previous tag, sorted into Keep a Changelog
+//! sections. `--write` inserts it above the newest entry.
+
+use crate::changelog::{
+    changelog_section, insert_entry, parse_changelog, uses_separators, ReleaseNotes,
+    CHANGELOG_FILE, CHANGELOG_HEADER,
+};
+use crate::commit_policy::CommitMessage;
+use crate::roadmap::{load_deliverables, load_milestones};
+use chrono::{Local, NaiveDate};
+use std::path::{Path, PathBuf};
+
+#[derive(Debug, Clone, Default)]
+pub struct ChangelogOptions {
+    /// Insert the entry into CHANGELOG.md instead of only returning it
+    pub write: bool,
+    /// Release date (YYYY-MM-DD); today if not set
+    pub date: Option<String>,
+}
+
+/// A drafted entry
+#[derive(Debug, Clone)]
+pub struct ChangelogDraft {
+    pub version: String,
+    /// Tag the commits were collected from; None means the whole history
+    pub previous_tag: Option<String>,
+    pub commits: usize,
+    /// The entry as markdown
+    pub entry: String,
+    pub path: PathBuf,
+    pub written: bool,
+}
+
+pub fn run_changelog(dir: &Path, options: &ChangelogOptions) -> Result<ChangelogDraft, String> {
+    let milestones = load_milestones(dir);
+    let current = milestones
+        .iter()
+        .find(|m| m.current)
+        .ok_or("roadmap.yaml has no current milestone with a version")?;
+    let status = current.status.as_deref().unwrap_or("planned");
+    if status != "released" {
+        return Err(format!(
+            "Current milestone v{} is {}, not released (set status: released in roadmap.yaml first)",
+            current.version, status
+        ));
+    }
+
+    let path = dir.join(CHANGELOG_FILE);
+    let existing = std::fs::read_to_string(&path).ok();
+    if let Some(content) = &existing {
+        if let Some(entry) = parse_changelog(content)
+            .into_iter()
+            .find(|e| e.version == current.version)
+        {
+            return Err(format!(
+                "{} already has an entry for {} (line {})",
+                CHANGELOG_FILE, current.version, entry.line
+            ));
+        }
+    }
+
+    let date = match &options.date {
+        Some(date) => NaiveDate::parse_from_str(date, "%Y-%m-%d")
+            .map_err(|_| format!("Invalid --date '{}' (expected YYYY-MM-DD)", date))?,
+        None => Local::now().date_naive(),
+    };
+
+    let previous_tag = previous_tag(dir, &current.version);
+    let commits = commits_since(dir, previous_tag.as_deref());
+
+    let mut notes = ReleaseNotes {
+        version: current.version.clone(),
+        date: date.format("%Y-%m-%d").to_string(),
+        summary: current.summary.clone(),
+        sections: Vec::new(),
+    };
+
+    // Done deliverables lead "Added", with the commits that referenced them
+    let deliverables = load_deliverables(dir);
+    let mut listed = Vec::new();
+    for item in current.items.iter().filter(|i| i.done) {
+        let title = deliverables
+            .iter()
+            .find(|d| d.id == item.key && d.milestone == current.version)
+            .and_then(|d| d.summary.clone())
+            .unwrap_or_else(|| item.key.clone());
+        notes.push("Added", format!("- **{}**", title));
+        for message in commits.iter().filter(|m| m.references.contains(&item.key)) {
+            notes.push("Added", format!("  - {}", message.description()));
+        }
+        listed.push(item.key.clone());
+    }
+
+    for message in &commits {
+        if message.is_generated() || message.references.iter().any(|r| listed.contains(r)) {
+            continue;
+        }
+        let Some(section) = changelog_section(message) else {
+            continue;
+        };
+        let breaking = if message.breaking {
+            "**BREAKING** "
+        } else {
+            ""
+        };
+        notes.push(section, format!("- {}{}", breaking, message.description()));
+    }
+
+    let separator = existing.as_deref().is_some_and(uses_separators);
+    let entry = notes.render(separator);
+    if options.write {
+        let content = insert_entry(existing.as_deref().unwrap_or(CHANGELOG_HEADER), &entry);
+        std::fs::write(&path, content)
+            .map_err(|e| format!("Cannot write {}: {}", path.display(), e))?;
+    }
+
+    Ok(ChangelogDraft {
+        version: current.version.clone(),
+        previous_tag,
+        commits: commits.len(),
+        entry,
+        path,
+        written: options.write,
+    })
+}
+
+fn git(dir: &Path, args: &[&str]) -> Option<String> {
+    let output = std::process::Command::new("git")
+        .args(args)
+        .current_dir(dir)
+        .output()
+        .ok()?;
+    output
+        .status
+        .success()
+        .then(|| String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+/// Newest tag reachable from HEAD, other than the one of this release
+fn previous_tag(dir: &Path, version: &str) -> Option<String> {
+    let tag = git(
+        dir,
+        &[
+            "describe",
+            "--tags",
+            "--abbrev=0",
+            &format!("--exclude=v{}", version),
+            &format!("--exclude={}", version),
+            "HEAD",
+        ],
+    )?;
+    Some(tag.trim().to_string()).filter(|t| !t.is_empty())
+}
+
+/// Non-merge commits after `tag` (all of them without one), oldest first
+fn commits_since(dir: &Path, tag: Option<&str>) -> Vec<CommitMessage> {
+    let range = match tag {
+        Some(tag) => format!("{}..HEAD", tag),
+        None => "HEAD".to_string(),
+    };
+    git(
+        dir,
+        &[
+            "log",
+            "--no-merges",
+            "--reverse",
+            "--format=%s",
+            &range,
+            "--",
+        ],
+    )
+    .unwrap_or_default()
+    .lines()
+    .filter(|subject| !subject.trim().is_empty())
+    .map(CommitMessage::parse)
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn git_ok(dir: &Path, args: &[&str]) {
+        let status = std::process::Command::new("git")
+            .args(args)
+            .current_dir(dir)
+            .env("GIT_AUTHOR_NAME", "Test")
+            .env("GIT_AUTHOR_EMAIL", "test@example.com")
+            .env("GIT_COMMITTER_NAME", "Test")
+            .env("GIT_COMMITTER_EMAIL", "test@example.com")
+            .status()
+            .unwrap();
+        assert!(status.success());
+    }
+
+    fn commit(dir: &Path, subject: &str) {
+        git_ok(dir, &["commit", "-q", "--allow-empty", "-m", subject]);
+    }
+
+    fn roadmap(dir: &Path, status: &str) {
+        std::fs::write(
+            dir.join(".asimov/roadmap.yaml"),
+            format!(
+                "current:\n  version: '1.1.0'\n  status: {}\n  summary: Hook chaining\n  deliverables:\n    - id: hook-manager\n      summary: Git hook manager\n      status: done\n    - id: later\n    - \"[x] Docs\"\n",
+                status
+            ),
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_run_changelog() {
+        let temp = TempDir::new().unwrap();
+        let dir = temp.path();
+        std::fs::create_dir_all(dir.join(".asimov")).unwrap();
+        git_ok(dir, &["init", "-q"]);
+        commit(dir, "Initial release");
+        git_ok(dir, &["tag", "v1.0.0"]);
+        commit(dir, "feat: [hook-manager] chain existing hooks");
+        commit(dir, "fix: crash on empty roadmap");
+        commit(dir, "chore: bump deps");
+        commit(dir, "Remove legacy flag");
+        commit(dir, "feat!: drop v1 schema");
+
+        roadmap(dir, "in_progress");
+        let err = run_changelog(dir, &ChangelogOptions::default()).unwrap_err();
+        assert!(err.contains("is in_progress, not released"), "{}", err);
+
+        roadmap(dir, "released");
+        let options = ChangelogOptions {
+            write: false,
+            date: Some("2026-02-01".to_string()),
+        };
+        let draft = run_changelog(dir, &options).unwrap();
+        assert_eq!(draft.previous_tag.as_deref(), Some("v1.0.0"));
+        assert_eq!(draft.commits, 5);
+        assert_eq!(
+            draft.entry,
+            "## [1.1.0] - 2026-02-01\n\nHook chaining\n\n\
+             ### Added\n\n- **Git hook manager**\n  - Chain existing hooks\n- **Docs**\n- **BREAKING** Drop v1 schema\n\n\
+             ### Removed\n\n- Remove legacy flag\n\n\
+             ### Fixed\n\n- Crash on empty roadmap\n\n"
+        );
+        assert!(!dir.join(CHANGELOG_FILE).exists());
+
+        let options = ChangelogOptions {
+            write: true,
+            ..options
+        };
+        run_changelog(dir, &options).unwrap();
+        let content = std::fs::read_to_string(dir.join(CHANGELOG_FILE)).unwrap();
+        assert!(content.starts_with(CHANGELOG_HEADER));
+        assert_eq!(parse_changelog(&content)[0].version, "1.1.0");
+
+        let err = run_changelog(dir, &options).unwrap_err();
+        assert!(err.contains("already has an entry for 1.1.0"), "{}", err);
+
+        let options = ChangelogOptions {
+            write: false,
+            date: Some("soon".to_string()),
+        };
+        std::fs::remove_file(dir.join(CHANGELOG_FILE)).unwrap();
+        assert!(run_changelog(dir, &options)
+            .unwrap_err()
+            .contains("Invalid --date"));
+    }
+}
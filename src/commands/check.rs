@@ -0,0 +1,599 @@
+//! Check command - run project.yaml quality gates natively (v12.4.0)
+//!
+//! Each gate runs through the shell (`sh -c`, as in the generated hook;
+//! `cmd /C` on Windows) in the project directory with its timeout, and its
+//! exit code, duration and output are captured. Passing gates are cached in
+//! `.asimov/cache/check.json`, keyed by the command, the timeout and a hash
+//! of the input files, so unchanged gates are skipped next time.
+//!
+//! Gates and the input hash always see the working tree, not the index:
+//! `--staged` only skips the run when nothing is staged, so unstaged edits
+//! still take part in a pre-commit check.
+
+use crate::quality::{load_quality_config, select_gates, GateProfile, QualityGate};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::io::Read;
+use std::path::Path;
+use std::process::{Command, Stdio};
+use std::time::{Duration, Instant};
+
+/// Cache location, relative to the project directory
+pub const CHECK_CACHE_FILE: &str = ".asimov/cache/check.json";
+
+/// Captured output kept per stream (tail)
+const MAX_CAPTURED_BYTES: usize = 64 * 1024;
+
+/// Directories never hashed when the project is not a git repository
+const UNHASHED_DIRS: &[&str] = &[".git", "target", "node_modules", "build", "dist"];
+
+#[derive(Debug, Clone, Default)]
+pub struct CheckOptions {
+    /// Run only these gates (any declared gate); empty = hook gates
+    pub gates: Vec<String>,
+    pub parallel: bool,
+    /// Only run when something is staged (pre-commit); gates still run
+    /// against the working tree
+    pub staged: bool,
+    /// Override the hook profile
+    pub profile: Option<GateProfile>,
+    /// Ignore cached results
+    pub no_cache: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GateStatus {
+    Passed,
+    Failed,
+    TimedOut,
+    /// Inputs unchanged since the last pass
+    Cached,
+    /// Not part of the active profile
+    Skipped,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct GateRun {
+    pub name: String,
+    pub command: String,
+    pub status: GateStatus,
+    pub exit_code: Option<i32>,
+    pub duration_ms: u64,
+    pub stdout: String,
+    pub stderr: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CheckResult {
+    pub success: bool,
+    pub profile: GateProfile,
+    /// Hash of the input files the gates ran against
+    pub input_hash: String,
+    pub gates: Vec<GateRun>,
+    /// Why nothing ran (no gates declared, nothing staged)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+}
+
+impl CheckResult {
+    pub fn failed(&self) -> Vec<&GateRun> {
+        self.gates
+            .iter()
+            .filter(|g| matches!(g.status, GateStatus::Failed | GateStatus::TimedOut))
+            .collect()
+    }
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct CheckCache {
+    gates: BTreeMap<String, CachedGate>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct CachedGate {
+    key: String,
+    duration_ms: u64,
+}
+
+/// Run the declared quality gates in `dir`
+pub fn run_check(dir: &Path, options: &CheckOptions) -> Result<CheckResult, String> {
+    let config = load_quality_config(dir);
+    let profile = options
+        .profile
+        .unwrap_or_else(|| config.hook.active_profile());
+
+    let gates = if options.gates.is_empty() {
+        config.gates()
+    } else {
+        select_gates(&config.quality, &config.hook, &options.gates)?
+    };
+
+    let mut result = CheckResult {
+        success: true,
+        profile,
+        input_hash: String::new(),
+        gates: Vec::new(),
+        note: None,
+    };
+
+    if gates.is_empty() {
+        result.note = Some("No quality gates declared in .asimov/project.yaml".to_string());
+        return Ok(result);
+    }
+    if options.staged && !has_staged_changes(dir) {
+        result.note = Some("Nothing staged".to_string());
+        return Ok(result);
+    }
+
+    result.input_hash = hash_inputs(dir);
+    let mut cache = if options.no_cache {
+        CheckCache::default()
+    } else {
+        load_cache(dir)
+    };
+
+    // Decide per gate: skipped, cached or to run
+    let mut runs: Vec<Option<GateRun>> = Vec::new();
+    let mut pending: Vec<(usize, &QualityGate)> = Vec::new();
+    for (i, gate) in gates.iter().enumerate() {
+        if !options.gates.contains(&gate.name) && !gate.profile.runs_in(profile) {
+            runs.push(Some(gate_run(gate, GateStatus::Skipped)));
+        } else if cache
+            .gates
+            .get(&gate.name)
+            .is_some_and(|c| c.key == gate_key(gate, &result.input_hash))
+        {
+            let mut run = gate_run(gate, GateStatus::Cached);
+            run.duration_ms = cache.gates[&gate.name].duration_ms;
+            runs.push(Some(run));
+        } else {
+            runs.push(None);
+            pending.push((i, gate));
+        }
+    }
+
+    let executed: Vec<(usize, GateRun)> = if options.parallel {
+        std::thread::scope(|scope| {
+            let handles: Vec<_> = pending
+                .iter()
+                .map(|(i, gate)| (*i, scope.spawn(move || execute_gate(dir, gate))))
+                .collect();
+            handles
+                .into_iter()
+                .map(|(i, h)| (i, h.join().expect("gate thread panicked")))
+                .collect()
+        })
+    } else {
+        let mut executed = Vec::new();
+        for (i, gate) in &pending {
+            let run = execute_gate(dir, gate);
+            let failed = run.status != GateStatus::Passed;
+            executed.push((*i, run));
+            // Sequential runs stop at the first failure, like the hook
+            if failed {
+                break;
+            }
+        }
+        executed
+    };
+
+    for (i, run) in executed {
+        if run.status == GateStatus::Passed {
+            cache.gates.insert(
+                run.name.clone(),
+                CachedGate {
+                    key: gate_key(&gates[i], &result.input_hash),
+                    duration_ms: run.duration_ms,
+                },
+            );
+        } else {
+            cache.gates.remove(&run.name);
+        }
+        runs[i] = Some(run);
+    }
+
+    result.gates = runs.into_iter().flatten().collect();
+    result.success = result.failed().is_empty();
+    if !options.no_cache {
+        save_cache(dir, &cache);
+    }
+    Ok(result)
+}
+
+fn gate_run(gate: &QualityGate, status: GateStatus) -> GateRun {
+    GateRun {
+        name: gate.name.clone(),
+        command: gate.command.clone(),
+        status,
+        exit_code: None,
+        duration_ms: 0,
+        stdout: String::new(),
+        stderr: String::new(),
+    }
+}
+
+/// Run one gate through the shell, enforcing its timeout
+pub(super) fn execute_gate(dir: &Path, gate: &QualityGate) -> GateRun {
+    let mut run = gate_run(gate, GateStatus::Failed);
+    let start = Instant::now();
+
+    let mut command = shell_command(&gate.command);
+    command
+        .current_dir(dir)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+    #[cfg(unix)]
+    {
+        // Own process group, so a timeout kills the whole pipeline
+        use std::os::unix::process::CommandExt;
+        command.process_group(0);
+    }
+
+    let mut child = match command.spawn() {
+        Ok(child) => child,
+        Err(e) => {
+            run.stderr = format!("Failed to start shell: {}", e);
+            return run;
+        }
+    };
+
+    let stdout = child.stdout.take().map(spawn_reader);
+    let stderr = child.stderr.take().map(spawn_reader);
+    let deadline = gate.timeout.map(|t| start + Duration::from_secs(t));
+
+    let mut timed_out = false;
+    let status = loop {
+        match child.try_wait() {
+            Ok(Some(status)) => break Some(status),
+            Ok(None) if deadline.is_some_and(|d| Instant::now() >= d) => {
+                kill_tree(&mut child);
+                let _ = child.wait();
+                timed_out = true;
+                break None;
+            }
+            Ok(None) => std::thread::sleep(Duration::from_millis(20)),
+            Err(e) => {
+                run.stderr = format!("Failed to wait for gate: {}", e);
+                break None;
+            }
+        }
+    };
+
+    run.duration_ms = start.elapsed().as_millis() as u64;
+    run.stdout = stdout.map(join_reader).unwrap_or_default();
+    let stderr = stderr.map(join_reader).unwrap_or_default();
+    if !stderr.is_empty() {
+        run.stderr = stderr;
+    }
+
+    if timed_out {
+        run.status = GateStatus::TimedOut;
+    } else if let Some(status) = status {
+        run.exit_code = status.code();
+        if status.success() {
+            run.status = GateStatus::Passed;
+        }
+    }
+    run
+}
+
+fn shell_command(cmd: &str) -> Command {
+    if cfg!(windows) {
+        let mut command = Command::new("cmd");
+        command.args(["/C", cmd]);
+        command
+    } else {
+        let mut command = Command::new("sh");
+        command.args(["-c", cmd]);
+        command
+    }
+}
+
+fn kill_tree(child: &mut std::process::Child) {
+    #[cfg(unix)]
+    {
+        let _ = Command::new("kill")
+            .args(["-KILL", "--", &format!("-{}", child.id())])
+            .stderr(Stdio::null())
+            .status();
+    }
+    let _ = child.kill();
+}
+
+fn spawn_reader<R: Read + Send + 'static>(mut stream: R) -> std::thread::JoinHandle<String> {
+    std::thread::spawn(move || {
+        let mut buf = Vec::new();
+        let _ = stream.read_to_end(&mut buf);
+        let start = buf.len().saturating_sub(MAX_CAPTURED_BYTES);
+        String::from_utf8_lossy(&buf[start..]).into_owned()
+    })
+}
+
+fn join_reader(handle: std::thread::JoinHandle<String>) -> String {
+    handle.join().unwrap_or_default()
+}
+
+fn has_staged_changes(dir: &Path) -> bool {
+    Command::new("git")
+        .args(["diff", "--cached", "--quiet"])
+        .current_dir(dir)
+        .status()
+        // Exit 1 = differences; errors (not a repo) count as "check anyway"
+        .map(|s| s.code() != Some(0))
+        .unwrap_or(true)
+}
+
+/// Cache key: command + timeout + input files
+fn gate_key(gate: &QualityGate, input_hash: &str) -> String {
+    let mut hasher = Fnv64::new();
+    hasher.write(gate.command.as_bytes());
+    hasher.write(&[0]);
+    if let Some(timeout) = gate.timeout {
+        hasher.write(timeout.to_string().as_bytes());
+    }
+    hasher.write(&[0]);
+    hasher.write(input_hash.as_bytes());
+    format!("{:016x}", hasher.finish())
+}
+
+/// Hash paths and contents of the project's input files.
+/// Git repos hash tracked and untracked, non-ignored files.
+fn hash_inputs(dir: &Path) -> String {
+    let mut files = git_files(dir).unwrap_or_else(|| walk_files(dir));
+    files.retain(|f| !f.starts_with(".asimov/cache/") && !f.starts_with(".asimov/sessions/"));
+    files.sort();
+    files.dedup();
+
+    let mut hasher = Fnv64::new();
+    for file in &files {
+        hasher.write(file.as_bytes());
+        hasher.write(&[0]);
+        if let Ok(content) = std::fs::read(dir.join(file)) {
+            hasher.write(&content);
+        }
+        hasher.write(&[0]);
+    }
+    format!("{:016x}", hasher.finish())
+}
+
+fn git_files(dir: &Path) -> Option<Vec<String>> {
+    let output = Command::new("git")
+        .args([
+            "ls-files",
+            "-z",
+            "--cached",
+            "--others",
+            "--exclude-standard",
+        ])
+        .current_dir(dir)
+        .stderr(Stdio::null())
+        .output()
+        .ok()
+        .filter(|o| o.status.success())?;
+    Some(
+        output
+            .stdout
+            .split(|b| *b == 0)
+            .filter(|f| !f.is_empty())
+            .map(|f| String::from_utf8_lossy(f).into_owned())
+            .collect(),
+    )
+}
+
+fn walk_files(dir: &Path) -> Vec<String> {
+    walkdir::WalkDir::new(dir)
+        .into_iter()
+        .filter_entry(|e| {
+            e.depth() == 0 || !UNHASHED_DIRS.contains(&e.file_name().to_string_lossy().as_ref())
+        })
+        .flatten()
+        .filter(|e| e.file_type().is_file())
+        .filter_map(|e| {
+            e.path()
+                .strip_prefix(dir)
+                .ok()
+                .map(|p| p.to_string_lossy().replace('\\', "/"))
+        })
+        .collect()
+}
+
+fn load_cache(dir: &Path) -> CheckCache {
+    std::fs::read_to_string(dir.join(CHECK_CACHE_FILE))
+        .ok()
+        .and_then(|c| serde_json::from_str(&c).ok())
+        .unwrap_or_default()
+}
+
+/// Best effort: a read-only checkout simply never caches
+fn save_cache(dir: &Path, cache: &CheckCache) {
+    let path = dir.join(CHECK_CACHE_FILE);
+    let Some(cache_dir) = path.parent() else {
+        return;
+    };
+    if std::fs::create_dir_all(cache_dir).is_err() {
+        return;
+    }
+    // Keep the cache out of git without touching the project's .gitignore
+    let ignore = cache_dir.join(".gitignore");
+    if !ignore.exists() {
+        let _ = std::fs::write(ignore, "*\n");
+    }
+    if let Ok(json) = serde_json::to_string_pretty(cache) {
+        let _ = std::fs::write(path, json);
+    }
+}
+
+/// FNV-1a (64-bit): stable across builds, unlike std's DefaultHasher
+struct Fnv64(u64);
+
+impl Fnv64 {
+    fn new() -> Self {
+        Self(0xcbf2_9ce4_8422_2325)
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 ^= u64::from(*byte);
+            self.0 = self.0.wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+
+    fn finish(&self) -> u64 {
+        self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn project(quality: &str) -> TempDir {
+        let temp = TempDir::new().unwrap();
+        std::fs::create_dir_all(temp.path().join(".asimov")).unwrap();
+        std::fs::write(
+            temp.path().join(".asimov/project.yaml"),
+            format!("identity:\n  name: x\n  type: generic\n{}", quality),
+        )
+        .unwrap();
+        temp
+    }
+
+    fn statuses(result: &CheckResult) -> Vec<(&str, GateStatus)> {
+        result
+            .gates
+            .iter()
+            .map(|g| (g.name.as_str(), g.status))
+            .collect()
+    }
+
+    #[test]
+    fn test_check_no_gates() {
+        let temp = project("");
+        let result = run_check(temp.path(), &CheckOptions::default()).unwrap();
+        assert!(result.success);
+        assert!(result.gates.is_empty());
+        assert!(result.note.is_some());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_check_runs_gates_and_caches() {
+        let temp = project("quality:\n  lint: \"echo linted\"\n  test: \"echo tested >&2\"\n");
+        let options = CheckOptions::default();
+
+        let first = run_check(temp.path(), &options).unwrap();
+        assert!(first.success);
+        assert_eq!(
+            statuses(&first),
+            vec![("lint", GateStatus::Passed), ("test", GateStatus::Passed)]
+        );
+        assert_eq!(first.gates[0].stdout.trim(), "linted");
+        assert_eq!(first.gates[1].stderr.trim(), "tested");
+        assert_eq!(first.gates[0].exit_code, Some(0));
+        assert!(temp.path().join(CHECK_CACHE_FILE).exists());
+
+        let second = run_check(temp.path(), &options).unwrap();
+        assert_eq!(
+            statuses(&second),
+            vec![("lint", GateStatus::Cached), ("test", GateStatus::Cached)]
+        );
+
+        // Changing an input file invalidates the cache
+        std::fs::write(temp.path().join("main.c"), "int main;").unwrap();
+        let third = run_check(temp.path(), &options).unwrap();
+        assert_eq!(third.gates[0].status, GateStatus::Passed);
+    }
+
+    #[test]
+    fn test_gate_key_includes_timeout() {
+        let gate = |timeout| QualityGate {
+            name: "test".to_string(),
+            command: "cargo test".to_string(),
+            timeout,
+            profile: GateProfile::Fast,
+        };
+        assert_eq!(gate_key(&gate(None), "abc"), gate_key(&gate(None), "abc"));
+        assert_ne!(
+            gate_key(&gate(None), "abc"),
+            gate_key(&gate(Some(60)), "abc")
+        );
+        assert_ne!(
+            gate_key(&gate(Some(60)), "abc"),
+            gate_key(&gate(Some(600)), "abc")
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_check_failure_stops_sequential_run() {
+        let temp = project("quality:\n  lint: \"exit 7\"\n  test: \"echo never\"\n");
+        let result = run_check(temp.path(), &CheckOptions::default()).unwrap();
+        assert!(!result.success);
+        assert_eq!(statuses(&result), vec![("lint", GateStatus::Failed)]);
+        assert_eq!(result.gates[0].exit_code, Some(7));
+
+        // Parallel runs every gate
+        let options = CheckOptions {
+            parallel: true,
+            ..Default::default()
+        };
+        let result = run_check(temp.path(), &options).unwrap();
+        assert_eq!(
+            statuses(&result),
+            vec![("lint", GateStatus::Failed), ("test", GateStatus::Passed)]
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_check_timeout_and_profile() {
+        let temp = project(
+            "quality:\n  lint:\n    command: \"sleep 5\"\n    timeout: 1\n  test: \"true\"\n",
+        );
+        let options = CheckOptions {
+            profile: Some(GateProfile::Fast),
+            ..Default::default()
+        };
+        let start = Instant::now();
+        let result = run_check(temp.path(), &options).unwrap();
+        assert!(start.elapsed() < Duration::from_secs(4));
+        assert_eq!(
+            statuses(&result),
+            vec![
+                ("lint", GateStatus::TimedOut),
+                ("test", GateStatus::Skipped)
+            ]
+        );
+
+        // test is a full-profile gate: skipped under fast unless requested
+        let options = CheckOptions {
+            profile: Some(GateProfile::Fast),
+            gates: vec!["test".to_string()],
+            ..Default::default()
+        };
+        let result = run_check(temp.path(), &options).unwrap();
+        assert_eq!(statuses(&result), vec![("test", GateStatus::Passed)]);
+    }
+
+    #[test]
+    fn test_check_unknown_gate() {
+        let temp = project("quality:\n  lint: \"true\"\n  test: \"# todo\"\n");
+        let options = CheckOptions {
+            gates: vec!["test".to_string()],
+            ..Default::default()
+        };
+        let err = run_check(temp.path(), &options).unwrap_err();
+        assert!(err.contains("'test'"));
+        assert!(err.contains("declared: lint"));
+    }
+
+    #[test]
+    fn test_fnv_is_stable() {
+        let mut h = Fnv64::new();
+        h.write(b"asimov");
+        assert_eq!(format!("{:016x}", h.finish()), "564558e5dc033f06");
+    }
+}
@@ -0,0 +1,146 @@
+//! Deps audit command implementation (v12.4.0, ADR-045)
+//!
+//! Fully offline: lockfiles on disk plus an optional local advisory database.
+
+use super::evaluate_licenses;
+use crate::deps::{
+    audit_lockfiles, require_lockfiles, AdvisoryDb, DepsAudit, DepsConfig, DEFAULT_ADVISORY_DB,
+};
+use serde::Serialize;
+use std::path::Path;
+
+#[derive(Debug, Clone, Default)]
+pub struct DepsAuditOptions {
+    /// Advisory database (overrides project.yaml `deps.advisories`)
+    pub advisories: Option<std::path::PathBuf>,
+    /// Fail on BLOATED_DEPS and license findings, not only advisories
+    pub strict: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct DepsAuditResult {
+    pub success: bool,
+    #[serde(flatten)]
+    pub audit: DepsAudit,
+    /// From LICENSE or the package manifest (same detection as doctor)
+    pub project_license: Option<String>,
+    /// Dependencies violating the license policy (see `asimov licenses`)
+    pub license_conflicts: Vec<String>,
+    /// Advisory database used, if any
+    pub advisory_db: Option<String>,
+}
+
+pub fn run_deps_audit(dir: &Path, options: &DepsAuditOptions) -> Result<DepsAuditResult, String> {
+    let mut lockfiles = require_lockfiles(dir)?;
+    // v12.4.0: Licenses from local metadata feed both the mix and the policy
+    let licenses = evaluate_licenses(dir, &mut lockfiles)?;
+
+    let config = DepsConfig::load(dir);
+    // An explicit database must exist; the default one is optional
+    let db_path = match (&options.advisories, &config.advisories) {
+        (Some(path), _) => Some(path.clone()),
+        (None, Some(path)) => Some(dir.join(path)),
+        (None, None) => Some(dir.join(DEFAULT_ADVISORY_DB)).filter(|p| p.exists()),
+    };
+    let db = db_path.as_deref().map(AdvisoryDb::load).transpose()?;
+
+    let audit = audit_lockfiles(&lockfiles, &config, db.as_ref());
+    let conflicts: Vec<String> = licenses
+        .check
+        .violations
+        .iter()
+        .map(|v| format!("{} {}: {}", v.name, v.version, v.reason))
+        .collect();
+
+    let success = audit.vulnerabilities.is_empty()
+        && (!options.strict || (audit.bloat.is_empty() && conflicts.is_empty()));
+
+    Ok(DepsAuditResult {
+        success,
+        audit,
+        project_license: licenses.project_license,
+        license_conflicts: conflicts,
+        advisory_db: db_path.map(|p| p.display().to_string()),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    const PACKAGE_LOCK: &str = r#"{
+  "lockfileVersion": 3,
+  "packages": {
+    "": { "name": "app", "dependencies": { "lodash": "^4.17.0", "gpl-lib": "^1" } },
+    "node_modules/lodash": { "version": "4.17.11", "license": "MIT" },
+    "node_modules/gpl-lib": { "version": "1.0.0", "license": "GPL-3.0" }
+  }
+}"#;
+
+    const ADVISORIES: &str = r#"
+advisories:
+  - id: GHSA-jf85-cpcp-j695
+    ecosystem: npm
+    package: lodash
+    affected: ["<4.17.12"]
+    severity: high
+    summary: Prototype pollution
+"#;
+
+    fn project(dir: &Path) {
+        std::fs::write(dir.join("package-lock.json"), PACKAGE_LOCK).unwrap();
+        std::fs::write(dir.join("package.json"), r#"{"license": "MIT"}"#).unwrap();
+    }
+
+    #[test]
+    fn test_no_lockfile_is_an_error() {
+        let temp = TempDir::new().unwrap();
+        let err = run_deps_audit(temp.path(), &DepsAuditOptions::default()).unwrap_err();
+        assert!(err.contains("No lockfile found"));
+    }
+
+    #[test]
+    fn test_default_advisory_db_flags_vulnerable_versions() {
+        let temp = TempDir::new().unwrap();
+        project(temp.path());
+        std::fs::create_dir_all(temp.path().join(".asimov")).unwrap();
+        std::fs::write(temp.path().join(DEFAULT_ADVISORY_DB), ADVISORIES).unwrap();
+
+        let result = run_deps_audit(temp.path(), &DepsAuditOptions::default()).unwrap();
+        assert!(!result.success);
+        assert_eq!(result.audit.vulnerabilities.len(), 1);
+        assert_eq!(result.audit.vulnerabilities[0].version, "4.17.11");
+        assert_eq!(result.project_license.as_deref(), Some("MIT"));
+        assert_eq!(result.license_conflicts.len(), 1);
+    }
+
+    #[test]
+    fn test_license_conflicts_fail_only_when_strict() {
+        let temp = TempDir::new().unwrap();
+        project(temp.path());
+
+        let result = run_deps_audit(temp.path(), &DepsAuditOptions::default()).unwrap();
+        assert!(result.success);
+        assert!(result.advisory_db.is_none());
+        assert_eq!(result.audit.licenses.len(), 2);
+
+        let strict = DepsAuditOptions {
+            strict: true,
+            ..Default::default()
+        };
+        assert!(!run_deps_audit(temp.path(), &strict).unwrap().success);
+    }
+
+    #[test]
+    fn test_missing_explicit_advisory_db_is_an_error() {
+        let temp = TempDir::new().unwrap();
+        project(temp.path());
+        let options = DepsAuditOptions {
+            advisories: Some(temp.path().join("missing.yaml")),
+            strict: false,
+        };
+        let err = run_deps_audit(temp.path(), &options).unwrap_err();
+        assert!(err.contains("Failed to read advisory database"));
+    }
+}
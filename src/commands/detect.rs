@@ -0,0 +1,135 @@
+//! Project type detection command (v12.4.0)
+
+use crate::templates::{detect_project_candidates, DetectionReport, ProjectType};
+use std::io::{self, BufRead, IsTerminal, Write};
+use std::path::Path;
+
+/// Types offered in the interactive prompt, in display order
+const PROMPT_TYPES: &[ProjectType] = &[
+    ProjectType::Rust,
+    ProjectType::Python,
+    ProjectType::Node,
+    ProjectType::Go,
+    ProjectType::Flutter,
+    ProjectType::Docs,
+    ProjectType::Arch,
+    ProjectType::Java,
+    ProjectType::Kotlin,
+    ProjectType::CSharp,
+    ProjectType::Cpp,
+    ProjectType::Ruby,
+    ProjectType::Php,
+    ProjectType::Swift,
+    ProjectType::Generic,
+];
+
+/// Rank project type candidates for a directory
+pub fn run_detect(dir: &Path) -> Result<DetectionReport, String> {
+    if !dir.is_dir() {
+        return Err(format!("Not a directory: {}", dir.display()));
+    }
+    Ok(detect_project_candidates(dir))
+}
+
+/// One line per candidate: "python 72% - pyproject.toml, uv.lock, 12 of 14 source files"
+pub fn describe_candidates(report: &DetectionReport) -> Vec<String> {
+    report
+        .candidates
+        .iter()
+        .map(|c| {
+            let evidence: Vec<&str> = c.evidence.iter().map(|e| e.detail.as_str()).collect();
+            format!(
+                "{} {:.0}% - {}",
+                c.project_type,
+                c.confidence * 100.0,
+                evidence.join(", ")
+            )
+        })
+        .collect()
+}
+
+/// Show the detection evidence and prompt for a project type.
+/// Empty input or EOF accepts the top candidate; so does a stdin that is
+/// not a terminal (CI, pipes), without waiting for input.
+pub fn prompt_project_type(report: &DetectionReport) -> ProjectType {
+    let detected = report.primary();
+
+    eprintln!();
+    if report.candidates.is_empty() {
+        eprintln!("No project markers found.");
+    } else {
+        eprintln!("Detected project type candidates:");
+        for line in describe_candidates(report) {
+            eprintln!("  - {}", line);
+        }
+    }
+    eprintln!();
+    if !io::stdin().is_terminal() {
+        eprintln!(
+            "Project type not specified and stdin is not a terminal: using {} (pass --type to choose)",
+            detected
+        );
+        return detected;
+    }
+    eprintln!("Project type not specified. Please select:");
+    for (i, pt) in PROMPT_TYPES.iter().enumerate() {
+        let marker = if *pt == detected { " (detected)" } else { "" };
+        eprintln!("  {}. {}{}", i + 1, pt, marker);
+    }
+    eprint!(
+        "Enter number [1-{}] (default: {}): ",
+        PROMPT_TYPES.len(),
+        detected
+    );
+    io::stderr().flush().ok();
+
+    let stdin = io::stdin();
+    let line = stdin.lock().lines().next();
+
+    if let Some(Ok(input)) = line {
+        let input = input.trim();
+        if input.is_empty() {
+            return detected;
+        }
+        if let Ok(n) = input.parse::<usize>() {
+            if n >= 1 && n <= PROMPT_TYPES.len() {
+                return PROMPT_TYPES[n - 1];
+            }
+        }
+    }
+
+    detected
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_run_detect_not_a_directory() {
+        let temp = TempDir::new().unwrap();
+        let file = temp.path().join("file.txt");
+        std::fs::write(&file, "x").unwrap();
+        assert!(run_detect(&file).is_err());
+    }
+
+    #[test]
+    fn test_describe_candidates() {
+        let temp = TempDir::new().unwrap();
+        std::fs::write(temp.path().join("Cargo.toml"), "").unwrap();
+        std::fs::write(temp.path().join("Cargo.lock"), "").unwrap();
+        let report = run_detect(temp.path()).unwrap();
+        assert_eq!(
+            describe_candidates(&report),
+            vec!["rust 100% - Cargo.toml, Cargo.lock"]
+        );
+    }
+
+    #[test]
+    fn test_prompt_types_cover_all_selectable_types() {
+        // Migration is only reachable via an explicit template
+        assert_eq!(PROMPT_TYPES.len(), 15);
+        assert!(!PROMPT_TYPES.contains(&ProjectType::Migration));
+    }
+}
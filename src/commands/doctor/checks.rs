@@ -0,0 +1,928 @@
+//! Built-in doctor checks (v12.4.0)
+//!
+//! One type per concern; `builtin_checks` lists them in report order.
+
+use super::{CheckCategory, CheckOutcome, CheckSeverity, DoctorCheck, DoctorContext};
+use crate::commands::evaluate_licenses;
+use crate::commands::refresh::regenerate_precommit_hook;
+use crate::deps::load_lockfiles;
+use crate::file_size::check_file_sizes;
+use crate::git_hooks::hooks_dir;
+use crate::templates::detect_project_type;
+use crate::update::cached_check_for_update;
+use crate::validator::{check_protocol_integrity, regenerate_protocol_files};
+use crate::{validate_file, ProjectType};
+use std::path::Path;
+use std::process::Command;
+
+/// Template written for a missing roadmap.yaml
+pub(super) const ROADMAP_TEMPLATE: &str =
+    "current:\n  version: \"0.1.0\"\n  status: in_progress\n  summary: \"Initial setup\"\n";
+
+/// Built-in checks, in report order (tools depend on the project type)
+pub(super) fn builtin_checks(project_type: Option<ProjectType>) -> Vec<Box<dyn DoctorCheck>> {
+    let mut checks: Vec<Box<dyn DoctorCheck>> = vec![
+        Box::new(AsimovDirCheck),
+        Box::new(RoadmapCheck),
+        Box::new(RoadmapValidCheck),
+        Box::new(GitRepoCheck),
+        Box::new(PrecommitHookCheck),
+        Box::new(ProtocolsCheck),
+    ];
+    if let Some(project_type) = project_type {
+        checks.extend(coding_tools(project_type));
+        checks.extend(audit_tools(project_type));
+        checks.push(Box::new(FileSizeCheck(project_type)));
+    }
+    checks.push(Box::new(LicensesCheck));
+    checks.push(Box::new(LatestVersionCheck));
+    checks
+}
+
+struct AsimovDirCheck;
+
+impl DoctorCheck for AsimovDirCheck {
+    fn id(&self) -> &str {
+        "asimov-dir"
+    }
+    fn name(&self) -> &str {
+        ".asimov/ directory"
+    }
+    fn category(&self) -> CheckCategory {
+        CheckCategory::Project
+    }
+    fn severity(&self) -> CheckSeverity {
+        CheckSeverity::Error
+    }
+    fn run(&self, ctx: &DoctorContext) -> Option<CheckOutcome> {
+        Some(if ctx.asimov_dir().is_dir() {
+            CheckOutcome::pass("exists")
+        } else {
+            CheckOutcome::fail("missing")
+                .with_detail(".asimov/ missing - run 'asimov doctor --fix' or 'asimov init'")
+        })
+    }
+    fn fix_action(&self, _ctx: &DoctorContext) -> Option<String> {
+        Some("create .asimov/".to_string())
+    }
+    fn fix(&self, ctx: &DoctorContext) -> Result<(), String> {
+        std::fs::create_dir_all(ctx.asimov_dir())
+            .map_err(|e| format!("Cannot create .asimov/: {}", e))
+    }
+}
+
+struct RoadmapCheck;
+
+impl DoctorCheck for RoadmapCheck {
+    fn id(&self) -> &str {
+        "roadmap"
+    }
+    fn name(&self) -> &str {
+        "roadmap.yaml"
+    }
+    fn category(&self) -> CheckCategory {
+        CheckCategory::Project
+    }
+    fn severity(&self) -> CheckSeverity {
+        CheckSeverity::Error
+    }
+    fn run(&self, ctx: &DoctorContext) -> Option<CheckOutcome> {
+        Some(if ctx.asimov_dir().join("roadmap.yaml").exists() {
+            CheckOutcome::pass("exists")
+        } else {
+            CheckOutcome::fail("missing")
+                .with_detail("roadmap.yaml missing - run 'asimov doctor --fix'")
+        })
+    }
+    fn fix_action(&self, _ctx: &DoctorContext) -> Option<String> {
+        Some("write roadmap.yaml template".to_string())
+    }
+    fn fix(&self, ctx: &DoctorContext) -> Result<(), String> {
+        // Runs after AsimovDirCheck, which creates .asimov/
+        std::fs::write(ctx.asimov_dir().join("roadmap.yaml"), ROADMAP_TEMPLATE)
+            .map_err(|e| format!("Cannot create roadmap.yaml: {}", e))
+    }
+}
+
+struct RoadmapValidCheck;
+
+impl DoctorCheck for RoadmapValidCheck {
+    fn id(&self) -> &str {
+        "roadmap-valid"
+    }
+    fn name(&self) -> &str {
+        "roadmap.yaml validation"
+    }
+    fn category(&self) -> CheckCategory {
+        CheckCategory::Project
+    }
+    fn severity(&self) -> CheckSeverity {
+        CheckSeverity::Error
+    }
+    fn run(&self, ctx: &DoctorContext) -> Option<CheckOutcome> {
+        let roadmap_path = ctx.asimov_dir().join("roadmap.yaml");
+        if !roadmap_path.exists() {
+            return None;
+        }
+        Some(match validate_file(&roadmap_path) {
+            Ok(r) if r.is_valid => CheckOutcome::pass("valid"),
+            Ok(r) => r
+                .errors
+                .into_iter()
+                .fold(CheckOutcome::fail("has errors"), |outcome, e| {
+                    outcome.with_detail(format!("roadmap.yaml: {}", e))
+                }),
+            Err(e) => CheckOutcome::fail(format!("failed: {}", e))
+                .with_detail(format!("roadmap.yaml: {}", e)),
+        })
+    }
+}
+
+struct GitRepoCheck;
+
+impl DoctorCheck for GitRepoCheck {
+    fn id(&self) -> &str {
+        "git-repo"
+    }
+    fn name(&self) -> &str {
+        "git repository"
+    }
+    fn category(&self) -> CheckCategory {
+        CheckCategory::Git
+    }
+    fn severity(&self) -> CheckSeverity {
+        CheckSeverity::Warn
+    }
+    fn run(&self, ctx: &DoctorContext) -> Option<CheckOutcome> {
+        Some(if ctx.dir.join(".git").exists() {
+            CheckOutcome::pass("found")
+        } else {
+            CheckOutcome::fail("not found").with_detail("Not a git repository")
+        })
+    }
+}
+
+struct PrecommitHookCheck;
+
+impl DoctorCheck for PrecommitHookCheck {
+    fn id(&self) -> &str {
+        "pre-commit-hook"
+    }
+    fn name(&self) -> &str {
+        "pre-commit hook"
+    }
+    fn category(&self) -> CheckCategory {
+        CheckCategory::Git
+    }
+    fn severity(&self) -> CheckSeverity {
+        CheckSeverity::Warn
+    }
+    fn run(&self, ctx: &DoctorContext) -> Option<CheckOutcome> {
+        // v12.4.0: Where git runs hooks from (core.hooksPath aware)
+        let hooks_dir = hooks_dir(ctx.dir).ok()?;
+        Some(if hooks_dir.join("pre-commit").exists() {
+            CheckOutcome::pass("installed")
+        } else {
+            CheckOutcome::fail("missing")
+                .with_detail("Git pre-commit hook missing - run 'asimov doctor --fix'")
+        })
+    }
+    fn fix_action(&self, _ctx: &DoctorContext) -> Option<String> {
+        Some("install git pre-commit hook".to_string())
+    }
+    fn fix(&self, ctx: &DoctorContext) -> Result<(), String> {
+        let project_type = ctx
+            .project_type
+            .unwrap_or_else(|| detect_project_type(ctx.dir));
+        regenerate_precommit_hook(ctx.dir, project_type)
+            .map(|_| ())
+            .map_err(|e| format!("Cannot install pre-commit hook: {}", e))
+    }
+}
+
+/// Protocol files against the embedded versions (v9.0.0)
+struct ProtocolsCheck;
+
+impl DoctorCheck for ProtocolsCheck {
+    fn id(&self) -> &str {
+        "protocols"
+    }
+    fn name(&self) -> &str {
+        "protocol files"
+    }
+    fn category(&self) -> CheckCategory {
+        CheckCategory::Protocols
+    }
+    fn severity(&self) -> CheckSeverity {
+        CheckSeverity::Error
+    }
+    fn run(&self, ctx: &DoctorContext) -> Option<CheckOutcome> {
+        if !ctx.asimov_dir().is_dir() {
+            return None;
+        }
+        let protocol_checks = check_protocol_integrity(ctx.dir);
+
+        let mut missing = Vec::new();
+        let mut outdated = Vec::new();
+        for check in &protocol_checks {
+            if !check.exists {
+                missing.push(check.filename.clone());
+            } else if check.outdated {
+                outdated.push(check.filename.clone());
+            }
+        }
+
+        if missing.is_empty() && outdated.is_empty() {
+            return Some(CheckOutcome::pass(format!(
+                "{} files OK",
+                protocol_checks.len()
+            )));
+        }
+
+        let mut summary = Vec::new();
+        let mut details = Vec::new();
+        if !missing.is_empty() {
+            summary.push(format!("{} missing", missing.len()));
+            details.push(format!(
+                "Missing protocol files: {} - run 'asimov doctor --fix'",
+                missing.join(", ")
+            ));
+        }
+        if !outdated.is_empty() {
+            summary.push(format!("{} outdated", outdated.len()));
+            details.push(format!(
+                "Outdated protocol files: {} - run 'asimov doctor --fix' to update",
+                outdated.join(", ")
+            ));
+        }
+        Some(
+            details
+                .into_iter()
+                .fold(CheckOutcome::fail(summary.join(", ")), |outcome, d| {
+                    outcome.with_detail(d)
+                }),
+        )
+    }
+    fn fix_action(&self, _ctx: &DoctorContext) -> Option<String> {
+        Some("regenerate protocol files".to_string())
+    }
+    fn fix(&self, ctx: &DoctorContext) -> Result<(), String> {
+        regenerate_protocol_files(ctx.dir)
+            .map(|_| ())
+            .map_err(|e| format!("Cannot regenerate protocol files: {}", e))
+    }
+}
+
+/// A command-line tool the project's standards rely on (v9.7.0 ADR-044)
+///
+/// No `--fix`: installing software is left to the user, so a missing tool
+/// reports its install command in the hint instead of running it.
+struct ToolCheck {
+    id: String,
+    name: String,
+    program: &'static str,
+    args: &'static [&'static str],
+    /// Warning shown when missing, with install instructions when known
+    hint: String,
+}
+
+impl ToolCheck {
+    /// A tool invoked directly
+    fn command(name: &'static str, args: &'static [&'static str]) -> Self {
+        Self {
+            id: format!("tool:{}", name),
+            name: name.to_string(),
+            program: name,
+            args,
+            hint: format!("{} not installed - coding standards may not work", name),
+        }
+    }
+
+    /// A cargo subcommand shipped as a rustup component
+    fn rustup(name: &'static str, args: &'static [&'static str], component: &str) -> Self {
+        let install = format!("rustup component add {}", component);
+        Self {
+            id: format!("tool:{}", name.replace(' ', "-")),
+            name: name.to_string(),
+            program: "cargo",
+            args,
+            hint: format!("{} not installed - run: {}", name, install),
+        }
+    }
+
+    /// An optional dependency audit tool (v9.8.0 ADR-045)
+    fn audit(name: &'static str, args: &'static [&'static str], install: &str) -> Self {
+        Self {
+            id: format!("tool:{}", name),
+            name: format!("{} (audit)", name),
+            // cargo subcommands run through cargo
+            program: if name.starts_with("cargo-") {
+                "cargo"
+            } else {
+                name
+            },
+            args,
+            hint: format!(
+                "{} not installed - dependency health checks will be skipped. Install: {}",
+                name, install
+            ),
+        }
+    }
+}
+
+impl DoctorCheck for ToolCheck {
+    fn id(&self) -> &str {
+        &self.id
+    }
+    fn name(&self) -> &str {
+        &self.name
+    }
+    fn category(&self) -> CheckCategory {
+        CheckCategory::Tools
+    }
+    fn severity(&self) -> CheckSeverity {
+        CheckSeverity::Warn
+    }
+    fn run(&self, _ctx: &DoctorContext) -> Option<CheckOutcome> {
+        Some(match Command::new(self.program).args(self.args).output() {
+            Ok(output) if output.status.success() => CheckOutcome::pass("installed"),
+            _ => CheckOutcome::fail("not found").with_detail(self.hint.clone()),
+        })
+    }
+}
+
+/// Coding standards tools per project type (v9.7.0 ADR-044)
+fn coding_tools(project_type: ProjectType) -> Vec<Box<dyn DoctorCheck>> {
+    let tools = match project_type {
+        ProjectType::Rust => vec![
+            ToolCheck::rustup("cargo", &["--version"], "cargo"),
+            ToolCheck::rustup("cargo fmt", &["fmt", "--version"], "rustfmt"),
+            ToolCheck::rustup("cargo clippy", &["clippy", "--version"], "clippy"),
+        ],
+        ProjectType::Python => vec![
+            ToolCheck::command("ruff", &["--version"]),
+            ToolCheck::command("pytest", &["--version"]),
+        ],
+        ProjectType::Node => vec![
+            ToolCheck::command("prettier", &["--version"]),
+            ToolCheck::command("eslint", &["--version"]),
+        ],
+        ProjectType::Go => vec![
+            ToolCheck::command("go", &["version"]),
+            ToolCheck::command("golangci-lint", &["--version"]),
+        ],
+        ProjectType::Flutter => vec![
+            ToolCheck::command("dart", &["--version"]),
+            ToolCheck::command("flutter", &["--version"]),
+        ],
+        // Note: java prints its version to stderr but exits 0
+        ProjectType::Java => vec![
+            ToolCheck::command("java", &["-version"]),
+            ToolCheck::command("javac", &["-version"]),
+        ],
+        ProjectType::Kotlin => vec![
+            ToolCheck::command("java", &["-version"]),
+            ToolCheck::command("ktlint", &["--version"]),
+        ],
+        ProjectType::CSharp => vec![ToolCheck::command("dotnet", &["--version"])],
+        ProjectType::Cpp => vec![
+            ToolCheck::command("cmake", &["--version"]),
+            ToolCheck::command("clang-format", &["--version"]),
+        ],
+        ProjectType::Ruby => vec![
+            ToolCheck::command("ruby", &["--version"]),
+            ToolCheck::command("rubocop", &["--version"]),
+        ],
+        ProjectType::Php => vec![
+            ToolCheck::command("php", &["--version"]),
+            ToolCheck::command("composer", &["--version"]),
+        ],
+        ProjectType::Swift => vec![
+            ToolCheck::command("swift", &["--version"]),
+            ToolCheck::command("swiftlint", &["version"]),
+        ],
+        // Note: --help exits 2, --version exits 0
+        ProjectType::Docs | ProjectType::Arch => {
+            vec![ToolCheck::command("markdownlint-cli2", &["--version"])]
+        }
+        _ => vec![],
+    };
+    tools
+        .into_iter()
+        .map(|t| Box::new(t) as Box<dyn DoctorCheck>)
+        .collect()
+}
+
+/// Dependency health audit tools per project type (v9.8.0 ADR-045)
+fn audit_tools(project_type: ProjectType) -> Vec<Box<dyn DoctorCheck>> {
+    let tools = match project_type {
+        ProjectType::Rust => vec![
+            ToolCheck::audit(
+                "cargo-audit",
+                &["audit", "--version"],
+                "cargo install cargo-audit",
+            ),
+            ToolCheck::audit(
+                "cargo-deny",
+                &["deny", "--version"],
+                "cargo install cargo-deny",
+            ),
+            ToolCheck::audit(
+                "cargo-outdated",
+                &["outdated", "--version"],
+                "cargo install cargo-outdated",
+            ),
+        ],
+        ProjectType::Python => vec![
+            ToolCheck::audit("pip-audit", &["--version"], "pip install pip-audit"),
+            ToolCheck::audit("pip-licenses", &["--version"], "pip install pip-licenses"),
+        ],
+        ProjectType::Node => vec![ToolCheck::audit(
+            "license-checker",
+            &["--version"],
+            "npm install -g license-checker",
+        )],
+        ProjectType::Go => vec![ToolCheck::audit(
+            "govulncheck",
+            &["--help"],
+            "go install golang.org/x/vuln/cmd/govulncheck@latest",
+        )],
+        ProjectType::Ruby => vec![ToolCheck::audit(
+            "bundle-audit",
+            &["version"],
+            "gem install bundler-audit",
+        )],
+        // v12.4.0: dotnet and composer ship their own audit commands
+        ProjectType::Java | ProjectType::Kotlin | ProjectType::Cpp | ProjectType::Swift => {
+            vec![ToolCheck::audit(
+                "osv-scanner",
+                &["--version"],
+                "go install github.com/google/osv-scanner/v2/cmd/osv-scanner@latest",
+            )]
+        }
+        _ => vec![],
+    };
+    tools
+        .into_iter()
+        .map(|t| Box::new(t) as Box<dyn DoctorCheck>)
+        .collect()
+}
+
+/// Source files against soft/hard line limits (v12.4.0)
+struct FileSizeCheck(ProjectType);
+
+impl DoctorCheck for FileSizeCheck {
+    fn id(&self) -> &str {
+        "file-sizes"
+    }
+    fn name(&self) -> &str {
+        "File sizes"
+    }
+    fn category(&self) -> CheckCategory {
+        CheckCategory::Project
+    }
+    fn severity(&self) -> CheckSeverity {
+        CheckSeverity::Error
+    }
+    fn run(&self, ctx: &DoctorContext) -> Option<CheckOutcome> {
+        let report = check_file_sizes(ctx.dir, self.0);
+        let hard = report.hard().count();
+        let soft = report.soft().count();
+        let mut outcome = match (hard, soft) {
+            (0, 0) => CheckOutcome::pass(format!("{} file(s) within limits", report.checked)),
+            (0, _) => CheckOutcome::pass(format!("{} file(s) over soft limit", soft)),
+            _ => CheckOutcome::fail(format!("{} file(s) over hard limit", hard)),
+        };
+        for v in report.hard() {
+            outcome = outcome.with_detail(format!(
+                "{} has {} lines (hard limit: {}) - split it or add a file_size exemption",
+                v.file, v.lines, v.limit
+            ));
+        }
+        for v in report.soft() {
+            outcome = outcome.with_warning(format!(
+                "{} has {} lines (soft limit: {})",
+                v.file, v.lines, v.limit
+            ));
+        }
+        Some(outcome)
+    }
+}
+
+/// Locked dependency licenses (v12.4.0, same policy as `asimov licenses`)
+struct LicensesCheck;
+
+impl DoctorCheck for LicensesCheck {
+    fn id(&self) -> &str {
+        "licenses"
+    }
+    fn name(&self) -> &str {
+        "Licenses"
+    }
+    fn category(&self) -> CheckCategory {
+        CheckCategory::Dependencies
+    }
+    fn severity(&self) -> CheckSeverity {
+        CheckSeverity::Error
+    }
+    fn run(&self, ctx: &DoctorContext) -> Option<CheckOutcome> {
+        let mut lockfiles = load_lockfiles(ctx.dir).ok()?;
+        if lockfiles.is_empty() {
+            return None;
+        }
+        let licenses = match evaluate_licenses(ctx.dir, &mut lockfiles) {
+            Ok(licenses) => licenses,
+            Err(e) => return Some(CheckOutcome::fail("invalid policy").with_detail(e)),
+        };
+
+        let violations = licenses.check.violations.len();
+        let mut outcome = if violations == 0 {
+            CheckOutcome::pass(format!("{} dependencies comply", licenses.check.checked))
+        } else {
+            CheckOutcome::fail(format!("{} violation(s)", violations))
+        };
+        for v in &licenses.check.violations {
+            outcome = outcome.with_detail(format!(
+                "License: {} {} ({}) - {}",
+                v.name,
+                v.version,
+                v.license.as_deref().unwrap_or("unknown"),
+                v.reason
+            ));
+        }
+        if !licenses.check.unknown.is_empty() {
+            outcome = outcome.with_warning(format!(
+                "{} dependencies without local license metadata (run: asimov licenses)",
+                licenses.check.unknown.len()
+            ));
+        }
+        Some(outcome)
+    }
+}
+
+/// Running binary against the latest release
+/// v12.4.0: Cached for the TTL; offline it only reads the cache
+struct LatestVersionCheck;
+
+impl DoctorCheck for LatestVersionCheck {
+    fn id(&self) -> &str {
+        "version"
+    }
+    fn name(&self) -> &str {
+        "asimov version"
+    }
+    fn category(&self) -> CheckCategory {
+        CheckCategory::Version
+    }
+    fn severity(&self) -> CheckSeverity {
+        CheckSeverity::Info
+    }
+    fn run(&self, _ctx: &DoctorContext) -> Option<CheckOutcome> {
+        let info = cached_check_for_update()?;
+        Some(if info.update_available {
+            CheckOutcome::fail(format!(
+                "v{} (update available: v{})",
+                info.current, info.latest
+            ))
+            .with_detail("Run 'asimov update' to upgrade")
+        } else {
+            CheckOutcome::pass(format!("v{} (latest)", info.current))
+        })
+    }
+}
+
+/// Detect project type from project.yaml
+pub(super) fn detect_project_type_from_yaml(dir: &Path) -> Option<ProjectType> {
+    let project_path = dir.join(".asimov").join("project.yaml");
+    if !project_path.exists() {
+        return None;
+    }
+
+    let content = std::fs::read_to_string(&project_path).ok()?;
+
+    // v12.4.0: Prefer the parsed identity.type, fall back to text matching
+    let declared = serde_yaml_ng::from_str::<serde_yaml_ng::Value>(&content)
+        .ok()
+        .and_then(|yaml| {
+            yaml.get("identity")?
+                .get("type")?
+                .as_str()?
+                .parse::<ProjectType>()
+                .ok()
+        });
+    if declared.is_some() {
+        return declared;
+    }
+
+    if content.contains("type: rust") {
+        Some(ProjectType::Rust)
+    } else if content.contains("type: python") {
+        Some(ProjectType::Python)
+    } else if content.contains("type: node") {
+        Some(ProjectType::Node)
+    } else if content.contains("type: go") {
+        Some(ProjectType::Go)
+    } else if content.contains("type: flutter") {
+        Some(ProjectType::Flutter)
+    } else if content.contains("type: docs") {
+        Some(ProjectType::Docs)
+    } else {
+        Some(ProjectType::Generic)
+    }
+}
+
+/// Detect project license from LICENSE file or package manifest (v9.8.0 ADR-045)
+pub fn detect_license(dir: &Path) -> Option<String> {
+    // Check LICENSE file variants
+    for name in [
+        "LICENSE",
+        "LICENSE.md",
+        "LICENSE.txt",
+        "LICENCE",
+        "LICENCE.md",
+    ] {
+        let path = dir.join(name);
+        if path.exists() {
+            if let Ok(content) = std::fs::read_to_string(&path) {
+                let content_lower = content.to_lowercase();
+                // v12.4.0: Source-available licenses
+                if content_lower.contains("elastic license 2.0") {
+                    return Some("Elastic-2.0".to_string());
+                }
+                if content_lower.contains("mit license")
+                    || content_lower.contains("permission is hereby granted, free of charge")
+                {
+                    return Some("MIT".to_string());
+                }
+                if content_lower.contains("apache license") && content_lower.contains("version 2.0")
+                {
+                    return Some("Apache-2.0".to_string());
+                }
+                if content_lower.contains("gnu general public license") {
+                    if content_lower.contains("version 3") {
+                        return Some("GPL-3.0".to_string());
+                    }
+                    return Some("GPL".to_string());
+                }
+                if content_lower.contains("gnu lesser general public") {
+                    return Some("LGPL".to_string());
+                }
+                if content_lower.contains("bsd") {
+                    if content_lower.contains("3-clause") || content_lower.contains("three clause")
+                    {
+                        return Some("BSD-3-Clause".to_string());
+                    }
+                    if content_lower.contains("2-clause") || content_lower.contains("two clause") {
+                        return Some("BSD-2-Clause".to_string());
+                    }
+                    return Some("BSD".to_string());
+                }
+                if content_lower.contains("proprietary")
+                    || content_lower.contains("all rights reserved")
+                {
+                    return Some("Proprietary".to_string());
+                }
+            }
+        }
+    }
+
+    // Check Cargo.toml for Rust projects
+    let cargo_path = dir.join("Cargo.toml");
+    if cargo_path.exists() {
+        if let Ok(content) = std::fs::read_to_string(&cargo_path) {
+            for line in content.lines() {
+                if line.starts_with("license") && line.contains('=') {
+                    let parts: Vec<&str> = line.split('=').collect();
+                    if parts.len() >= 2 {
+                        let license = parts[1].trim().trim_matches('"').trim_matches('\'');
+                        return Some(license.to_string());
+                    }
+                }
+            }
+        }
+    }
+
+    // Check package.json for Node projects
+    let pkg_path = dir.join("package.json");
+    if pkg_path.exists() {
+        if let Ok(content) = std::fs::read_to_string(&pkg_path) {
+            if let Ok(json) = serde_json::from_str::<serde_json::Value>(&content) {
+                if let Some(license) = json.get("license").and_then(|l| l.as_str()) {
+                    return Some(license.to_string());
+                }
+            }
+        }
+    }
+
+    // Check pyproject.toml for Python projects
+    let pyproject_path = dir.join("pyproject.toml");
+    if pyproject_path.exists() {
+        if let Ok(content) = std::fs::read_to_string(&pyproject_path) {
+            for line in content.lines() {
+                if line.contains("license") && line.contains('=') {
+                    let parts: Vec<&str> = line.split('=').collect();
+                    if parts.len() >= 2 {
+                        let license = parts[1].trim().trim_matches('"').trim_matches('\'');
+                        if !license.is_empty() && !license.starts_with('{') {
+                            return Some(license.to_string());
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_builtin_check_ids_are_unique() {
+        let checks = builtin_checks(Some(ProjectType::Rust));
+        let mut ids: Vec<&str> = checks.iter().map(|c| c.id()).collect();
+        let total = ids.len();
+        ids.sort();
+        ids.dedup();
+        assert_eq!(ids.len(), total);
+        assert!(ids.contains(&"tool:cargo-clippy"));
+        assert!(ids.contains(&"tool:cargo-audit"));
+
+        // Tool checks need a project type
+        assert!(!builtin_checks(None)
+            .iter()
+            .any(|c| c.category() == CheckCategory::Tools));
+    }
+
+    #[test]
+    fn test_tool_check_prints_install_command_without_running_it() {
+        let ctx = DoctorContext {
+            dir: Path::new("."),
+            project_type: None,
+            fix: true,
+        };
+        let tool = ToolCheck::audit(
+            "asimov-no-such-tool",
+            &["--version"],
+            "cargo install asimov-no-such-tool",
+        );
+        let outcome = tool.run(&ctx).unwrap();
+        assert!(!outcome.passed);
+        assert!(outcome.details[0].contains("Install: cargo install"));
+        // Guidance only: --fix never runs package managers
+        assert!(tool.fix_action(&ctx).is_none());
+        assert!(tool.fix(&ctx).is_err());
+    }
+
+    // v9.8.0: License detection tests (ADR-045)
+    #[test]
+    fn test_detect_project_type_from_yaml_identity() {
+        let temp = TempDir::new().unwrap();
+        let asimov_dir = temp.path().join(".asimov");
+        std::fs::create_dir_all(&asimov_dir).unwrap();
+        std::fs::write(
+            asimov_dir.join("project.yaml"),
+            "identity:\n  name: svc\n  type: csharp\n",
+        )
+        .unwrap();
+        assert_eq!(
+            detect_project_type_from_yaml(temp.path()),
+            Some(ProjectType::CSharp)
+        );
+
+        std::fs::write(
+            asimov_dir.join("project.yaml"),
+            "identity: [broken\ntype: go\n",
+        )
+        .unwrap();
+        assert_eq!(
+            detect_project_type_from_yaml(temp.path()),
+            Some(ProjectType::Go)
+        );
+    }
+
+    #[test]
+    fn test_detect_license_mit() {
+        let temp = TempDir::new().unwrap();
+        std::fs::write(
+            temp.path().join("LICENSE"),
+            "MIT License\n\nCopyright (c) 2025\n\nPermission is hereby granted...",
+        )
+        .unwrap();
+        let license = detect_license(temp.path());
+        assert_eq!(license, Some("MIT".to_string()));
+    }
+
+    #[test]
+    fn test_detect_license_apache() {
+        let temp = TempDir::new().unwrap();
+        std::fs::write(
+            temp.path().join("LICENSE"),
+            "Apache License\nVersion 2.0, January 2004\n...",
+        )
+        .unwrap();
+        let license = detect_license(temp.path());
+        assert_eq!(license, Some("Apache-2.0".to_string()));
+    }
+
+    #[test]
+    fn test_detect_license_gpl3() {
+        let temp = TempDir::new().unwrap();
+        std::fs::write(
+            temp.path().join("LICENSE"),
+            "GNU General Public License\nVersion 3, 29 June 2007\n...",
+        )
+        .unwrap();
+        let license = detect_license(temp.path());
+        assert_eq!(license, Some("GPL-3.0".to_string()));
+    }
+
+    #[test]
+    fn test_detect_license_bsd3() {
+        let temp = TempDir::new().unwrap();
+        std::fs::write(
+            temp.path().join("LICENSE"),
+            "BSD 3-Clause License\n\nCopyright...",
+        )
+        .unwrap();
+        let license = detect_license(temp.path());
+        assert_eq!(license, Some("BSD-3-Clause".to_string()));
+    }
+
+    #[test]
+    fn test_detect_license_proprietary() {
+        let temp = TempDir::new().unwrap();
+        std::fs::write(
+            temp.path().join("LICENSE"),
+            "Proprietary License\nAll Rights Reserved.\n...",
+        )
+        .unwrap();
+        let license = detect_license(temp.path());
+        assert_eq!(license, Some("Proprietary".to_string()));
+    }
+
+    #[test]
+    fn test_detect_license_from_cargo_toml() {
+        let temp = TempDir::new().unwrap();
+        std::fs::write(
+            temp.path().join("Cargo.toml"),
+            "[package]\nname = \"test\"\nversion = \"0.1.0\"\nlicense = \"MIT\"\n",
+        )
+        .unwrap();
+        let license = detect_license(temp.path());
+        assert_eq!(license, Some("MIT".to_string()));
+    }
+
+    #[test]
+    fn test_detect_license_from_package_json() {
+        let temp = TempDir::new().unwrap();
+        std::fs::write(
+            temp.path().join("package.json"),
+            r#"{"name": "test", "version": "1.0.0", "license": "ISC"}"#,
+        )
+        .unwrap();
+        let license = detect_license(temp.path());
+        assert_eq!(license, Some("ISC".to_string()));
+    }
+
+    #[test]
+    fn test_detect_license_from_pyproject() {
+        let temp = TempDir::new().unwrap();
+        std::fs::write(
+            temp.path().join("pyproject.toml"),
+            "[project]\nname = \"test\"\nversion = \"0.1.0\"\nlicense = \"Apache-2.0\"\n",
+        )
+        .unwrap();
+        let license = detect_license(temp.path());
+        assert_eq!(license, Some("Apache-2.0".to_string()));
+    }
+
+    #[test]
+    fn test_detect_license_none() {
+        let temp = TempDir::new().unwrap();
+        // No license files at all
+        let license = detect_license(temp.path());
+        assert_eq!(license, None);
+    }
+
+    #[test]
+    fn test_detect_license_priority() {
+        let temp = TempDir::new().unwrap();
+        // LICENSE file takes priority over Cargo.toml
+        std::fs::write(
+            temp.path().join("LICENSE"),
+            "MIT License\n\nPermission is hereby granted...",
+        )
+        .unwrap();
+        std::fs::write(
+            temp.path().join("Cargo.toml"),
+            "[package]\nlicense = \"Apache-2.0\"\n",
+        )
+        .unwrap();
+        let license = detect_license(temp.path());
+        assert_eq!(license, Some("MIT".to_string()));
+    }
+}
@@ -0,0 +1,295 @@
+//! Project-declared doctor checks (v12.4.0)
+//!
+//! `doctor.checks` in project.yaml lists shell commands with an expected
+//! exit code and, optionally, a pattern their output must match:
+//!
+//! ```yaml
+//! doctor:
+//!   checks:
+//!     - id: codeowners
+//!       name: CODEOWNERS exists
+//!       command: test -f .github/CODEOWNERS
+//!       severity: error
+//!     - id: ci-validates
+//!       command: cat .github/workflows/*.yml
+//!       output: "asimov validate"
+//!       hint: Add 'asimov validate' to CI
+//! ```
+
+use super::{CheckCategory, CheckOutcome, CheckSeverity, DoctorCheck, DoctorContext};
+use crate::commands::check::execute_gate;
+use crate::commands::{GateRun, GateStatus};
+use crate::quality::{GateProfile, QualityGate};
+use regex::Regex;
+use serde::Deserialize;
+use std::collections::BTreeSet;
+use std::path::Path;
+
+/// Seconds a custom check may run when no timeout is declared
+pub const CUSTOM_CHECK_TIMEOUT: u64 = 60;
+
+/// A `doctor.checks` entry as written in project.yaml
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CustomCheckSpec {
+    pub id: String,
+    /// Display name (defaults to the id)
+    #[serde(default)]
+    pub name: Option<String>,
+    /// Shell command, run in the project directory
+    pub command: String,
+    /// Expected exit code (default 0)
+    #[serde(default)]
+    pub exit_code: i32,
+    /// Regex that stdout or stderr must match
+    #[serde(default)]
+    pub output: Option<String>,
+    /// Severity when the check fails (default warn)
+    #[serde(default = "default_severity")]
+    pub severity: CheckSeverity,
+    /// Shell command `asimov doctor --fix` runs when the check fails
+    #[serde(default)]
+    pub fix: Option<String>,
+    /// Shown when the check fails
+    #[serde(default)]
+    pub hint: Option<String>,
+    /// Seconds (default 60, 0 = none)
+    #[serde(default)]
+    pub timeout: Option<u64>,
+}
+
+fn default_severity() -> CheckSeverity {
+    CheckSeverity::Warn
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct DoctorSection {
+    #[serde(default)]
+    checks: Vec<CustomCheckSpec>,
+}
+
+/// A validated custom check
+#[derive(Debug, Clone)]
+pub struct CustomCheck {
+    spec: CustomCheckSpec,
+    output: Option<Regex>,
+}
+
+/// Load `doctor.checks` from `<dir>/.asimov/project.yaml`
+pub fn load_custom_checks(dir: &Path) -> Result<Vec<CustomCheck>, String> {
+    let Ok(content) = std::fs::read_to_string(dir.join(".asimov").join("project.yaml")) else {
+        return Ok(Vec::new());
+    };
+    let Ok(yaml) = serde_yaml_ng::from_str::<serde_yaml_ng::Value>(&content) else {
+        // Broken YAML is reported by validate, not here
+        return Ok(Vec::new());
+    };
+    let Some(section) = yaml.get("doctor") else {
+        return Ok(Vec::new());
+    };
+
+    let invalid = |e: String| format!("Invalid doctor checks in project.yaml: {}", e);
+    let section: DoctorSection =
+        serde_yaml_ng::from_value(section.clone()).map_err(|e| invalid(e.to_string()))?;
+
+    let mut seen = BTreeSet::new();
+    section
+        .checks
+        .into_iter()
+        .map(|spec| {
+            if spec.command.trim().is_empty() {
+                return Err(invalid(format!("check '{}' has no command", spec.id)));
+            }
+            if !seen.insert(spec.id.clone()) {
+                return Err(invalid(format!("duplicate check id '{}'", spec.id)));
+            }
+            let output = spec
+                .output
+                .as_deref()
+                .map(Regex::new)
+                .transpose()
+                .map_err(|e| invalid(format!("check '{}': {}", spec.id, e)))?;
+            Ok(CustomCheck { spec, output })
+        })
+        .collect()
+}
+
+impl CustomCheck {
+    fn execute(&self, dir: &Path, command: &str) -> GateRun {
+        let timeout = self.spec.timeout.unwrap_or(CUSTOM_CHECK_TIMEOUT);
+        let gate = QualityGate {
+            name: self.spec.id.clone(),
+            command: command.to_string(),
+            timeout: (timeout > 0).then_some(timeout),
+            profile: GateProfile::Full,
+        };
+        execute_gate(dir, &gate)
+    }
+
+    /// Why a finished run does not meet the expectation, if it doesn't
+    fn mismatch(&self, run: &GateRun) -> Option<String> {
+        if run.status == GateStatus::TimedOut {
+            return Some(format!(
+                "timed out after {}s",
+                self.spec.timeout.unwrap_or(CUSTOM_CHECK_TIMEOUT)
+            ));
+        }
+        match run.exit_code {
+            Some(code) if code == self.spec.exit_code => {}
+            Some(code) => {
+                return Some(format!("exit {} (expected {})", code, self.spec.exit_code));
+            }
+            None => return Some("terminated by signal".to_string()),
+        }
+        let pattern = self.output.as_ref()?;
+        if pattern.is_match(&run.stdout) || pattern.is_match(&run.stderr) {
+            None
+        } else {
+            Some(format!("output does not match /{}/", pattern))
+        }
+    }
+}
+
+impl DoctorCheck for CustomCheck {
+    fn id(&self) -> &str {
+        &self.spec.id
+    }
+    fn name(&self) -> &str {
+        self.spec.name.as_deref().unwrap_or(&self.spec.id)
+    }
+    fn category(&self) -> CheckCategory {
+        CheckCategory::Custom
+    }
+    fn severity(&self) -> CheckSeverity {
+        self.spec.severity
+    }
+    fn run(&self, ctx: &DoctorContext) -> Option<CheckOutcome> {
+        let run = self.execute(ctx.dir, &self.spec.command);
+        Some(match self.mismatch(&run) {
+            None => CheckOutcome::pass("ok"),
+            Some(reason) => {
+                let detail = match &self.spec.hint {
+                    Some(hint) => format!("{}: {}", self.name(), hint),
+                    None => format!("{}: '{}' {}", self.name(), self.spec.command, reason),
+                };
+                CheckOutcome::fail(reason).with_detail(detail)
+            }
+        })
+    }
+    fn fix_action(&self, _ctx: &DoctorContext) -> Option<String> {
+        self.spec.fix.clone()
+    }
+    fn fix(&self, ctx: &DoctorContext) -> Result<(), String> {
+        let command = self.spec.fix.as_deref().unwrap_or_default();
+        let run = self.execute(ctx.dir, command);
+        match run.status {
+            GateStatus::Passed => Ok(()),
+            _ => Err(format!(
+                "{}: fix '{}' failed: {}",
+                self.name(),
+                command,
+                run.stderr.lines().last().unwrap_or("no output")
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn project(checks: &str) -> TempDir {
+        let temp = TempDir::new().unwrap();
+        std::fs::create_dir_all(temp.path().join(".asimov")).unwrap();
+        std::fs::write(
+            temp.path().join(".asimov/project.yaml"),
+            format!("identity:\n  name: x\ndoctor:\n  checks:\n{}", checks),
+        )
+        .unwrap();
+        temp
+    }
+
+    fn ctx(dir: &Path) -> DoctorContext<'_> {
+        DoctorContext {
+            dir,
+            project_type: None,
+            fix: false,
+        }
+    }
+
+    #[test]
+    fn test_no_doctor_section() {
+        let temp = TempDir::new().unwrap();
+        assert!(load_custom_checks(temp.path()).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_exit_code_and_output_expectations() {
+        let temp = project(
+            "    - id: codeowners\n      command: test -f CODEOWNERS\n      severity: error\n      hint: Add a CODEOWNERS file\n    - id: ci\n      name: CI runs asimov validate\n      command: cat ci.yml\n      output: 'asimov validate'\n    - id: absent\n      command: test -f secrets.env\n      exit_code: 1\n",
+        );
+        std::fs::write(temp.path().join("ci.yml"), "run: asimov validate\n").unwrap();
+        let checks = load_custom_checks(temp.path()).unwrap();
+        assert_eq!(checks.len(), 3);
+        let ctx = ctx(temp.path());
+
+        let codeowners = checks[0].run(&ctx).unwrap();
+        assert!(!codeowners.passed);
+        assert_eq!(codeowners.message, "exit 1 (expected 0)");
+        assert_eq!(
+            codeowners.details,
+            vec!["codeowners: Add a CODEOWNERS file"]
+        );
+        assert_eq!(checks[0].severity(), CheckSeverity::Error);
+
+        assert!(checks[1].run(&ctx).unwrap().passed);
+        assert_eq!(checks[1].name(), "CI runs asimov validate");
+        assert_eq!(checks[1].severity(), CheckSeverity::Warn);
+        assert!(checks[2].run(&ctx).unwrap().passed);
+
+        std::fs::write(temp.path().join("ci.yml"), "run: cargo test\n").unwrap();
+        let ci = checks[1].run(&ctx).unwrap();
+        assert!(!ci.passed);
+        assert!(ci.message.contains("does not match"));
+    }
+
+    #[test]
+    fn test_fix_command() {
+        let temp = project(
+            "    - id: codeowners\n      command: test -f CODEOWNERS\n      fix: touch CODEOWNERS\n",
+        );
+        let checks = load_custom_checks(temp.path()).unwrap();
+        let ctx = ctx(temp.path());
+        assert_eq!(
+            checks[0].fix_action(&ctx).as_deref(),
+            Some("touch CODEOWNERS")
+        );
+        checks[0].fix(&ctx).unwrap();
+        assert!(checks[0].run(&ctx).unwrap().passed);
+    }
+
+    #[test]
+    fn test_timeout() {
+        let temp = project("    - id: slow\n      command: sleep 5\n      timeout: 1\n");
+        let checks = load_custom_checks(temp.path()).unwrap();
+        let outcome = checks[0].run(&ctx(temp.path())).unwrap();
+        assert_eq!(outcome.message, "timed out after 1s");
+    }
+
+    #[test]
+    fn test_invalid_checks_are_errors() {
+        let duplicate =
+            project("    - id: a\n      command: 'true'\n    - id: a\n      command: 'true'\n");
+        let err = load_custom_checks(duplicate.path()).unwrap_err();
+        assert!(err.contains("duplicate check id 'a'"));
+
+        let regex = project("    - id: a\n      command: 'true'\n      output: '('\n");
+        assert!(load_custom_checks(regex.path())
+            .unwrap_err()
+            .starts_with("Invalid doctor checks in project.yaml"));
+
+        let unknown = project("    - id: a\n      command: 'true'\n      severity: fatal\n");
+        assert!(load_custom_checks(unknown.path()).is_err());
+    }
+}
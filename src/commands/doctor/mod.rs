@@ -0,0 +1,682 @@
+//! Doctor command implementation
+//! v9.7.0: Add coding standards tool checks (ADR-044)
+//! v12.4.0: Read-only by default, repairs only with --fix
+//! v12.4.0: Registry of `DoctorCheck` implementations with severities,
+//! plus checks declared in project.yaml (`doctor.checks`)
+
+mod checks;
+mod custom;
+
+pub use checks::detect_license;
+pub use custom::{load_custom_checks, CustomCheck, CustomCheckSpec, CUSTOM_CHECK_TIMEOUT};
+
+use checks::{builtin_checks, detect_project_type_from_yaml};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+/// Options for doctor command (v12.4.0)
+#[derive(Debug, Clone, Default)]
+pub struct DoctorOptions {
+    /// Apply repairs instead of only reporting them
+    pub fix: bool,
+}
+
+/// How much a failing check matters
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CheckSeverity {
+    /// Reported only
+    Info,
+    /// Reported as a warning
+    Warn,
+    /// Fails doctor
+    Error,
+}
+
+impl std::fmt::Display for CheckSeverity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CheckSeverity::Info => write!(f, "info"),
+            CheckSeverity::Warn => write!(f, "warn"),
+            CheckSeverity::Error => write!(f, "error"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CheckCategory {
+    Project,
+    Git,
+    Protocols,
+    Tools,
+    Dependencies,
+    Version,
+    /// Declared in project.yaml
+    Custom,
+}
+
+/// What one run of a check found
+#[derive(Debug, Clone, Default)]
+pub struct CheckOutcome {
+    pub passed: bool,
+    pub message: String,
+    /// Reported at the check's severity when it fails
+    pub details: Vec<String>,
+    /// Always reported as warnings (e.g. soft limits on a passing check)
+    pub warnings: Vec<String>,
+}
+
+impl CheckOutcome {
+    pub fn pass(message: impl Into<String>) -> Self {
+        Self {
+            passed: true,
+            message: message.into(),
+            ..Default::default()
+        }
+    }
+
+    pub fn fail(message: impl Into<String>) -> Self {
+        Self {
+            passed: false,
+            message: message.into(),
+            ..Default::default()
+        }
+    }
+
+    pub fn with_detail(mut self, detail: impl Into<String>) -> Self {
+        self.details.push(detail.into());
+        self
+    }
+
+    pub fn with_warning(mut self, warning: impl Into<String>) -> Self {
+        self.warnings.push(warning.into());
+        self
+    }
+}
+
+/// What checks get to look at
+#[derive(Debug, Clone)]
+pub struct DoctorContext<'a> {
+    pub dir: &'a Path,
+    /// From project.yaml `identity.type`
+    pub project_type: Option<crate::ProjectType>,
+    /// Repairs are applied, not only offered
+    pub fix: bool,
+}
+
+impl DoctorContext<'_> {
+    pub fn asimov_dir(&self) -> PathBuf {
+        self.dir.join(".asimov")
+    }
+}
+
+/// A single doctor check
+pub trait DoctorCheck {
+    /// Stable identifier, e.g. `roadmap` or `tool:cargo-audit`
+    fn id(&self) -> &str;
+    /// Display name
+    fn name(&self) -> &str;
+    fn category(&self) -> CheckCategory;
+    /// Severity when the check fails
+    fn severity(&self) -> CheckSeverity;
+    /// Inspect the project; None when the check does not apply
+    fn run(&self, ctx: &DoctorContext) -> Option<CheckOutcome>;
+    /// Repair `--fix` applies when the check fails, if it has one
+    fn fix_action(&self, _ctx: &DoctorContext) -> Option<String> {
+        None
+    }
+    /// Apply the repair described by `fix_action`
+    fn fix(&self, _ctx: &DoctorContext) -> Result<(), String> {
+        Err(format!("{}: no fix available", self.id()))
+    }
+}
+
+/// Ordered set of checks; later checks see earlier repairs
+#[derive(Default)]
+pub struct DoctorRegistry {
+    checks: Vec<Box<dyn DoctorCheck>>,
+}
+
+impl DoctorRegistry {
+    /// Built-in checks for the project type
+    pub fn builtin(project_type: Option<crate::ProjectType>) -> Self {
+        Self {
+            checks: builtin_checks(project_type),
+        }
+    }
+
+    pub fn register(&mut self, check: Box<dyn DoctorCheck>) {
+        self.checks.push(check);
+    }
+
+    pub fn ids(&self) -> Vec<&str> {
+        self.checks.iter().map(|c| c.id()).collect()
+    }
+
+    /// Run every check, applying repairs when `ctx.fix` is set
+    pub fn run(&self, ctx: &DoctorContext, result: &mut DoctorResult) {
+        for check in &self.checks {
+            run_check(check.as_ref(), ctx, result);
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct DoctorCheckResult {
+    pub id: String,
+    pub category: CheckCategory,
+    pub name: String,
+    /// Severity when failing
+    pub severity: CheckSeverity,
+    pub passed: bool,
+    pub message: String,
+    /// Repaired during this run (only with --fix)
+    pub fixed: bool,
+    /// Repair --fix applies (or applied)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fix: Option<String>,
+}
+
+impl DoctorCheckResult {
+    /// Failed at error severity
+    pub fn is_error(&self) -> bool {
+        !self.passed && self.severity == CheckSeverity::Error
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct DoctorResult {
+    pub checks: Vec<DoctorCheckResult>,
+    /// Details of failed error-level checks
+    pub issues: Vec<String>,
+    /// Details of failed warn-level checks, plus check warnings
+    pub warnings: Vec<String>,
+    /// Details of failed info-level checks
+    pub notes: Vec<String>,
+    pub license: Option<String>, // v9.8.0: Detected project license
+    /// v12.4.0: Ran with --fix (repairs applied, not only reported)
+    pub fix: bool,
+    /// v12.4.0: Repairs `asimov doctor --fix` would apply
+    pub fixes_available: Vec<String>,
+    /// v12.4.0: Repairs applied with --fix
+    pub fixes_applied: Vec<String>,
+}
+
+impl DoctorResult {
+    /// No error-level check failed and no issues were reported
+    pub fn healthy(&self) -> bool {
+        !self.checks.iter().any(DoctorCheckResult::is_error) && self.issues.is_empty()
+    }
+}
+
+/// Run doctor without modifying anything (backwards compatible)
+pub fn run_doctor(dir: &Path) -> DoctorResult {
+    run_doctor_with_options(dir, &DoctorOptions::default())
+}
+
+/// Run doctor with options (v12.4.0): built-in checks, then project.yaml checks
+pub fn run_doctor_with_options(dir: &Path, options: &DoctorOptions) -> DoctorResult {
+    let mut result = DoctorResult {
+        checks: Vec::new(),
+        issues: Vec::new(),
+        warnings: Vec::new(),
+        notes: Vec::new(),
+        // v9.8.0: Detect project license (ADR-045)
+        license: detect_license(dir),
+        fix: options.fix,
+        fixes_available: Vec::new(),
+        fixes_applied: Vec::new(),
+    };
+
+    let ctx = DoctorContext {
+        dir,
+        project_type: detect_project_type_from_yaml(dir),
+        fix: options.fix,
+    };
+
+    let mut registry = DoctorRegistry::builtin(ctx.project_type);
+    match load_custom_checks(dir) {
+        Ok(custom) => {
+            for check in custom {
+                registry.register(Box::new(check));
+            }
+        }
+        Err(e) => result.issues.push(e),
+    }
+    registry.run(&ctx, &mut result);
+
+    result
+}
+
+fn run_check(check: &dyn DoctorCheck, ctx: &DoctorContext, result: &mut DoctorResult) {
+    let Some(mut outcome) = check.run(ctx) else {
+        return;
+    };
+
+    let mut fixed = false;
+    let fix = if outcome.passed {
+        None
+    } else {
+        check.fix_action(ctx)
+    };
+    if let Some(repair) = &fix {
+        if !ctx.fix {
+            result.fixes_available.push(repair.clone());
+        } else {
+            match check.fix(ctx) {
+                Ok(()) => {
+                    result.fixes_applied.push(repair.clone());
+                    if let Some(rerun) = check.run(ctx) {
+                        outcome = rerun;
+                    }
+                    fixed = outcome.passed;
+                }
+                Err(e) => outcome.details.push(e),
+            }
+        }
+    }
+
+    let severity = check.severity();
+    if !outcome.passed {
+        match severity {
+            CheckSeverity::Error => result.issues.extend(outcome.details),
+            CheckSeverity::Warn => result.warnings.extend(outcome.details),
+            CheckSeverity::Info => result.notes.extend(outcome.details),
+        }
+    }
+    result.warnings.extend(outcome.warnings);
+
+    result.checks.push(DoctorCheckResult {
+        id: check.id().to_string(),
+        category: check.category(),
+        name: check.name().to_string(),
+        severity,
+        passed: outcome.passed,
+        message: outcome.message,
+        fixed,
+        fix,
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_run_doctor_empty_dir() {
+        let temp = TempDir::new().unwrap();
+        let result = run_doctor(temp.path());
+        // v12.4.0: Read-only - reports the missing files without creating them
+        assert!(!temp.path().join(".asimov").exists());
+        assert!(!result.healthy());
+        assert!(result.fixes_applied.is_empty());
+        assert_eq!(
+            result.fixes_available,
+            vec!["create .asimov/", "write roadmap.yaml template"]
+        );
+    }
+
+    #[test]
+    fn test_run_doctor_with_asimov() {
+        let temp = TempDir::new().unwrap();
+        let asimov_dir = temp.path().join(".asimov");
+        std::fs::create_dir_all(&asimov_dir).unwrap();
+        std::fs::write(
+            asimov_dir.join("roadmap.yaml"),
+            "current:\n  version: '1.0'\n  status: planned\n  summary: Test\n",
+        )
+        .unwrap();
+
+        let result = run_doctor(temp.path());
+        assert!(result
+            .checks
+            .iter()
+            .any(|c| c.name.contains("roadmap") && c.passed));
+    }
+
+    #[test]
+    fn test_doctor_check_result_severity() {
+        let mut check = DoctorCheckResult {
+            id: "test".to_string(),
+            category: CheckCategory::Project,
+            name: "test".to_string(),
+            severity: CheckSeverity::Warn,
+            passed: false,
+            message: "missing".to_string(),
+            fixed: false,
+            fix: None,
+        };
+        assert!(!check.is_error());
+        check.severity = CheckSeverity::Error;
+        assert!(check.is_error());
+        assert!(CheckSeverity::Error > CheckSeverity::Warn);
+        assert_eq!(CheckSeverity::Info.to_string(), "info");
+    }
+
+    #[test]
+    fn test_doctor_result_fields() {
+        let r = DoctorResult {
+            checks: vec![],
+            issues: vec![],
+            warnings: vec!["w".to_string()],
+            notes: vec![],
+            license: Some("MIT".to_string()), // v9.8.0
+            fix: false,
+            fixes_available: vec![],
+            fixes_applied: vec![],
+        };
+        assert!(r.healthy());
+        assert_eq!(r.license, Some("MIT".to_string()));
+    }
+
+    /// Fails until its marker file exists; the fix creates it
+    struct MarkerCheck(CheckSeverity);
+
+    impl DoctorCheck for MarkerCheck {
+        fn id(&self) -> &str {
+            "marker"
+        }
+        fn name(&self) -> &str {
+            "marker file"
+        }
+        fn category(&self) -> CheckCategory {
+            CheckCategory::Custom
+        }
+        fn severity(&self) -> CheckSeverity {
+            self.0
+        }
+        fn run(&self, ctx: &DoctorContext) -> Option<CheckOutcome> {
+            Some(if ctx.dir.join("marker").exists() {
+                CheckOutcome::pass("exists")
+            } else {
+                CheckOutcome::fail("missing").with_detail("marker missing")
+            })
+        }
+        fn fix_action(&self, _ctx: &DoctorContext) -> Option<String> {
+            Some("create marker".to_string())
+        }
+        fn fix(&self, ctx: &DoctorContext) -> Result<(), String> {
+            std::fs::write(ctx.dir.join("marker"), "").map_err(|e| e.to_string())
+        }
+    }
+
+    fn run_registry(dir: &Path, severity: CheckSeverity, fix: bool) -> DoctorResult {
+        let mut registry = DoctorRegistry::default();
+        registry.register(Box::new(MarkerCheck(severity)));
+        let mut result = DoctorResult {
+            checks: vec![],
+            issues: vec![],
+            warnings: vec![],
+            notes: vec![],
+            license: None,
+            fix,
+            fixes_available: vec![],
+            fixes_applied: vec![],
+        };
+        let ctx = DoctorContext {
+            dir,
+            project_type: None,
+            fix,
+        };
+        registry.run(&ctx, &mut result);
+        result
+    }
+
+    #[test]
+    fn test_registry_routes_details_by_severity() {
+        let temp = TempDir::new().unwrap();
+
+        let result = run_registry(temp.path(), CheckSeverity::Error, false);
+        assert!(!result.healthy());
+        assert_eq!(result.issues, vec!["marker missing"]);
+        assert_eq!(result.fixes_available, vec!["create marker"]);
+
+        let result = run_registry(temp.path(), CheckSeverity::Warn, false);
+        assert!(result.healthy());
+        assert_eq!(result.warnings, vec!["marker missing"]);
+
+        let result = run_registry(temp.path(), CheckSeverity::Info, false);
+        assert!(result.healthy());
+        assert_eq!(result.notes, vec!["marker missing"]);
+        assert!(!temp.path().join("marker").exists());
+    }
+
+    #[test]
+    fn test_registry_fix_reruns_check() {
+        let temp = TempDir::new().unwrap();
+        let result = run_registry(temp.path(), CheckSeverity::Error, true);
+        assert!(result.healthy());
+        assert_eq!(result.fixes_applied, vec!["create marker"]);
+        let check = &result.checks[0];
+        assert!(check.passed && check.fixed);
+        assert_eq!(check.message, "exists");
+        assert_eq!(check.fix.as_deref(), Some("create marker"));
+    }
+
+    #[test]
+    fn test_run_doctor_includes_project_checks() {
+        let temp = TempDir::new().unwrap();
+        let asimov_dir = temp.path().join(".asimov");
+        std::fs::create_dir_all(&asimov_dir).unwrap();
+        std::fs::write(asimov_dir.join("roadmap.yaml"), checks::ROADMAP_TEMPLATE).unwrap();
+        std::fs::write(
+            asimov_dir.join("project.yaml"),
+            "identity:\n  name: x\ndoctor:\n  checks:\n    - id: codeowners\n      command: test -f CODEOWNERS\n      severity: error\n",
+        )
+        .unwrap();
+
+        let result = run_doctor(temp.path());
+        let check = result.checks.iter().find(|c| c.id == "codeowners").unwrap();
+        assert_eq!(check.category, CheckCategory::Custom);
+        assert!(check.is_error());
+        assert!(result.issues.iter().any(|i| i.starts_with("codeowners:")));
+
+        std::fs::write(
+            asimov_dir.join("project.yaml"),
+            "identity:\n  name: x\ndoctor:\n  checks: oops\n",
+        )
+        .unwrap();
+        let result = run_doctor(temp.path());
+        assert!(result
+            .issues
+            .iter()
+            .any(|i| i.starts_with("Invalid doctor checks")));
+    }
+
+    #[test]
+    fn test_run_doctor_all_checks() {
+        let temp = TempDir::new().unwrap();
+
+        // Initialize git repo
+        let _ = std::process::Command::new("git")
+            .args(["init"])
+            .current_dir(temp.path())
+            .output();
+        let _ = std::process::Command::new("git")
+            .args(["config", "user.email", "test@test.com"])
+            .current_dir(temp.path())
+            .output();
+        let _ = std::process::Command::new("git")
+            .args(["config", "user.name", "Test"])
+            .current_dir(temp.path())
+            .output();
+
+        // Create asimov project
+        let asimov_dir = temp.path().join(".asimov");
+        std::fs::create_dir_all(&asimov_dir).unwrap();
+        std::fs::write(
+            asimov_dir.join("roadmap.yaml"),
+            "current:\n  version: '1.0'\n  status: planned\n  summary: Test\n",
+        )
+        .unwrap();
+        std::fs::write(
+            asimov_dir.join("project.yaml"),
+            "identity:\n  project: Test\n  tagline: Test project\n",
+        )
+        .unwrap();
+
+        let result = run_doctor(temp.path());
+        // Should have multiple checks
+        assert!(!result.checks.is_empty());
+    }
+
+    #[test]
+    fn test_run_doctor_with_issues() {
+        let temp = TempDir::new().unwrap();
+        let asimov_dir = temp.path().join(".asimov");
+        std::fs::create_dir_all(&asimov_dir).unwrap();
+        // Missing roadmap.yaml - should trigger a check failure
+        let result = run_doctor(temp.path());
+        // Should have some checks
+        assert!(!result.checks.is_empty());
+    }
+
+    #[test]
+    fn test_run_doctor_no_roadmap_create() {
+        let temp = TempDir::new().unwrap();
+        let asimov_dir = temp.path().join(".asimov");
+        std::fs::create_dir_all(&asimov_dir).unwrap();
+        // No roadmap - only --fix creates it
+        run_doctor(temp.path());
+        assert!(!temp.path().join(".asimov/roadmap.yaml").exists());
+
+        let result = run_doctor_with_options(temp.path(), &DoctorOptions { fix: true });
+        assert!(temp.path().join(".asimov/roadmap.yaml").exists());
+        assert!(result.checks.iter().any(|c| c.fixed));
+    }
+
+    #[test]
+    fn test_run_doctor_invalid_roadmap() {
+        let temp = TempDir::new().unwrap();
+        let asimov_dir = temp.path().join(".asimov");
+        std::fs::create_dir_all(&asimov_dir).unwrap();
+        // Invalid roadmap
+        std::fs::write(asimov_dir.join("roadmap.yaml"), "invalid: [[[").unwrap();
+        let result = run_doctor(temp.path());
+        assert!(!result.issues.is_empty());
+    }
+
+    #[test]
+    fn test_run_doctor_with_hooks() {
+        let temp = TempDir::new().unwrap();
+        let asimov_dir = temp.path().join(".asimov");
+        std::fs::create_dir_all(&asimov_dir).unwrap();
+        // Create valid roadmap
+        std::fs::write(
+            asimov_dir.join("roadmap.yaml"),
+            "current:\n  version: \"1.0\"\n  status: in_progress\n  summary: Test\n",
+        )
+        .unwrap();
+        // Create claude hooks
+        let claude_dir = temp.path().join(".claude");
+        let hooks_dir = claude_dir.join("hooks");
+        std::fs::create_dir_all(&hooks_dir).unwrap();
+        std::fs::write(claude_dir.join("settings.json"), "{}").unwrap();
+        std::fs::write(hooks_dir.join("session-start.sh"), "#!/bin/bash").unwrap();
+        std::fs::write(hooks_dir.join("pre-compact.sh"), "#!/bin/bash").unwrap();
+        // Init git
+        std::process::Command::new("git")
+            .args(["init"])
+            .current_dir(temp.path())
+            .output()
+            .unwrap();
+
+        let result = run_doctor(temp.path());
+        // Should have all checks passed
+        assert!(!result.checks.is_empty());
+    }
+
+    #[test]
+    fn test_run_doctor_no_asimov_dir_create() {
+        let temp = TempDir::new().unwrap();
+        // No .asimov dir at all - doctor --fix should create it
+        let result = run_doctor_with_options(temp.path(), &DoctorOptions { fix: true });
+        // Should have created .asimov/
+        assert!(temp.path().join(".asimov").exists());
+        assert!(result.checks.iter().any(|c| c.name.contains("directory")));
+    }
+
+    #[test]
+    fn test_run_doctor_fix_installs_hook_and_protocols() {
+        let temp = TempDir::new().unwrap();
+        std::process::Command::new("git")
+            .args(["init"])
+            .current_dir(temp.path())
+            .output()
+            .unwrap();
+        let asimov_dir = temp.path().join(".asimov");
+        std::fs::create_dir_all(&asimov_dir).unwrap();
+        std::fs::write(asimov_dir.join("roadmap.yaml"), checks::ROADMAP_TEMPLATE).unwrap();
+        std::fs::write(asimov_dir.join("project.yaml"), "identity:\n  type: docs\n").unwrap();
+        let hook = temp.path().join(".git/hooks/pre-commit");
+        let _ = std::fs::remove_file(&hook);
+
+        let result = run_doctor(temp.path());
+        assert!(!hook.exists());
+        assert!(!asimov_dir.join("green.json").exists());
+        assert!(result
+            .fixes_available
+            .contains(&"install git pre-commit hook".to_string()));
+        assert!(result
+            .issues
+            .iter()
+            .any(|i| i.contains("green.json") && i.contains("doctor --fix")));
+
+        let result = run_doctor_with_options(temp.path(), &DoctorOptions { fix: true });
+        assert!(hook.exists());
+        assert!(asimov_dir.join("green.json").exists());
+        assert!(result.fixes_available.is_empty());
+        assert!(result
+            .checks
+            .iter()
+            .any(|c| c.name == "pre-commit hook" && c.fixed));
+        assert!(result
+            .checks
+            .iter()
+            .any(|c| c.name == "protocol files" && c.passed && c.fixed));
+
+        let json = serde_json::to_value(&result).unwrap();
+        assert_eq!(json["fix"], true);
+        assert!(json["checks"][0].get("fixed").is_some());
+        assert_eq!(json["checks"][0]["severity"], "error");
+        assert_eq!(json["checks"][0]["category"], "project");
+    }
+
+    #[test]
+    fn test_run_doctor_with_license() {
+        let temp = TempDir::new().unwrap();
+        std::fs::write(
+            temp.path().join("LICENSE"),
+            "MIT License\nPermission is hereby granted...",
+        )
+        .unwrap();
+        let result = run_doctor(temp.path());
+        assert_eq!(result.license, Some("MIT".to_string()));
+    }
+
+    #[test]
+    fn test_run_doctor_reports_file_sizes() {
+        let temp = TempDir::new().unwrap();
+        let asimov_dir = temp.path().join(".asimov");
+        std::fs::create_dir_all(&asimov_dir).unwrap();
+        std::fs::write(
+            asimov_dir.join("project.yaml"),
+            "identity:\n  name: test\n  type: ruby\n",
+        )
+        .unwrap();
+        std::fs::write(temp.path().join("big.rb"), "x = 1\n".repeat(801)).unwrap();
+        std::fs::write(temp.path().join("long.rb"), "x = 1\n".repeat(301)).unwrap();
+
+        let result = run_doctor(temp.path());
+        let check = result
+            .checks
+            .iter()
+            .find(|c| c.name == "File sizes")
+            .unwrap();
+        assert!(!check.passed);
+        assert!(result.issues.iter().any(|i| i.starts_with("big.rb")));
+        assert!(result.warnings.iter().any(|w| w.starts_with("long.rb")));
+    }
+}
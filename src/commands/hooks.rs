@@ -0,0 +1,522 @@
+//! AI CLI session hooks - v12.4.0
+//!
+//! `asimov hooks install --ai <id>` renders `.asimov/hooks/*.sh.tpl` into
+//! executable scripts and registers them in the CLI's own settings JSON
+//! (e.g. `.claude/settings.json`). The merge only appends asimov's entries:
+//! user hooks and every other setting are kept as they are.
+//! Project templates in `.asimov/hooks/` override the embedded ones.
+//!
+//! Without `--ai` the same commands manage git hooks (see `git_hooks`).
+
+use super::{load_launch_config, AiProfile, HookSettings};
+use crate::git_hooks::{
+    git_hooks_status, install_git_hooks, uninstall_git_hooks, GitHook, GitHookChange, GitHookState,
+};
+use crate::templates::{detect_project_type, ProjectType};
+use serde_json::{json, Value};
+use std::path::{Path, PathBuf};
+
+/// Marker in rendered hook scripts; scripts without it are never overwritten
+pub const INSTALLED_HOOK_MARKER: &str = "# Installed by: asimov hooks install";
+
+const SESSION_START_TEMPLATE: &str = include_str!("../../.asimov/hooks/session-start.sh.tpl");
+const PRE_COMPACT_TEMPLATE: &str = include_str!("../../.asimov/hooks/pre-compact.sh.tpl");
+
+/// A session hook asimov provides
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AiHook {
+    SessionStart,
+    PreCompact,
+}
+
+impl AiHook {
+    pub const ALL: [AiHook; 2] = [AiHook::SessionStart, AiHook::PreCompact];
+
+    /// Script name without extension (`session-start`, `pre-compact`)
+    pub fn name(&self) -> &'static str {
+        match self {
+            AiHook::SessionStart => "session-start",
+            AiHook::PreCompact => "pre-compact",
+        }
+    }
+
+    /// Rendered script, relative to the project root
+    pub fn script(&self) -> PathBuf {
+        Path::new(".asimov")
+            .join("hooks")
+            .join(format!("{}.sh", self.name()))
+    }
+
+    /// The CLI's event name for this hook, if it has one
+    pub fn event<'a>(&self, settings: &'a HookSettings) -> Option<&'a str> {
+        match self {
+            AiHook::SessionStart => settings.session_start.as_deref(),
+            AiHook::PreCompact => settings.compact.as_deref(),
+        }
+    }
+
+    /// Project template if present, else the embedded one
+    pub fn template(&self, dir: &Path) -> String {
+        let path = dir
+            .join(".asimov")
+            .join("hooks")
+            .join(format!("{}.sh.tpl", self.name()));
+        std::fs::read_to_string(path).unwrap_or_else(|_| {
+            match self {
+                AiHook::SessionStart => SESSION_START_TEMPLATE,
+                AiHook::PreCompact => PRE_COMPACT_TEMPLATE,
+            }
+            .to_string()
+        })
+    }
+}
+
+/// Fill `{{AI}}` / `{{AI_NAME}}` in a hook template
+pub fn render_hook(template: &str, profile: &AiProfile) -> String {
+    template
+        .replace("{{AI_NAME}}", &profile.name)
+        .replace("{{AI}}", &profile.id)
+}
+
+/// Command registered for `hook`, rooted at the CLI's project dir variable
+pub fn hook_command(hook: AiHook, settings: &HookSettings) -> String {
+    let script = hook.script().to_string_lossy().replace('\\', "/");
+    match &settings.project_dir_env {
+        Some(var) => format!("\"${}\"/{}", var, script),
+        None => script,
+    }
+}
+
+/// Commands registered under `event` in a settings document
+fn event_commands(settings: &Value, event: &str) -> Vec<String> {
+    settings
+        .get("hooks")
+        .and_then(|hooks| hooks.get(event))
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| entry.get("hooks").and_then(Value::as_array))
+        .flatten()
+        .filter_map(|hook| hook.get("command").and_then(Value::as_str))
+        .map(str::to_string)
+        .collect()
+}
+
+/// Add `command` under `hooks.<event>` unless present; true if added
+pub fn merge_hook_settings(
+    settings: &mut Value,
+    event: &str,
+    command: &str,
+) -> Result<bool, String> {
+    if event_commands(settings, event).iter().any(|c| c == command) {
+        return Ok(false);
+    }
+    let root = settings
+        .as_object_mut()
+        .ok_or("settings must be a JSON object")?;
+    let hooks = root
+        .entry("hooks")
+        .or_insert_with(|| json!({}))
+        .as_object_mut()
+        .ok_or("\"hooks\" must be an object")?;
+    hooks
+        .entry(event)
+        .or_insert_with(|| json!([]))
+        .as_array_mut()
+        .ok_or_else(|| format!("\"hooks.{}\" must be an array", event))?
+        .push(json!({ "hooks": [{ "type": "command", "command": command }] }));
+    Ok(true)
+}
+
+fn read_settings(path: &Path) -> Result<Value, String> {
+    match std::fs::read_to_string(path) {
+        Ok(content) if content.trim().is_empty() => Ok(json!({})),
+        Ok(content) => serde_json::from_str(&content)
+            .map_err(|e| format!("Cannot parse {}: {} (left unchanged)", path.display(), e)),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(json!({})),
+        Err(e) => Err(format!("Cannot read {}: {}", path.display(), e)),
+    }
+}
+
+fn find_profile(dir: &Path, ai: &str) -> Result<(AiProfile, HookSettings), String> {
+    let config = load_launch_config(dir)?;
+    let profile = config
+        .profiles
+        .into_iter()
+        .find(|p| p.matches(ai))
+        .ok_or_else(|| format!("Unknown AI '{}'", ai))?;
+    let settings = profile.hooks.clone().ok_or_else(|| {
+        format!(
+            "{} has no native session hooks; use `asimov --supervise` instead",
+            profile.name
+        )
+    })?;
+    Ok((profile, settings))
+}
+
+/// Hooks install result
+#[derive(Debug, Clone)]
+pub struct HooksInstallResult {
+    pub profile: AiProfile,
+    /// Settings file that was merged into
+    pub settings: PathBuf,
+    /// Rendered scripts (relative to the project root)
+    pub scripts: Vec<PathBuf>,
+    /// Existing scripts asimov did not write, left as they are
+    pub kept: Vec<PathBuf>,
+    /// Events asimov's hooks were added to
+    pub added: Vec<String>,
+    /// Events that already ran asimov's hooks
+    pub unchanged: Vec<String>,
+}
+
+/// Render hook scripts and register them in the CLI's settings
+pub fn run_hooks_install(dir: &Path, ai: &str) -> Result<HooksInstallResult, String> {
+    let (profile, hook_settings) = find_profile(dir, ai)?;
+    if !dir.join(".asimov").is_dir() {
+        return Err("Not in an asimov project. Run: asimov init".to_string());
+    }
+
+    let settings_path = dir.join(&hook_settings.settings);
+    let mut settings = read_settings(&settings_path)?;
+
+    let mut result = HooksInstallResult {
+        profile: profile.clone(),
+        settings: settings_path.clone(),
+        scripts: Vec::new(),
+        kept: Vec::new(),
+        added: Vec::new(),
+        unchanged: Vec::new(),
+    };
+    for hook in AiHook::ALL {
+        let Some(event) = hook.event(&hook_settings) else {
+            continue;
+        };
+        let script = dir.join(hook.script());
+        match std::fs::read_to_string(&script) {
+            Ok(existing) if !existing.contains(INSTALLED_HOOK_MARKER) => {
+                result.kept.push(hook.script());
+            }
+            _ => {
+                write_script(&script, &render_hook(&hook.template(dir), &profile))?;
+                result.scripts.push(hook.script());
+            }
+        }
+
+        let command = hook_command(hook, &hook_settings);
+        if merge_hook_settings(&mut settings, event, &command)
+            .map_err(|e| format!("{}: {}", settings_path.display(), e))?
+        {
+            result.added.push(event.to_string());
+        } else {
+            result.unchanged.push(event.to_string());
+        }
+    }
+
+    if !result.added.is_empty() {
+        if let Some(parent) = settings_path.parent() {
+            std::fs::create_dir_all(parent)
+                .map_err(|e| format!("Cannot create {}: {}", parent.display(), e))?;
+        }
+        let content = serde_json::to_string_pretty(&settings).map_err(|e| e.to_string())?;
+        std::fs::write(&settings_path, content + "\n")
+            .map_err(|e| format!("Cannot write {}: {}", settings_path.display(), e))?;
+    }
+    Ok(result)
+}
+
+fn write_script(path: &Path, content: &str) -> Result<(), String> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)
+            .map_err(|e| format!("Cannot create {}: {}", parent.display(), e))?;
+    }
+    std::fs::write(path, content).map_err(|e| format!("Cannot write {}: {}", path.display(), e))?;
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        std::fs::set_permissions(path, std::fs::Permissions::from_mode(0o755))
+            .map_err(|e| format!("Cannot make {} executable: {}", path.display(), e))?;
+    }
+    Ok(())
+}
+
+/// One hook of one AI CLI
+#[derive(Debug, Clone)]
+pub struct HookEventStatus {
+    pub hook: AiHook,
+    pub event: String,
+    /// asimov's command is registered under the event
+    pub registered: bool,
+    /// The script exists (and is executable on Unix)
+    pub script_ok: bool,
+    /// Other commands under the same event (user or other tools)
+    pub other_hooks: usize,
+}
+
+/// What is wired for one AI CLI
+#[derive(Debug, Clone)]
+pub struct AiHooksStatus {
+    pub profile: AiProfile,
+    /// None when the CLI has no native hooks
+    pub settings: Option<PathBuf>,
+    pub events: Vec<HookEventStatus>,
+    /// Settings file exists but could not be read
+    pub error: Option<String>,
+}
+
+/// Hook wiring for every profile
+pub fn run_hooks_status(dir: &Path) -> Result<Vec<AiHooksStatus>, String> {
+    let config = load_launch_config(dir)?;
+    Ok(config
+        .profiles
+        .into_iter()
+        .map(|profile| hooks_status(dir, profile))
+        .collect())
+}
+
+fn hooks_status(dir: &Path, profile: AiProfile) -> AiHooksStatus {
+    let Some(hook_settings) = profile.hooks.clone() else {
+        return AiHooksStatus {
+            profile,
+            settings: None,
+            events: Vec::new(),
+            error: None,
+        };
+    };
+    let settings_path = dir.join(&hook_settings.settings);
+    let (settings, error) = match read_settings(&settings_path) {
+        Ok(settings) => (settings, None),
+        Err(e) => (json!({}), Some(e)),
+    };
+
+    let events = AiHook::ALL
+        .iter()
+        .filter_map(|&hook| {
+            let event = hook.event(&hook_settings)?;
+            let command = hook_command(hook, &hook_settings);
+            let commands = event_commands(&settings, event);
+            let registered = commands.contains(&command);
+            Some(HookEventStatus {
+                hook,
+                event: event.to_string(),
+                registered,
+                script_ok: is_executable(&dir.join(hook.script())),
+                other_hooks: commands.len() - usize::from(registered),
+            })
+        })
+        .collect();
+
+    AiHooksStatus {
+        profile,
+        settings: Some(settings_path),
+        events,
+        error,
+    }
+}
+
+/// Git hooks state: the hooks dir in use and each managed hook
+#[derive(Debug, Clone)]
+pub struct GitHooksStatus {
+    pub hooks_dir: PathBuf,
+    pub hooks: Vec<(GitHook, GitHookState)>,
+}
+
+/// Install git hooks (all when `hooks` is empty), chaining to foreign ones
+pub fn run_git_hooks_install(dir: &Path, hooks: &[GitHook]) -> Result<Vec<GitHookChange>, String> {
+    let hooks = if hooks.is_empty() {
+        &GitHook::ALL[..]
+    } else {
+        hooks
+    };
+    install_git_hooks(dir, project_type(dir), hooks)
+}
+
+/// Remove asimov's git hooks and restore the ones they chained to
+pub fn run_git_hooks_uninstall(dir: &Path) -> Result<Vec<GitHookChange>, String> {
+    uninstall_git_hooks(dir)
+}
+
+/// Git hooks state; None outside a git repository
+pub fn run_git_hooks_status(dir: &Path) -> Option<GitHooksStatus> {
+    git_hooks_status(dir)
+        .ok()
+        .map(|(hooks_dir, hooks)| GitHooksStatus { hooks_dir, hooks })
+}
+
+/// Project type from project.yaml's identity.type, else detected
+fn project_type(dir: &Path) -> ProjectType {
+    std::fs::read_to_string(dir.join(".asimov/project.yaml"))
+        .ok()
+        .and_then(|content| serde_yaml_ng::from_str::<serde_yaml_ng::Value>(&content).ok())
+        .and_then(|yaml| {
+            yaml.get("identity")?
+                .get("type")?
+                .as_str()?
+                .parse::<ProjectType>()
+                .ok()
+        })
+        .unwrap_or_else(|| detect_project_type(dir))
+}
+
+fn is_executable(path: &Path) -> bool {
+    let Ok(metadata) = std::fs::metadata(path) else {
+        return false;
+    };
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        metadata.is_file() && metadata.permissions().mode() & 0o111 != 0
+    }
+    #[cfg(not(unix))]
+    {
+        metadata.is_file()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::builtin_profiles;
+    use tempfile::TempDir;
+
+    fn project() -> TempDir {
+        let temp = TempDir::new().unwrap();
+        std::fs::create_dir_all(temp.path().join(".asimov")).unwrap();
+        temp
+    }
+
+    #[test]
+    fn test_render_hook_fills_profile() {
+        let claude = &builtin_profiles()[0];
+        let rendered = render_hook(
+            &AiHook::PreCompact.template(Path::new("/nonexistent")),
+            claude,
+        );
+        assert!(rendered.contains("Installed by: asimov hooks install --ai claude"));
+        assert!(!rendered.contains("{{"));
+    }
+
+    #[test]
+    fn test_merge_keeps_user_hooks() {
+        let mut settings = json!({
+            "model": "opus",
+            "hooks": {
+                "PreCompact": [{ "hooks": [{ "type": "command", "command": "./mine.sh" }] }]
+            }
+        });
+        assert!(merge_hook_settings(&mut settings, "PreCompact", "asimov.sh").unwrap());
+        assert!(!merge_hook_settings(&mut settings, "PreCompact", "asimov.sh").unwrap());
+        assert_eq!(settings["model"], "opus");
+        assert_eq!(
+            event_commands(&settings, "PreCompact"),
+            vec!["./mine.sh", "asimov.sh"]
+        );
+
+        let mut bad = json!({ "hooks": [] });
+        assert!(merge_hook_settings(&mut bad, "PreCompact", "asimov.sh").is_err());
+    }
+
+    #[test]
+    fn test_hooks_install_and_status() {
+        let temp = project();
+        let settings_path = temp.path().join(".claude/settings.json");
+        std::fs::create_dir_all(settings_path.parent().unwrap()).unwrap();
+        std::fs::write(
+            &settings_path,
+            r#"{"permissions": {"allow": ["Bash(ls)"]}, "hooks": {"SessionStart": [{"hooks": [{"type": "command", "command": "echo hi"}]}]}}"#,
+        )
+        .unwrap();
+
+        let result = run_hooks_install(temp.path(), "claude").unwrap();
+        assert_eq!(result.added, vec!["SessionStart", "PreCompact"]);
+        assert!(is_executable(
+            &temp.path().join(".asimov/hooks/session-start.sh")
+        ));
+
+        let settings: Value =
+            serde_json::from_str(&std::fs::read_to_string(&settings_path).unwrap()).unwrap();
+        assert_eq!(settings["permissions"]["allow"][0], "Bash(ls)");
+        assert_eq!(
+            event_commands(&settings, "SessionStart"),
+            vec![
+                "echo hi",
+                "\"$CLAUDE_PROJECT_DIR\"/.asimov/hooks/session-start.sh"
+            ]
+        );
+
+        // Idempotent, and user-owned scripts survive
+        std::fs::write(
+            temp.path().join(".asimov/hooks/pre-compact.sh"),
+            "#!/bin/sh\necho mine\n",
+        )
+        .unwrap();
+        let again = run_hooks_install(temp.path(), "Claude Code").unwrap();
+        assert!(again.added.is_empty());
+        assert_eq!(again.unchanged.len(), 2);
+        assert_eq!(again.kept, vec![AiHook::PreCompact.script()]);
+        assert!(
+            std::fs::read_to_string(temp.path().join(".asimov/hooks/pre-compact.sh"))
+                .unwrap()
+                .contains("mine")
+        );
+
+        let status = run_hooks_status(temp.path()).unwrap();
+        let claude = status.iter().find(|s| s.profile.id == "claude").unwrap();
+        assert!(claude.events.iter().all(|e| e.registered && e.script_ok));
+        assert_eq!(claude.events[0].other_hooks, 1);
+        let gemini = status.iter().find(|s| s.profile.id == "gemini").unwrap();
+        assert!(gemini.events.iter().all(|e| !e.registered));
+        let codex = status.iter().find(|s| s.profile.id == "codex").unwrap();
+        assert!(codex.settings.is_none());
+    }
+
+    #[test]
+    fn test_hooks_install_errors() {
+        let temp = project();
+        assert!(run_hooks_install(temp.path(), "codex")
+            .unwrap_err()
+            .contains("--supervise"));
+        assert!(run_hooks_install(temp.path(), "cursor")
+            .unwrap_err()
+            .contains("Unknown AI"));
+
+        let settings_path = temp.path().join(".gemini/settings.json");
+        std::fs::create_dir_all(settings_path.parent().unwrap()).unwrap();
+        std::fs::write(&settings_path, "{ not json").unwrap();
+        let err = run_hooks_install(temp.path(), "gemini").unwrap_err();
+        assert!(err.contains("left unchanged"), "{}", err);
+        assert_eq!(
+            std::fs::read_to_string(&settings_path).unwrap(),
+            "{ not json"
+        );
+
+        let outside = TempDir::new().unwrap();
+        assert!(run_hooks_install(outside.path(), "claude")
+            .unwrap_err()
+            .contains("asimov init"));
+    }
+
+    #[test]
+    fn test_git_hooks_install_uses_project_type() {
+        let temp = project();
+        std::fs::create_dir_all(temp.path().join(".git/hooks")).unwrap();
+        std::fs::write(
+            temp.path().join(".asimov/project.yaml"),
+            "identity:\n  name: test\n  type: python\n",
+        )
+        .unwrap();
+        assert!(run_git_hooks_status(temp.path())
+            .unwrap()
+            .hooks
+            .iter()
+            .all(|(_, state)| *state == GitHookState::Missing));
+
+        let changes = run_git_hooks_install(temp.path(), &[]).unwrap();
+        assert_eq!(changes.len(), 3);
+        let precommit = std::fs::read_to_string(temp.path().join(".git/hooks/pre-commit")).unwrap();
+        assert!(precommit.contains("ruff"), "{}", precommit);
+
+        assert_eq!(run_git_hooks_uninstall(temp.path()).unwrap().len(), 3);
+        assert!(run_git_hooks_status(TempDir::new().unwrap().path()).is_none());
+    }
+}
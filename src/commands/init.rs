@@ -1,10 +1,9 @@
 //! Init command implementation
 //! v9.7.0: Add dev dependencies for coding standards tools (ADR-044)
 
-use crate::{
-    get_template_by_name, git_precommit_hook, protocols::PROTOCOL_FILES, roadmap_template,
-    ProjectType,
-};
+use crate::git_hooks::{install_git_hooks, GitHook, HookAction};
+use crate::workspace::{detect_workspace, packages_yaml_section};
+use crate::{get_template_by_name, protocols::PROTOCOL_FILES, roadmap_template, ProjectType};
 use std::path::Path;
 
 #[derive(Debug, Clone)]
@@ -17,6 +16,7 @@ pub struct InitResult {
     pub hooks_installed: Vec<String>,
     pub deps_added: Vec<String>, // v9.7.0: Dev dependencies added
     pub install_instructions: Vec<String>, // v9.7.0: Manual install instructions
+    pub packages: Vec<String>,   // v12.4.0: Detected workspace packages
     pub error: Option<String>,
 }
 
@@ -30,10 +30,11 @@ pub fn run_init(dir: &Path, name: &str, type_str: &str, force: bool) -> InitResu
         hooks_installed: Vec::new(),
         deps_added: Vec::new(),
         install_instructions: Vec::new(),
+        packages: Vec::new(),
         error: None,
     };
 
-    // v10.3.1: Support all 21 templates, map to base ProjectType for hooks/deps
+    // v10.3.1: Support all templates, map to base ProjectType for hooks/deps
     let template_name = type_str.to_lowercase();
     let project_type = match template_name.as_str() {
         // Base templates
@@ -45,22 +46,33 @@ pub fn run_init(dir: &Path, name: &str, type_str: &str, force: bool) -> InitResu
         "docs" | "documentation" => ProjectType::Docs,
         "arch" | "architecture" => ProjectType::Arch,
         "generic" => ProjectType::Generic,
+        "java" | "jvm" => ProjectType::Java,
+        "kotlin" | "kt" => ProjectType::Kotlin,
+        "csharp" | "c#" | "cs" | "dotnet" => ProjectType::CSharp,
+        "cpp" | "c++" | "cxx" | "c" => ProjectType::Cpp,
+        "ruby" | "rb" => ProjectType::Ruby,
+        "php" => ProjectType::Php,
+        "swift" => ProjectType::Swift,
         // Extended templates map to their base types
         "api-rust" => ProjectType::Rust,
         "api-go" => ProjectType::Go,
         "api-fastapi" => ProjectType::Python,
         "api-nestjs" => ProjectType::Node,
-        "api-spring" => ProjectType::Generic, // Java not yet a ProjectType
+        "api-spring" => ProjectType::Java,
         "web-nextjs" | "web-react" | "web-vue" | "web-angular" => ProjectType::Node,
         "mono-turbo" | "mono-nx" | "mono-pnpm" => ProjectType::Node,
         "admin-dashboard" => ProjectType::Node,
-        other => {
-            result.error = Some(format!(
-                "Unknown template: '{}'. Use 'asimov init --help' to see all templates",
-                other
-            ));
-            return result;
-        }
+        // v12.4.0: Installed template packs declare their base type
+        other => match crate::templates::packs::find_user_pack(other) {
+            Some(pack) => pack.project_type(),
+            None => {
+                result.error = Some(format!(
+                    "Unknown template: '{}'. Use 'asimov init --help' to see all templates",
+                    other
+                ));
+                return result;
+            }
+        },
     };
     result.project_type = Some(project_type);
 
@@ -73,6 +85,11 @@ pub fn run_init(dir: &Path, name: &str, type_str: &str, force: bool) -> InitResu
         "dart" => "flutter",
         "documentation" => "docs",
         "architecture" => "arch",
+        "jvm" => "java",
+        "kt" => "kotlin",
+        "c#" | "cs" | "dotnet" => "csharp",
+        "c++" | "cxx" | "c" => "cpp",
+        "rb" => "ruby",
         other => other,
     };
 
@@ -104,14 +121,20 @@ pub fn run_init(dir: &Path, name: &str, type_str: &str, force: bool) -> InitResu
     let project_path = asimov_dir.join("project.yaml");
     let project_existed = project_path.exists();
     if !project_existed || force {
-        // v10.3.1: Use unified template lookup for all 21 templates
+        // v10.3.1: Use unified template lookup for all templates
         let template = get_template_by_name(template_key).unwrap_or_else(|| {
             // Fallback to generic if template not found (shouldn't happen)
             get_template_by_name("generic").unwrap()
         });
-        let content = template
+        let mut content = template
             .replace("{PROJECT_NAME}", name)
             .replace("{PROJECT_TAGLINE}", "Your project tagline");
+        // v12.4.0: Record detected workspace packages for per-package hooks
+        let workspace = detect_workspace(dir);
+        if workspace.is_multi_package() {
+            content.push_str(&packages_yaml_section(&workspace.packages));
+            result.packages = workspace.packages.into_iter().map(|p| p.path).collect();
+        }
         if let Err(e) = std::fs::write(&project_path, content) {
             result.error = Some(format!("Failed to write project.yaml: {}", e));
             return result;
@@ -166,23 +189,15 @@ pub fn run_init(dir: &Path, name: &str, type_str: &str, force: bool) -> InitResu
     // asimov warmup outputs all context directly - no hooks needed
 
     // Install git pre-commit hook if in git repo
-    let git_hooks_dir = dir.join(".git").join("hooks");
-    if git_hooks_dir.exists() {
-        let precommit_path = git_hooks_dir.join("pre-commit");
-        if !precommit_path.exists() || force {
-            if let Err(e) = std::fs::write(&precommit_path, git_precommit_hook()) {
-                // Non-fatal
-                let _ = e;
-            } else {
-                #[cfg(unix)]
-                {
-                    use std::os::unix::fs::PermissionsExt;
-                    let _ = std::fs::set_permissions(
-                        &precommit_path,
-                        std::fs::Permissions::from_mode(0o755),
-                    );
+    // v12.4.0: Via the hook manager - honors core.hooksPath and chains to an existing hook
+    if dir.join(".git").exists() {
+        let precommit = crate::git_hooks::hooks_dir(dir).map(|d| d.join("pre-commit"));
+        if force || precommit.is_ok_and(|path| !path.exists()) {
+            // Non-fatal
+            if let Ok(changes) = install_git_hooks(dir, project_type, &[GitHook::PreCommit]) {
+                if changes.iter().any(|c| c.action != HookAction::Unchanged) {
+                    result.hooks_installed.push("git pre-commit".to_string());
                 }
-                result.hooks_installed.push("git pre-commit".to_string());
             }
         }
     }
@@ -202,6 +217,12 @@ fn setup_dev_dependencies(dir: &Path, project_type: ProjectType, result: &mut In
         ProjectType::Node => setup_node_deps(dir, result),
         ProjectType::Go => setup_go_deps(result),
         ProjectType::Flutter => setup_flutter_deps(result),
+        ProjectType::Java | ProjectType::Kotlin => setup_jvm_deps(project_type, result),
+        ProjectType::CSharp => setup_csharp_deps(result),
+        ProjectType::Cpp => setup_cpp_deps(result),
+        ProjectType::Ruby => setup_ruby_deps(result),
+        ProjectType::Php => setup_php_deps(result),
+        ProjectType::Swift => setup_swift_deps(result),
         ProjectType::Docs | ProjectType::Arch | ProjectType::Generic => setup_docs_deps(result),
         ProjectType::Migration => {} // No deps needed
     }
@@ -352,6 +373,65 @@ fn setup_flutter_deps(result: &mut InitResult) {
         .push("dart pub add --dev test".to_string());
 }
 
+/// Print install instructions for Java/Kotlin (v12.4.0)
+fn setup_jvm_deps(project_type: ProjectType, result: &mut InitResult) {
+    if project_type == ProjectType::Kotlin {
+        result
+            .install_instructions
+            .push("Add plugin: id(\"org.jlleitschuh.gradle.ktlint\")".to_string());
+    } else {
+        result
+            .install_instructions
+            .push("Add plugin: com.diffplug.spotless (google-java-format)".to_string());
+    }
+    result
+        .install_instructions
+        .push("go install github.com/google/osv-scanner/v2/cmd/osv-scanner@latest".to_string());
+}
+
+/// Print install instructions for C# (v12.4.0)
+fn setup_csharp_deps(result: &mut InitResult) {
+    result.install_instructions.push(
+        "Enable in Directory.Build.props: <TreatWarningsAsErrors>true</TreatWarningsAsErrors>"
+            .to_string(),
+    );
+}
+
+/// Print install instructions for C/C++ (v12.4.0)
+fn setup_cpp_deps(result: &mut InitResult) {
+    result
+        .install_instructions
+        .push("Install clang-format and clang-tidy (LLVM)".to_string());
+    result
+        .install_instructions
+        .push("clang-format -style=google -dump-config > .clang-format".to_string());
+}
+
+/// Print install instructions for Ruby (v12.4.0)
+fn setup_ruby_deps(result: &mut InitResult) {
+    result
+        .install_instructions
+        .push("bundle add rubocop rspec --group development,test".to_string());
+    result
+        .install_instructions
+        .push("gem install bundler-audit".to_string());
+}
+
+/// Print install instructions for PHP (v12.4.0)
+fn setup_php_deps(result: &mut InitResult) {
+    result.install_instructions.push(
+        "composer require --dev phpunit/phpunit phpstan/phpstan friendsofphp/php-cs-fixer"
+            .to_string(),
+    );
+}
+
+/// Print install instructions for Swift (v12.4.0)
+fn setup_swift_deps(result: &mut InitResult) {
+    result
+        .install_instructions
+        .push("brew install swiftlint swift-format".to_string());
+}
+
 /// Print install instructions for docs projects
 fn setup_docs_deps(result: &mut InitResult) {
     result
@@ -375,7 +455,10 @@ mod tests {
 
     #[test]
     fn test_run_init_all_types() {
-        for t in &["rust", "python", "node", "go", "flutter", "docs", "generic"] {
+        for t in &[
+            "rust", "python", "node", "go", "flutter", "docs", "generic", "java", "kotlin",
+            "csharp", "cpp", "ruby", "php", "swift",
+        ] {
             let temp = TempDir::new().unwrap();
             let result = run_init(temp.path(), "Test", t, false);
             assert!(result.success, "Failed for type: {}", t);
@@ -404,6 +487,7 @@ mod tests {
             hooks_installed: vec!["pre-commit".to_string()],
             deps_added: vec!["cargo-husky".to_string()],
             install_instructions: vec![],
+            packages: vec![],
             error: None,
         };
         assert!(r.success);
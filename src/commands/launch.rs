@@ -1,12 +1,142 @@
 //! Launch command implementation - AI-agnostic (v10.7.0 ADR-061)
+//!
+//! v12.4.0: Profiles are data. The built-ins (Claude Code, Gemini CLI,
+//! Codex CLI) can be overridden and extended by `ai_profiles` in the user
+//! config (`~/.config/asimov/config.yaml`) and then in `.asimov/project.yaml`:
+//!
+//! ```yaml
+//! default_ai: aider           # launched when several CLIs are installed
+//! ai_profiles:
+//!   - id: claude              # override one field of a built-in
+//!     model: sonnet           # passed as `<model_flag> sonnet`
+//!   - id: aider               # add a new CLI
+//!     name: Aider
+//!     binary: aider
+//!     env: [AIDER_SESSION]
+//!     model_flag: --model
+//!     model: ollama_chat/qwen2.5-coder
+//!     auto_args: [--yes-always]
+//!     prompt: file            # arg (default) | file | none
+//!     prompt_flag: --read
+//!     compact_markers: ["Summarizing chat"]   # seen by `asimov --supervise`
+//! ```
+//!
+//! v12.4.0: Launch is supervised by default: `auto_args` (permission
+//! skipping and the like) are only passed with `--autonomy auto` or
+//! `autonomy: auto` in the user config. project.yaml cannot raise autonomy,
+//! so cloning a repository never grants an AI more permissions.
+//!
+//! For the same reason project.yaml cannot change what an existing profile
+//! runs (`binary`, `args`, `auto_args`, `prompt`, `prompt_flag`,
+//! `model_flag`, `hooks`); per-repo model selection goes through `model`,
+//! a single value that cannot look like a flag. A profile project.yaml
+//! adds only loads when its binary is listed, verbatim, in the user config:
+//!
+//! ```yaml
+//! allowed_binaries: [./bin/local-llm]   # ~/.config/asimov/config.yaml
+//! ```
+
+use crate::supervisor::Markers;
+use serde::Deserialize;
+use std::path::Path;
+
+/// How much an AI CLI may do without asking (v12.4.0)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Autonomy {
+    /// The CLI's own permission prompts stay on
+    #[default]
+    Supervised,
+    /// Adds the profile's `auto_args` (e.g. skip permission prompts)
+    Auto,
+}
+
+impl std::str::FromStr for Autonomy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "supervised" => Ok(Autonomy::Supervised),
+            "auto" => Ok(Autonomy::Auto),
+            other => Err(format!(
+                "Unknown autonomy '{}' (expected supervised or auto)",
+                other
+            )),
+        }
+    }
+}
+
+impl std::fmt::Display for Autonomy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Autonomy::Supervised => write!(f, "supervised"),
+            Autonomy::Auto => write!(f, "auto"),
+        }
+    }
+}
+
+/// How the warmup context reaches the AI CLI (v12.4.0)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PromptMode {
+    /// As a command-line argument (after `prompt_flag`, if set)
+    #[default]
+    Arg,
+    /// Written to a temporary file whose path is passed (after `prompt_flag`)
+    File,
+    /// Not passed; the CLI is started bare
+    None,
+}
 
 /// AI CLI profile with detection and launch info
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct AiProfile {
-    pub name: &'static str,
-    pub binary: &'static str,
-    pub env_vars: &'static [&'static str],
-    pub auto_mode_args: &'static [&'static str],
+    /// v12.4.0: Key used to override the profile from config
+    pub id: String,
+    pub name: String,
+    pub binary: String,
+    /// Set inside a session of this AI
+    pub env_vars: Vec<String>,
+    /// Always passed, after the model
+    pub args: Vec<String>,
+    /// v12.4.0: Flag that selects the model (e.g. `--model`)
+    pub model_flag: Option<String>,
+    /// v12.4.0: Passed after `model_flag`; the per-repo override
+    pub model: Option<String>,
+    /// v12.4.0: Passed only with autonomy `auto` (permission skipping)
+    pub auto_mode_args: Vec<String>,
+    pub prompt: PromptMode,
+    pub prompt_flag: Option<String>,
+    /// v12.4.0: Output that signals compaction / session start to `--supervise`
+    pub markers: Markers,
+    /// v12.4.0: Native hook registration for `asimov hooks install`
+    pub hooks: Option<HookSettings>,
+}
+
+/// Where an AI CLI registers session hooks (v12.4.0)
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct HookSettings {
+    /// Settings JSON with a `hooks` section, relative to the project root
+    pub settings: String,
+    /// Event run when a session starts, resumes or is cleared
+    #[serde(default)]
+    pub session_start: Option<String>,
+    /// Event run before the conversation is compacted
+    #[serde(default)]
+    pub compact: Option<String>,
+    /// Variable the CLI sets to the project root when running hooks
+    #[serde(default)]
+    pub project_dir_env: Option<String>,
+}
+
+fn hook_settings(settings: &str, session_start: &str, compact: &str, env: &str) -> HookSettings {
+    HookSettings {
+        settings: settings.to_string(),
+        session_start: Some(session_start.to_string()),
+        compact: Some(compact.to_string()),
+        project_dir_env: Some(env.to_string()),
+    }
 }
 
 impl AiProfile {
@@ -23,36 +153,353 @@ impl AiProfile {
         let find_cmd = "where";
 
         std::process::Command::new(find_cmd)
-            .arg(self.binary)
+            .arg(&self.binary)
             .output()
             .ok()
             .map(|o| o.status.success())
             .unwrap_or(false)
     }
+
+    /// Arguments for a launch at the given autonomy, before the prompt
+    pub fn launch_args(&self, autonomy: Autonomy) -> Vec<String> {
+        let mut args: Vec<String> = match (&self.model_flag, &self.model) {
+            (Some(flag), Some(model)) => vec![flag.clone(), model.clone()],
+            _ => Vec::new(),
+        };
+        args.extend(self.args.iter().cloned());
+        if autonomy == Autonomy::Auto {
+            args.extend(self.auto_mode_args.iter().cloned());
+        }
+        args
+    }
+
+    /// True if `name` is this profile's id, display name or binary
+    pub fn matches(&self, name: &str) -> bool {
+        [&self.id, &self.name, &self.binary]
+            .iter()
+            .any(|candidate| candidate.eq_ignore_ascii_case(name.trim()))
+    }
+
+    /// Arguments that hand `prompt` (or the file holding it) to the CLI
+    pub fn prompt_args(&self, prompt: &str, prompt_file: &Path) -> Vec<String> {
+        let value = match self.prompt {
+            PromptMode::Arg => prompt.to_string(),
+            PromptMode::File => prompt_file.display().to_string(),
+            PromptMode::None => return Vec::new(),
+        };
+        self.prompt_flag.iter().cloned().chain([value]).collect()
+    }
 }
 
-/// Known AI CLI profiles (ADR-061)
-pub const AI_PROFILES: &[AiProfile] = &[
+fn builtin(
+    id: &str,
+    name: &str,
+    env_vars: &[&str],
+    args: &[&str],
+    auto_mode_args: &[&str],
+) -> AiProfile {
+    let strings = |items: &[&str]| items.iter().map(|s| s.to_string()).collect();
     AiProfile {
-        name: "Claude Code",
-        binary: "claude",
-        env_vars: &["CLAUDECODE", "CLAUDE_CODE_ENTRYPOINT"],
-        auto_mode_args: &["--dangerously-skip-permissions", "--model", "opus"],
-    },
-    AiProfile {
-        name: "Gemini CLI",
-        binary: "gemini",
-        env_vars: &["GEMINI_CLI"],
-        auto_mode_args: &["--yolo"],
-    },
-    AiProfile {
-        name: "Codex CLI",
-        binary: "codex",
-        env_vars: &["CODEX_CLI"],
-        auto_mode_args: &["--full-auto"],
-    },
+        id: id.to_string(),
+        name: name.to_string(),
+        binary: id.to_string(),
+        env_vars: strings(env_vars),
+        args: strings(args),
+        model_flag: Some("--model".to_string()),
+        model: None,
+        auto_mode_args: strings(auto_mode_args),
+        prompt: PromptMode::Arg,
+        prompt_flag: None,
+        markers: Markers::default(),
+        hooks: None,
+    }
+}
+
+/// Known AI CLI profiles (ADR-061), the defaults config builds on
+pub fn builtin_profiles() -> Vec<AiProfile> {
+    let mut claude = builtin(
+        "claude",
+        "Claude Code",
+        &["CLAUDECODE", "CLAUDE_CODE_ENTRYPOINT"],
+        &[],
+        &["--dangerously-skip-permissions"],
+    );
+    claude.model = Some("opus".to_string());
+    claude.markers.compact = vec!["Compacting conversation".to_string()];
+    claude.hooks = Some(hook_settings(
+        ".claude/settings.json",
+        "SessionStart",
+        "PreCompact",
+        "CLAUDE_PROJECT_DIR",
+    ));
+    let mut gemini = builtin("gemini", "Gemini CLI", &["GEMINI_CLI"], &[], &["--yolo"]);
+    gemini.hooks = Some(hook_settings(
+        ".gemini/settings.json",
+        "SessionStart",
+        "PreCompress",
+        "GEMINI_PROJECT_DIR",
+    ));
+    vec![
+        claude,
+        gemini,
+        builtin("codex", "Codex CLI", &["CODEX_CLI"], &[], &["--full-auto"]),
+    ]
+}
+
+/// An `ai_profiles` entry; unset fields keep the overridden profile's values
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct AiProfileSpec {
+    pub id: String,
+    #[serde(default)]
+    pub name: Option<String>,
+    /// Required for new profiles
+    #[serde(default)]
+    pub binary: Option<String>,
+    #[serde(default)]
+    pub env: Option<Vec<String>>,
+    #[serde(default)]
+    pub args: Option<Vec<String>>,
+    #[serde(default)]
+    pub model_flag: Option<String>,
+    /// Model name; the only launch argument project.yaml may change
+    #[serde(default)]
+    pub model: Option<String>,
+    /// Only passed with autonomy `auto`
+    #[serde(default)]
+    pub auto_args: Option<Vec<String>>,
+    #[serde(default)]
+    pub prompt: Option<PromptMode>,
+    #[serde(default)]
+    pub prompt_flag: Option<String>,
+    /// Output text meaning the conversation is being compacted
+    #[serde(default)]
+    pub compact_markers: Option<Vec<String>>,
+    /// Output text meaning a session started, resumed or was cleared
+    #[serde(default)]
+    pub session_start_markers: Option<Vec<String>>,
+    /// Native hook settings for `asimov hooks install`
+    #[serde(default)]
+    pub hooks: Option<HookSettings>,
+}
+
+/// Launch settings in the user config and project.yaml
+#[derive(Debug, Default, Deserialize)]
+struct LaunchSection {
+    #[serde(default)]
+    ai_profiles: Vec<AiProfileSpec>,
+    #[serde(default)]
+    default_ai: Option<String>,
+    /// Honored in the user config only
+    #[serde(default)]
+    autonomy: Option<Autonomy>,
+    /// Binaries project.yaml may add profiles for; user config only
+    #[serde(default)]
+    allowed_binaries: Vec<String>,
+}
+
+/// Apply `specs` over `profiles`: same id overrides, new ids are appended
+pub fn merge_ai_profiles(
+    profiles: &mut Vec<AiProfile>,
+    specs: Vec<AiProfileSpec>,
+) -> Result<(), String> {
+    for spec in specs {
+        let index = match profiles.iter().position(|p| p.id == spec.id) {
+            Some(index) => index,
+            None => {
+                let binary = spec
+                    .binary
+                    .clone()
+                    .ok_or_else(|| format!("new AI profile '{}' needs a binary", spec.id))?;
+                profiles.push(AiProfile {
+                    id: spec.id.clone(),
+                    name: spec.id.clone(),
+                    binary,
+                    env_vars: Vec::new(),
+                    args: Vec::new(),
+                    model_flag: None,
+                    model: None,
+                    auto_mode_args: Vec::new(),
+                    prompt: PromptMode::Arg,
+                    prompt_flag: None,
+                    markers: Markers::default(),
+                    hooks: None,
+                });
+                profiles.len() - 1
+            }
+        };
+        let profile = &mut profiles[index];
+        if let Some(name) = spec.name {
+            profile.name = name;
+        }
+        if let Some(binary) = spec.binary {
+            profile.binary = binary;
+        }
+        if let Some(env) = spec.env {
+            profile.env_vars = env;
+        }
+        if let Some(args) = spec.args {
+            profile.args = args;
+        }
+        if spec.model_flag.is_some() {
+            profile.model_flag = spec.model_flag;
+        }
+        if let Some(model) = spec.model {
+            if !is_model_name(&model) {
+                return Err(format!(
+                    "model '{}' of AI profile '{}' must be one word not starting with '-'",
+                    model, profile.id
+                ));
+            }
+            profile.model = Some(model);
+        }
+        if let Some(auto_args) = spec.auto_args {
+            profile.auto_mode_args = auto_args;
+        }
+        if let Some(prompt) = spec.prompt {
+            profile.prompt = prompt;
+        }
+        if spec.prompt_flag.is_some() {
+            profile.prompt_flag = spec.prompt_flag;
+        }
+        if let Some(markers) = spec.compact_markers {
+            profile.markers.compact = markers;
+        }
+        if let Some(markers) = spec.session_start_markers {
+            profile.markers.session_start = markers;
+        }
+        if spec.hooks.is_some() {
+            profile.hooks = spec.hooks;
+        }
+        if profile.binary.trim().is_empty() {
+            return Err(format!("AI profile '{}' has an empty binary", profile.id));
+        }
+    }
+    Ok(())
+}
+
+/// A model value that cannot be mistaken for a flag or split into several args
+fn is_model_name(model: &str) -> bool {
+    !model.is_empty()
+        && !model.starts_with('-')
+        && model
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || "._:/@-".contains(c))
+}
+
+/// Fields that decide what a profile executes; project.yaml may not override them
+const PROJECT_LOCKED_FIELDS: [&str; 7] = [
+    "binary",
+    "args",
+    "auto_args",
+    "prompt",
+    "prompt_flag",
+    "model_flag",
+    "hooks",
 ];
 
+/// `merge_ai_profiles` for project.yaml: existing profiles keep what they
+/// execute, new ones need a binary from `allowed_binaries`
+pub fn merge_project_ai_profiles(
+    profiles: &mut Vec<AiProfile>,
+    specs: Vec<AiProfileSpec>,
+    allowed_binaries: &[String],
+) -> Result<(), String> {
+    for spec in &specs {
+        if profiles.iter().any(|p| p.id == spec.id) {
+            let set = [
+                spec.binary.is_some(),
+                spec.args.is_some(),
+                spec.auto_args.is_some(),
+                spec.prompt.is_some(),
+                spec.prompt_flag.is_some(),
+                spec.model_flag.is_some(),
+                spec.hooks.is_some(),
+            ];
+            if let Some((field, _)) = PROJECT_LOCKED_FIELDS.iter().zip(set).find(|(_, set)| *set) {
+                return Err(format!(
+                    "'{}' of AI profile '{}' can only be changed in the user config (project.yaml may set name, env, model and markers)",
+                    field, spec.id
+                ));
+            }
+        } else if let Some(binary) = &spec.binary {
+            if !allowed_binaries.contains(binary) {
+                return Err(format!(
+                    "AI profile '{}' runs '{}', which is not in allowed_binaries in the user config",
+                    spec.id, binary
+                ));
+            }
+        }
+    }
+    merge_ai_profiles(profiles, specs)
+}
+
+/// Read launch settings from a YAML file (missing or unparsable file = none)
+fn read_launch_section(path: &Path) -> Result<LaunchSection, String> {
+    let Some(yaml) = std::fs::read_to_string(path)
+        .ok()
+        .and_then(|content| serde_yaml_ng::from_str::<serde_yaml_ng::Value>(&content).ok())
+        .filter(|yaml| yaml.is_mapping())
+    else {
+        return Ok(LaunchSection::default());
+    };
+    serde_yaml_ng::from_value(yaml)
+        .map_err(|e| format!("Invalid launch settings in {}: {}", path.display(), e))
+}
+
+/// Profiles and defaults for `asimov` launch (v12.4.0)
+#[derive(Debug, Clone)]
+pub struct LaunchConfig {
+    pub profiles: Vec<AiProfile>,
+    /// Project `default_ai`, else the user's
+    pub default_ai: Option<String>,
+    /// User `autonomy`, else supervised
+    pub autonomy: Autonomy,
+}
+
+/// Built-in profiles, overridden by user config, then by the project in `dir`
+pub fn load_launch_config(dir: &Path) -> Result<LaunchConfig, String> {
+    load_launch_config_with(dir, crate::paths::user_config_file().as_deref())
+}
+
+/// `load_launch_config` with the user config file injected (None = no user config)
+pub fn load_launch_config_with(
+    dir: &Path,
+    user_config: Option<&Path>,
+) -> Result<LaunchConfig, String> {
+    let user = match user_config {
+        Some(path) => Some((read_launch_section(path)?, path)),
+        None => None,
+    };
+    let project_path = dir.join(".asimov").join("project.yaml");
+    let project = read_launch_section(&project_path)?;
+
+    let mut config = LaunchConfig {
+        profiles: builtin_profiles(),
+        default_ai: None,
+        autonomy: Autonomy::default(),
+    };
+    let mut allowed_binaries = Vec::new();
+    if let Some((user, path)) = user {
+        merge_ai_profiles(&mut config.profiles, user.ai_profiles)
+            .map_err(|e| format!("Invalid ai_profiles in {}: {}", path.display(), e))?;
+        config.default_ai = user.default_ai;
+        config.autonomy = user.autonomy.unwrap_or_default();
+        allowed_binaries = user.allowed_binaries;
+    }
+    merge_project_ai_profiles(&mut config.profiles, project.ai_profiles, &allowed_binaries)
+        .map_err(|e| format!("Invalid ai_profiles in {}: {}", project_path.display(), e))?;
+    if project.default_ai.is_some() {
+        config.default_ai = project.default_ai;
+    }
+    Ok(config)
+}
+
+/// Built-in profiles, overridden by user config, then by the project in `dir`
+pub fn load_ai_profiles(dir: &Path) -> Result<Vec<AiProfile>, String> {
+    load_launch_config(dir).map(|config| config.profiles)
+}
+
 #[derive(Debug, Clone)]
 pub enum LaunchResult {
     /// No AI CLIs found
@@ -63,18 +510,31 @@ pub enum LaunchResult {
     Launching(AiProfile),
     /// Multiple AIs found - user must select
     MultipleFound(Vec<AiProfile>),
+    /// v12.4.0: `--ai`/`default_ai` names no profile
+    UnknownAi(String),
+    /// v12.4.0: `--ai`/`default_ai` names a profile whose binary is not in PATH
+    NotInstalled(AiProfile),
 }
 
-pub fn check_launch_conditions() -> LaunchResult {
+/// Decide what `asimov` does; `preferred` comes from `--ai` or `default_ai`
+pub fn check_launch_conditions(profiles: &[AiProfile], preferred: Option<&str>) -> LaunchResult {
     // Check if inside any AI session
-    for profile in AI_PROFILES {
+    for profile in profiles {
         if profile.is_inside() {
-            return LaunchResult::InsideAi(profile.name.to_string());
+            return LaunchResult::InsideAi(profile.name.clone());
         }
     }
 
+    if let Some(name) = preferred {
+        return match profiles.iter().find(|p| p.matches(name)) {
+            None => LaunchResult::UnknownAi(name.to_string()),
+            Some(profile) if !profile.is_installed() => LaunchResult::NotInstalled(profile.clone()),
+            Some(profile) => LaunchResult::Launching(profile.clone()),
+        };
+    }
+
     // Find installed AI CLIs
-    let installed: Vec<AiProfile> = AI_PROFILES
+    let installed: Vec<AiProfile> = profiles
         .iter()
         .filter(|p| p.is_installed())
         .cloned()
@@ -93,7 +553,7 @@ mod tests {
 
     #[test]
     fn test_ai_profile_struct() {
-        let profile = &AI_PROFILES[0];
+        let profile = &builtin_profiles()[0];
         assert_eq!(profile.name, "Claude Code");
         assert_eq!(profile.binary, "claude");
     }
@@ -102,7 +562,7 @@ mod tests {
     fn test_launch_result_variants() {
         let _ = LaunchResult::NoAiFound;
         let _ = LaunchResult::InsideAi("Claude".to_string());
-        let _ = LaunchResult::Launching(AI_PROFILES[0].clone());
+        let _ = LaunchResult::Launching(builtin_profiles()[0].clone());
         let _ = LaunchResult::MultipleFound(vec![]);
     }
 
@@ -118,7 +578,7 @@ mod tests {
         // Set CLAUDECODE to trigger InsideAi detection
         std::env::set_var("CLAUDECODE", "1");
 
-        let result = check_launch_conditions();
+        let result = check_launch_conditions(&builtin_profiles(), None);
 
         // Restore original values
         if let Some(val) = orig_claudecode {
@@ -138,7 +598,7 @@ mod tests {
         // This test verifies launch conditions work.
         // Since env vars are global state and tests run in parallel,
         // we just verify the function returns a valid variant.
-        let result = check_launch_conditions();
+        let result = check_launch_conditions(&builtin_profiles(), None);
         // Verify it's a valid variant (exhaustive match)
         match result {
             LaunchResult::InsideAi(_) => {
@@ -153,6 +613,9 @@ mod tests {
             LaunchResult::MultipleFound(_) => {
                 // This is expected when multiple AIs are found
             }
+            LaunchResult::UnknownAi(_) | LaunchResult::NotInstalled(_) => {
+                panic!("no preferred AI was given")
+            }
         }
     }
 
@@ -162,7 +625,7 @@ mod tests {
         let orig = std::env::var("CLAUDE_CODE_ENTRYPOINT").ok();
 
         std::env::set_var("CLAUDE_CODE_ENTRYPOINT", "test");
-        let result = check_launch_conditions();
+        let result = check_launch_conditions(&builtin_profiles(), None);
 
         // Restore
         if let Some(val) = orig {
@@ -177,7 +640,7 @@ mod tests {
     #[test]
     fn test_check_launch_conditions_coverage() {
         // Exercise check_launch_conditions - result depends on environment
-        let result = check_launch_conditions();
+        let result = check_launch_conditions(&builtin_profiles(), None);
         // All variants are valid
         assert!(matches!(
             result,
@@ -190,7 +653,7 @@ mod tests {
 
     #[test]
     fn test_ai_profile_is_inside() {
-        let profile = &AI_PROFILES[0];
+        let profile = &builtin_profiles()[0];
         // Test that setting env var makes is_inside() return true
         // (Don't test the negative case - env vars are global state and
         // we might be running inside Claude Code which sets them)
@@ -200,4 +663,272 @@ mod tests {
             "Expected is_inside() with CLAUDECODE=1"
         );
     }
+
+    fn spec(yaml: &str) -> Vec<AiProfileSpec> {
+        serde_yaml_ng::from_str(yaml).unwrap()
+    }
+
+    #[test]
+    fn test_merge_overrides_builtin_fields() {
+        let mut profiles = builtin_profiles();
+        merge_ai_profiles(&mut profiles, spec("- id: claude\n  model: sonnet\n")).unwrap();
+        let claude = &profiles[0];
+        assert_eq!(claude.model.as_deref(), Some("sonnet"));
+        assert!(claude.args.is_empty());
+        assert_eq!(
+            claude.auto_mode_args,
+            vec!["--dangerously-skip-permissions"]
+        );
+        assert_eq!(claude.name, "Claude Code");
+        assert_eq!(
+            claude.env_vars,
+            vec!["CLAUDECODE", "CLAUDE_CODE_ENTRYPOINT"]
+        );
+        assert_eq!(profiles.len(), 3);
+        assert_eq!(claude.markers.compact, vec!["Compacting conversation"]);
+
+        merge_ai_profiles(
+            &mut profiles,
+            spec(
+                "- id: gemini
+  compact_markers: [Compressing]
+  session_start_markers: [Welcome]
+",
+            ),
+        )
+        .unwrap();
+        assert_eq!(profiles[1].markers.compact, vec!["Compressing"]);
+        assert_eq!(profiles[1].markers.session_start, vec!["Welcome"]);
+    }
+
+    #[test]
+    fn test_merge_adds_new_profile() {
+        let mut profiles = builtin_profiles();
+        merge_ai_profiles(
+            &mut profiles,
+            spec("- id: aider\n  name: Aider\n  binary: aider\n  env: [AIDER_SESSION]\n  prompt: file\n  prompt_flag: --read\n"),
+        )
+        .unwrap();
+        let aider = profiles.last().unwrap();
+        assert_eq!(aider.id, "aider");
+        assert_eq!(aider.binary, "aider");
+        assert_eq!(aider.prompt, PromptMode::File);
+        assert!(aider.auto_mode_args.is_empty());
+
+        let err = merge_ai_profiles(&mut profiles, spec("- id: local\n")).unwrap_err();
+        assert!(err.contains("needs a binary"));
+    }
+
+    #[test]
+    fn test_prompt_args() {
+        let file = Path::new("/tmp/warmup.json");
+        let mut profile = builtin_profiles()[0].clone();
+        assert_eq!(profile.prompt_args("{}", file), vec!["{}"]);
+
+        profile.prompt = PromptMode::File;
+        profile.prompt_flag = Some("--read".to_string());
+        assert_eq!(
+            profile.prompt_args("{}", file),
+            vec!["--read", "/tmp/warmup.json"]
+        );
+
+        profile.prompt = PromptMode::None;
+        assert!(profile.prompt_args("{}", file).is_empty());
+    }
+
+    #[test]
+    fn test_load_ai_profiles_from_project() {
+        let temp = tempfile::TempDir::new().unwrap();
+        std::fs::create_dir_all(temp.path().join(".asimov")).unwrap();
+        std::fs::write(
+            temp.path().join(".asimov/project.yaml"),
+            "identity:\n  name: x\n  type: rust\nai_profiles:\n  - id: wrapper\n    binary: ./bin/local-llm\n    args: [--ctx, '32k']\n    prompt: none\n",
+        )
+        .unwrap();
+        let user_config = temp.path().join("config.yaml");
+        let err = load_launch_config_with(temp.path(), Some(&user_config)).unwrap_err();
+        assert!(
+            err.contains("runs './bin/local-llm', which is not in allowed_binaries"),
+            "{}",
+            err
+        );
+
+        std::fs::write(&user_config, "allowed_binaries: [./bin/local-llm]\n").unwrap();
+        let profiles = load_launch_config_with(temp.path(), Some(&user_config))
+            .unwrap()
+            .profiles;
+        let wrapper = profiles.iter().find(|p| p.id == "wrapper").unwrap();
+        assert_eq!(wrapper.binary, "./bin/local-llm");
+        assert_eq!(wrapper.prompt, PromptMode::None);
+
+        std::fs::write(
+            temp.path().join(".asimov/project.yaml"),
+            "ai_profiles:\n  - id: claude\n    flags: [--fast]\n",
+        )
+        .unwrap();
+        let err = load_ai_profiles(temp.path()).unwrap_err();
+        assert!(err.starts_with("Invalid launch settings in"), "{}", err);
+    }
+
+    #[test]
+    fn test_project_cannot_change_what_a_profile_executes() {
+        let temp = tempfile::TempDir::new().unwrap();
+        std::fs::create_dir_all(temp.path().join(".asimov")).unwrap();
+        let project = temp.path().join(".asimov/project.yaml");
+        let user_config = temp.path().join("config.yaml");
+        // Allow-listing only admits new profiles, never overrides
+        std::fs::write(&user_config, "allowed_binaries: [./evil]\n").unwrap();
+        for (field, yaml) in [
+            ("binary", "binary: ./evil"),
+            ("args", "args: [--dangerously-skip-permissions]"),
+            ("auto_args", "auto_args: []"),
+            // Passed after the supervised args: would grant auto autonomy
+            ("prompt_flag", "prompt_flag: --dangerously-skip-permissions"),
+            ("prompt", "prompt: none"),
+            ("model_flag", "model_flag: --yolo"),
+            ("hooks", "hooks: {settings: x.json}"),
+        ] {
+            std::fs::write(
+                &project,
+                format!("ai_profiles:\n  - id: claude\n    {}\n", yaml),
+            )
+            .unwrap();
+            let err = load_launch_config_with(temp.path(), Some(&user_config)).unwrap_err();
+            assert!(
+                err.contains(&format!(
+                    "'{}' of AI profile 'claude' can only be changed in the user config",
+                    field
+                )),
+                "{}",
+                err
+            );
+        }
+
+        // The model is the one launch argument a repo may pick, as a single word
+        for model in ["--dangerously-skip-permissions", "sonnet --yolo", "\"\""] {
+            std::fs::write(
+                &project,
+                format!("ai_profiles:\n  - id: claude\n    model: {}\n", model),
+            )
+            .unwrap();
+            let err = load_launch_config_with(temp.path(), Some(&user_config)).unwrap_err();
+            assert!(
+                err.contains("must be one word not starting with '-'"),
+                "{}",
+                err
+            );
+        }
+
+        std::fs::write(
+            &project,
+            "ai_profiles:\n  - id: claude\n    name: Team Claude\n    env: [TEAM_AI]\n    model: claude-sonnet-4.5\n",
+        )
+        .unwrap();
+        let config = load_launch_config_with(temp.path(), Some(&user_config)).unwrap();
+        let claude = config.profiles.iter().find(|p| p.id == "claude").unwrap();
+        assert_eq!(claude.name, "Team Claude");
+        assert_eq!(claude.binary, "claude");
+        assert_eq!(
+            claude.launch_args(config.autonomy),
+            vec!["--model", "claude-sonnet-4.5"]
+        );
+
+        // The user config itself may still override anything
+        std::fs::write(
+            &user_config,
+            "ai_profiles:\n  - id: claude\n    binary: claude-beta\n",
+        )
+        .unwrap();
+        let config = load_launch_config_with(temp.path(), Some(&user_config)).unwrap();
+        assert_eq!(config.profiles[0].binary, "claude-beta");
+    }
+
+    #[test]
+    fn test_autonomy_parse_and_launch_args() {
+        assert_eq!(Autonomy::default(), Autonomy::Supervised);
+        assert_eq!("AUTO".parse::<Autonomy>().unwrap(), Autonomy::Auto);
+        assert_eq!(Autonomy::Auto.to_string(), "auto");
+        assert!("yolo".parse::<Autonomy>().is_err());
+
+        let claude = &builtin_profiles()[0];
+        assert_eq!(
+            claude.launch_args(Autonomy::Supervised),
+            vec!["--model", "opus"]
+        );
+        assert_eq!(
+            claude.launch_args(Autonomy::Auto),
+            vec!["--model", "opus", "--dangerously-skip-permissions"]
+        );
+        for profile in builtin_profiles() {
+            assert!(
+                !profile
+                    .launch_args(Autonomy::Supervised)
+                    .iter()
+                    .any(|a| { profile.auto_mode_args.contains(a) }),
+                "{} passes auto args when supervised",
+                profile.id
+            );
+        }
+    }
+
+    #[test]
+    fn test_check_launch_conditions_preferred() {
+        let mut profiles = Vec::new();
+        merge_ai_profiles(
+            &mut profiles,
+            spec(
+                "- id: shell
+  name: Shell AI
+  binary: sh
+  env: [ASIMOV_TEST_NEVER_SET]
+- id: ghost
+  binary: asimov-test-missing-binary
+",
+            ),
+        )
+        .unwrap();
+
+        assert!(matches!(
+            check_launch_conditions(&profiles, Some("shell ai")),
+            LaunchResult::Launching(p) if p.id == "shell"
+        ));
+        assert!(matches!(
+            check_launch_conditions(&profiles, Some("ghost")),
+            LaunchResult::NotInstalled(p) if p.id == "ghost"
+        ));
+        assert!(matches!(
+            check_launch_conditions(&profiles, Some("cursor")),
+            LaunchResult::UnknownAi(name) if name == "cursor"
+        ));
+    }
+
+    #[test]
+    fn test_load_launch_config_from_project() {
+        let temp = tempfile::TempDir::new().unwrap();
+        std::fs::create_dir_all(temp.path().join(".asimov")).unwrap();
+        let project = temp.path().join(".asimov/project.yaml");
+        std::fs::write(
+            &project,
+            "default_ai: gemini
+autonomy: auto
+",
+        )
+        .unwrap();
+
+        let config = load_launch_config(temp.path()).unwrap();
+        assert_eq!(config.default_ai.as_deref(), Some("gemini"));
+
+        // Parsed, but load_launch_config only honors autonomy from the user config
+        let section = read_launch_section(&project).unwrap();
+        assert_eq!(section.autonomy, Some(Autonomy::Auto));
+
+        std::fs::write(
+            &project,
+            "autonomy: reckless
+",
+        )
+        .unwrap();
+        let err = load_launch_config(temp.path()).unwrap_err();
+        assert!(err.starts_with("Invalid launch settings in"), "{}", err);
+    }
 }
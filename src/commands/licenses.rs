@@ -0,0 +1,127 @@
+//! Licenses command implementation (v12.4.0, ADR-045)
+//!
+//! Checks dependency licenses against the project.yaml `licenses` policy,
+//! or a default derived from the project's own license.
+
+use super::detect_license;
+use crate::deps::{
+    check_licenses, require_lockfiles, resolve_licenses, LicenseCheck, LicensePolicy, Lockfile,
+    PackageCaches,
+};
+use serde::Serialize;
+use std::path::Path;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct LicensesResult {
+    pub success: bool,
+    /// From LICENSE or the package manifest (same detection as doctor)
+    pub project_license: Option<String>,
+    /// "project.yaml" or "default"
+    pub policy_source: String,
+    pub policy: LicensePolicy,
+    pub lockfiles: Vec<String>,
+    #[serde(flatten)]
+    pub check: LicenseCheck,
+}
+
+pub fn run_licenses(dir: &Path) -> Result<LicensesResult, String> {
+    let mut lockfiles = require_lockfiles(dir)?;
+    evaluate_licenses(dir, &mut lockfiles)
+}
+
+/// Resolve missing licenses from local metadata, then apply the policy
+pub fn evaluate_licenses(dir: &Path, lockfiles: &mut [Lockfile]) -> Result<LicensesResult, String> {
+    let project_license = detect_license(dir);
+    let (policy, policy_source) = match LicensePolicy::load(dir)? {
+        Some(policy) => (policy, "project.yaml"),
+        None => (
+            LicensePolicy::default_for(project_license.as_deref()),
+            "default",
+        ),
+    };
+
+    resolve_licenses(dir, lockfiles, &PackageCaches::from_env());
+    let check = check_licenses(lockfiles, &policy);
+
+    Ok(LicensesResult {
+        success: check.violations.is_empty(),
+        project_license,
+        policy_source: policy_source.to_string(),
+        policy,
+        lockfiles: lockfiles.iter().map(|l| l.path.clone()).collect(),
+        check,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn npm_project(dir: &Path, project_yaml: Option<&str>) {
+        std::fs::write(
+            dir.join("package-lock.json"),
+            r#"{
+  "lockfileVersion": 3,
+  "packages": {
+    "": { "name": "app", "dependencies": { "agpl-lib": "^1", "ok-lib": "^1", "bare": "^1" } },
+    "node_modules/agpl-lib": { "version": "1.0.0", "license": "AGPL-3.0-only" },
+    "node_modules/ok-lib": { "version": "1.0.0", "license": "MIT" },
+    "node_modules/bare": { "version": "0.1.0" }
+  }
+}"#,
+        )
+        .unwrap();
+        std::fs::write(dir.join("LICENSE"), "Elastic License 2.0\n\nURL: ...\n").unwrap();
+        if let Some(yaml) = project_yaml {
+            std::fs::create_dir_all(dir.join(".asimov")).unwrap();
+            std::fs::write(dir.join(".asimov/project.yaml"), yaml).unwrap();
+        }
+    }
+
+    #[test]
+    fn test_default_policy_denies_agpl_for_elastic_project() {
+        let temp = TempDir::new().unwrap();
+        npm_project(temp.path(), None);
+
+        let result = run_licenses(temp.path()).unwrap();
+        assert!(!result.success);
+        assert_eq!(result.project_license.as_deref(), Some("Elastic-2.0"));
+        assert_eq!(result.policy_source, "default");
+        assert_eq!(result.check.violations.len(), 1);
+        assert_eq!(result.check.violations[0].name, "agpl-lib");
+        assert_eq!(result.check.unknown, vec!["bare@0.1.0"]);
+    }
+
+    #[test]
+    fn test_project_policy_and_local_metadata() {
+        let temp = TempDir::new().unwrap();
+        npm_project(
+            temp.path(),
+            Some("identity:\n  name: x\n  type: node\nlicenses:\n  allow: [MIT, ISC]\n  exceptions: [agpl-lib]\n"),
+        );
+        std::fs::create_dir_all(temp.path().join("node_modules/bare")).unwrap();
+        std::fs::write(
+            temp.path().join("node_modules/bare/package.json"),
+            r#"{"name": "bare", "license": "ISC"}"#,
+        )
+        .unwrap();
+
+        let result = run_licenses(temp.path()).unwrap();
+        assert!(result.success, "{:?}", result.check.violations);
+        assert_eq!(result.policy_source, "project.yaml");
+        assert_eq!(result.check.checked, 2);
+        assert!(result.check.unknown.is_empty());
+    }
+
+    #[test]
+    fn test_invalid_policy_is_an_error() {
+        let temp = TempDir::new().unwrap();
+        npm_project(
+            temp.path(),
+            Some("identity:\n  name: x\n  type: node\nlicenses:\n  unknown: sometimes\n"),
+        );
+        let err = run_licenses(temp.path()).unwrap_err();
+        assert!(err.contains("Invalid licenses policy"));
+    }
+}
@@ -0,0 +1,167 @@
+//! Commit message lint command (v12.4.0)
+//!
+//! `asimov lint-commit <file|range>` checks a message file (what the
+//! commit-msg hook passes) or the commits of a git revision range against
+//! the project's `commit_policy`.
+
+use crate::commit_policy::{lint_commit_message, load_commit_policy, CommitMessage};
+use crate::roadmap::load_deliverables;
+use std::path::Path;
+
+/// One checked message
+#[derive(Debug, Clone)]
+pub struct CommitLint {
+    /// Short hash; None for a message file
+    pub commit: Option<String>,
+    pub subject: String,
+    /// Milestone of the referenced deliverable
+    pub milestone: Option<String>,
+    pub violations: Vec<String>,
+}
+
+/// Lint command result
+#[derive(Debug, Clone)]
+pub struct LintCommitResult {
+    pub commits: Vec<CommitLint>,
+}
+
+impl LintCommitResult {
+    pub fn is_ok(&self) -> bool {
+        self.commits.iter().all(|c| c.violations.is_empty())
+    }
+}
+
+/// Lint a message file, or every non-merge commit of a revision range
+/// (`main..HEAD`; a single revision checks just that commit)
+pub fn run_lint_commit(dir: &Path, target: &str) -> Result<LintCommitResult, String> {
+    let policy = load_commit_policy(dir)?;
+    let deliverables = load_deliverables(dir);
+
+    let file = dir.join(target);
+    let messages = if file.is_file() {
+        let message = std::fs::read_to_string(&file)
+            .map_err(|e| format!("Cannot read {}: {}", file.display(), e))?;
+        vec![(None, message)]
+    } else {
+        commit_messages(dir, target)?
+    };
+
+    let commits = messages
+        .into_iter()
+        .map(|(commit, message)| {
+            let message = CommitMessage::parse(&message);
+            CommitLint {
+                commit,
+                milestone: message
+                    .deliverable(&deliverables)
+                    .map(|d| d.milestone.clone()),
+                violations: lint_commit_message(&message, &policy, &deliverables),
+                subject: message.subject,
+            }
+        })
+        .collect();
+    Ok(LintCommitResult { commits })
+}
+
+fn commit_messages(dir: &Path, range: &str) -> Result<Vec<(Option<String>, String)>, String> {
+    let mut args = vec!["log", "--no-merges", "--format=%h%x1f%B%x1e"];
+    if !range.contains("..") {
+        args.push("--no-walk");
+    }
+    let output = std::process::Command::new("git")
+        .args(&args)
+        .arg(range)
+        .arg("--")
+        .current_dir(dir)
+        .output()
+        .map_err(|e| format!("Failed to run git: {}", e))?;
+    if !output.status.success() {
+        return Err(format!("'{}' is neither a file nor a commit range", range));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout)
+        .split('\x1e')
+        .filter_map(|record| {
+            let (hash, message) = record.trim_start().split_once('\x1f')?;
+            Some((Some(hash.to_string()), message.to_string()))
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn git(dir: &Path, args: &[&str]) {
+        let status = std::process::Command::new("git")
+            .args(args)
+            .current_dir(dir)
+            .env("GIT_AUTHOR_NAME", "Test")
+            .env("GIT_AUTHOR_EMAIL", "test@example.com")
+            .env("GIT_COMMITTER_NAME", "Test")
+            .env("GIT_COMMITTER_EMAIL", "test@example.com")
+            .status()
+            .unwrap();
+        assert!(status.success());
+    }
+
+    fn project() -> TempDir {
+        let temp = TempDir::new().unwrap();
+        std::fs::create_dir_all(temp.path().join(".asimov")).unwrap();
+        std::fs::write(
+            temp.path().join(".asimov/project.yaml"),
+            "commit_policy:\n  types: [feat, fix]\n  require_deliverable: true\n",
+        )
+        .unwrap();
+        std::fs::write(
+            temp.path().join(".asimov/roadmap.yaml"),
+            "current:\n  version: '2.0.0'\n  deliverables:\n    - id: lint\n",
+        )
+        .unwrap();
+        temp
+    }
+
+    #[test]
+    fn test_lint_commit_file() {
+        let temp = project();
+        std::fs::write(temp.path().join("MSG"), "feat: [lint] add lint-commit\n").unwrap();
+        let result = run_lint_commit(temp.path(), "MSG").unwrap();
+        assert!(result.is_ok());
+        assert_eq!(result.commits[0].commit, None);
+        assert_eq!(result.commits[0].milestone.as_deref(), Some("2.0.0"));
+
+        std::fs::write(temp.path().join("MSG"), "update things\n").unwrap();
+        let result = run_lint_commit(temp.path(), "MSG").unwrap();
+        assert!(!result.is_ok());
+        assert_eq!(result.commits[0].violations.len(), 2);
+    }
+
+    #[test]
+    fn test_lint_commit_range() {
+        let temp = project();
+        let dir = temp.path();
+        git(dir, &["init", "-q"]);
+        git(
+            dir,
+            &["commit", "-q", "--allow-empty", "-m", "chore: start"],
+        );
+        git(
+            dir,
+            &["commit", "-q", "--allow-empty", "-m", "feat: [lint] one"],
+        );
+        git(dir, &["commit", "-q", "--allow-empty", "-m", "wip"]);
+
+        let result = run_lint_commit(dir, "HEAD~2..HEAD").unwrap();
+        assert_eq!(result.commits.len(), 2);
+        assert_eq!(result.commits[0].subject, "wip");
+        assert!(!result.commits[0].violations.is_empty());
+        assert!(result.commits[1].violations.is_empty());
+
+        let single = run_lint_commit(dir, "HEAD~1").unwrap();
+        assert_eq!(single.commits.len(), 1);
+        assert!(single.is_ok());
+
+        let err = run_lint_commit(dir, "no-such-ref").unwrap_err();
+        assert!(err.contains("neither a file nor a commit range"), "{}", err);
+    }
+}
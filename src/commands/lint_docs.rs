@@ -88,6 +88,7 @@ pub fn run_lint_docs(dir: &Path, fix: bool, semantic: bool) -> LintDocsResult {
             deprecated_patterns: patterns,
             expected_version: None,
             check_help: false,
+            check_changelog: true,
         };
 
         let semantic_result = check_semantic(dir, &config);
@@ -2,26 +2,44 @@
 //!
 //! All command logic is here for testability. main.rs handles only CLI parsing and output.
 
+mod changelog;
+mod check;
+mod deps;
+mod detect;
 mod doctor;
+mod hooks;
 mod init;
 mod launch;
+mod licenses;
+mod lint_commit;
 mod lint_docs;
 mod refresh;
 mod replay;
 mod role;
+mod sessions;
 mod stats;
+mod template;
 mod update;
 mod validate;
 mod warmup;
 
+pub use changelog::*;
+pub use check::*;
+pub use deps::*;
+pub use detect::*;
 pub use doctor::*;
+pub use hooks::*;
 pub use init::*;
 pub use launch::*;
+pub use licenses::*;
+pub use lint_commit::*;
 pub use lint_docs::*;
 pub use refresh::*;
 pub use replay::*;
 pub use role::*;
+pub use sessions::*;
 pub use stats::*;
+pub use template::*;
 pub use update::*;
 pub use validate::*;
 pub use warmup::*;
@@ -2,17 +2,15 @@
 //! v9.5.0: Enhanced with project migration assistant (ADR-042)
 //! v9.6.0: Always regenerate pre-commit hooks (ADR-043)
 
+use super::prompt_project_type;
+use crate::git_hooks::{refresh_git_hooks, GitHook, HookAction};
 use crate::templates::{
-    detect_project_type, precommit_hook_template, project_template, ProjectType,
+    detect_project_candidates, detect_project_type, project_template, ProjectType,
 };
 use crate::{validate_directory_with_regeneration, validator::regenerate_protocol_files};
 use std::io::{self, BufRead, Write};
 use std::path::Path;
 
-// Unix-specific imports for setting executable permissions
-#[cfg(unix)]
-use std::os::unix::fs::PermissionsExt;
-
 /// Options for refresh command
 #[derive(Debug, Clone, Default)]
 pub struct RefreshOptions {
@@ -44,6 +42,8 @@ pub struct RefreshResult {
     pub coding_standards_upgraded: bool,
     // v9.6.0: Hook regeneration (ADR-043)
     pub hook_regenerated: bool,
+    // v12.4.0: Existing hook not written by asimov, left untouched
+    pub hook_kept: bool,
     pub dry_run: bool,
     pub error: Option<String>,
 }
@@ -67,6 +67,7 @@ pub fn run_refresh_with_options(dir: &Path, options: RefreshOptions) -> RefreshR
         project_type_was_missing: false,
         coding_standards_upgraded: false,
         hook_regenerated: false,
+        hook_kept: false,
         dry_run: options.dry_run,
         error: None,
     };
@@ -130,7 +131,8 @@ pub fn run_refresh_with_options(dir: &Path, options: RefreshOptions) -> RefreshR
         }
     } else {
         // No project.yaml - detect type and offer to create
-        let detected_type = detect_project_type(dir);
+        let report = detect_project_candidates(dir);
+        let detected_type = report.primary();
         result.project_type_detected = Some(detected_type);
         result.project_type_was_missing = true;
 
@@ -138,7 +140,7 @@ pub fn run_refresh_with_options(dir: &Path, options: RefreshOptions) -> RefreshR
             let project_type = if options.yes {
                 detected_type
             } else {
-                prompt_project_type(detected_type)
+                prompt_project_type(&report)
             };
 
             // Generate project.yaml
@@ -155,11 +157,11 @@ pub fn run_refresh_with_options(dir: &Path, options: RefreshOptions) -> RefreshR
     // v9.6.0: Always regenerate pre-commit hook (ADR-043 - No SPOF)
     if !options.dry_run {
         if let Some(project_type) = result.project_type_detected {
-            if let Err(e) = regenerate_precommit_hook(dir, project_type) {
+            match regenerate_precommit_hook(dir, project_type) {
+                Ok(true) => result.hook_regenerated = true,
+                Ok(false) => result.hook_kept = true,
                 // Non-fatal: git might not be initialized
-                eprintln!("Note: Could not regenerate pre-commit hook: {}", e);
-            } else {
-                result.hook_regenerated = true;
+                Err(e) => eprintln!("Note: Could not regenerate pre-commit hook: {}", e),
             }
         }
     }
@@ -222,11 +224,11 @@ fn check_project_migration(
         t.parse::<ProjectType>()
             .unwrap_or_else(|_| detect_project_type(dir))
     } else {
-        let detected = detect_project_type(dir);
+        let report = detect_project_candidates(dir);
         if options.yes {
-            detected
+            report.primary()
         } else {
-            prompt_project_type(detected)
+            prompt_project_type(&report)
         }
     };
 
@@ -246,14 +248,7 @@ fn check_project_migration(
         .is_some();
 
     // Need upgrade if: has coding_standards but missing new sections (for programming types)
-    let is_programming_type = matches!(
-        project_type,
-        ProjectType::Rust
-            | ProjectType::Python
-            | ProjectType::Node
-            | ProjectType::Go
-            | ProjectType::Flutter
-    );
+    let is_programming_type = project_type.is_code();
 
     let needs_coding_upgrade = is_programming_type
         && has_coding_standards
@@ -310,78 +305,17 @@ fn apply_project_migration(
 }
 
 /// Regenerate pre-commit hook for direct coding standards enforcement (v9.6.0 ADR-043)
-fn regenerate_precommit_hook(dir: &Path, project_type: ProjectType) -> Result<(), String> {
-    let git_dir = dir.join(".git");
-    if !git_dir.is_dir() {
-        return Err("Not a git repository".to_string());
-    }
-
-    let hooks_dir = git_dir.join("hooks");
-    std::fs::create_dir_all(&hooks_dir)
-        .map_err(|e| format!("Failed to create hooks directory: {}", e))?;
-
-    let hook_path = hooks_dir.join("pre-commit");
-    let hook_content = precommit_hook_template(project_type);
-
-    std::fs::write(&hook_path, hook_content)
-        .map_err(|e| format!("Failed to write pre-commit hook: {}", e))?;
-
-    // Make executable (Unix only - Windows doesn't need this)
-    #[cfg(unix)]
-    {
-        let mut perms = std::fs::metadata(&hook_path)
-            .map_err(|e| format!("Failed to get hook metadata: {}", e))?
-            .permissions();
-        perms.set_mode(0o755);
-        std::fs::set_permissions(&hook_path, perms)
-            .map_err(|e| format!("Failed to set hook permissions: {}", e))?;
-    }
-
-    Ok(())
-}
-
-/// Prompt user for project type selection
-fn prompt_project_type(detected: ProjectType) -> ProjectType {
-    let types = [
-        ("rust", ProjectType::Rust),
-        ("python", ProjectType::Python),
-        ("node", ProjectType::Node),
-        ("go", ProjectType::Go),
-        ("flutter", ProjectType::Flutter),
-        ("docs", ProjectType::Docs),
-        ("arch", ProjectType::Arch),
-        ("generic", ProjectType::Generic),
-    ];
-
-    eprintln!();
-    eprintln!("Project type not specified. Please select:");
-    for (i, (name, _)) in types.iter().enumerate() {
-        let marker = if types[i].1 == detected {
-            " (detected)"
-        } else {
-            ""
-        };
-        eprintln!("  {}. {}{}", i + 1, name, marker);
-    }
-    eprint!("Enter number [1-8] (default: {}): ", detected);
-    io::stderr().flush().ok();
-
-    let stdin = io::stdin();
-    let line = stdin.lock().lines().next();
-
-    if let Some(Ok(input)) = line {
-        let input = input.trim();
-        if input.is_empty() {
-            return detected;
-        }
-        if let Ok(n) = input.parse::<usize>() {
-            if n >= 1 && n <= types.len() {
-                return types[n - 1].1;
-            }
-        }
-    }
-
-    detected
+/// Returns Ok(false) when an existing hook was not generated by asimov (kept as is)
+/// v12.4.0: Delegates to the hook manager, which honors core.hooksPath and also
+/// regenerates asimov's commit-msg and pre-push hooks when installed
+pub(super) fn regenerate_precommit_hook(
+    dir: &Path,
+    project_type: ProjectType,
+) -> Result<bool, String> {
+    let changes = refresh_git_hooks(dir, project_type)?;
+    Ok(!changes
+        .iter()
+        .any(|c| c.hook == GitHook::PreCommit && c.action == HookAction::Kept))
 }
 
 /// Prompt user for coding_standards upgrade
@@ -448,6 +382,7 @@ mod tests {
             project_type_was_missing: false,
             coding_standards_upgraded: false,
             hook_regenerated: true,
+            hook_kept: false,
             dry_run: false,
             error: None,
         };
@@ -721,4 +656,34 @@ coding_standards:
         assert!(result.success);
         assert!(!result.hook_regenerated); // No git, no hook
     }
+
+    #[test]
+    fn test_refresh_keeps_custom_hook() {
+        let temp = TempDir::new().unwrap();
+        let asimov_dir = temp.path().join(".asimov");
+        let hooks_dir = temp.path().join(".git/hooks");
+        std::fs::create_dir_all(&asimov_dir).unwrap();
+        std::fs::create_dir_all(&hooks_dir).unwrap();
+        std::fs::write(
+            asimov_dir.join("roadmap.yaml"),
+            "current:\n  version: '1.0'\n  status: planned\n  summary: Test\n",
+        )
+        .unwrap();
+        std::fs::write(temp.path().join("Cargo.toml"), "[package]").unwrap();
+        let custom = "#!/bin/sh\ncargo nextest run\n";
+        std::fs::write(hooks_dir.join("pre-commit"), custom).unwrap();
+
+        let options = RefreshOptions {
+            yes: true,
+            dry_run: false,
+        };
+        let result = run_refresh_with_options(temp.path(), options);
+        assert!(result.success);
+        assert!(!result.hook_regenerated);
+        assert!(result.hook_kept);
+        assert_eq!(
+            std::fs::read_to_string(hooks_dir.join("pre-commit")).unwrap(),
+            custom
+        );
+    }
 }
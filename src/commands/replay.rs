@@ -1,33 +1,71 @@
 //! Replay command implementation
+//! v12.4.0: Per-commit numstat for the selected range (not just HEAD~1),
+//! author/path/until filters, optional patches and JSON output
 
+use crate::commit_policy::CommitMessage;
+use crate::roadmap::load_deliverables;
+use serde::Serialize;
+use std::collections::BTreeSet;
 use std::path::Path;
 
-#[derive(Debug, Clone)]
+/// Which commits to replay
+#[derive(Debug, Clone, Default)]
+pub struct ReplayOptions {
+    /// Last N commits
+    pub commits: Option<usize>,
+    pub yesterday: bool,
+    pub since: Option<String>,
+    pub until: Option<String>,
+    /// Author name or email pattern (as `git log --author`)
+    pub author: Option<String>,
+    /// Only commits touching these paths; stats are limited to them too
+    pub paths: Vec<String>,
+    /// Include each commit's patch
+    pub diffs: bool,
+}
+
+/// Lines changed in one file of a commit
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct FileChange {
+    pub path: String,
+    pub insertions: usize,
+    pub deletions: usize,
+    /// Binary files have no line counts
+    pub binary: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
 pub struct CommitInfo {
     pub hash: String,
     pub date: String,
     pub time: String,
     pub message: String,
+    /// v12.4.0: Milestone of the roadmap deliverable the subject references as [id]
+    pub milestone: Option<String>,
+    pub author: String,
+    pub files: Vec<FileChange>,
+    pub insertions: usize,
+    pub deletions: usize,
+    /// Patch, when requested
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub diff: Option<String>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct ReplayResult {
     pub success: bool,
     pub is_git_repo: bool,
     pub range_description: String,
     pub commits: Vec<CommitInfo>,
+    /// Distinct files changed across the range
     pub total_files_changed: usize,
     pub total_insertions: usize,
     pub total_deletions: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub error: Option<String>,
 }
 
-pub fn run_replay(
-    dir: &Path,
-    commits: Option<usize>,
-    yesterday: bool,
-    since: Option<String>,
-) -> ReplayResult {
+pub fn run_replay(dir: &Path, options: &ReplayOptions) -> ReplayResult {
     use chrono::Local;
 
     let mut result = ReplayResult {
@@ -47,35 +85,54 @@ pub fn run_replay(
     }
     result.is_git_repo = true;
 
+    // Records start with \x1e; the header is followed by numstat lines
     let mut args = vec![
         "log".to_string(),
-        "--pretty=format:%H|%ci|%s".to_string(),
-        "--date=local".to_string(),
+        "--numstat".to_string(),
+        "--format=%x1e%H%x1f%ci%x1f%an%x1f%s".to_string(),
     ];
 
-    result.range_description = if let Some(n) = commits {
+    let mut range = Vec::new();
+    if let Some(n) = options.commits {
         args.push(format!("-{}", n));
-        format!("Last {} commits", n)
-    } else if yesterday {
+        range.push(format!("Last {} commits", n));
+    }
+    if options.yesterday {
         let yesterday_date = Local::now().date_naive() - chrono::Duration::days(1);
         args.push(format!("--since={} 00:00:00", yesterday_date));
         args.push(format!("--until={} 23:59:59", yesterday_date));
-        format!("Yesterday ({})", yesterday_date)
-    } else if let Some(ref since_arg) = since {
-        args.push(format!("--since={}", since_arg));
-        format!("Since {}", since_arg)
-    } else {
+        range.push(format!("Yesterday ({})", yesterday_date));
+    }
+    if let Some(ref since) = options.since {
+        args.push(format!("--since={}", since));
+        range.push(format!("Since {}", since));
+    }
+    if let Some(ref until) = options.until {
+        args.push(format!("--until={}", until));
+        range.push(format!("Until {}", until));
+    }
+    if range.is_empty() {
         let today = Local::now().format("%Y-%m-%d").to_string();
         args.push(format!("--since={} 00:00:00", today));
-        format!("Today ({})", today)
-    };
+        range.push(format!("Today ({})", today));
+    }
+    if let Some(ref author) = options.author {
+        args.push(format!("--author={}", author));
+        range.push(format!("by {}", author));
+    }
+    if !options.paths.is_empty() {
+        range.push(format!("in {}", options.paths.join(", ")));
+    }
+    result.range_description = range.join(", ");
+    args.push("--".to_string());
+    args.extend(options.paths.iter().cloned());
 
     let output = std::process::Command::new("git")
         .args(&args)
         .current_dir(dir)
         .output();
 
-    let commits_output = match output {
+    let log_output = match output {
         Ok(o) if o.status.success() => String::from_utf8_lossy(&o.stdout).to_string(),
         _ => {
             result.error = Some("Failed to get git log".to_string());
@@ -83,55 +140,80 @@ pub fn run_replay(
         }
     };
 
-    for line in commits_output.lines() {
-        let parts: Vec<&str> = line.splitn(3, '|').collect();
-        if parts.len() >= 3 {
-            let datetime_parts: Vec<&str> = parts[1].split_whitespace().collect();
-            result.commits.push(CommitInfo {
-                hash: parts[0][..7].to_string(),
-                date: datetime_parts.first().unwrap_or(&"").to_string(),
-                time: datetime_parts.get(1).unwrap_or(&"").to_string(),
-                message: parts[2].to_string(),
-            });
+    let deliverables = load_deliverables(dir);
+    result.commits = log_output
+        .split('\x1e')
+        .filter_map(|record| parse_commit(record, &deliverables))
+        .collect();
+
+    if options.diffs {
+        for commit in &mut result.commits {
+            commit.diff = commit_patch(dir, &commit.hash, &options.paths);
         }
     }
 
-    // Get diff stats
-    if let Ok(output) = std::process::Command::new("git")
-        .args(["diff", "--stat", "HEAD~1..HEAD"])
-        .current_dir(dir)
-        .output()
-    {
-        if output.status.success() {
-            let stat = String::from_utf8_lossy(&output.stdout);
-            for line in stat.lines() {
-                if line.contains("changed") {
-                    if let Some(files) = line.split_whitespace().next() {
-                        result.total_files_changed = files.parse().unwrap_or(0);
-                    }
-                    if line.contains("insertion") {
-                        for part in line.split(',') {
-                            if part.contains("insertion") {
-                                if let Some(num) = part.split_whitespace().next() {
-                                    result.total_insertions = num.parse().unwrap_or(0);
-                                }
-                            }
-                            if part.contains("deletion") {
-                                if let Some(num) = part.split_whitespace().next() {
-                                    result.total_deletions = num.parse().unwrap_or(0);
-                                }
-                            }
-                        }
-                    }
-                }
-            }
-        }
+    let mut files = BTreeSet::new();
+    for commit in &result.commits {
+        result.total_insertions += commit.insertions;
+        result.total_deletions += commit.deletions;
+        files.extend(commit.files.iter().map(|f| f.path.as_str()));
     }
+    result.total_files_changed = files.len();
 
     result.success = true;
     result
 }
 
+/// One `git log --numstat` record
+fn parse_commit(record: &str, deliverables: &[crate::roadmap::Deliverable]) -> Option<CommitInfo> {
+    let mut lines = record.lines();
+    let header: Vec<&str> = lines.next()?.splitn(4, '\x1f').collect();
+    if header.len() < 4 {
+        return None;
+    }
+    let datetime: Vec<&str> = header[1].split_whitespace().collect();
+    let files: Vec<FileChange> = lines.filter_map(parse_numstat).collect();
+    Some(CommitInfo {
+        hash: header[0].chars().take(7).collect(),
+        date: datetime.first().unwrap_or(&"").to_string(),
+        time: datetime.get(1).unwrap_or(&"").to_string(),
+        message: header[3].to_string(),
+        milestone: CommitMessage::parse(header[3])
+            .deliverable(deliverables)
+            .map(|d| d.milestone.clone()),
+        author: header[2].to_string(),
+        insertions: files.iter().map(|f| f.insertions).sum(),
+        deletions: files.iter().map(|f| f.deletions).sum(),
+        files,
+        diff: None,
+    })
+}
+
+/// `<insertions>\t<deletions>\t<path>`; binary files show `-` counts
+fn parse_numstat(line: &str) -> Option<FileChange> {
+    let mut parts = line.splitn(3, '\t');
+    let insertions = parts.next()?;
+    let deletions = parts.next()?;
+    let path = parts.next()?;
+    Some(FileChange {
+        path: path.to_string(),
+        insertions: insertions.parse().unwrap_or(0),
+        deletions: deletions.parse().unwrap_or(0),
+        binary: insertions == "-",
+    })
+}
+
+fn commit_patch(dir: &Path, hash: &str, paths: &[String]) -> Option<String> {
+    std::process::Command::new("git")
+        .args(["show", "--format=", "--patch", "--no-color", hash, "--"])
+        .args(paths)
+        .current_dir(dir)
+        .output()
+        .ok()
+        .filter(|o| o.status.success())
+        .map(|o| String::from_utf8_lossy(&o.stdout).to_string())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -140,7 +222,7 @@ mod tests {
     #[test]
     fn test_run_replay_not_git() {
         let temp = TempDir::new().unwrap();
-        let result = run_replay(temp.path(), None, false, None);
+        let result = run_replay(temp.path(), &ReplayOptions::default());
         assert!(!result.success);
         assert!(!result.is_git_repo);
     }
@@ -152,6 +234,12 @@ mod tests {
             date: "2025-01-01".to_string(),
             time: "12:00".to_string(),
             message: "Test".to_string(),
+            milestone: None,
+            author: "Test".to_string(),
+            files: vec![],
+            insertions: 0,
+            deletions: 0,
+            diff: None,
         };
         assert_eq!(c.hash, "abc1234");
     }
@@ -200,13 +288,31 @@ mod tests {
             .output();
 
         // Test replay with various options
-        let result = run_replay(temp.path(), Some(5), false, None);
+        let result = run_replay(
+            temp.path(),
+            &ReplayOptions {
+                commits: Some(5),
+                ..Default::default()
+            },
+        );
         assert!(result.is_git_repo);
 
-        let result2 = run_replay(temp.path(), None, true, None);
+        let result2 = run_replay(
+            temp.path(),
+            &ReplayOptions {
+                yesterday: true,
+                ..Default::default()
+            },
+        );
         assert!(result2.is_git_repo);
 
-        let result3 = run_replay(temp.path(), None, false, Some("1 hour ago".to_string()));
+        let result3 = run_replay(
+            temp.path(),
+            &ReplayOptions {
+                since: Some("1 hour ago".to_string()),
+                ..Default::default()
+            },
+        );
         assert!(result3.is_git_repo);
     }
 
@@ -228,7 +334,13 @@ mod tests {
             .current_dir(temp.path())
             .output();
 
-        let result = run_replay(temp.path(), None, true, None);
+        let result = run_replay(
+            temp.path(),
+            &ReplayOptions {
+                yesterday: true,
+                ..Default::default()
+            },
+        );
         assert!(result.is_git_repo);
     }
 
@@ -240,8 +352,77 @@ mod tests {
             .current_dir(temp.path())
             .output()
             .unwrap();
-        let result = run_replay(temp.path(), None, false, None);
+        let result = run_replay(temp.path(), &ReplayOptions::default());
         // No commits
         assert!(result.commits.is_empty());
     }
+
+    #[test]
+    fn test_parse_numstat() {
+        let change = parse_numstat("12\t3\tsrc/main.rs").unwrap();
+        assert_eq!((change.insertions, change.deletions), (12, 3));
+        assert_eq!(change.path, "src/main.rs");
+        assert!(!change.binary);
+        assert!(parse_numstat("-\t-\tlogo.png").unwrap().binary);
+        assert!(parse_numstat("").is_none());
+    }
+
+    #[test]
+    fn test_run_replay_range_stats_and_filters() {
+        let temp = TempDir::new().unwrap();
+        let commit = |author: &str, file: &str, content: &str, message: &str| {
+            std::fs::write(temp.path().join(file), content).unwrap();
+            for args in [vec!["add", "."], vec!["commit", "-q", "-m", message]] {
+                std::process::Command::new("git")
+                    .args(&args)
+                    .current_dir(temp.path())
+                    .env("GIT_AUTHOR_NAME", author)
+                    .env("GIT_AUTHOR_EMAIL", "dev@test.com")
+                    .env("GIT_COMMITTER_NAME", author)
+                    .env("GIT_COMMITTER_EMAIL", "dev@test.com")
+                    .output()
+                    .unwrap();
+            }
+        };
+        std::process::Command::new("git")
+            .args(["init", "-q"])
+            .current_dir(temp.path())
+            .output()
+            .unwrap();
+        commit("Ada", "a.txt", "1\n2\n3\n", "first");
+        commit("Bob", "b.txt", "1\n", "second");
+        commit("Ada", "a.txt", "1\n", "third");
+
+        let all = ReplayOptions {
+            commits: Some(10),
+            ..Default::default()
+        };
+        let result = run_replay(temp.path(), &all);
+        assert!(result.success, "{:?}", result.error);
+        assert_eq!(result.commits.len(), 3);
+        assert_eq!(result.commits[0].message, "third");
+        assert_eq!(result.commits[0].deletions, 2);
+        assert_eq!(result.total_insertions, 4);
+        assert_eq!(result.total_deletions, 2);
+        assert_eq!(result.total_files_changed, 2);
+        assert!(result.commits[0].diff.is_none());
+
+        let ada = ReplayOptions {
+            author: Some("Ada".to_string()),
+            paths: vec!["a.txt".to_string()],
+            diffs: true,
+            ..all.clone()
+        };
+        let result = run_replay(temp.path(), &ada);
+        assert_eq!(result.commits.len(), 2);
+        assert!(result.commits.iter().all(|c| c.author == "Ada"));
+        assert!(result.range_description.contains("by Ada, in a.txt"));
+        assert!(result.commits[0].diff.as_ref().unwrap().contains("-2"));
+
+        let future = ReplayOptions {
+            until: Some("2000-01-01".to_string()),
+            ..all
+        };
+        assert!(run_replay(temp.path(), &future).commits.is_empty());
+    }
 }
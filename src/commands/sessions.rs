@@ -0,0 +1,620 @@
+//! Session journal (v12.4.0)
+//!
+//! Every AI session gets `.asimov/sessions/<id>.jsonl`, one JSON event per
+//! line: `start` (AI profile, role, milestone, WIP deliverable, protocol
+//! hash, HEAD), `warmup`, `role` and `check` while it runs, and `end` with
+//! the commits it produced and the gate results of the last `asimov check`
+//! run inside it. Closing a session never runs gates itself.
+//!
+//! `asimov launch` opens the record and closes it when the AI CLI exits. It
+//! exports `ASIMOV_SESSION` so the AI's own `asimov warmup` and
+//! `asimov role` calls land in the same journal. A warmup outside a
+//! launched session opens a record of its own, closed by the next one.
+
+use crate::commands::{load_launch_config, CheckResult, GateStatus};
+use crate::protocols::protocol_hash;
+use crate::roadmap::{current_milestone, load_deliverables};
+use serde::{Deserialize, Serialize};
+use std::io::Write as _;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// Journal location, relative to the project directory
+pub const SESSIONS_DIR: &str = ".asimov/sessions";
+
+/// Id of the session a launched AI CLI runs in
+pub const SESSION_ENV: &str = "ASIMOV_SESSION";
+
+/// Role a session starts with (`asimov role <code>` records changes)
+pub const ROLE_ENV: &str = "ASIMOV_ROLE";
+
+/// What opened a session
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SessionSource {
+    Launch,
+    Warmup,
+}
+
+impl std::fmt::Display for SessionSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SessionSource::Launch => write!(f, "launch"),
+            SessionSource::Warmup => write!(f, "warmup"),
+        }
+    }
+}
+
+/// First line of a session record
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SessionStart {
+    pub id: String,
+    /// RFC 3339, local time
+    pub time: String,
+    pub source: SessionSource,
+    /// AI profile id
+    pub ai: Option<String>,
+    pub autonomy: Option<String>,
+    /// Ran under the session supervisor (`--supervise`)
+    pub supervisor: bool,
+    pub role: Option<String>,
+    /// Version of the current roadmap milestone
+    pub milestone: Option<String>,
+    /// Id of the first deliverable with `status: wip`
+    pub wip: Option<String>,
+    /// See `protocols::protocol_hash`
+    pub protocol_hash: String,
+    /// Commit the session started from
+    pub head: Option<String>,
+    pub asimov_version: String,
+}
+
+/// Last line of a closed session record
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SessionEnd {
+    pub time: String,
+    /// Exit code of the AI CLI; None when closed by hand or by a later warmup
+    pub exit_code: Option<i32>,
+    pub head: Option<String>,
+    /// Commits since the start HEAD, newest first
+    pub commits: Vec<SessionCommit>,
+    pub gates: Vec<SessionGate>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SessionCommit {
+    pub hash: String,
+    pub subject: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SessionGate {
+    pub name: String,
+    pub status: GateStatus,
+}
+
+/// One line of a session record
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum SessionEntry {
+    Start(SessionStart),
+    Warmup {
+        time: String,
+    },
+    Role {
+        time: String,
+        role: String,
+    },
+    Check {
+        time: String,
+        gates: Vec<SessionGate>,
+    },
+    End(SessionEnd),
+}
+
+/// A session record, folded
+#[derive(Debug, Clone, Serialize)]
+pub struct Session {
+    pub start: SessionStart,
+    pub warmups: usize,
+    /// Roles selected during the session, in order
+    pub roles: Vec<String>,
+    /// Gate results of the last `asimov check` run in the session
+    pub last_check: Option<Vec<SessionGate>>,
+    pub end: Option<SessionEnd>,
+}
+
+impl Session {
+    pub fn id(&self) -> &str {
+        &self.start.id
+    }
+
+    /// The role last selected, else the one the session started with
+    pub fn role(&self) -> Option<&str> {
+        self.roles
+            .last()
+            .map(String::as_str)
+            .or(self.start.role.as_deref())
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.end.is_none()
+    }
+
+    /// Fold the lines of a record; None without a `start` line
+    pub fn parse(content: &str) -> Option<Self> {
+        let mut entries = content
+            .lines()
+            .filter_map(|line| serde_json::from_str::<SessionEntry>(line).ok());
+        let Some(SessionEntry::Start(start)) = entries.next() else {
+            return None;
+        };
+        let mut session = Session {
+            start,
+            warmups: 0,
+            roles: Vec::new(),
+            last_check: None,
+            end: None,
+        };
+        for entry in entries {
+            match entry {
+                SessionEntry::Warmup { .. } => session.warmups += 1,
+                SessionEntry::Role { role, .. } => session.roles.push(role),
+                SessionEntry::Check { gates, .. } => session.last_check = Some(gates),
+                SessionEntry::End(end) => session.end = Some(end),
+                SessionEntry::Start(_) => {}
+            }
+        }
+        Some(session)
+    }
+}
+
+/// How a session is opened
+#[derive(Debug, Clone)]
+pub struct NewSession {
+    pub source: SessionSource,
+    pub ai: Option<String>,
+    pub autonomy: Option<String>,
+    /// Ran under the session supervisor (`--supervise`)
+    pub supervisor: bool,
+}
+
+fn now() -> String {
+    chrono::Local::now().to_rfc3339_opts(chrono::SecondsFormat::Secs, false)
+}
+
+fn session_path(dir: &Path, id: &str) -> PathBuf {
+    dir.join(SESSIONS_DIR).join(format!("{}.jsonl", id))
+}
+
+fn git_head(dir: &Path) -> Option<String> {
+    let output = Command::new("git")
+        .args(["rev-parse", "--verify", "-q", "HEAD"])
+        .current_dir(dir)
+        .output()
+        .ok()?;
+    output
+        .status
+        .success()
+        .then(|| String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+/// Commits reachable from HEAD but not from `since` (all of them without)
+fn commits_since(dir: &Path, since: Option<&str>) -> Vec<SessionCommit> {
+    let range = match since {
+        Some(since) => format!("{}..HEAD", since),
+        None => "HEAD".to_string(),
+    };
+    let Ok(output) = Command::new("git")
+        .args(["log", "--format=%h%x1f%s", &range, "--"])
+        .current_dir(dir)
+        .output()
+    else {
+        return Vec::new();
+    };
+    if !output.status.success() {
+        return Vec::new();
+    }
+    String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .filter_map(|line| {
+            let (hash, subject) = line.split_once('\x1f')?;
+            Some(SessionCommit {
+                hash: hash.to_string(),
+                subject: subject.to_string(),
+            })
+        })
+        .collect()
+}
+
+fn append_entry(path: &Path, entry: &SessionEntry, create: bool) -> Result<(), String> {
+    let line = serde_json::to_string(entry)
+        .map_err(|e| format!("Failed to serialize session entry: {}", e))?;
+    let mut file = std::fs::OpenOptions::new()
+        .append(true)
+        .create_new(create)
+        .open(path)
+        .map_err(|e| format!("Cannot write {}: {}", path.display(), e))?;
+    writeln!(file, "{}", line).map_err(|e| format!("Cannot write {}: {}", path.display(), e))
+}
+
+/// Open a session record; requires an asimov project (`.asimov/`)
+pub fn start_session(dir: &Path, new: &NewSession) -> Result<SessionStart, String> {
+    if !dir.join(".asimov").is_dir() {
+        return Err(format!("No .asimov directory in {}", dir.display()));
+    }
+    let sessions = dir.join(SESSIONS_DIR);
+    std::fs::create_dir_all(&sessions)
+        .map_err(|e| format!("Cannot create {}: {}", sessions.display(), e))?;
+    // A personal journal: keep it out of git (and out of gate input hashes)
+    let ignore = sessions.join(".gitignore");
+    if !ignore.exists() {
+        let _ = std::fs::write(ignore, "*\n");
+    }
+
+    let id = format!(
+        "{}-{}",
+        chrono::Local::now().format("%Y%m%d-%H%M%S"),
+        std::process::id()
+    );
+    let wip = load_deliverables(dir)
+        .into_iter()
+        .find(|d| d.status.as_deref() == Some("wip"))
+        .map(|d| d.id);
+    let start = SessionStart {
+        time: now(),
+        source: new.source,
+        ai: new.ai.clone(),
+        autonomy: new.autonomy.clone(),
+        supervisor: new.supervisor,
+        role: std::env::var(ROLE_ENV)
+            .ok()
+            .filter(|r| !r.trim().is_empty()),
+        milestone: current_milestone(dir),
+        wip,
+        protocol_hash: protocol_hash(dir),
+        head: git_head(dir),
+        asimov_version: env!("CARGO_PKG_VERSION").to_string(),
+        id,
+    };
+    append_entry(
+        &session_path(dir, &start.id),
+        &SessionEntry::Start(start.clone()),
+        true,
+    )?;
+    Ok(start)
+}
+
+/// Close an open session: commits since its start HEAD, plus the gate
+/// results of its last check (none if it never ran one)
+pub fn close_session(dir: &Path, id: &str, exit_code: Option<i32>) -> Result<SessionEnd, String> {
+    let session = find_session(dir, id)?;
+    if !session.is_open() {
+        return Err(format!("Session {} is already closed", session.id()));
+    }
+    let head = git_head(dir);
+    let commits = if head.is_some() {
+        commits_since(dir, session.start.head.as_deref())
+    } else {
+        Vec::new()
+    };
+    let end = SessionEnd {
+        time: now(),
+        exit_code,
+        head,
+        commits,
+        gates: session.last_check.clone().unwrap_or_default(),
+    };
+    append_entry(
+        &session_path(dir, session.id()),
+        &SessionEntry::End(end.clone()),
+        false,
+    )?;
+    Ok(end)
+}
+
+/// Gate outcomes of a check run, as journaled
+pub fn session_gates(result: &CheckResult) -> Vec<SessionGate> {
+    result
+        .gates
+        .iter()
+        .map(|gate| SessionGate {
+            name: gate.name.clone(),
+            status: gate.status,
+        })
+        .collect()
+}
+
+/// The open session named by `ASIMOV_SESSION`, if it belongs to `dir`
+pub fn active_session(dir: &Path) -> Option<String> {
+    let id = std::env::var(SESSION_ENV).ok()?;
+    let content = std::fs::read_to_string(session_path(dir, &id)).ok()?;
+    Session::parse(&content)
+        .filter(Session::is_open)
+        .map(|_| id)
+}
+
+/// Record a warmup: in the active session, else in a new warmup session
+/// (closing the previous warmup sessions, whose AI never reported back)
+pub fn record_warmup(dir: &Path) -> Result<String, String> {
+    if let Some(id) = active_session(dir) {
+        append_entry(
+            &session_path(dir, &id),
+            &SessionEntry::Warmup { time: now() },
+            false,
+        )?;
+        return Ok(id);
+    }
+
+    for session in load_sessions(dir) {
+        if session.is_open() && session.start.source == SessionSource::Warmup {
+            close_session(dir, session.id(), None)?;
+        }
+    }
+    let ai = load_launch_config(dir).ok().and_then(|config| {
+        config
+            .profiles
+            .into_iter()
+            .find(|p| p.is_inside())
+            .map(|p| p.id)
+    });
+    let start = start_session(
+        dir,
+        &NewSession {
+            source: SessionSource::Warmup,
+            ai,
+            autonomy: None,
+            supervisor: false,
+        },
+    )?;
+    Ok(start.id)
+}
+
+/// Record a role change in the active session; false without one
+pub fn record_role(dir: &Path, role: &str) -> Result<bool, String> {
+    let Some(id) = active_session(dir) else {
+        return Ok(false);
+    };
+    append_entry(
+        &session_path(dir, &id),
+        &SessionEntry::Role {
+            time: now(),
+            role: role.to_string(),
+        },
+        false,
+    )?;
+    Ok(true)
+}
+
+/// Record a check run in the active session; false without one
+pub fn record_check(dir: &Path, result: &CheckResult) -> Result<bool, String> {
+    let Some(id) = active_session(dir) else {
+        return Ok(false);
+    };
+    append_entry(
+        &session_path(dir, &id),
+        &SessionEntry::Check {
+            time: now(),
+            gates: session_gates(result),
+        },
+        false,
+    )?;
+    Ok(true)
+}
+
+/// All session records, newest first
+pub fn load_sessions(dir: &Path) -> Vec<Session> {
+    let Ok(entries) = std::fs::read_dir(dir.join(SESSIONS_DIR)) else {
+        return Vec::new();
+    };
+    let mut sessions: Vec<Session> = entries
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "jsonl"))
+        .filter_map(|path| std::fs::read_to_string(path).ok())
+        .filter_map(|content| Session::parse(&content))
+        .collect();
+    sessions.sort_by(|a, b| b.id().cmp(a.id()));
+    sessions
+}
+
+/// The session whose id starts with `id` ("last" = the newest)
+pub fn find_session(dir: &Path, id: &str) -> Result<Session, String> {
+    let sessions = load_sessions(dir);
+    if id == "last" {
+        return sessions
+            .into_iter()
+            .next()
+            .ok_or_else(|| "No sessions recorded".to_string());
+    }
+    let mut matches: Vec<Session> = sessions
+        .into_iter()
+        .filter(|s| s.id().starts_with(id))
+        .collect();
+    match matches.len() {
+        0 => Err(format!("Unknown session '{}'", id)),
+        1 => Ok(matches.remove(0)),
+        n => Err(format!(
+            "Session id '{}' is ambiguous ({} matches): {}",
+            id,
+            n,
+            matches
+                .iter()
+                .map(Session::id)
+                .collect::<Vec<_>>()
+                .join(", ")
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn git(dir: &Path, args: &[&str]) {
+        let status = Command::new("git")
+            .args(args)
+            .current_dir(dir)
+            .env("GIT_AUTHOR_NAME", "Test")
+            .env("GIT_AUTHOR_EMAIL", "test@example.com")
+            .env("GIT_COMMITTER_NAME", "Test")
+            .env("GIT_COMMITTER_EMAIL", "test@example.com")
+            .status()
+            .unwrap();
+        assert!(status.success());
+    }
+
+    fn project() -> TempDir {
+        let temp = TempDir::new().unwrap();
+        std::fs::create_dir_all(temp.path().join(".asimov")).unwrap();
+        std::fs::write(
+            temp.path().join(".asimov/roadmap.yaml"),
+            "current:\n  version: '2.0.0'\n  deliverables:\n    - id: done-one\n      status: done\n    - id: journal\n      status: wip\n",
+        )
+        .unwrap();
+        temp
+    }
+
+    fn launch() -> NewSession {
+        NewSession {
+            source: SessionSource::Launch,
+            ai: Some("claude".to_string()),
+            autonomy: Some("supervised".to_string()),
+            supervisor: true,
+        }
+    }
+
+    #[test]
+    fn test_session_records_commits_and_gates() {
+        let temp = project();
+        let dir = temp.path();
+        git(dir, &["init", "-q"]);
+        git(dir, &["commit", "-q", "--allow-empty", "-m", "before"]);
+
+        let start = start_session(dir, &launch()).unwrap();
+        assert_eq!(start.milestone.as_deref(), Some("2.0.0"));
+        assert_eq!(start.wip.as_deref(), Some("journal"));
+        assert_eq!(start.protocol_hash, protocol_hash(dir));
+        assert!(start.head.is_some());
+
+        git(
+            dir,
+            &["commit", "-q", "--allow-empty", "-m", "[journal] one"],
+        );
+        git(
+            dir,
+            &["commit", "-q", "--allow-empty", "-m", "[journal] two"],
+        );
+        // What `record_check` appends when the AI runs `asimov check`
+        let check = |status| SessionEntry::Check {
+            time: now(),
+            gates: vec![SessionGate {
+                name: "test".to_string(),
+                status,
+            }],
+        };
+        let path = session_path(dir, &start.id);
+        append_entry(&path, &check(GateStatus::Failed), false).unwrap();
+        append_entry(&path, &check(GateStatus::Passed), false).unwrap();
+        let end = close_session(dir, &start.id, Some(0)).unwrap();
+        assert_eq!(end.commits.len(), 2);
+        assert_eq!(end.commits[0].subject, "[journal] two");
+
+        let session = find_session(dir, "last").unwrap();
+        assert!(!session.is_open());
+        assert_eq!(session.end.unwrap().gates[0].status, GateStatus::Passed);
+
+        let err = close_session(dir, &start.id, None).unwrap_err();
+        assert!(err.contains("already closed"), "{}", err);
+
+        // No check in the session: no gates, and none are run on close
+        let other = project();
+        let unchecked = start_session(other.path(), &launch()).unwrap();
+        let end = close_session(other.path(), &unchecked.id, Some(0)).unwrap();
+        assert!(end.gates.is_empty());
+    }
+
+    #[test]
+    fn test_session_parse_folds_events() {
+        let start = SessionStart {
+            id: "20260101-090000-1".to_string(),
+            time: "2026-01-01T09:00:00+00:00".to_string(),
+            source: SessionSource::Launch,
+            ai: None,
+            autonomy: None,
+            supervisor: false,
+            role: Some("dev".to_string()),
+            milestone: None,
+            wip: None,
+            protocol_hash: "sha256:0".to_string(),
+            head: None,
+            asimov_version: "1.0.0".to_string(),
+        };
+        let lines = [
+            SessionEntry::Start(start),
+            SessionEntry::Warmup {
+                time: "t".to_string(),
+            },
+            SessionEntry::Role {
+                time: "t".to_string(),
+                role: "qa".to_string(),
+            },
+            SessionEntry::Check {
+                time: "t".to_string(),
+                gates: Vec::new(),
+            },
+        ]
+        .iter()
+        .map(|e| serde_json::to_string(e).unwrap())
+        .collect::<Vec<_>>()
+        .join("\n");
+
+        let session = Session::parse(&format!("{}\nnot json\n", lines)).unwrap();
+        assert_eq!(session.warmups, 1);
+        assert_eq!(session.role(), Some("qa"));
+        assert_eq!(session.last_check, Some(Vec::new()));
+        assert!(session.is_open());
+        assert!(lines.contains(r#""event":"start""#));
+
+        assert!(Session::parse("").is_none());
+        assert!(Session::parse(r#"{"event":"warmup","time":"t"}"#).is_none());
+    }
+
+    #[test]
+    fn test_find_session_by_prefix() {
+        let temp = project();
+        let dir = temp.path().join(SESSIONS_DIR);
+        std::fs::create_dir_all(&dir).unwrap();
+        for id in [
+            "20260101-090000-1",
+            "20260101-100000-2",
+            "20260102-090000-3",
+        ] {
+            let start = start_session(temp.path(), &launch()).unwrap();
+            let content = std::fs::read_to_string(session_path(temp.path(), &start.id))
+                .unwrap()
+                .replace(&start.id, id);
+            std::fs::remove_file(session_path(temp.path(), &start.id)).unwrap();
+            std::fs::write(dir.join(format!("{}.jsonl", id)), content).unwrap();
+        }
+
+        assert_eq!(load_sessions(temp.path())[0].id(), "20260102-090000-3");
+        assert_eq!(
+            find_session(temp.path(), "20260101-10").unwrap().id(),
+            "20260101-100000-2"
+        );
+        let err = find_session(temp.path(), "20260101").unwrap_err();
+        assert!(err.contains("ambiguous (2 matches)"), "{}", err);
+        let err = find_session(temp.path(), "1999").unwrap_err();
+        assert!(err.contains("Unknown session"), "{}", err);
+    }
+
+    #[test]
+    fn test_start_session_requires_project() {
+        let temp = TempDir::new().unwrap();
+        let err = start_session(temp.path(), &launch()).unwrap_err();
+        assert!(err.contains("No .asimov directory"), "{}", err);
+        assert!(load_sessions(temp.path()).is_empty());
+    }
+}
@@ -1,9 +1,28 @@
 //! Stats command implementation
+//!
+//! v12.4.0: History analytics, replayed from the git history of
+//! roadmap.yaml and CHANGELOG.md: days from `in_progress` to `released` per
+//! milestone, deliverables completed per week and the burndown of the
+//! current milestone. Session records (`asimov sessions`) add how many
+//! sessions, and how many with `--autonomy auto`, worked on each milestone.
 
+use crate::changelog::{parse_changelog, CHANGELOG_FILE};
+use crate::commands::load_sessions;
+use crate::commit_policy::CommitMessage;
 use crate::resolve_protocol_dir;
+use crate::roadmap::{load_deliverables, parse_milestones, Milestone};
+use chrono::{Datelike, Duration, Local, NaiveDate, TimeZone};
+use serde::Serialize;
+use std::collections::{BTreeMap, HashSet};
 use std::path::Path;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
+pub struct StatsOptions {
+    /// Start of the analytics window: YYYY-MM-DD or any git date ("4 weeks ago")
+    pub since: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
 pub struct StatsResult {
     pub total_commits: usize,
     pub asimov_commits: usize,
@@ -12,11 +31,68 @@ pub struct StatsResult {
     pub milestone_version: Option<String>,
     pub milestone_summary: Option<String>,
     pub milestone_status: Option<String>,
+    /// v12.4.0: Commits referencing a deliverable of the current milestone as [id]
+    pub milestone_commits: usize,
+    /// v12.4.0: First day of the analytics window (--since)
+    pub since: Option<String>,
+    pub history: StatsHistory,
+}
+
+/// Analytics from the git history of roadmap.yaml and CHANGELOG.md
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct StatsHistory {
+    pub milestones: Vec<MilestoneCycle>,
+    /// Every ISO week of the window, oldest first
+    pub weekly: Vec<WeeklyDeliverables>,
+    /// Current milestone, one point per day its deliverables changed
+    pub burndown: Vec<BurndownPoint>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct MilestoneCycle {
+    pub version: String,
+    /// First day roadmap.yaml had it `in_progress`
+    pub started: Option<String>,
+    /// First day roadmap.yaml had it `released`, else its CHANGELOG.md date
+    pub released: Option<String>,
+    /// Days from started to released
+    pub days: Option<i64>,
+    /// Recorded sessions that worked on it
+    pub sessions: usize,
+    /// Of those, launched with `--autonomy auto`
+    pub autonomous_sessions: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct WeeklyDeliverables {
+    /// ISO week, e.g. 2026-W42
+    pub week: String,
+    /// Deliverables that became done (`status: done` or `[x]`)
+    pub completed: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct BurndownPoint {
+    pub date: String,
+    pub total: usize,
+    pub remaining: usize,
 }
 
 pub fn run_stats(dir: &Path) -> StatsResult {
-    use chrono::Local;
+    collect_stats(dir, None)
+}
 
+/// Stats with the analytics window limited by `--since` (v12.4.0)
+pub fn run_stats_with_options(dir: &Path, options: &StatsOptions) -> Result<StatsResult, String> {
+    let since = options
+        .since
+        .as_deref()
+        .map(|since| resolve_since(dir, since))
+        .transpose()?;
+    Ok(collect_stats(dir, since))
+}
+
+fn collect_stats(dir: &Path, since: Option<NaiveDate>) -> StatsResult {
     let today = Local::now().format("%Y-%m-%d").to_string();
     let mut result = StatsResult {
         total_commits: 0,
@@ -26,6 +102,9 @@ pub fn run_stats(dir: &Path) -> StatsResult {
         milestone_version: None,
         milestone_summary: None,
         milestone_status: None,
+        milestone_commits: 0,
+        since: since.map(|d| d.to_string()),
+        history: StatsHistory::default(),
     };
 
     // Get git stats
@@ -91,9 +170,291 @@ pub fn run_stats(dir: &Path) -> StatsResult {
         }
     }
 
+    if let Some(ref version) = result.milestone_version {
+        result.milestone_commits = count_milestone_commits(dir, version);
+    }
+
+    result.history = stats_history(dir, since);
     result
 }
 
+/// Commits whose subject references a deliverable of milestone `version`
+fn count_milestone_commits(dir: &Path, version: &str) -> usize {
+    let deliverables: Vec<_> = load_deliverables(dir)
+        .into_iter()
+        .filter(|d| d.milestone == version)
+        .collect();
+    if deliverables.is_empty() {
+        return 0;
+    }
+    std::process::Command::new("git")
+        .args(["log", "--format=%s"])
+        .current_dir(dir)
+        .output()
+        .ok()
+        .filter(|o| o.status.success())
+        .map(|o| {
+            String::from_utf8_lossy(&o.stdout)
+                .lines()
+                .filter(|subject| {
+                    CommitMessage::parse(subject)
+                        .deliverable(&deliverables)
+                        .is_some()
+                })
+                .count()
+        })
+        .unwrap_or(0)
+}
+
+/// A date as git understands it, resolved to a day
+fn resolve_since(dir: &Path, since: &str) -> Result<NaiveDate, String> {
+    if let Ok(date) = NaiveDate::parse_from_str(since.trim(), "%Y-%m-%d") {
+        return Ok(date);
+    }
+    // git's approxidate: "4 weeks ago", "last monday", "2026-01-05 12:00"
+    git(dir, &["rev-parse", &format!("--since={}", since)])
+        .and_then(|out| out.trim().strip_prefix("--max-age=")?.parse::<i64>().ok())
+        .and_then(|secs| Local.timestamp_opt(secs, 0).single())
+        .map(|time| time.date_naive())
+        .ok_or_else(|| {
+            format!(
+                "Cannot resolve --since '{}' (use YYYY-MM-DD, or a git date such as '4 weeks ago' inside a git repository)",
+                since
+            )
+        })
+}
+
+fn git(dir: &Path, args: &[&str]) -> Option<String> {
+    let output = std::process::Command::new("git")
+        .args(args)
+        .current_dir(dir)
+        .output()
+        .ok()?;
+    output
+        .status
+        .success()
+        .then(|| String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+/// roadmap.yaml as of one day
+struct Snapshot {
+    date: NaiveDate,
+    milestones: Vec<Milestone>,
+}
+
+/// Every committed roadmap.yaml, oldest first, then uncommitted edits as today
+fn roadmap_snapshots(dir: &Path) -> Vec<Snapshot> {
+    let path = resolve_protocol_dir(dir).join("roadmap.yaml");
+    let parse = |content: &str| {
+        serde_yaml_ng::from_str::<serde_yaml_ng::Value>(content)
+            .ok()
+            .map(|roadmap| parse_milestones(&roadmap))
+    };
+    let mut snapshots = Vec::new();
+    if let Some(rel) = path.strip_prefix(dir).ok().and_then(|rel| rel.to_str()) {
+        let log = git(dir, &["log", "--reverse", "--format=%H %cs", "--", rel]);
+        for line in log.unwrap_or_default().lines() {
+            let Some((hash, date)) = line.split_once(' ') else {
+                continue;
+            };
+            let (Ok(date), Some(content)) = (
+                NaiveDate::parse_from_str(date, "%Y-%m-%d"),
+                git(dir, &["show", &format!("{}:./{}", hash, rel)]),
+            ) else {
+                continue;
+            };
+            if let Some(milestones) = parse(&content) {
+                snapshots.push(Snapshot { date, milestones });
+            }
+        }
+    }
+    if let Some(milestones) = std::fs::read_to_string(&path)
+        .ok()
+        .and_then(|content| parse(&content))
+    {
+        if snapshots
+            .last()
+            .is_none_or(|last| last.milestones != milestones)
+        {
+            snapshots.push(Snapshot {
+                date: Local::now().date_naive(),
+                milestones,
+            });
+        }
+    }
+    snapshots
+}
+
+/// Release dates from CHANGELOG.md headings; undated headings get the day
+/// they were first committed
+fn changelog_dates(dir: &Path) -> BTreeMap<String, NaiveDate> {
+    let Ok(content) = std::fs::read_to_string(dir.join(CHANGELOG_FILE)) else {
+        return BTreeMap::new();
+    };
+    let mut dates = BTreeMap::new();
+    for entry in parse_changelog(&content) {
+        let date = match &entry.date {
+            Some(date) => NaiveDate::parse_from_str(date, "%Y-%m-%d").ok(),
+            None => git(
+                dir,
+                &[
+                    "log",
+                    "--reverse",
+                    "--format=%cs",
+                    &format!("-S{}", entry.heading),
+                    "--",
+                    CHANGELOG_FILE,
+                ],
+            )
+            .and_then(|out| {
+                let first = out.lines().next()?.to_string();
+                NaiveDate::parse_from_str(&first, "%Y-%m-%d").ok()
+            }),
+        };
+        if let Some(date) = date {
+            dates.entry(entry.version).or_insert(date);
+        }
+    }
+    dates
+}
+
+fn iso_week(date: NaiveDate) -> String {
+    let week = date.iso_week();
+    format!("{}-W{:02}", week.year(), week.week())
+}
+
+fn stats_history(dir: &Path, since: Option<NaiveDate>) -> StatsHistory {
+    let snapshots = roadmap_snapshots(dir);
+    let in_window = |date: &NaiveDate| since.is_none_or(|since| *date >= since);
+    let mut history = StatsHistory::default();
+
+    // in_progress -> released, per version, in order of first appearance.
+    // A release is only observed if the milestone was listed unreleased before.
+    let mut cycles: Vec<(String, Option<NaiveDate>, Option<NaiveDate>, bool)> = Vec::new();
+    for snapshot in &snapshots {
+        for milestone in &snapshot.milestones {
+            let version = milestone.version.trim_start_matches('v');
+            let released = milestone.status.as_deref() == Some("released");
+            let index = match cycles.iter().position(|(v, ..)| v == version) {
+                Some(index) => index,
+                None => {
+                    cycles.push((version.to_string(), None, None, !released));
+                    cycles.len() - 1
+                }
+            };
+            let cycle = &mut cycles[index];
+            match milestone.status.as_deref() {
+                Some("in_progress") => {
+                    cycle.1.get_or_insert(snapshot.date);
+                }
+                Some("released") => {
+                    cycle.2.get_or_insert(snapshot.date);
+                }
+                _ => {}
+            }
+        }
+    }
+    let changelog = changelog_dates(dir);
+    let sessions = load_sessions(dir);
+    for (version, started, released, observed) in cycles {
+        let changelog = changelog.get(&version).copied();
+        let released = if observed {
+            released.or(changelog)
+        } else {
+            changelog.or(released)
+        };
+        if started.is_none() && released.is_none() {
+            continue;
+        }
+        // Milestones released before the window are out of it
+        if released.is_some_and(|date| !in_window(&date)) {
+            continue;
+        }
+        let worked: Vec<_> = sessions
+            .iter()
+            .filter(|s| {
+                s.start
+                    .milestone
+                    .as_deref()
+                    .map(|m| m.trim_start_matches('v'))
+                    == Some(version.as_str())
+            })
+            .collect();
+        history.milestones.push(MilestoneCycle {
+            days: started
+                .zip(released)
+                .filter(|(started, released)| released >= started)
+                .map(|(started, released)| (released - started).num_days()),
+            started: started.map(|d| d.to_string()),
+            released: released.map(|d| d.to_string()),
+            sessions: worked.len(),
+            autonomous_sessions: worked
+                .iter()
+                .filter(|s| s.start.autonomy.as_deref() == Some("auto"))
+                .count(),
+            version,
+        });
+    }
+
+    // Deliverables done in the first snapshot predate the history
+    let mut done: HashSet<(String, String)> = HashSet::new();
+    let mut completed: BTreeMap<NaiveDate, usize> = BTreeMap::new();
+    for (i, snapshot) in snapshots.iter().enumerate() {
+        for milestone in &snapshot.milestones {
+            for item in milestone.items.iter().filter(|item| item.done) {
+                if done.insert((milestone.version.clone(), item.key.clone())) && i > 0 {
+                    *completed.entry(snapshot.date).or_default() += 1;
+                }
+            }
+        }
+    }
+    let first = since.or_else(|| completed.keys().next().copied());
+    if let Some(first) = first {
+        let today = Local::now().date_naive();
+        let mut week_start =
+            first - Duration::days(i64::from(first.weekday().num_days_from_monday()));
+        while week_start <= today {
+            let week_end = week_start + Duration::days(7);
+            history.weekly.push(WeeklyDeliverables {
+                week: iso_week(week_start),
+                completed: completed
+                    .range(week_start.max(first)..week_end)
+                    .map(|(_, count)| count)
+                    .sum(),
+            });
+            week_start = week_end;
+        }
+    }
+
+    // Burndown of today's current milestone
+    let current = snapshots
+        .last()
+        .and_then(|s| s.milestones.iter().find(|m| m.current))
+        .map(|m| m.version.clone());
+    if let Some(current) = current {
+        for snapshot in snapshots.iter().filter(|s| in_window(&s.date)) {
+            let Some(milestone) = snapshot.milestones.iter().find(|m| m.version == current) else {
+                continue;
+            };
+            let point = BurndownPoint {
+                date: snapshot.date.to_string(),
+                total: milestone.items.len(),
+                remaining: milestone.items.iter().filter(|i| !i.done).count(),
+            };
+            // Last snapshot of the day wins
+            if history
+                .burndown
+                .last()
+                .is_some_and(|p| p.date == point.date)
+            {
+                history.burndown.pop();
+            }
+            history.burndown.push(point);
+        }
+    }
+    history
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -116,6 +477,9 @@ mod tests {
             milestone_version: Some("1.0.0".to_string()),
             milestone_summary: Some("Test".to_string()),
             milestone_status: Some("active".to_string()),
+            milestone_commits: 3,
+            since: None,
+            history: StatsHistory::default(),
         };
         assert_eq!(r.today_commits, 5);
     }
@@ -184,4 +548,195 @@ mod tests {
         // Should have 0 commits since no asimov dir
         assert_eq!(result.total_commits, 0);
     }
+
+    #[test]
+    fn test_run_stats_milestone_commits() {
+        let temp = TempDir::new().unwrap();
+        let git = |args: &[&str]| {
+            std::process::Command::new("git")
+                .args(args)
+                .current_dir(temp.path())
+                .env("GIT_AUTHOR_NAME", "Test")
+                .env("GIT_AUTHOR_EMAIL", "test@test.com")
+                .env("GIT_COMMITTER_NAME", "Test")
+                .env("GIT_COMMITTER_EMAIL", "test@test.com")
+                .output()
+                .unwrap()
+        };
+        git(&["init", "-q"]);
+        std::fs::create_dir_all(temp.path().join(".asimov")).unwrap();
+        std::fs::write(
+            temp.path().join(".asimov/roadmap.yaml"),
+            "current:\n  version: '2.0.0'\n  status: in_progress\n  summary: Test\n  deliverables:\n    - id: api\n",
+        )
+        .unwrap();
+        git(&[
+            "commit",
+            "-q",
+            "--allow-empty",
+            "-m",
+            "feat: [api] endpoint",
+        ]);
+        git(&["commit", "-q", "--allow-empty", "-m", "[api] fix: typo"]);
+        git(&["commit", "-q", "--allow-empty", "-m", "chore: [other] tidy"]);
+
+        let result = run_stats(temp.path());
+        assert_eq!(result.total_commits, 3);
+        assert_eq!(result.milestone_commits, 2);
+    }
+
+    /// Commit the roadmap as of `date`
+    fn commit_roadmap(dir: &Path, date: &str, roadmap: &str) {
+        std::fs::write(dir.join(".asimov/roadmap.yaml"), roadmap).unwrap();
+        for args in [&["add", "-A"][..], &["commit", "-q", "-m", "roadmap"][..]] {
+            let status = std::process::Command::new("git")
+                .args(args)
+                .current_dir(dir)
+                .env("GIT_AUTHOR_NAME", "Test")
+                .env("GIT_AUTHOR_EMAIL", "test@test.com")
+                .env("GIT_COMMITTER_NAME", "Test")
+                .env("GIT_COMMITTER_EMAIL", "test@test.com")
+                .env("GIT_AUTHOR_DATE", format!("{}T12:00:00", date))
+                .env("GIT_COMMITTER_DATE", format!("{}T12:00:00", date))
+                .status()
+                .unwrap();
+            assert!(status.success());
+        }
+    }
+
+    fn history_repo() -> TempDir {
+        let temp = TempDir::new().unwrap();
+        let dir = temp.path();
+        std::process::Command::new("git")
+            .args(["init", "-q"])
+            .current_dir(dir)
+            .status()
+            .unwrap();
+        std::fs::create_dir_all(dir.join(".asimov/sessions")).unwrap();
+        std::fs::write(
+            dir.join(".asimov/sessions/20260106-090000-1.jsonl"),
+            r#"{"event":"start","id":"20260106-090000-1","time":"2026-01-06T09:00:00+00:00","source":"launch","ai":"claude","autonomy":"auto","supervisor":false,"role":null,"milestone":"1.0.0","wip":"a","protocol_hash":"sha256:0","head":null,"asimov_version":"12.4.0"}"#,
+        )
+        .unwrap();
+
+        let milestone = |status: &str, a: &str, b: &str, c: &str| {
+            format!(
+                "current:\n  version: '1.0.0'\n  status: {}\n  summary: One\n  deliverables:\n    - id: a\n      status: {}\n    - id: b\n      status: {}\n    - \"[{}] c\"\n",
+                status, a, b, c
+            )
+        };
+        commit_roadmap(
+            dir,
+            "2026-01-05",
+            &milestone("in_progress", "todo", "todo", " "),
+        );
+        commit_roadmap(
+            dir,
+            "2026-01-07",
+            &milestone("in_progress", "done", "wip", " "),
+        );
+        commit_roadmap(
+            dir,
+            "2026-01-14",
+            &milestone("released", "done", "done", "x"),
+        );
+        commit_roadmap(
+            dir,
+            "2026-01-15",
+            "current:\n  version: '1.1.0'\n  status: in_progress\n  summary: Two\n  deliverables:\n    - id: x\n    - id: y\n",
+        );
+        temp
+    }
+
+    #[test]
+    fn test_stats_history() {
+        let temp = history_repo();
+        let history = run_stats(temp.path()).history;
+
+        assert_eq!(history.milestones.len(), 2);
+        let one = &history.milestones[0];
+        assert_eq!(one.version, "1.0.0");
+        assert_eq!(one.started.as_deref(), Some("2026-01-05"));
+        assert_eq!(one.released.as_deref(), Some("2026-01-14"));
+        assert_eq!(one.days, Some(9));
+        assert_eq!((one.sessions, one.autonomous_sessions), (1, 1));
+        assert_eq!(history.milestones[1].released, None);
+        assert_eq!(history.milestones[1].days, None);
+
+        assert_eq!(history.weekly[0].week, "2026-W02");
+        assert_eq!(history.weekly[0].completed, 1);
+        assert_eq!(history.weekly[1].week, "2026-W03");
+        assert_eq!(history.weekly[1].completed, 2);
+
+        assert_eq!(
+            history.burndown,
+            vec![BurndownPoint {
+                date: "2026-01-15".to_string(),
+                total: 2,
+                remaining: 2,
+            }]
+        );
+
+        // Uncommitted progress is today's point
+        std::fs::write(
+            temp.path().join(".asimov/roadmap.yaml"),
+            "current:\n  version: '1.1.0'\n  status: in_progress\n  summary: Two\n  deliverables:\n    - id: x\n      status: done\n    - id: y\n",
+        )
+        .unwrap();
+        let burndown = run_stats(temp.path()).history.burndown;
+        assert_eq!(burndown.len(), 2);
+        assert_eq!(burndown[1].remaining, 1);
+        assert_eq!(burndown[1].date, Local::now().date_naive().to_string());
+    }
+
+    #[test]
+    fn test_stats_since() {
+        let temp = history_repo();
+        let options = StatsOptions {
+            since: Some("2026-01-15".to_string()),
+        };
+        let result = run_stats_with_options(temp.path(), &options).unwrap();
+        assert_eq!(result.since.as_deref(), Some("2026-01-15"));
+        assert_eq!(result.history.milestones.len(), 1);
+        assert_eq!(result.history.milestones[0].version, "1.1.0");
+        assert_eq!(result.history.weekly[0].week, "2026-W03");
+        assert_eq!(result.history.weekly[0].completed, 0);
+
+        let relative = StatsOptions {
+            since: Some("2 weeks ago".to_string()),
+        };
+        let result = run_stats_with_options(temp.path(), &relative).unwrap();
+        let expected = Local::now().date_naive() - Duration::days(14);
+        assert_eq!(result.since, Some(expected.to_string()));
+
+        let outside = TempDir::new().unwrap();
+        let err = run_stats_with_options(outside.path(), &relative).unwrap_err();
+        assert!(err.contains("Cannot resolve --since"), "{}", err);
+    }
+
+    #[test]
+    fn test_changelog_dates() {
+        let temp = history_repo();
+        std::fs::write(
+            temp.path().join("CHANGELOG.md"),
+            "# Changelog\n\n## [Unreleased]\n\n## [1.1.0]\n\n## [v1.0.0] - 2026-01-13\n\n## [0.9.0] - 2025-12-01\n",
+        )
+        .unwrap();
+        commit_roadmap(
+            temp.path(),
+            "2026-01-20",
+            "current:\n  version: '1.1.0'\n  status: in_progress\n  summary: Two\nnext:\n  - version: '0.9.0'\n    status: released\n    summary: Old\n",
+        );
+
+        let dates = changelog_dates(temp.path());
+        assert_eq!(dates.len(), 3);
+        assert_eq!(dates["1.0.0"].to_string(), "2026-01-13");
+        assert_eq!(dates["1.1.0"].to_string(), "2026-01-20");
+
+        // Observed in the roadmap beats CHANGELOG.md; first seen released does not
+        let milestones = run_stats(temp.path()).history.milestones;
+        assert_eq!(milestones[0].released.as_deref(), Some("2026-01-14"));
+        assert_eq!(milestones[2].version, "0.9.0");
+        assert_eq!(milestones[2].released.as_deref(), Some("2025-12-01"));
+    }
 }
@@ -0,0 +1,105 @@
+//! Template pack command - v12.4.0
+//!
+//! Install, list and remove user-level template packs.
+//! Packs live in the user config directory (see `paths::user_templates_dir`).
+
+use crate::paths::user_templates_dir;
+use crate::templates::packs::{self, TemplateManifest};
+use crate::templates::{list_templates, templates_dir};
+use std::path::{Path, PathBuf};
+
+/// Template list result
+#[derive(Debug, Clone)]
+pub struct TemplateListResult {
+    /// Embedded templates shipped with the binary
+    pub builtin: Vec<String>,
+    /// Project-local templates in .asimov/templates/
+    pub local: Vec<String>,
+    /// Installed user-level packs
+    pub packs: Vec<TemplateManifest>,
+    /// Where packs are installed (None if no user directory could be resolved)
+    pub packs_dir: Option<PathBuf>,
+}
+
+fn require_templates_dir() -> Result<PathBuf, String> {
+    user_templates_dir()
+        .ok_or_else(|| "Cannot determine user config directory. Set ASIMOV_HOME".to_string())
+}
+
+/// Install a template pack from a directory or .tar.gz
+pub fn run_template_install(source: &Path, force: bool) -> Result<TemplateManifest, String> {
+    let root = require_templates_dir()?;
+    packs::install_pack(source, &root, force)
+}
+
+/// Remove an installed template pack
+pub fn run_template_remove(name: &str) -> Result<TemplateManifest, String> {
+    let root = require_templates_dir()?;
+    packs::remove_pack(&root, name)
+}
+
+/// List embedded, project-local and installed templates
+pub fn run_template_list() -> TemplateListResult {
+    run_template_list_in(user_templates_dir())
+}
+
+/// `run_template_list` with packs read from `packs_dir`
+pub fn run_template_list_in(packs_dir: Option<PathBuf>) -> TemplateListResult {
+    let packs = packs_dir
+        .as_deref()
+        .map(packs::list_packs)
+        .unwrap_or_default();
+
+    let local: Vec<String> = std::fs::read_dir(templates_dir())
+        .map(|entries| {
+            let mut names: Vec<String> = entries
+                .flatten()
+                .filter_map(|e| {
+                    e.file_name()
+                        .to_string_lossy()
+                        .strip_suffix(".yaml")
+                        .map(|s| s.to_string())
+                })
+                .collect();
+            names.sort();
+            names
+        })
+        .unwrap_or_default();
+
+    let builtin = list_templates()
+        .into_iter()
+        .filter(|name| !local.contains(name) && !packs.iter().any(|p| &p.name == name))
+        .collect();
+
+    TemplateListResult {
+        builtin,
+        local,
+        packs,
+        packs_dir,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_run_template_list_has_builtins() {
+        // Not the developer's installed packs
+        let home = TempDir::new().unwrap();
+        let packs_dir = home.path().join("templates");
+        let result = run_template_list_in(Some(packs_dir.clone()));
+
+        assert_eq!(result.packs_dir, Some(packs_dir));
+        assert!(result.packs.is_empty());
+        assert!(result.builtin.contains(&"rust".to_string()) || !result.local.is_empty());
+        assert!(result.builtin.len() + result.local.len() >= 21);
+    }
+
+    #[test]
+    fn test_run_template_install_missing_source() {
+        let result = run_template_install(Path::new("/nonexistent/pack"), false);
+        assert!(result.is_err());
+    }
+}
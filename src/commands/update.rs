@@ -1,6 +1,24 @@
 //! Update command implementation
+//! v12.4.0: Channels, pinned versions and rollback
 
-use crate::{check_for_update, perform_update};
+use crate::update::{
+    binary_version, check_channel, perform_update, pinned_version_check, require_signed_release,
+    rollback_binary, Channel, VersionCheck,
+};
+
+/// Options for `asimov update` (v12.4.0)
+#[derive(Debug, Clone, Default)]
+pub struct UpdateOptions {
+    /// Only check, don't install
+    pub check_only: bool,
+    /// Install without a valid release signature
+    pub insecure: bool,
+    pub channel: Channel,
+    /// Install exactly this release (may be older than the running one)
+    pub version: Option<String>,
+    /// Restore the binary replaced by the last update
+    pub rollback: bool,
+}
 
 #[derive(Debug, Clone)]
 pub enum UpdateResult {
@@ -29,54 +47,100 @@ pub enum UpdateResult {
     CheckFailed {
         error: String,
     },
+    /// v12.4.0: `to` is the restored binary's version, when it reports one
+    RolledBack {
+        from: String,
+        to: Option<String>,
+    },
+    RollbackFailed {
+        error: String,
+    },
 }
 
-pub fn run_update(check_only: bool) -> UpdateResult {
-    match check_for_update() {
-        Ok(info) => {
-            if info.update_available {
-                if check_only {
-                    return UpdateResult::UpdateAvailable {
-                        current: info.current,
-                        latest: info.latest,
-                    };
-                }
-                if let Some(url) = info.download_url {
-                    match perform_update(&url, info.checksums_url.as_deref()) {
-                        Ok(()) => UpdateResult::Updated {
-                            from: info.current,
-                            to: info.latest,
-                        },
-                        Err(e) => UpdateResult::UpdateFailed {
-                            current: info.current,
-                            latest: info.latest,
-                            error: e,
-                            download_url: url,
-                        },
-                    }
-                } else {
-                    UpdateResult::NoBinaryAvailable {
-                        current: info.current,
-                        latest: info.latest,
-                    }
-                }
-            } else {
-                UpdateResult::AlreadyLatest {
-                    current: info.current,
-                    latest: info.latest,
-                }
+/// Check for, and unless `check_only` install, a release
+pub fn run_update(options: &UpdateOptions) -> UpdateResult {
+    if options.rollback {
+        return run_rollback();
+    }
+    let check = match &options.version {
+        Some(version) => pinned_version_check(version).and_then(|info| {
+            if !options.insecure && !options.check_only {
+                require_signed_release(&info.latest)?;
             }
-        }
+            Ok(info)
+        }),
+        None => check_channel(options.channel),
+    };
+    match check {
+        Ok(info) => install_release(info, options),
         Err(e) => UpdateResult::CheckFailed {
             error: e.to_string(),
         },
     }
 }
 
+fn install_release(info: VersionCheck, options: &UpdateOptions) -> UpdateResult {
+    if !info.update_available {
+        return UpdateResult::AlreadyLatest {
+            current: info.current,
+            latest: info.latest,
+        };
+    }
+    if options.check_only {
+        return UpdateResult::UpdateAvailable {
+            current: info.current,
+            latest: info.latest,
+        };
+    }
+    let Some(url) = info.download_url else {
+        return UpdateResult::NoBinaryAvailable {
+            current: info.current,
+            latest: info.latest,
+        };
+    };
+    match perform_update(&url, info.checksums_url.as_deref(), options.insecure) {
+        Ok(()) => UpdateResult::Updated {
+            from: info.current,
+            to: info.latest,
+        },
+        Err(e) => UpdateResult::UpdateFailed {
+            current: info.current,
+            latest: info.latest,
+            error: e.to_string(),
+            download_url: url,
+        },
+    }
+}
+
+#[cfg_attr(feature = "coverage", coverage(off))]
+fn run_rollback() -> UpdateResult {
+    let rollback = std::env::current_exe()
+        .map_err(|e| format!("Could not determine current executable path: {}", e))
+        .and_then(|exe| {
+            rollback_binary(&exe)
+                .map_err(|e| e.to_string())
+                .map(|_| exe)
+        });
+    match rollback {
+        Ok(exe) => UpdateResult::RolledBack {
+            from: crate::CURRENT_VERSION.to_string(),
+            to: binary_version(&exe),
+        },
+        Err(error) => UpdateResult::RollbackFailed { error },
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    fn check_only() -> UpdateOptions {
+        UpdateOptions {
+            check_only: true,
+            ..Default::default()
+        }
+    }
+
     #[test]
     fn test_update_result_variants() {
         let _ = UpdateResult::AlreadyLatest {
@@ -104,13 +168,47 @@ mod tests {
         let _ = UpdateResult::CheckFailed {
             error: "err".to_string(),
         };
+        let _ = UpdateResult::RolledBack {
+            from: "2.0".to_string(),
+            to: Some("1.0".to_string()),
+        };
+        let _ = UpdateResult::RollbackFailed {
+            error: "err".to_string(),
+        };
+    }
+
+    #[test]
+    fn test_run_update_invalid_pinned_version() {
+        let result = run_update(&UpdateOptions {
+            version: Some("latest".to_string()),
+            ..Default::default()
+        });
+        match result {
+            UpdateResult::CheckFailed { error } => assert!(error.contains("Invalid version")),
+            other => panic!("Unexpected result: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_run_update_unsigned_pinned_version() {
+        let result = run_update(&UpdateOptions {
+            version: Some("12.3.0".to_string()),
+            ..Default::default()
+        });
+        match result {
+            UpdateResult::CheckFailed { error } => {
+                assert!(error.contains("predates signed releases (the first is 12.4.0)"));
+                assert!(error.contains("--insecure"));
+            }
+            other => panic!("Unexpected result: {:?}", other),
+        }
     }
 
     #[test]
     fn test_run_update_check_only() {
         // This tests the check_only path - won't actually update
         // Note: This will make a network call to check for updates
-        let result = run_update(true);
+        let result = run_update(&check_only());
         // Result depends on network state and version comparison
         match result {
             UpdateResult::AlreadyLatest { .. } => {}
@@ -124,8 +222,8 @@ mod tests {
     fn test_run_update_network() {
         // This test exercises the network code path
         // May succeed or fail depending on network availability
-        let result = run_update(true); // check_only mode
-                                       // Just verify it returns a valid variant
+        let result = run_update(&check_only()); // check_only mode
+                                                // Just verify it returns a valid variant
         match result {
             UpdateResult::AlreadyLatest { .. } => (),
             UpdateResult::UpdateAvailable { .. } => (),
@@ -138,7 +236,7 @@ mod tests {
     fn test_run_update_actual_check() {
         // Run the actual update check - exercises network code
         // This will hit either AlreadyLatest, UpdateAvailable, or CheckFailed
-        let result = run_update(true);
+        let result = run_update(&check_only());
 
         // Verify we got one of the expected check-only results
         let is_valid = matches!(
@@ -1,5 +1,6 @@
 //! Validate command implementation
 
+use crate::file_size::{check_project_file_sizes, FileSizeReport};
 use crate::{
     check_ethics_status, check_green_status, check_sycophancy_status, scan_directory_for_red_flags,
     validate_directory_with_regeneration, EthicsStatus, GreenStatus, SycophancyStatus,
@@ -40,6 +41,8 @@ pub struct ValidateResult {
     pub green: GreenStatus,
     pub ethics_scan: Option<EthicsScanResult>,
     pub regenerated: Vec<String>,
+    /// v12.4.0: Source file size limits (hard violations fail validation)
+    pub file_sizes: FileSizeReport,
 }
 
 pub fn run_validate(dir: &Path, ethics_scan: bool) -> ValidateResult {
@@ -53,8 +56,13 @@ pub fn run_validate(dir: &Path, ethics_scan: bool) -> ValidateResult {
         green: check_green_status(dir),
         ethics_scan: None,
         regenerated: Vec::new(),
+        file_sizes: check_project_file_sizes(dir),
     };
 
+    if !result.file_sizes.passed() {
+        result.success = false;
+    }
+
     match validate_directory_with_regeneration(dir, true) {
         Ok((results, regen_info)) => {
             for r in results {
@@ -92,6 +100,15 @@ pub fn run_validate(dir: &Path, ethics_scan: bool) -> ValidateResult {
         }
     }
 
+    // v12.4.0: project.yaml can require a minimum asimov version
+    if let Err(e) = crate::update::check_min_asimov_version(dir) {
+        result.success = false;
+        if let Some(project) = result.project.as_mut() {
+            project.valid = false;
+            project.errors.push(e);
+        }
+    }
+
     if ethics_scan {
         if let Ok(matches) = scan_directory_for_red_flags(dir) {
             let ethics_matches: Vec<EthicsMatch> = matches
@@ -203,6 +220,7 @@ mod tests {
             green: GreenStatus::Hardcoded,
             ethics_scan: None,
             regenerated: vec![],
+            file_sizes: FileSizeReport::default(),
         };
         assert!(r.success);
     }
@@ -320,4 +338,20 @@ mod tests {
         // Ethics scan will check for red flags
         assert!(result.ethics_scan.is_some());
     }
+
+    #[test]
+    fn test_run_validate_fails_on_hard_file_size_limit() {
+        let temp = TempDir::new().unwrap();
+        let asimov_dir = temp.path().join(".asimov");
+        std::fs::create_dir_all(&asimov_dir).unwrap();
+        std::fs::write(
+            asimov_dir.join("project.yaml"),
+            "identity:\n  name: test\n  type: python\n",
+        )
+        .unwrap();
+        std::fs::write(temp.path().join("big.py"), "x = 1\n".repeat(1001)).unwrap();
+        let result = run_validate(temp.path(), false);
+        assert!(!result.success);
+        assert_eq!(result.file_sizes.hard().count(), 1);
+    }
 }
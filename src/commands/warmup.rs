@@ -1,6 +1,9 @@
 //! Warmup command implementation
 //! v12.2.0: Minimal warmup - just warmup protocol + tools
+//! v12.4.0: Honors ASIMOV_OFFLINE (no network tools, offline directive)
+//! v12.4.0: Fails when the binary is older than project.yaml's min_asimov_version
 
+use crate::update::{check_min_asimov_version, is_offline};
 use crate::WarmupProtocol;
 use std::path::Path;
 
@@ -11,6 +14,8 @@ pub struct ToolInfo {
     pub path: String,
     pub version: Option<String>,
     pub directive: String,
+    /// v12.4.0: Useless without network access (dropped when offline)
+    pub requires_network: bool,
 }
 
 /// v12.2.0: Minimal warmup result - just warmup protocol + tools
@@ -22,14 +27,19 @@ pub struct WarmupResult {
     pub error: Option<String>,
     /// v9.17.0: Tool detection
     pub tools_available: Vec<ToolInfo>,
+    /// v12.4.0: ASIMOV_OFFLINE is set; the session must not use the network
+    pub offline: bool,
 }
 
+/// Directive added to warmup output in offline mode (v12.4.0)
+pub const OFFLINE_DIRECTIVE: &str = "Offline mode (ASIMOV_OFFLINE): there is no network access. Do not use WebSearch, WebFetch or other network tools; work from local files and say when current information cannot be verified.";
+
 /// Detect CLI tools available in PATH (v12.3.0: made public for refresh --json)
 pub fn detect_tools() -> Vec<ToolInfo> {
     let mut tools = Vec::new();
 
     // Helper to detect a tool
-    fn detect_tool(name: &str, directive: &str) -> Option<ToolInfo> {
+    fn detect_tool(name: &str, directive: &str, requires_network: bool) -> Option<ToolInfo> {
         #[cfg(unix)]
         let find_cmd = "which";
         #[cfg(windows)]
@@ -64,6 +74,7 @@ pub fn detect_tools() -> Vec<ToolInfo> {
             path,
             version,
             directive: directive.to_string(),
+            requires_network,
         })
     }
 
@@ -71,6 +82,7 @@ pub fn detect_tools() -> Vec<ToolInfo> {
     if let Some(tool) = detect_tool(
         "ref",
         "Use `ref fetch <url>` via Bash instead of WebFetch. Bypasses bot protection, outputs JSON.",
+        true,
     ) {
         tools.push(tool);
     }
@@ -79,6 +91,7 @@ pub fn detect_tools() -> Vec<ToolInfo> {
     if let Some(tool) = detect_tool(
         "forge",
         "Use `forge` for financial modeling. 173 functions, Monte Carlo, scenarios, decision trees.",
+        false,
     ) {
         tools.push(tool);
     }
@@ -93,6 +106,7 @@ pub fn run_warmup(dir: &Path, _check_updates: bool) -> WarmupResult {
         warmup_protocol: None,
         error: None,
         tools_available: Vec::new(),
+        offline: is_offline(),
     };
 
     // Check if .asimov directory exists
@@ -102,11 +116,19 @@ pub fn run_warmup(dir: &Path, _check_updates: bool) -> WarmupResult {
         return result;
     }
 
+    if let Err(e) = check_min_asimov_version(dir) {
+        result.error = Some(e);
+        return result;
+    }
+
     // Load warmup protocol
     result.warmup_protocol = Some(crate::protocols::load_warmup_protocol());
 
     // Detect available CLI tools
     result.tools_available = detect_tools();
+    if result.offline {
+        result.tools_available.retain(|t| !t.requires_network);
+    }
 
     result.success = true;
     result
@@ -175,6 +197,7 @@ mod tests {
             }),
             error: None,
             tools_available: vec![],
+            offline: false,
         };
         assert!(r.success);
         assert!(r.warmup_protocol.is_some());
@@ -189,6 +212,7 @@ mod tests {
             path: "/usr/bin/test-tool".to_string(),
             version: Some("1.0.0".to_string()),
             directive: "Use this tool".to_string(),
+            requires_network: false,
         };
         assert_eq!(tool.name, "test-tool");
         assert!(tool.version.is_some());
@@ -0,0 +1,355 @@
+//! Commit message policy (v12.4.0)
+//!
+//! Declared in project.yaml, enforced by the commit-msg hook and
+//! `asimov lint-commit`:
+//!
+//! ```yaml
+//! commit_policy:
+//!   types: [feat, fix, docs, refactor, test, chore]   # conventional commits
+//!   require_deliverable: true                         # subject needs [id]
+//!   validate_references: true                         # default; false allows any [tag]
+//!   banned_phrases: ["wip", "fixed stuff"]
+//! ```
+//!
+//! `[id]` references are checked against the roadmap deliverable ids (see
+//! `roadmap`), which is what lets replay and stats attribute a commit to a
+//! milestone. Without a `commit_policy` section nothing is checked, so
+//! subjects like `[WIP] ...` or `[docs] ...` keep working.
+
+use crate::roadmap::Deliverable;
+use regex::Regex;
+use serde::Deserialize;
+use std::path::Path;
+use std::sync::LazyLock;
+
+/// `type(scope)!: description`
+static CONVENTIONAL: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^([a-z]+)(?:\(([^()]*)\))?(!)?:\s+(\S.*)").unwrap());
+
+/// `[deliverable-id]`
+static REFERENCE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"\[([A-Za-z0-9][A-Za-z0-9._-]*)\]").unwrap());
+
+/// The `commit_policy:` section of project.yaml
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct CommitPolicy {
+    /// Allowed conventional-commit types; empty allows any subject
+    pub types: Vec<String>,
+    /// Subjects must reference a roadmap deliverable as `[id]`
+    pub require_deliverable: bool,
+    /// `[id]` references must name a roadmap deliverable; true once the
+    /// section exists, false without one
+    #[serde(default = "validate_references_default")]
+    pub validate_references: bool,
+    /// Rejected anywhere in the message (case-insensitive)
+    pub banned_phrases: Vec<String>,
+}
+
+fn validate_references_default() -> bool {
+    true
+}
+
+/// Load `commit_policy` from `<dir>/.asimov/project.yaml` (default if absent)
+pub fn load_commit_policy(dir: &Path) -> Result<CommitPolicy, String> {
+    let path = dir.join(".asimov").join("project.yaml");
+    let Ok(content) = std::fs::read_to_string(&path) else {
+        return Ok(CommitPolicy::default());
+    };
+    let yaml: serde_yaml_ng::Value = serde_yaml_ng::from_str(&content)
+        .map_err(|e| format!("Invalid YAML in {}: {}", path.display(), e))?;
+    match yaml.get("commit_policy") {
+        Some(policy) => serde_yaml_ng::from_value(policy.clone())
+            .map_err(|e| format!("Invalid commit_policy in {}: {}", path.display(), e)),
+        None => Ok(CommitPolicy::default()),
+    }
+}
+
+/// The parts of a commit message the policy looks at
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CommitMessage {
+    /// First line
+    pub subject: String,
+    /// Whole message without comment lines
+    pub text: String,
+    /// Conventional-commit type, if the subject has one
+    pub kind: Option<String>,
+    pub scope: Option<String>,
+    pub breaking: bool,
+    /// `[id]` references in the subject, in order
+    pub references: Vec<String>,
+}
+
+impl CommitMessage {
+    /// Parse a message as git stores it, dropping `#` comments and the
+    /// `git commit -v` diff below the scissors line
+    pub fn parse(message: &str) -> Self {
+        let text = message
+            .lines()
+            .take_while(|line| !(line.starts_with("# ") && line.contains(">8")))
+            .filter(|line| !line.starts_with('#'))
+            .collect::<Vec<_>>()
+            .join("\n")
+            .trim()
+            .to_string();
+        let subject = text.lines().next().unwrap_or_default().trim().to_string();
+
+        // References may lead the subject: "[id] feat: ..." or "feat: [id] ..."
+        let references: Vec<String> = REFERENCE
+            .captures_iter(&subject)
+            .map(|c| c[1].to_string())
+            .collect();
+        let header = REFERENCE.replace_all(&subject, "");
+        let header = header.trim_start();
+        let conventional = CONVENTIONAL.captures(header);
+
+        CommitMessage {
+            kind: conventional.as_ref().map(|c| c[1].to_string()),
+            scope: conventional
+                .as_ref()
+                .and_then(|c| c.get(2))
+                .map(|m| m.as_str().to_string()),
+            breaking: conventional.as_ref().is_some_and(|c| c.get(3).is_some()),
+            subject,
+            text,
+            references,
+        }
+    }
+
+    /// Messages git writes itself (merges, reverts, fixup!/squash!)
+    pub fn is_generated(&self) -> bool {
+        ["Merge ", "Revert \"", "fixup! ", "squash! ", "amend! "]
+            .iter()
+            .any(|prefix| self.subject.starts_with(prefix))
+    }
+
+    /// The subject without references and `type(scope)!:`, capitalized
+    pub fn description(&self) -> String {
+        let header = REFERENCE.replace_all(&self.subject, "");
+        let header = header.trim();
+        let description = match CONVENTIONAL.captures(header) {
+            Some(caps) => caps.get(4).map_or("", |m| m.as_str()).trim(),
+            None => header,
+        };
+        let description = description.split_whitespace().collect::<Vec<_>>().join(" ");
+        let mut chars = description.chars();
+        match chars.next() {
+            Some(first) => first.to_uppercase().chain(chars).collect(),
+            None => description,
+        }
+    }
+
+    /// The first referenced deliverable that exists in the roadmap
+    pub fn deliverable<'a>(&self, deliverables: &'a [Deliverable]) -> Option<&'a Deliverable> {
+        self.references
+            .iter()
+            .find_map(|r| deliverables.iter().find(|d| &d.id == r))
+    }
+}
+
+/// Policy violations of one message (empty when it complies)
+pub fn lint_commit_message(
+    message: &CommitMessage,
+    policy: &CommitPolicy,
+    deliverables: &[Deliverable],
+) -> Vec<String> {
+    let mut violations = Vec::new();
+    if message.subject.is_empty() {
+        violations.push("Empty commit message".to_string());
+        return violations;
+    }
+
+    let lower = message.text.to_lowercase();
+    for phrase in &policy.banned_phrases {
+        if !phrase.trim().is_empty() && lower.contains(&phrase.trim().to_lowercase()) {
+            violations.push(format!("Contains banned phrase '{}'", phrase.trim()));
+        }
+    }
+    if message.is_generated() {
+        return violations;
+    }
+
+    if !policy.types.is_empty() {
+        match &message.kind {
+            None => violations.push(format!(
+                "Subject is not a conventional commit: expected '<type>[(scope)][!]: <description>' with type one of {}",
+                policy.types.join(", ")
+            )),
+            Some(kind) if !policy.types.contains(kind) => violations.push(format!(
+                "Type '{}' is not allowed (allowed: {})",
+                kind,
+                policy.types.join(", ")
+            )),
+            Some(_) => {}
+        }
+    }
+
+    let ids = || {
+        if deliverables.is_empty() {
+            "roadmap.yaml has no deliverable ids".to_string()
+        } else {
+            let ids: Vec<&str> = deliverables.iter().map(|d| d.id.as_str()).collect();
+            format!("ids: {}", ids.join(", "))
+        }
+    };
+    if policy.require_deliverable && message.references.is_empty() {
+        violations.push(format!(
+            "Subject must reference a roadmap deliverable as [id] ({})",
+            ids()
+        ));
+    }
+    if policy.require_deliverable || policy.validate_references {
+        for reference in &message.references {
+            if !deliverables.iter().any(|d| &d.id == reference) {
+                violations.push(format!("Unknown deliverable [{}] ({})", reference, ids()));
+            }
+        }
+    }
+    violations
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn deliverables() -> Vec<Deliverable> {
+        vec![Deliverable {
+            id: "hook-manager".to_string(),
+            milestone: "1.2.0".to_string(),
+            summary: None,
+            status: Some("wip".to_string()),
+        }]
+    }
+
+    fn policy() -> CommitPolicy {
+        CommitPolicy {
+            types: vec!["feat".to_string(), "fix".to_string()],
+            require_deliverable: true,
+            validate_references: true,
+            banned_phrases: vec!["Fixed stuff".to_string()],
+        }
+    }
+
+    #[test]
+    fn test_parse_commit_message() {
+        let message =
+            CommitMessage::parse("[hook-manager] feat(hooks)!: chain hooks\n\nBody\n# comment\n");
+        assert_eq!(message.kind.as_deref(), Some("feat"));
+        assert_eq!(message.scope.as_deref(), Some("hooks"));
+        assert!(message.breaking);
+        assert_eq!(message.references, vec!["hook-manager"]);
+        assert_eq!(
+            message.text,
+            "[hook-manager] feat(hooks)!: chain hooks\n\nBody"
+        );
+        assert_eq!(message.description(), "Chain hooks");
+
+        let message = CommitMessage::parse("fix: [a] and [b.c] done");
+        assert_eq!(message.kind.as_deref(), Some("fix"));
+        assert_eq!(message.references, vec!["a", "b.c"]);
+        assert_eq!(message.description(), "And done");
+
+        let message = CommitMessage::parse("feat: éclair support");
+        assert_eq!(message.description(), "Éclair support");
+        let message = CommitMessage::parse("[i18n] fix(ui): 日本語 labels");
+        assert_eq!(message.description(), "日本語 labels");
+
+        let message = CommitMessage::parse("Update README");
+        assert_eq!(message.kind, None);
+        assert!(message.references.is_empty());
+
+        let verbose =
+            "fix: x\n# ------------------------ >8 ------------------------\ndiff --git a b\n";
+        assert_eq!(CommitMessage::parse(verbose).text, "fix: x");
+    }
+
+    #[test]
+    fn test_lint_commit_message() {
+        let ok = CommitMessage::parse("feat: [hook-manager] chain existing hooks");
+        assert!(lint_commit_message(&ok, &policy(), &deliverables()).is_empty());
+        assert_eq!(ok.deliverable(&deliverables()).unwrap().milestone, "1.2.0");
+
+        let bad = CommitMessage::parse("Fixed stuff");
+        let violations = lint_commit_message(&bad, &policy(), &deliverables());
+        assert_eq!(violations.len(), 3, "{:?}", violations);
+        assert!(violations[0].contains("banned phrase 'Fixed stuff'"));
+        assert!(violations[1].contains("not a conventional commit"));
+        assert!(violations[2].contains("ids: hook-manager"));
+
+        let wrong = CommitMessage::parse("chore: [nope] tidy");
+        let violations = lint_commit_message(&wrong, &policy(), &deliverables());
+        assert!(violations[0].contains("Type 'chore' is not allowed"));
+        assert!(violations[1].contains("Unknown deliverable [nope]"));
+
+        let merge = CommitMessage::parse("Merge branch 'main'");
+        assert!(lint_commit_message(&merge, &policy(), &deliverables()).is_empty());
+        let empty = CommitMessage::parse("# only a comment\n");
+        assert_eq!(
+            lint_commit_message(&empty, &policy(), &deliverables()),
+            vec!["Empty commit message"]
+        );
+    }
+
+    #[test]
+    fn test_lint_without_policy_allows_any_tag() {
+        let default = CommitPolicy::default();
+        for subject in ["[user-045] Add hooks", "[WIP] half done", "[docs] Fix typo"] {
+            let tagged = CommitMessage::parse(subject);
+            assert!(lint_commit_message(&tagged, &default, &deliverables()).is_empty());
+        }
+
+        // A section without require_deliverable still validates references...
+        let policy = CommitPolicy {
+            validate_references: true,
+            ..Default::default()
+        };
+        let tagged = CommitMessage::parse("[WIP] half done");
+        let violations = lint_commit_message(&tagged, &policy, &deliverables());
+        assert_eq!(violations.len(), 1);
+        assert!(violations[0].contains("Unknown deliverable [WIP]"));
+        // ...unless it opts out
+        let policy = CommitPolicy {
+            types: vec!["feat".to_string()],
+            ..Default::default()
+        };
+        let tagged = CommitMessage::parse("[WIP] feat: half done");
+        assert!(lint_commit_message(&tagged, &policy, &deliverables()).is_empty());
+    }
+
+    #[test]
+    fn test_load_commit_policy() {
+        let temp = TempDir::new().unwrap();
+        assert_eq!(
+            load_commit_policy(temp.path()).unwrap(),
+            CommitPolicy::default()
+        );
+
+        std::fs::create_dir_all(temp.path().join(".asimov")).unwrap();
+        let project = temp.path().join(".asimov/project.yaml");
+        std::fs::write(
+            &project,
+            "commit_policy:\n  types: [feat]\n  require_deliverable: true\n",
+        )
+        .unwrap();
+        let policy = load_commit_policy(temp.path()).unwrap();
+        assert_eq!(policy.types, vec!["feat"]);
+        assert!(policy.require_deliverable);
+        assert!(
+            policy.validate_references,
+            "on by default within the section"
+        );
+        assert!(policy.banned_phrases.is_empty());
+
+        std::fs::write(
+            &project,
+            "commit_policy:\n  types: [feat]\n  validate_references: false\n",
+        )
+        .unwrap();
+        assert!(!load_commit_policy(temp.path()).unwrap().validate_references);
+
+        std::fs::write(&project, "commit_policy:\n  type: [feat]\n").unwrap();
+        let err = load_commit_policy(temp.path()).unwrap_err();
+        assert!(err.contains("Invalid commit_policy"), "{}", err);
+    }
+}
@@ -0,0 +1,184 @@
+//! Offline advisory database (v12.4.0)
+//!
+//! A local YAML or JSON file, refreshed however the team likes (a mirror
+//! job, a vendored copy) and never fetched by asimov itself:
+//!
+//! ```yaml
+//! advisories:
+//!   - id: GHSA-jf85-cpcp-j695
+//!     ecosystem: npm
+//!     package: lodash
+//!     affected: ["<4.17.12"]
+//!     severity: high
+//!     summary: Prototype pollution
+//! ```
+//!
+//! `affected` entries are comma-separated comparators (`>=1.0, <1.2.3`),
+//! an exact version, or `*`. Any matching entry flags the package.
+
+use super::{Ecosystem, LockedPackage};
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use std::path::Path;
+
+/// Default database location, relative to the project root
+pub const DEFAULT_ADVISORY_DB: &str = ".asimov/advisories.yaml";
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Advisory {
+    pub id: String,
+    pub ecosystem: Ecosystem,
+    pub package: String,
+    #[serde(default)]
+    pub affected: Vec<String>,
+    #[serde(default)]
+    pub severity: Option<String>,
+    #[serde(default)]
+    pub summary: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct AdvisoryDb {
+    #[serde(default)]
+    pub advisories: Vec<Advisory>,
+}
+
+/// A locked package matched by an advisory
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct AdvisoryMatch {
+    pub advisory: Advisory,
+    pub version: String,
+}
+
+impl AdvisoryDb {
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let content = std::fs::read_to_string(path)
+            .map_err(|e| format!("Failed to read advisory database {}: {}", path.display(), e))?;
+        serde_yaml_ng::from_str(&content)
+            .map_err(|e| format!("Invalid advisory database {}: {}", path.display(), e))
+    }
+
+    /// Advisories affecting a locked package
+    pub fn matches<'a>(&'a self, package: &'a LockedPackage) -> impl Iterator<Item = &'a Advisory> {
+        self.advisories.iter().filter(move |a| {
+            a.ecosystem == package.ecosystem
+                && a.package == package.name
+                && a.affected
+                    .iter()
+                    .any(|r| version_matches(r, &package.version))
+        })
+    }
+}
+
+/// True if `version` satisfies every comparator in `requirement`
+pub fn version_matches(requirement: &str, version: &str) -> bool {
+    requirement.split(',').map(str::trim).all(|comparator| {
+        if comparator.is_empty() || comparator == "*" {
+            return true;
+        }
+        let (op, bound) = ["<=", ">=", "==", "<", ">", "="]
+            .iter()
+            .find_map(|op| comparator.strip_prefix(op).map(|rest| (*op, rest.trim())))
+            .unwrap_or(("=", comparator));
+        let ordering = compare_versions(version, bound);
+        match op {
+            "<" => ordering == Ordering::Less,
+            "<=" => ordering != Ordering::Greater,
+            ">" => ordering == Ordering::Greater,
+            ">=" => ordering != Ordering::Less,
+            _ => ordering == Ordering::Equal,
+        }
+    })
+}
+
+/// Compare dotted versions numerically; a pre-release sorts before its release.
+/// Accepts `v` prefixes (Go) and ignores build metadata.
+pub fn compare_versions(a: &str, b: &str) -> Ordering {
+    fn split(v: &str) -> (Vec<&str>, Option<&str>) {
+        let v = v.trim().trim_start_matches('v');
+        let v = v.split('+').next().unwrap_or(v);
+        let (core, pre) = match v.split_once('-') {
+            Some((core, pre)) => (core, Some(pre)),
+            None => (v, None),
+        };
+        (core.split('.').collect(), pre)
+    }
+
+    let ((a_core, a_pre), (b_core, b_pre)) = (split(a), split(b));
+    for i in 0..a_core.len().max(b_core.len()) {
+        let (x, y) = (
+            a_core.get(i).copied().unwrap_or("0"),
+            b_core.get(i).copied().unwrap_or("0"),
+        );
+        let ordering = match (x.parse::<u64>(), y.parse::<u64>()) {
+            (Ok(x), Ok(y)) => x.cmp(&y),
+            _ => x.cmp(y),
+        };
+        if ordering != Ordering::Equal {
+            return ordering;
+        }
+    }
+    match (a_pre, b_pre) {
+        (None, None) => Ordering::Equal,
+        (Some(_), None) => Ordering::Less,
+        (None, Some(_)) => Ordering::Greater,
+        (Some(x), Some(y)) => x.cmp(y),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compare_versions() {
+        assert_eq!(compare_versions("1.10.0", "1.9.9"), Ordering::Greater);
+        assert_eq!(compare_versions("v0.14.0", "0.14"), Ordering::Equal);
+        assert_eq!(compare_versions("2.0.0-rc.1", "2.0.0"), Ordering::Less);
+        assert_eq!(compare_versions("1.0.0+build.5", "1.0.0"), Ordering::Equal);
+    }
+
+    #[test]
+    fn test_version_matches() {
+        assert!(version_matches("<4.17.12", "4.17.11"));
+        assert!(!version_matches("<4.17.12", "4.17.12"));
+        assert!(version_matches(">=1.0, <1.2.3", "1.2.0"));
+        assert!(!version_matches(">=1.0, <1.2.3", "0.9.0"));
+        assert!(version_matches("1.2.3", "1.2.3"));
+        assert!(version_matches("*", "9.9.9"));
+    }
+
+    #[test]
+    fn test_matches_ecosystem_and_range() {
+        let db: AdvisoryDb = serde_yaml_ng::from_str(
+            r#"
+advisories:
+  - id: TEST-1
+    ecosystem: npm
+    package: lodash
+    affected: ["<4.17.12", ">=5.0.0, <5.0.2"]
+  - id: TEST-2
+    ecosystem: cargo
+    package: lodash
+    affected: ["*"]
+"#,
+        )
+        .unwrap();
+        let package = |version: &str| LockedPackage {
+            ecosystem: Ecosystem::Npm,
+            name: "lodash".to_string(),
+            version: version.to_string(),
+            direct: true,
+            license: None,
+            dependencies: Vec::new(),
+        };
+        let ids = |version: &str| {
+            db.matches(&package(version))
+                .map(|a| a.id.clone())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(ids("4.17.11"), vec!["TEST-1"]);
+        assert_eq!(ids("5.0.1"), vec!["TEST-1"]);
+        assert!(ids("4.17.21").is_empty());
+    }
+}
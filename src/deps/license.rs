@@ -0,0 +1,624 @@
+//! Dependency license compatibility (v12.4.0, ADR-045)
+//!
+//! Licenses come from the lockfile when it records them (package-lock.json),
+//! otherwise from local package metadata: `~/.cargo/registry/src`,
+//! `node_modules/*/package.json`, virtualenv `*.dist-info/METADATA`, the Go
+//! module cache and the pub cache. Nothing is fetched.
+//!
+//! ```yaml
+//! licenses:
+//!   allow: [MIT, Apache-2.0, BSD-*, ISC, Unicode-*]
+//!   deny: [AGPL-*, GPL-*, SSPL-*]
+//!   exceptions: [some-reviewed-crate]
+//!   unknown: warn            # allow | warn | deny
+//! ```
+//!
+//! Without a `licenses` section, strong copyleft licenses are denied unless
+//! the project itself is copyleft.
+
+use super::lockfile::{toml_tables, unquote};
+use super::{Ecosystem, Lockfile};
+use crate::commands::detect_license;
+use crate::workspace::wildcard_match;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+/// Broad license families for compatibility warnings
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LicenseFamily {
+    Permissive,
+    WeakCopyleft,
+    StrongCopyleft,
+    /// Proprietary and source-available (Elastic-2.0, BUSL)
+    Proprietary,
+    Unknown,
+}
+
+/// Classify an SPDX id (or expression, by its most permissive alternative)
+pub fn license_family(license: &str) -> LicenseFamily {
+    let upper = license.to_uppercase().replace('/', " OR ");
+    if upper.contains(" OR ") {
+        return upper
+            .split(" OR ")
+            .map(|alt| license_family(alt.trim_matches(|c| c == '(' || c == ')' || c == ' ')))
+            .min_by_key(|f| match f {
+                LicenseFamily::Permissive => 0,
+                LicenseFamily::WeakCopyleft => 1,
+                LicenseFamily::StrongCopyleft => 2,
+                LicenseFamily::Proprietary => 3,
+                LicenseFamily::Unknown => 4,
+            })
+            .unwrap_or(LicenseFamily::Unknown);
+    }
+    if upper.contains("AGPL")
+        || upper.contains("SSPL")
+        || (upper.contains("GPL") && !upper.contains("LGPL"))
+    {
+        LicenseFamily::StrongCopyleft
+    } else if upper.contains("LGPL") || upper.contains("MPL") || upper.contains("EPL") {
+        LicenseFamily::WeakCopyleft
+    } else if [
+        "MIT",
+        "APACHE",
+        "BSD",
+        "ISC",
+        "ZLIB",
+        "UNLICENSE",
+        "0BSD",
+        "CC0",
+        "UNICODE",
+    ]
+    .iter()
+    .any(|p| upper.contains(p))
+    {
+        LicenseFamily::Permissive
+    } else if ["PROPRIETARY", "UNLICENSED", "ELASTIC", "BUSL"]
+        .iter()
+        .any(|p| upper.contains(p))
+    {
+        LicenseFamily::Proprietary
+    } else {
+        LicenseFamily::Unknown
+    }
+}
+
+/// What to do with dependencies whose license cannot be determined
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum UnknownLicense {
+    Allow,
+    #[default]
+    Warn,
+    Deny,
+}
+
+/// The `licenses` section of project.yaml
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LicensePolicy {
+    /// If set, every dependency needs one of these (wildcards allowed)
+    #[serde(default)]
+    pub allow: Vec<String>,
+    #[serde(default)]
+    pub deny: Vec<String>,
+    /// Package names exempt from the policy (reviewed exceptions)
+    #[serde(default)]
+    pub exceptions: Vec<String>,
+    #[serde(default)]
+    pub unknown: UnknownLicense,
+}
+
+impl LicensePolicy {
+    /// Policy declared in `<dir>/.asimov/project.yaml`, if any
+    pub fn load(dir: &Path) -> Result<Option<Self>, String> {
+        let Some(yaml) = std::fs::read_to_string(dir.join(".asimov").join("project.yaml"))
+            .ok()
+            .and_then(|c| serde_yaml_ng::from_str::<serde_yaml_ng::Value>(&c).ok())
+        else {
+            return Ok(None);
+        };
+        yaml.get("licenses")
+            .map(|l| {
+                serde_yaml_ng::from_value(l.clone())
+                    .map_err(|e| format!("Invalid licenses policy in project.yaml: {}", e))
+            })
+            .transpose()
+    }
+
+    /// Default policy: no strong copyleft in non-copyleft projects
+    pub fn default_for(project_license: Option<&str>) -> Self {
+        let copyleft_project =
+            project_license.is_some_and(|l| license_family(l) == LicenseFamily::StrongCopyleft);
+        LicensePolicy {
+            deny: if copyleft_project {
+                Vec::new()
+            } else {
+                ["AGPL-*", "GPL-*", "SSPL-*"].map(str::to_string).to_vec()
+            },
+            ..Default::default()
+        }
+    }
+
+    /// Check an SPDX expression: one `OR` alternative must have every `AND` term acceptable
+    pub fn evaluate(&self, license: &str) -> Result<(), String> {
+        let expression = license.replace('/', " OR ");
+        let mut reasons = Vec::new();
+        for alternative in split_keyword(&expression, "OR") {
+            let rejected = split_keyword(&alternative, "AND")
+                .into_iter()
+                .find_map(|term| {
+                    // "Apache-2.0 WITH LLVM-exception" is judged by its license
+                    let id = split_keyword(&term, "WITH")
+                        .into_iter()
+                        .next()
+                        .unwrap_or_default();
+                    if self.deny.iter().any(|p| license_matches(p, &id)) {
+                        Some(format!("{} is denied", id))
+                    } else if !self.allow.is_empty()
+                        && !self.allow.iter().any(|p| license_matches(p, &id))
+                    {
+                        Some(format!("{} is not allowed", id))
+                    } else {
+                        None
+                    }
+                });
+            match rejected {
+                None => return Ok(()),
+                Some(reason) => reasons.push(reason),
+            }
+        }
+        Err(reasons.join("; "))
+    }
+}
+
+/// Split an SPDX expression on a keyword, ignoring parentheses
+fn split_keyword(expression: &str, keyword: &str) -> Vec<String> {
+    let mut parts = vec![String::new()];
+    for word in expression
+        .replace(['(', ')'], " ")
+        .split_whitespace()
+        .map(str::to_string)
+    {
+        if word.eq_ignore_ascii_case(keyword) {
+            parts.push(String::new());
+        } else if let Some(last) = parts.last_mut() {
+            if !last.is_empty() {
+                last.push(' ');
+            }
+            last.push_str(&word);
+        }
+    }
+    parts.retain(|p| !p.is_empty());
+    parts
+}
+
+/// Case-insensitive wildcard match; `GPL-3.0` also covers `GPL-3.0-only`, `-or-later` and `+`
+pub fn license_matches(pattern: &str, license: &str) -> bool {
+    let (pattern, license) = (pattern.trim().to_uppercase(), license.trim().to_uppercase());
+    let base = license
+        .trim_end_matches('+')
+        .trim_end_matches("-ONLY")
+        .trim_end_matches("-OR-LATER");
+    wildcard_match(&pattern, &license) || wildcard_match(&pattern, base)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct LicenseViolation {
+    pub ecosystem: Ecosystem,
+    pub name: String,
+    pub version: String,
+    pub license: Option<String>,
+    pub reason: String,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct LicenseCheck {
+    pub checked: usize,
+    pub violations: Vec<LicenseViolation>,
+    /// "name@version" of packages without license data (policy `unknown: warn`)
+    pub unknown: Vec<String>,
+}
+
+/// Evaluate every locked package against a policy
+pub fn check_licenses(lockfiles: &[Lockfile], policy: &LicensePolicy) -> LicenseCheck {
+    let mut check = LicenseCheck::default();
+    for package in lockfiles.iter().flat_map(|l| &l.packages) {
+        if policy.exceptions.iter().any(|e| e == &package.name) {
+            continue;
+        }
+        check.checked += 1;
+        let violation = |reason: String| LicenseViolation {
+            ecosystem: package.ecosystem,
+            name: package.name.clone(),
+            version: package.version.clone(),
+            license: package.license.clone(),
+            reason,
+        };
+        match &package.license {
+            Some(license) => {
+                if let Err(reason) = policy.evaluate(license) {
+                    check.violations.push(violation(reason));
+                }
+            }
+            None => match policy.unknown {
+                UnknownLicense::Allow => {}
+                UnknownLicense::Warn => check
+                    .unknown
+                    .push(format!("{}@{}", package.name, package.version)),
+                UnknownLicense::Deny => check
+                    .violations
+                    .push(violation("license unknown".to_string())),
+            },
+        }
+    }
+    check
+}
+
+/// Where local package metadata lives
+#[derive(Debug, Clone, Default)]
+pub struct PackageCaches {
+    /// `registry/src/<index>/` directories holding unpacked crates
+    pub cargo_registries: Vec<PathBuf>,
+    pub go_mod_cache: Option<PathBuf>,
+    pub pub_cache: Option<PathBuf>,
+}
+
+impl PackageCaches {
+    /// Standard locations, honouring CARGO_HOME, GOMODCACHE/GOPATH and PUB_CACHE
+    pub fn from_env() -> Self {
+        let env = |name: &str| std::env::var(name).ok().filter(|v| !v.is_empty());
+        let home = crate::paths::home_dir();
+
+        let cargo_home = env("CARGO_HOME")
+            .map(PathBuf::from)
+            .or_else(|| home.as_ref().map(|h| h.join(".cargo")));
+        let cargo_registries = cargo_home
+            .and_then(|c| std::fs::read_dir(c.join("registry").join("src")).ok())
+            .map(|entries| entries.flatten().map(|e| e.path()).collect())
+            .unwrap_or_default();
+
+        let go_mod_cache = env("GOMODCACHE").map(PathBuf::from).or_else(|| {
+            env("GOPATH")
+                .map(PathBuf::from)
+                .or_else(|| home.as_ref().map(|h| h.join("go")))
+                .map(|g| g.join("pkg").join("mod"))
+        });
+
+        let pub_cache = env("PUB_CACHE")
+            .map(PathBuf::from)
+            .or_else(|| home.as_ref().map(|h| h.join(".pub-cache")));
+
+        PackageCaches {
+            cargo_registries,
+            go_mod_cache,
+            pub_cache,
+        }
+    }
+}
+
+/// Fill in missing licenses from local package metadata
+pub fn resolve_licenses(dir: &Path, lockfiles: &mut [Lockfile], caches: &PackageCaches) {
+    let site_packages = python_site_packages(dir);
+    for lockfile in lockfiles.iter_mut() {
+        for package in lockfile.packages.iter_mut().filter(|p| p.license.is_none()) {
+            package.license = match package.ecosystem {
+                Ecosystem::Cargo => caches.cargo_registries.iter().find_map(|registry| {
+                    crate_license(&registry.join(format!("{}-{}", package.name, package.version)))
+                }),
+                Ecosystem::Npm => npm_package_license(
+                    &dir.join("node_modules")
+                        .join(&package.name)
+                        .join("package.json"),
+                ),
+                Ecosystem::PyPI => site_packages
+                    .iter()
+                    .find_map(|sp| python_license(sp, &package.name, &package.version)),
+                Ecosystem::Go => caches.go_mod_cache.as_ref().and_then(|cache| {
+                    detect_license(&cache.join(format!(
+                        "{}@{}",
+                        escape_go_module(&package.name),
+                        package.version
+                    )))
+                }),
+                Ecosystem::Pub => caches.pub_cache.as_ref().and_then(|cache| {
+                    detect_license(
+                        &cache
+                            .join("hosted")
+                            .join("pub.dev")
+                            .join(format!("{}-{}", package.name, package.version)),
+                    )
+                }),
+            };
+        }
+    }
+}
+
+/// `license` from an unpacked crate's Cargo.toml, else its LICENSE file
+fn crate_license(crate_dir: &Path) -> Option<String> {
+    let manifest = std::fs::read_to_string(crate_dir.join("Cargo.toml")).ok()?;
+    toml_tables(&manifest)
+        .iter()
+        .find(|t| t.header == "package")
+        .and_then(|t| t.get("license"))
+        .map(unquote)
+        .or_else(|| detect_license(crate_dir))
+}
+
+fn npm_package_license(package_json: &Path) -> Option<String> {
+    let json: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(package_json).ok()?).ok()?;
+    match json.get("license") {
+        Some(serde_json::Value::String(s)) => Some(s.clone()),
+        Some(serde_json::Value::Object(o)) => o.get("type")?.as_str().map(str::to_string),
+        // Legacy: "licenses": [{"type": "MIT"}, ...]
+        _ => {
+            let types: Vec<&str> = json
+                .get("licenses")?
+                .as_array()?
+                .iter()
+                .filter_map(|l| l.get("type")?.as_str())
+                .collect();
+            (!types.is_empty()).then(|| types.join(" OR "))
+        }
+    }
+}
+
+/// site-packages directories of the project's virtualenvs
+fn python_site_packages(dir: &Path) -> Vec<PathBuf> {
+    let mut found = Vec::new();
+    for venv in [".venv", "venv"] {
+        // Windows layout
+        let windows = dir.join(venv).join("Lib").join("site-packages");
+        if windows.is_dir() {
+            found.push(windows);
+        }
+        let Ok(entries) = std::fs::read_dir(dir.join(venv).join("lib")) else {
+            continue;
+        };
+        found.extend(
+            entries
+                .flatten()
+                .map(|e| e.path().join("site-packages"))
+                .filter(|p| p.is_dir()),
+        );
+    }
+    found
+}
+
+/// License from `<name>-<version>.dist-info/METADATA`
+fn python_license(site_packages: &Path, name: &str, version: &str) -> Option<String> {
+    // Wheel installers normalize "-" and "." in names to "_"
+    let normalize = |s: &str| s.to_lowercase().replace(['-', '.'], "_");
+    let wanted = normalize(&format!("{}-{}.dist-info", name, version));
+    let dist_info = std::fs::read_dir(site_packages)
+        .ok()?
+        .flatten()
+        .map(|e| e.path())
+        .find(|p| {
+            p.file_name()
+                .is_some_and(|f| normalize(&f.to_string_lossy()) == wanted)
+        })?;
+    let metadata = std::fs::read_to_string(dist_info.join("METADATA")).ok()?;
+
+    let header = |key: &str| {
+        metadata
+            .lines()
+            .take_while(|l| !l.is_empty())
+            .find_map(|l| l.strip_prefix(key))
+            .map(|v| v.trim().to_string())
+            .filter(|v| !v.is_empty() && !v.eq_ignore_ascii_case("UNKNOWN"))
+    };
+    header("License-Expression:")
+        .or_else(|| {
+            metadata
+                .lines()
+                .filter_map(|l| l.strip_prefix("Classifier: License :: "))
+                .find_map(classifier_license)
+        })
+        // Free-form License: fields are often the whole license text
+        .or_else(|| header("License:").filter(|v| v.len() <= 40))
+}
+
+/// SPDX id for a trove classifier such as "OSI Approved :: MIT License"
+fn classifier_license(classifier: &str) -> Option<String> {
+    let name = classifier.rsplit(" :: ").next()?;
+    let spdx = match name {
+        "MIT License" => "MIT",
+        "Apache Software License" => "Apache-2.0",
+        "BSD License" => "BSD-3-Clause",
+        "ISC License (ISCL)" => "ISC",
+        "Mozilla Public License 2.0 (MPL 2.0)" => "MPL-2.0",
+        "Python Software Foundation License" => "PSF-2.0",
+        "GNU General Public License v2 (GPLv2)" => "GPL-2.0",
+        "GNU General Public License v3 (GPLv3)" => "GPL-3.0",
+        "GNU Affero General Public License v3" => "AGPL-3.0",
+        "GNU Lesser General Public License v3 (LGPLv3)" => "LGPL-3.0",
+        "GNU Lesser General Public License v2 (LGPLv2)" => "LGPL-2.0",
+        "OSI Approved" => return None,
+        other => other,
+    };
+    Some(spdx.to_string())
+}
+
+/// Module cache paths escape capitals: `github.com/Foo` -> `github.com/!foo`
+fn escape_go_module(module: &str) -> String {
+    module
+        .chars()
+        .flat_map(|c| {
+            if c.is_ascii_uppercase() {
+                vec!['!', c.to_ascii_lowercase()]
+            } else {
+                vec![c]
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::deps::LockedPackage;
+    use tempfile::TempDir;
+
+    fn lockfile(ecosystem: Ecosystem, packages: &[(&str, &str, Option<&str>)]) -> Lockfile {
+        Lockfile {
+            path: "test.lock".to_string(),
+            ecosystem,
+            packages: packages
+                .iter()
+                .map(|(name, version, license)| LockedPackage {
+                    ecosystem,
+                    name: name.to_string(),
+                    version: version.to_string(),
+                    direct: true,
+                    license: license.map(str::to_string),
+                    dependencies: Vec::new(),
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_license_families() {
+        assert_eq!(
+            license_family("MIT OR Apache-2.0"),
+            LicenseFamily::Permissive
+        );
+        assert_eq!(license_family("MIT/Apache-2.0"), LicenseFamily::Permissive);
+        assert_eq!(
+            license_family("GPL-3.0-only"),
+            LicenseFamily::StrongCopyleft
+        );
+        assert_eq!(license_family("LGPL-2.1"), LicenseFamily::WeakCopyleft);
+        assert_eq!(license_family("Elastic-2.0"), LicenseFamily::Proprietary);
+    }
+
+    #[test]
+    fn test_evaluate_expressions() {
+        let policy = LicensePolicy::default_for(Some("Elastic-2.0"));
+        assert!(policy.evaluate("MIT").is_ok());
+        assert!(policy.evaluate("AGPL-3.0-or-later").is_err());
+        assert!(policy.evaluate("GPL-2.0+").is_err());
+        assert!(policy.evaluate("LGPL-2.1").is_ok());
+        assert!(policy.evaluate("(GPL-2.0 OR MIT)").is_ok());
+        assert!(policy.evaluate("MIT AND GPL-3.0").is_err());
+        assert!(policy.evaluate("Apache-2.0 WITH LLVM-exception").is_ok());
+
+        // Copyleft projects may use copyleft dependencies
+        assert!(LicensePolicy::default_for(Some("GPL-3.0"))
+            .evaluate("AGPL-3.0")
+            .is_ok());
+
+        let allow_list = LicensePolicy {
+            allow: vec!["MIT".into(), "Apache-*".into()],
+            ..Default::default()
+        };
+        assert!(allow_list.evaluate("apache-2.0").is_ok());
+        let err = allow_list.evaluate("MPL-2.0").unwrap_err();
+        assert_eq!(err, "MPL-2.0 is not allowed");
+    }
+
+    #[test]
+    fn test_check_licenses_unknown_and_exceptions() {
+        let lockfiles = [lockfile(
+            Ecosystem::Npm,
+            &[
+                ("ok", "1.0.0", Some("MIT")),
+                ("agpl", "2.0.0", Some("AGPL-3.0")),
+                ("reviewed", "1.0.0", Some("GPL-3.0")),
+                ("mystery", "0.1.0", None),
+            ],
+        )];
+        let mut policy = LicensePolicy::default_for(Some("MIT"));
+        policy.exceptions.push("reviewed".into());
+
+        let check = check_licenses(&lockfiles, &policy);
+        assert_eq!(check.checked, 3);
+        assert_eq!(check.violations.len(), 1);
+        assert_eq!(check.violations[0].name, "agpl");
+        assert_eq!(check.unknown, vec!["mystery@0.1.0"]);
+
+        policy.unknown = UnknownLicense::Deny;
+        assert_eq!(check_licenses(&lockfiles, &policy).violations.len(), 2);
+    }
+
+    #[test]
+    fn test_load_policy_from_project_yaml() {
+        let temp = TempDir::new().unwrap();
+        assert_eq!(LicensePolicy::load(temp.path()).unwrap(), None);
+        std::fs::create_dir_all(temp.path().join(".asimov")).unwrap();
+        std::fs::write(
+            temp.path().join(".asimov/project.yaml"),
+            "identity:\n  name: x\n  type: rust\nlicenses:\n  deny: [AGPL-*]\n  unknown: deny\n",
+        )
+        .unwrap();
+        let policy = LicensePolicy::load(temp.path()).unwrap().unwrap();
+        assert_eq!(policy.deny, vec!["AGPL-*"]);
+        assert_eq!(policy.unknown, UnknownLicense::Deny);
+    }
+
+    #[test]
+    fn test_resolve_licenses_from_local_metadata() {
+        let temp = TempDir::new().unwrap();
+        let root = temp.path();
+
+        // Cargo registry
+        let registry = root.join("registry/src/index.crates.io-abc");
+        std::fs::create_dir_all(registry.join("serde-1.0.0")).unwrap();
+        std::fs::write(
+            registry.join("serde-1.0.0/Cargo.toml"),
+            "[package]\nname = \"serde\"\nversion = \"1.0.0\"\nlicense = \"MIT OR Apache-2.0\"\n",
+        )
+        .unwrap();
+        // node_modules
+        std::fs::create_dir_all(root.join("node_modules/left-pad")).unwrap();
+        std::fs::write(
+            root.join("node_modules/left-pad/package.json"),
+            r#"{"licenses": [{"type": "WTFPL"}]}"#,
+        )
+        .unwrap();
+        // virtualenv
+        let dist = root.join(".venv/lib/python3.12/site-packages/Flask_Cors-4.0.0.dist-info");
+        std::fs::create_dir_all(&dist).unwrap();
+        std::fs::write(
+            dist.join("METADATA"),
+            "Metadata-Version: 2.1\nName: Flask-Cors\nLicense: UNKNOWN\nClassifier: License :: OSI Approved :: MIT License\n\nlong description\n",
+        )
+        .unwrap();
+        // Go module cache
+        let module = root.join("gomod/github.com/!burnt!sushi/toml@v1.3.2");
+        std::fs::create_dir_all(&module).unwrap();
+        std::fs::write(module.join("LICENSE"), "The MIT License (MIT)\n").unwrap();
+
+        let mut lockfiles = vec![
+            lockfile(Ecosystem::Cargo, &[("serde", "1.0.0", None)]),
+            lockfile(Ecosystem::Npm, &[("left-pad", "1.3.0", None)]),
+            lockfile(Ecosystem::PyPI, &[("flask-cors", "4.0.0", None)]),
+            lockfile(
+                Ecosystem::Go,
+                &[("github.com/BurntSushi/toml", "v1.3.2", None)],
+            ),
+            lockfile(Ecosystem::Pub, &[("http", "1.0.0", None)]),
+        ];
+        let caches = PackageCaches {
+            cargo_registries: vec![registry],
+            go_mod_cache: Some(root.join("gomod")),
+            pub_cache: None,
+        };
+        resolve_licenses(root, &mut lockfiles, &caches);
+
+        let licenses: Vec<Option<&str>> = lockfiles
+            .iter()
+            .map(|l| l.packages[0].license.as_deref())
+            .collect();
+        assert_eq!(
+            licenses,
+            vec![
+                Some("MIT OR Apache-2.0"),
+                Some("WTFPL"),
+                Some("MIT"),
+                Some("MIT"),
+                None
+            ]
+        );
+    }
+}
@@ -0,0 +1,647 @@
+//! Lockfile parsers (v12.4.0)
+//!
+//! Offline, dependency-free readers for the lockfiles `asimov deps audit`
+//! understands. Each parser returns the locked third-party packages; local
+//! workspace members are not counted.
+
+use super::{Ecosystem, LockedPackage};
+use serde_json::Value as Json;
+use serde_yaml_ng::Value as Yaml;
+use std::collections::BTreeSet;
+
+/// Cargo.lock: packages with a `source` are dependencies, the rest are workspace members
+pub fn parse_cargo_lock(content: &str) -> Vec<LockedPackage> {
+    let tables = toml_tables(content);
+    let packages: Vec<&TomlTable> = tables.iter().filter(|t| t.header == "package").collect();
+
+    let direct: BTreeSet<String> = packages
+        .iter()
+        .filter(|t| t.get("source").is_none())
+        .flat_map(|t| t.get("dependencies").map(string_array).unwrap_or_default())
+        .map(|d| d.split_whitespace().next().unwrap_or_default().to_string())
+        .collect();
+
+    packages
+        .iter()
+        .filter(|t| t.get("source").is_some())
+        .filter_map(|t| {
+            let name = unquote(t.get("name")?);
+            Some(LockedPackage {
+                ecosystem: Ecosystem::Cargo,
+                direct: direct.contains(&name),
+                version: unquote(t.get("version")?),
+                license: None,
+                // "name", "name version" or "name version (source)"
+                dependencies: t
+                    .get("dependencies")
+                    .map(string_array)
+                    .unwrap_or_default()
+                    .iter()
+                    .map(|d| d.split(" (").next().unwrap_or(d).to_string())
+                    .collect(),
+                name,
+            })
+        })
+        .collect()
+}
+
+/// poetry.lock, with direct dependencies taken from pyproject.toml when present
+pub fn parse_poetry_lock(content: &str, pyproject: Option<&str>) -> Vec<LockedPackage> {
+    let direct = pyproject.map(pyproject_dependencies).unwrap_or_default();
+    let mut packages: Vec<LockedPackage> = Vec::new();
+
+    for table in toml_tables(content) {
+        match table.header.as_str() {
+            "package" => {
+                let (Some(name), Some(version)) = (table.get("name"), table.get("version")) else {
+                    continue;
+                };
+                let name = normalize_python_name(&unquote(name));
+                packages.push(LockedPackage {
+                    ecosystem: Ecosystem::PyPI,
+                    direct: direct.contains(&name),
+                    name,
+                    version: unquote(version),
+                    license: None,
+                    dependencies: Vec::new(),
+                });
+            }
+            "package.dependencies" => {
+                if let Some(last) = packages.last_mut() {
+                    last.dependencies = table
+                        .entries
+                        .iter()
+                        .map(|(k, _)| normalize_python_name(&unquote(k)))
+                        .collect();
+                }
+            }
+            _ => {}
+        }
+    }
+    packages
+}
+
+/// Names declared in pyproject.toml (PEP 621 and Poetry sections)
+fn pyproject_dependencies(content: &str) -> BTreeSet<String> {
+    let mut names = BTreeSet::new();
+    for table in toml_tables(content) {
+        let header = table.header.as_str();
+        if header == "project" {
+            for spec in table
+                .get("dependencies")
+                .map(string_array)
+                .unwrap_or_default()
+            {
+                let name: String = spec
+                    .chars()
+                    .take_while(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+                    .collect();
+                names.insert(normalize_python_name(&name));
+            }
+        } else if header == "tool.poetry.dependencies"
+            || header == "tool.poetry.dev-dependencies"
+            || (header.starts_with("tool.poetry.group.") && header.ends_with(".dependencies"))
+        {
+            names.extend(
+                table
+                    .entries
+                    .iter()
+                    .map(|(k, _)| normalize_python_name(&unquote(k)))
+                    .filter(|k| k != "python"),
+            );
+        }
+    }
+    names
+}
+
+fn normalize_python_name(name: &str) -> String {
+    name.trim().to_lowercase().replace(['_', '.'], "-")
+}
+
+/// package-lock.json (lockfileVersion 1, 2 and 3)
+pub fn parse_package_lock(
+    content: &str,
+    package_json: Option<&str>,
+) -> Result<Vec<LockedPackage>, String> {
+    let json: Json = serde_json::from_str(content).map_err(|e| format!("Invalid JSON: {}", e))?;
+
+    if let Some(entries) = json.get("packages").and_then(|p| p.as_object()) {
+        let direct = entries
+            .get("")
+            .map(json_dependency_names)
+            .unwrap_or_default();
+        return Ok(entries
+            .iter()
+            .filter_map(|(path, entry)| {
+                // Workspace members live outside node_modules
+                let (_, name) = path.rsplit_once("node_modules/")?;
+                if entry.get("link").and_then(|l| l.as_bool()) == Some(true) {
+                    return None;
+                }
+                Some(LockedPackage {
+                    ecosystem: Ecosystem::Npm,
+                    // Nested copies are never the ones the root depends on
+                    direct: direct.contains(name) && path.matches("node_modules/").count() == 1,
+                    name: name.to_string(),
+                    version: entry.get("version")?.as_str()?.to_string(),
+                    license: npm_license(entry),
+                    dependencies: json_dependency_names(entry).into_iter().collect(),
+                })
+            })
+            .collect());
+    }
+
+    // v1: nested "dependencies" trees with "requires"
+    let direct = package_json
+        .and_then(|p| serde_json::from_str::<Json>(p).ok())
+        .map(|p| json_dependency_names(&p))
+        .unwrap_or_default();
+    let mut packages = Vec::new();
+    if let Some(deps) = json.get("dependencies") {
+        flatten_npm_v1(deps, &direct, true, &mut packages);
+    }
+    Ok(packages)
+}
+
+fn flatten_npm_v1(deps: &Json, direct: &BTreeSet<String>, top: bool, out: &mut Vec<LockedPackage>) {
+    let Some(map) = deps.as_object() else {
+        return;
+    };
+    for (name, entry) in map {
+        if let Some(version) = entry.get("version").and_then(|v| v.as_str()) {
+            out.push(LockedPackage {
+                ecosystem: Ecosystem::Npm,
+                name: name.clone(),
+                version: version.to_string(),
+                direct: top && direct.contains(name),
+                license: npm_license(entry),
+                dependencies: entry
+                    .get("requires")
+                    .and_then(|r| r.as_object())
+                    .map(|r| r.keys().cloned().collect())
+                    .unwrap_or_default(),
+            });
+        }
+        if let Some(nested) = entry.get("dependencies") {
+            flatten_npm_v1(nested, direct, false, out);
+        }
+    }
+}
+
+fn json_dependency_names(entry: &Json) -> BTreeSet<String> {
+    ["dependencies", "devDependencies", "optionalDependencies"]
+        .iter()
+        .filter_map(|key| entry.get(key).and_then(|d| d.as_object()))
+        .flat_map(|d| d.keys().cloned())
+        .collect()
+}
+
+fn npm_license(entry: &Json) -> Option<String> {
+    match entry.get("license")? {
+        Json::String(s) => Some(s.clone()),
+        Json::Object(o) => o.get("type")?.as_str().map(str::to_string),
+        _ => None,
+    }
+}
+
+/// pnpm-lock.yaml (v5 "/name/1.0.0", v6 "/name@1.0.0", v9 "name@1.0.0" + snapshots)
+pub fn parse_pnpm_lock(content: &str) -> Result<Vec<LockedPackage>, String> {
+    let yaml: Yaml =
+        serde_yaml_ng::from_str(content).map_err(|e| format!("Invalid YAML: {}", e))?;
+
+    let mut direct = BTreeSet::new();
+    let importers: Vec<&Yaml> = match yaml.get("importers").and_then(|i| i.as_mapping()) {
+        Some(importers) => importers.values().collect(),
+        None => vec![&yaml],
+    };
+    for importer in importers {
+        for key in ["dependencies", "devDependencies", "optionalDependencies"] {
+            direct.extend(yaml_keys(importer.get(key)));
+        }
+    }
+
+    // v9 moved the dependency edges (and peer variants) to `snapshots`
+    let entries = yaml.get("snapshots").or_else(|| yaml.get("packages"));
+    let mut seen = BTreeSet::new();
+    let mut packages = Vec::new();
+    for (key, entry) in entries.and_then(|p| p.as_mapping()).into_iter().flatten() {
+        let Some((name, version)) = key.as_str().and_then(pnpm_package_key) else {
+            continue;
+        };
+        if !seen.insert((name.clone(), version.clone())) {
+            continue;
+        }
+        let mut dependencies: Vec<String> =
+            yaml_keys(entry.get("dependencies")).into_iter().collect();
+        dependencies.extend(yaml_keys(entry.get("optionalDependencies")));
+        packages.push(LockedPackage {
+            ecosystem: Ecosystem::Npm,
+            direct: direct.contains(&name),
+            name,
+            version,
+            license: None,
+            dependencies,
+        });
+    }
+    Ok(packages)
+}
+
+/// Split a pnpm package key into (name, version)
+fn pnpm_package_key(key: &str) -> Option<(String, String)> {
+    let key = key.trim_start_matches('/');
+    let key = key.split('(').next().unwrap_or(key);
+    // Scoped names start with '@', so look for the version '@' after the first char
+    if let Some(at) = key[1..].rfind('@').map(|i| i + 1) {
+        return Some((key[..at].to_string(), key[at + 1..].to_string()));
+    }
+    let (name, version) = key.rsplit_once('/')?;
+    Some((name.to_string(), version.split('_').next()?.to_string()))
+}
+
+fn yaml_keys(value: Option<&Yaml>) -> BTreeSet<String> {
+    value
+        .and_then(|v| v.as_mapping())
+        .map(|m| {
+            m.keys()
+                .filter_map(|k| k.as_str())
+                .map(str::to_string)
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// go.sum module hashes, with direct requirements from go.mod when present
+pub fn parse_go_sum(content: &str, go_mod: Option<&str>) -> Vec<LockedPackage> {
+    let direct = go_mod.map(go_mod_direct).unwrap_or_default();
+    let mut seen = BTreeSet::new();
+    content
+        .lines()
+        .filter_map(|line| {
+            let mut parts = line.split_whitespace();
+            let (module, version) = (parts.next()?, parts.next()?);
+            // "/go.mod" hashes are only needed for version selection
+            if version.ends_with("/go.mod") || !seen.insert((module, version)) {
+                return None;
+            }
+            Some(LockedPackage {
+                ecosystem: Ecosystem::Go,
+                name: module.to_string(),
+                version: version.to_string(),
+                direct: direct.contains(module),
+                license: None,
+                dependencies: Vec::new(),
+            })
+        })
+        .collect()
+}
+
+/// Modules required by go.mod without an `// indirect` marker
+fn go_mod_direct(content: &str) -> BTreeSet<String> {
+    let mut direct = BTreeSet::new();
+    let mut in_block = false;
+    for line in content.lines().map(str::trim) {
+        let requirement = if in_block {
+            if line == ")" {
+                in_block = false;
+                continue;
+            }
+            line
+        } else if line == "require (" {
+            in_block = true;
+            continue;
+        } else if let Some(rest) = line.strip_prefix("require ") {
+            rest
+        } else {
+            continue;
+        };
+        if !requirement.contains("// indirect") {
+            if let Some(module) = requirement.split_whitespace().next() {
+                direct.insert(module.to_string());
+            }
+        }
+    }
+    direct
+}
+
+/// pubspec.lock: `dependency` is "direct main", "direct dev" or "transitive"
+pub fn parse_pubspec_lock(content: &str) -> Result<Vec<LockedPackage>, String> {
+    let yaml: Yaml =
+        serde_yaml_ng::from_str(content).map_err(|e| format!("Invalid YAML: {}", e))?;
+    Ok(yaml
+        .get("packages")
+        .and_then(|p| p.as_mapping())
+        .into_iter()
+        .flatten()
+        .filter_map(|(name, entry)| {
+            Some(LockedPackage {
+                ecosystem: Ecosystem::Pub,
+                name: name.as_str()?.to_string(),
+                version: entry.get("version")?.as_str()?.to_string(),
+                direct: entry
+                    .get("dependency")
+                    .and_then(|d| d.as_str())
+                    .is_some_and(|d| d.starts_with("direct")),
+                license: None,
+                dependencies: Vec::new(),
+            })
+        })
+        .collect())
+}
+
+// ============================================================================
+// Minimal TOML reader: tables, `key = value`, multi-line arrays and inline tables
+// ============================================================================
+
+pub(super) struct TomlTable {
+    pub(super) header: String,
+    pub(super) entries: Vec<(String, String)>,
+}
+
+impl TomlTable {
+    pub(super) fn get(&self, key: &str) -> Option<&str> {
+        self.entries
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, v)| v.as_str())
+    }
+}
+
+pub(super) fn toml_tables(content: &str) -> Vec<TomlTable> {
+    let mut tables = vec![TomlTable {
+        header: String::new(),
+        entries: Vec::new(),
+    }];
+    let mut pending: Option<(String, String)> = None;
+
+    for line in content.lines() {
+        if let Some((key, mut value)) = pending.take() {
+            value.push('\n');
+            value.push_str(line);
+            if bracket_depth(&value) > 0 {
+                pending = Some((key, value));
+            } else if let Some(table) = tables.last_mut() {
+                table.entries.push((key, value));
+            }
+            continue;
+        }
+
+        let trimmed = line.trim();
+        if trimmed.is_empty() || trimmed.starts_with('#') {
+            continue;
+        }
+        if trimmed.starts_with('[') && !trimmed.contains('=') {
+            tables.push(TomlTable {
+                header: trimmed
+                    .trim_matches(|c| c == '[' || c == ']')
+                    .trim()
+                    .to_string(),
+                entries: Vec::new(),
+            });
+            continue;
+        }
+        let Some((key, value)) = trimmed.split_once('=') else {
+            continue;
+        };
+        let (key, value) = (key.trim().to_string(), value.trim().to_string());
+        if bracket_depth(&value) > 0 {
+            pending = Some((key, value));
+        } else if let Some(table) = tables.last_mut() {
+            table.entries.push((key, value));
+        }
+    }
+    tables
+}
+
+/// Unclosed `[`/`{` count, ignoring brackets inside strings
+fn bracket_depth(value: &str) -> i32 {
+    let mut depth = 0;
+    let mut quote: Option<char> = None;
+    let mut escaped = false;
+    for c in value.chars() {
+        match quote {
+            Some(q) => {
+                if escaped {
+                    escaped = false;
+                } else if c == '\\' && q == '"' {
+                    escaped = true;
+                } else if c == q {
+                    quote = None;
+                }
+            }
+            None => match c {
+                '"' | '\'' => quote = Some(c),
+                '[' | '{' => depth += 1,
+                ']' | '}' => depth -= 1,
+                _ => {}
+            },
+        }
+    }
+    depth
+}
+
+pub(super) fn unquote(value: &str) -> String {
+    value
+        .trim()
+        .trim_matches(|c| c == '"' || c == '\'')
+        .to_string()
+}
+
+/// Quoted strings of a TOML array, in order
+fn string_array(value: &str) -> Vec<String> {
+    let mut items = Vec::new();
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        if c == '"' || c == '\'' {
+            let item: String = chars.by_ref().take_while(|x| *x != c).collect();
+            items.push(item);
+        }
+    }
+    items
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_cargo_lock() {
+        let lock = r#"
+version = 3
+
+[[package]]
+name = "app"
+version = "0.1.0"
+dependencies = [
+ "serde",
+ "syn 2.0.0",
+]
+
+[[package]]
+name = "serde"
+version = "1.0.0"
+source = "registry+https://github.com/rust-lang/crates.io-index"
+dependencies = [
+ "syn 1.0.0 (registry+https://github.com/rust-lang/crates.io-index)",
+]
+
+[[package]]
+name = "syn"
+version = "1.0.0"
+source = "registry+https://github.com/rust-lang/crates.io-index"
+
+[[package]]
+name = "syn"
+version = "2.0.0"
+source = "registry+https://github.com/rust-lang/crates.io-index"
+"#;
+        let packages = parse_cargo_lock(lock);
+        assert_eq!(packages.len(), 3);
+        assert!(packages.iter().all(|p| p.name != "app"));
+        let serde = packages.iter().find(|p| p.name == "serde").unwrap();
+        assert!(serde.direct);
+        assert_eq!(serde.dependencies, vec!["syn 1.0.0"]);
+    }
+
+    #[test]
+    fn test_parse_poetry_lock_with_pyproject() {
+        let lock = r#"
+[[package]]
+name = "requests"
+version = "2.31.0"
+description = "Python HTTP [for humans]"
+files = [
+    {file = "requests-2.31.0.tar.gz", hash = "sha256:abc"},
+]
+
+[package.dependencies]
+certifi = ">=2017.4.17"
+urllib3 = {version = ">=1.21.1,<3", optional = true}
+
+[[package]]
+name = "certifi"
+version = "2024.2.2"
+
+[[package]]
+name = "urllib3"
+version = "2.2.1"
+
+[metadata]
+lock-version = "2.0"
+"#;
+        let pyproject = "[tool.poetry.dependencies]\npython = \"^3.11\"\nRequests = \"^2.31\"\n";
+        let packages = parse_poetry_lock(lock, Some(pyproject));
+        assert_eq!(packages.len(), 3);
+        assert!(packages[0].direct);
+        assert_eq!(packages[0].dependencies, vec!["certifi", "urllib3"]);
+        assert!(!packages[1].direct);
+    }
+
+    #[test]
+    fn test_parse_package_lock_v3() {
+        let lock = r#"{
+  "lockfileVersion": 3,
+  "packages": {
+    "": { "name": "app", "dependencies": { "a": "^1.0.0" } },
+    "node_modules/a": { "version": "1.0.0", "license": "MIT", "dependencies": { "b": "^2" } },
+    "node_modules/b": { "version": "2.0.0", "license": "ISC" },
+    "node_modules/a/node_modules/b": { "version": "1.0.0", "license": "GPL-3.0" },
+    "packages/local": { "version": "0.0.1" },
+    "node_modules/local": { "resolved": "packages/local", "link": true }
+  }
+}"#;
+        let packages = parse_package_lock(lock, None).unwrap();
+        assert_eq!(packages.len(), 3);
+        let a = packages.iter().find(|p| p.name == "a").unwrap();
+        assert!(a.direct);
+        assert_eq!(a.license.as_deref(), Some("MIT"));
+        assert_eq!(packages.iter().filter(|p| p.name == "b").count(), 2);
+    }
+
+    #[test]
+    fn test_parse_package_lock_v1() {
+        let lock = r#"{
+  "lockfileVersion": 1,
+  "dependencies": {
+    "a": { "version": "1.0.0", "requires": { "b": "^1" },
+           "dependencies": { "b": { "version": "1.5.0" } } },
+    "b": { "version": "2.0.0" }
+  }
+}"#;
+        let package_json = r#"{ "dependencies": { "a": "^1.0.0" } }"#;
+        let packages = parse_package_lock(lock, Some(package_json)).unwrap();
+        assert_eq!(packages.len(), 3);
+        assert!(packages.iter().find(|p| p.name == "a").unwrap().direct);
+        assert!(!packages.iter().any(|p| p.name == "b" && p.direct));
+    }
+
+    #[test]
+    fn test_parse_pnpm_lock_versions() {
+        assert_eq!(
+            pnpm_package_key("/@types/node/20.1.0"),
+            Some(("@types/node".into(), "20.1.0".into()))
+        );
+        assert_eq!(
+            pnpm_package_key("/react-dom@18.2.0(react@18.2.0)"),
+            Some(("react-dom".into(), "18.2.0".into()))
+        );
+        assert_eq!(
+            pnpm_package_key("@babel/core@7.24.0"),
+            Some(("@babel/core".into(), "7.24.0".into()))
+        );
+
+        let lock = r#"
+lockfileVersion: '9.0'
+importers:
+  .:
+    dependencies:
+      a:
+        specifier: ^1.0.0
+        version: 1.0.0
+packages:
+  a@1.0.0:
+    resolution: {integrity: sha512-x}
+  b@2.0.0:
+    resolution: {integrity: sha512-y}
+snapshots:
+  a@1.0.0:
+    dependencies:
+      b: 2.0.0
+  b@2.0.0: {}
+"#;
+        let packages = parse_pnpm_lock(lock).unwrap();
+        assert_eq!(packages.len(), 2);
+        assert!(packages[0].direct);
+        assert_eq!(packages[0].dependencies, vec!["b"]);
+    }
+
+    #[test]
+    fn test_parse_go_sum_with_go_mod() {
+        let sum = "github.com/a/x v1.0.0 h1:abc=\ngithub.com/a/x v1.0.0/go.mod h1:def=\ngithub.com/b/y v0.2.0/go.mod h1:ghi=\ngolang.org/x/text v0.14.0 h1:jkl=\n";
+        let go_mod = "module example.com/app\n\ngo 1.22\n\nrequire (\n\tgithub.com/a/x v1.0.0\n\tgolang.org/x/text v0.14.0 // indirect\n)\n";
+        let packages = parse_go_sum(sum, Some(go_mod));
+        assert_eq!(packages.len(), 2);
+        assert!(packages[0].direct);
+        assert!(!packages[1].direct);
+    }
+
+    #[test]
+    fn test_parse_pubspec_lock() {
+        let lock = r#"
+packages:
+  http:
+    dependency: "direct main"
+    source: hosted
+    version: "1.2.0"
+  meta:
+    dependency: transitive
+    source: hosted
+    version: "1.11.0"
+sdks:
+  dart: ">=3.2.0 <4.0.0"
+"#;
+        let packages = parse_pubspec_lock(lock).unwrap();
+        assert_eq!(packages.len(), 2);
+        assert!(packages[0].direct);
+        assert!(!packages[1].direct);
+    }
+}
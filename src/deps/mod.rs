@@ -0,0 +1,391 @@
+//! Dependency health audit from local lockfiles (v12.4.0, ADR-045)
+//!
+//! Reads Cargo.lock, package-lock.json, pnpm-lock.yaml, go.sum, pubspec.lock
+//! and poetry.lock without network access or package-manager tooling, then
+//! reports duplicate versions, dependency count and depth (green protocol
+//! `BLOATED_DEPS`), the license mix, and matches against an optional local
+//! advisory database. Thresholds live in project.yaml:
+//!
+//! ```yaml
+//! deps:
+//!   max_dependencies: 300
+//!   max_depth: 12
+//!   advisories: .asimov/advisories.yaml
+//! ```
+
+mod advisory;
+mod license;
+mod lockfile;
+
+pub use advisory::*;
+pub use license::*;
+pub use lockfile::*;
+
+use crate::green::AntiPatternCategory;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
+use std::fmt;
+use std::path::Path;
+
+/// Above this many locked packages the audit reports BLOATED_DEPS
+pub const DEFAULT_MAX_DEPENDENCIES: usize = 300;
+/// Above this dependency depth the audit reports BLOATED_DEPS
+pub const DEFAULT_MAX_DEPTH: usize = 12;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Ecosystem {
+    Cargo,
+    Npm,
+    Go,
+    Pub,
+    PyPI,
+}
+
+impl fmt::Display for Ecosystem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Ecosystem::Cargo => write!(f, "cargo"),
+            Ecosystem::Npm => write!(f, "npm"),
+            Ecosystem::Go => write!(f, "go"),
+            Ecosystem::Pub => write!(f, "pub"),
+            Ecosystem::PyPI => write!(f, "pypi"),
+        }
+    }
+}
+
+/// A third-party package pinned by a lockfile
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct LockedPackage {
+    pub ecosystem: Ecosystem,
+    pub name: String,
+    pub version: String,
+    /// Required by the project itself (not only transitively)
+    pub direct: bool,
+    pub license: Option<String>,
+    /// Names ("name" or "name version") of the packages this one depends on
+    #[serde(skip)]
+    pub dependencies: Vec<String>,
+}
+
+/// A parsed lockfile
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Lockfile {
+    pub path: String,
+    pub ecosystem: Ecosystem,
+    pub packages: Vec<LockedPackage>,
+}
+
+/// Lockfiles read by the audit
+pub const LOCKFILES: &[(&str, Ecosystem)] = &[
+    ("Cargo.lock", Ecosystem::Cargo),
+    ("package-lock.json", Ecosystem::Npm),
+    ("pnpm-lock.yaml", Ecosystem::Npm),
+    ("go.sum", Ecosystem::Go),
+    ("pubspec.lock", Ecosystem::Pub),
+    ("poetry.lock", Ecosystem::PyPI),
+];
+
+/// Parse every known lockfile in `dir`
+pub fn load_lockfiles(dir: &Path) -> Result<Vec<Lockfile>, String> {
+    let sibling = |name: &str| std::fs::read_to_string(dir.join(name)).ok();
+    let mut lockfiles = Vec::new();
+
+    for (name, ecosystem) in LOCKFILES {
+        let Ok(content) = std::fs::read_to_string(dir.join(name)) else {
+            continue;
+        };
+        let packages = match *name {
+            "Cargo.lock" => Ok(parse_cargo_lock(&content)),
+            "package-lock.json" => parse_package_lock(&content, sibling("package.json").as_deref()),
+            "pnpm-lock.yaml" => parse_pnpm_lock(&content),
+            "go.sum" => Ok(parse_go_sum(&content, sibling("go.mod").as_deref())),
+            "pubspec.lock" => parse_pubspec_lock(&content),
+            _ => Ok(parse_poetry_lock(
+                &content,
+                sibling("pyproject.toml").as_deref(),
+            )),
+        }
+        .map_err(|e| format!("{}: {}", name, e))?;
+
+        lockfiles.push(Lockfile {
+            path: name.to_string(),
+            ecosystem: *ecosystem,
+            packages,
+        });
+    }
+    Ok(lockfiles)
+}
+
+/// Parse the lockfiles in `dir`, failing when there are none
+pub fn require_lockfiles(dir: &Path) -> Result<Vec<Lockfile>, String> {
+    let lockfiles = load_lockfiles(dir)?;
+    if lockfiles.is_empty() {
+        return Err(format!(
+            "No lockfile found ({})",
+            LOCKFILES
+                .iter()
+                .map(|(name, _)| *name)
+                .collect::<Vec<_>>()
+                .join(", ")
+        ));
+    }
+    Ok(lockfiles)
+}
+
+/// `deps` settings from project.yaml
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+pub struct DepsConfig {
+    #[serde(default)]
+    pub max_dependencies: Option<usize>,
+    #[serde(default)]
+    pub max_depth: Option<usize>,
+    /// Advisory database path, relative to the project root
+    #[serde(default)]
+    pub advisories: Option<String>,
+}
+
+impl DepsConfig {
+    pub fn load(dir: &Path) -> Self {
+        std::fs::read_to_string(dir.join(".asimov").join("project.yaml"))
+            .ok()
+            .and_then(|c| serde_yaml_ng::from_str::<serde_yaml_ng::Value>(&c).ok())
+            .and_then(|yaml| serde_yaml_ng::from_value(yaml.get("deps")?.clone()).ok())
+            .unwrap_or_default()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct LockfileSummary {
+    pub path: String,
+    pub ecosystem: Ecosystem,
+    pub packages: usize,
+    pub direct: usize,
+    /// Longest shortest-path from a direct dependency; None without graph data
+    pub depth: Option<usize>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct DuplicateDependency {
+    pub ecosystem: Ecosystem,
+    pub name: String,
+    pub versions: Vec<String>,
+}
+
+/// A green-protocol finding
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct BloatFinding {
+    pub category: String,
+    pub message: String,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct DepsAudit {
+    pub lockfiles: Vec<LockfileSummary>,
+    pub total: usize,
+    pub direct: usize,
+    pub max_depth: Option<usize>,
+    pub duplicates: Vec<DuplicateDependency>,
+    pub bloat: Vec<BloatFinding>,
+    /// Declared package licenses and how many packages use each
+    pub licenses: BTreeMap<String, usize>,
+    /// Packages whose lockfile records no license
+    pub unknown_licenses: usize,
+    pub vulnerabilities: Vec<AdvisoryMatch>,
+}
+
+/// Audit parsed lockfiles against thresholds and an optional advisory database
+pub fn audit_lockfiles(
+    lockfiles: &[Lockfile],
+    config: &DepsConfig,
+    advisories: Option<&AdvisoryDb>,
+) -> DepsAudit {
+    let mut audit = DepsAudit::default();
+
+    for lockfile in lockfiles {
+        let depth = dependency_depth(&lockfile.packages);
+        let direct = lockfile.packages.iter().filter(|p| p.direct).count();
+        audit.total += lockfile.packages.len();
+        audit.direct += direct;
+        audit.max_depth = audit.max_depth.max(depth);
+        audit.lockfiles.push(LockfileSummary {
+            path: lockfile.path.clone(),
+            ecosystem: lockfile.ecosystem,
+            packages: lockfile.packages.len(),
+            direct,
+            depth,
+        });
+
+        let mut versions: BTreeMap<&str, BTreeSet<&str>> = BTreeMap::new();
+        for package in &lockfile.packages {
+            versions
+                .entry(&package.name)
+                .or_default()
+                .insert(&package.version);
+
+            match &package.license {
+                Some(license) => *audit.licenses.entry(license.clone()).or_default() += 1,
+                None => audit.unknown_licenses += 1,
+            }
+
+            for advisory in advisories.into_iter().flat_map(|db| db.matches(package)) {
+                audit.vulnerabilities.push(AdvisoryMatch {
+                    advisory: advisory.clone(),
+                    version: package.version.clone(),
+                });
+            }
+        }
+        audit
+            .duplicates
+            .extend(
+                versions
+                    .into_iter()
+                    .filter(|(_, v)| v.len() > 1)
+                    .map(|(name, v)| DuplicateDependency {
+                        ecosystem: lockfile.ecosystem,
+                        name: name.to_string(),
+                        versions: v.into_iter().map(str::to_string).collect(),
+                    }),
+            );
+    }
+
+    let bloated = AntiPatternCategory::BloatedDeps.to_string();
+    let mut bloat = |message: String| {
+        audit.bloat.push(BloatFinding {
+            category: bloated.clone(),
+            message,
+        })
+    };
+    let max_dependencies = config.max_dependencies.unwrap_or(DEFAULT_MAX_DEPENDENCIES);
+    if audit.total > max_dependencies {
+        bloat(format!(
+            "{} locked packages (limit: {})",
+            audit.total, max_dependencies
+        ));
+    }
+    let max_depth = config.max_depth.unwrap_or(DEFAULT_MAX_DEPTH);
+    if let Some(depth) = audit.max_depth.filter(|d| *d > max_depth) {
+        bloat(format!("dependency depth {} (limit: {})", depth, max_depth));
+    }
+    if !audit.duplicates.is_empty() {
+        bloat(format!(
+            "{} package(s) locked at more than one version",
+            audit.duplicates.len()
+        ));
+    }
+
+    audit
+}
+
+/// Depth of the dependency graph, counting direct dependencies as depth 1.
+/// Without any edges (go.sum, pubspec.lock) the depth is unknown.
+fn dependency_depth(packages: &[LockedPackage]) -> Option<usize> {
+    if packages.iter().all(|p| p.dependencies.is_empty()) {
+        return None;
+    }
+
+    let mut by_name: BTreeMap<&str, Vec<usize>> = BTreeMap::new();
+    for (i, p) in packages.iter().enumerate() {
+        by_name.entry(&p.name).or_default().push(i);
+    }
+    // "name version" pins one copy; a bare name may refer to any copy
+    let resolve = |dep: &str| -> Vec<usize> {
+        let (name, version) = dep.split_once(' ').unwrap_or((dep, ""));
+        let candidates = by_name.get(name).cloned().unwrap_or_default();
+        let pinned: Vec<usize> = candidates
+            .iter()
+            .copied()
+            .filter(|i| packages[*i].version == version)
+            .collect();
+        if pinned.is_empty() {
+            candidates
+        } else {
+            pinned
+        }
+    };
+    let edges: Vec<Vec<usize>> = packages
+        .iter()
+        .map(|p| p.dependencies.iter().flat_map(|d| resolve(d)).collect())
+        .collect();
+
+    // Without direct markers, start from packages nothing else depends on
+    let mut roots: Vec<usize> = (0..packages.len())
+        .filter(|i| packages[*i].direct)
+        .collect();
+    if roots.is_empty() {
+        let referenced: BTreeSet<usize> = edges.iter().flatten().copied().collect();
+        roots = (0..packages.len())
+            .filter(|i| !referenced.contains(i))
+            .collect();
+    }
+
+    let mut depth = vec![0usize; packages.len()];
+    let mut queue: VecDeque<usize> = roots.into_iter().collect();
+    for i in &queue {
+        depth[*i] = 1;
+    }
+    while let Some(i) = queue.pop_front() {
+        for &next in &edges[i] {
+            if depth[next] == 0 {
+                depth[next] = depth[i] + 1;
+                queue.push_back(next);
+            }
+        }
+    }
+    depth.into_iter().max()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn package(name: &str, version: &str, direct: bool, deps: &[&str]) -> LockedPackage {
+        LockedPackage {
+            ecosystem: Ecosystem::Cargo,
+            name: name.to_string(),
+            version: version.to_string(),
+            direct,
+            license: None,
+            dependencies: deps.iter().map(|d| d.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn test_dependency_depth_follows_pinned_versions() {
+        let packages = vec![
+            package("a", "1.0.0", true, &["b", "c 1.0.0"]),
+            package("b", "1.0.0", false, &["c 2.0.0"]),
+            package("c", "1.0.0", false, &[]),
+            package("c", "2.0.0", false, &["d"]),
+            package("d", "1.0.0", false, &["a"]),
+        ];
+        assert_eq!(dependency_depth(&packages), Some(4));
+        assert_eq!(dependency_depth(&[package("x", "1", true, &[])]), None);
+    }
+
+    #[test]
+    fn test_audit_reports_duplicates_and_bloat() {
+        let lockfile = Lockfile {
+            path: "Cargo.lock".to_string(),
+            ecosystem: Ecosystem::Cargo,
+            packages: vec![
+                package("a", "1.0.0", true, &["syn 1.0.0"]),
+                package("syn", "1.0.0", false, &[]),
+                package("syn", "2.0.0", true, &[]),
+            ],
+        };
+        let config = DepsConfig {
+            max_dependencies: Some(2),
+            max_depth: Some(1),
+            advisories: None,
+        };
+        let audit = audit_lockfiles(&[lockfile], &config, None);
+        assert_eq!(audit.total, 3);
+        assert_eq!(audit.direct, 2);
+        assert_eq!(audit.max_depth, Some(2));
+        assert_eq!(audit.duplicates[0].versions, vec!["1.0.0", "2.0.0"]);
+        assert_eq!(audit.bloat.len(), 3);
+        assert!(audit.bloat.iter().all(|f| f.category == "BLOATED_DEPS"));
+        assert_eq!(audit.unknown_licenses, 3);
+    }
+}
@@ -0,0 +1,442 @@
+//! Source file size limits (v12.4.0)
+//!
+//! Native replacement for the hook's shell `FILE SIZE CHECK` loop. Limits
+//! come from `coding_standards.code.file_size` (or `coding_standards.file_size`)
+//! in project.yaml, with optional per-glob overrides and exemptions:
+//!
+//! ```yaml
+//! coding_standards:
+//!   code:
+//!     file_size:
+//!       soft_limit: 1000
+//!       hard_limit: 1500
+//!       limits:
+//!         - glob: "tests/**/*.rs"
+//!           hard_limit: 2500
+//!         - glob: "*.md"
+//!           soft_limit: 500
+//!       exempt:
+//!         - "src/generated/**"
+//! ```
+//!
+//! The first matching `limits` entry wins; other files of the project's
+//! source extensions use the defaults. Soft limits warn, hard limits fail.
+//! Unset top-level limits fall back to the project type's defaults, so a
+//! `soft_limit` alone keeps the default hard limit; `hard_limit: null` or
+//! `hard_limit: 0` turns hard enforcement off.
+
+use crate::templates::{detect_project_type, ProjectType};
+use crate::workspace::wildcard_match;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use std::process::{Command, Stdio};
+
+/// Per-type defaults used when project.yaml declares no limits
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SourceLimits {
+    pub extensions: &'static [&'static str],
+    pub soft: Option<usize>,
+    pub hard: Option<usize>,
+    /// Directories never checked (build output, vendored code)
+    pub exclude_dirs: &'static [&'static str],
+}
+
+/// Defaults match the project templates' `coding_standards` (ADR-043)
+pub fn default_limits(project_type: ProjectType) -> SourceLimits {
+    let (extensions, soft, hard, exclude_dirs): (&[&str], _, _, &[&str]) = match project_type {
+        ProjectType::Rust => (&["rs"], 1000, Some(1500), &["target", "cli/target"]),
+        ProjectType::Python => (&["py"], 500, Some(1000), &["venv", "__pycache__", ".venv"]),
+        ProjectType::Node => (
+            &["ts", "js", "tsx", "jsx"],
+            400,
+            Some(800),
+            &["node_modules", "dist", "build"],
+        ),
+        ProjectType::Go => (&["go"], 500, Some(1000), &["vendor"]),
+        ProjectType::Flutter => (&["dart"], 400, Some(800), &[".dart_tool", "build"]),
+        ProjectType::Java => (&["java"], 500, Some(1000), &["target", "build", ".gradle"]),
+        ProjectType::Kotlin => (&["kt", "kts"], 500, Some(1000), &["build", ".gradle"]),
+        ProjectType::CSharp => (&["cs"], 500, Some(1000), &["bin", "obj"]),
+        ProjectType::Cpp => (
+            &["c", "cc", "cpp", "cxx", "h", "hh", "hpp"],
+            500,
+            Some(1000),
+            &["build", "third_party", "vendor"],
+        ),
+        ProjectType::Ruby => (&["rb"], 300, Some(800), &["vendor", ".bundle"]),
+        ProjectType::Php => (&["php"], 500, Some(1000), &["vendor"]),
+        ProjectType::Swift => (&["swift"], 500, Some(1000), &[".build"]),
+        // Docs-like projects only warn on long markdown
+        ProjectType::Docs | ProjectType::Arch | ProjectType::Generic | ProjectType::Migration => {
+            (&["md"], 800, None, &["node_modules"])
+        }
+    };
+    SourceLimits {
+        extensions,
+        soft: Some(soft),
+        hard,
+        exclude_dirs,
+    }
+}
+
+/// A per-glob override
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct GlobLimit {
+    pub glob: String,
+    #[serde(default)]
+    pub soft_limit: Option<usize>,
+    #[serde(default)]
+    pub hard_limit: Option<usize>,
+}
+
+/// `file_size` settings from project.yaml
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+pub struct FileSizeConfig {
+    #[serde(default)]
+    pub soft_limit: Option<usize>,
+    /// None when unset (type default); an explicit `null` reads as Some(0),
+    /// which disables the hard limit
+    #[serde(default, deserialize_with = "null_as_zero")]
+    pub hard_limit: Option<usize>,
+    #[serde(default)]
+    pub limits: Vec<GlobLimit>,
+    #[serde(default)]
+    pub exempt: Vec<String>,
+}
+
+fn null_as_zero<'de, D: serde::Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<usize>, D::Error> {
+    Ok(Some(
+        Option::<usize>::deserialize(deserializer)?.unwrap_or(0),
+    ))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LimitLevel {
+    Soft,
+    Hard,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct FileSizeViolation {
+    pub file: String,
+    pub lines: usize,
+    pub limit: usize,
+    pub level: LimitLevel,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct FileSizeReport {
+    pub checked: usize,
+    pub exempt: usize,
+    pub violations: Vec<FileSizeViolation>,
+}
+
+impl FileSizeReport {
+    pub fn hard(&self) -> impl Iterator<Item = &FileSizeViolation> {
+        self.violations
+            .iter()
+            .filter(|v| v.level == LimitLevel::Hard)
+    }
+
+    pub fn soft(&self) -> impl Iterator<Item = &FileSizeViolation> {
+        self.violations
+            .iter()
+            .filter(|v| v.level == LimitLevel::Soft)
+    }
+
+    /// True if no file exceeds a hard limit
+    pub fn passed(&self) -> bool {
+        self.hard().next().is_none()
+    }
+}
+
+/// Load `file_size` from `<dir>/.asimov/project.yaml`
+pub fn load_file_size_config(dir: &Path) -> FileSizeConfig {
+    let Some(yaml) = std::fs::read_to_string(dir.join(".asimov").join("project.yaml"))
+        .ok()
+        .and_then(|c| serde_yaml_ng::from_str::<serde_yaml_ng::Value>(&c).ok())
+    else {
+        return FileSizeConfig::default();
+    };
+    let standards = yaml.get("coding_standards");
+    standards
+        .and_then(|cs| cs.get("code"))
+        .and_then(|code| code.get("file_size"))
+        .or_else(|| standards.and_then(|cs| cs.get("file_size")))
+        .and_then(|fs| serde_yaml_ng::from_value(fs.clone()).ok())
+        .unwrap_or_default()
+}
+
+/// Check file sizes using the project's declared type (`identity.type`), else detection
+pub fn check_project_file_sizes(dir: &Path) -> FileSizeReport {
+    let declared = std::fs::read_to_string(dir.join(".asimov").join("project.yaml"))
+        .ok()
+        .and_then(|c| serde_yaml_ng::from_str::<serde_yaml_ng::Value>(&c).ok())
+        .and_then(|yaml| {
+            yaml.get("identity")
+                .and_then(|i| i.get("type"))
+                .and_then(|t| t.as_str())
+                .and_then(|t| t.parse().ok())
+        });
+    check_file_sizes(dir, declared.unwrap_or_else(|| detect_project_type(dir)))
+}
+
+/// Check source file sizes in `dir` against the configured limits
+pub fn check_file_sizes(dir: &Path, project_type: ProjectType) -> FileSizeReport {
+    let config = load_file_size_config(dir);
+    let defaults = default_limits(project_type);
+    let soft = config.soft_limit.or(defaults.soft);
+    let hard = match config.hard_limit {
+        Some(0) => None,
+        Some(limit) => Some(limit),
+        None => defaults.hard,
+    };
+
+    let mut report = FileSizeReport::default();
+    let mut files = list_files(dir, defaults.exclude_dirs);
+    files.sort();
+
+    for file in files {
+        let (file_soft, file_hard) =
+            if let Some(limit) = config.limits.iter().find(|l| glob_match(&l.glob, &file)) {
+                (limit.soft_limit, limit.hard_limit)
+            } else if has_extension(&file, defaults.extensions) {
+                (soft, hard)
+            } else {
+                continue;
+            };
+        if config.exempt.iter().any(|e| glob_match(e, &file)) {
+            report.exempt += 1;
+            continue;
+        }
+        if file_soft.is_none() && file_hard.is_none() {
+            continue;
+        }
+
+        let Ok(content) = std::fs::read(dir.join(&file)) else {
+            continue;
+        };
+        report.checked += 1;
+        let lines = String::from_utf8_lossy(&content).lines().count();
+
+        if let Some(limit) = file_hard.filter(|h| lines > *h) {
+            report.violations.push(FileSizeViolation {
+                file,
+                lines,
+                limit,
+                level: LimitLevel::Hard,
+            });
+        } else if let Some(limit) = file_soft.filter(|s| lines > *s) {
+            report.violations.push(FileSizeViolation {
+                file,
+                lines,
+                limit,
+                level: LimitLevel::Soft,
+            });
+        }
+    }
+    report
+}
+
+/// Glob match on a `/`-separated relative path.
+/// `**` spans directories; patterns without `/` match the file name anywhere.
+pub fn glob_match(pattern: &str, path: &str) -> bool {
+    let pattern = pattern.trim().trim_start_matches("./");
+    if !pattern.contains('/') {
+        let name = path.rsplit('/').next().unwrap_or(path);
+        return wildcard_match(pattern, name);
+    }
+    let pattern = pattern.trim_end_matches('/');
+    let pat: Vec<&str> = pattern.split('/').collect();
+    let segs: Vec<&str> = path.split('/').collect();
+    // "dir/" or "dir" also covers everything below it
+    match_segments(&pat, &segs) || {
+        let mut below = pat.clone();
+        below.push("**");
+        match_segments(&below, &segs)
+    }
+}
+
+fn match_segments(pat: &[&str], segs: &[&str]) -> bool {
+    match pat.split_first() {
+        None => segs.is_empty(),
+        Some((&"**", rest)) => (0..=segs.len()).any(|i| match_segments(rest, &segs[i..])),
+        Some((first, rest)) => segs
+            .split_first()
+            .is_some_and(|(seg, tail)| wildcard_match(first, seg) && match_segments(rest, tail)),
+    }
+}
+
+fn has_extension(file: &str, extensions: &[&str]) -> bool {
+    Path::new(file)
+        .extension()
+        .and_then(|e| e.to_str())
+        .is_some_and(|ext| extensions.contains(&ext))
+}
+
+/// Tracked and untracked, non-ignored files (git), or a filtered walk
+fn list_files(dir: &Path, exclude_dirs: &[&str]) -> Vec<String> {
+    let excluded = |file: &str| {
+        exclude_dirs
+            .iter()
+            .any(|d| file.starts_with(&format!("{}/", d)))
+    };
+
+    if let Some(output) = Command::new("git")
+        .args([
+            "ls-files",
+            "-z",
+            "--cached",
+            "--others",
+            "--exclude-standard",
+        ])
+        .current_dir(dir)
+        .stderr(Stdio::null())
+        .output()
+        .ok()
+        .filter(|o| o.status.success())
+    {
+        return output
+            .stdout
+            .split(|b| *b == 0)
+            .filter(|f| !f.is_empty())
+            .map(|f| String::from_utf8_lossy(f).into_owned())
+            .filter(|f| !excluded(f))
+            .collect();
+    }
+
+    walkdir::WalkDir::new(dir)
+        .into_iter()
+        .filter_entry(|e| {
+            let name = e.file_name().to_string_lossy();
+            e.depth() == 0 || !(name.starts_with('.') || name == "node_modules")
+        })
+        .flatten()
+        .filter(|e| e.file_type().is_file())
+        .filter_map(|e| {
+            e.path()
+                .strip_prefix(dir)
+                .ok()
+                .map(|p| p.to_string_lossy().replace('\\', "/"))
+        })
+        .filter(|f| !excluded(f))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn write_lines(root: &Path, rel: &str, lines: usize) {
+        let path = root.join(rel);
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, "x\n".repeat(lines)).unwrap();
+    }
+
+    fn write_project(root: &Path, file_size: &str) {
+        std::fs::create_dir_all(root.join(".asimov")).unwrap();
+        std::fs::write(
+            root.join(".asimov/project.yaml"),
+            format!(
+                "identity:\n  name: x\n  type: rust\ncoding_standards:\n  code:\n    file_size:\n{}",
+                file_size
+            ),
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_glob_match() {
+        assert!(glob_match("*.rs", "src/deep/main.rs"));
+        assert!(glob_match("src/**/*.rs", "src/main.rs"));
+        assert!(glob_match("src/**/*.rs", "src/a/b/main.rs"));
+        assert!(!glob_match("src/**/*.rs", "tests/main.rs"));
+        assert!(glob_match("src/generated", "src/generated/schema.rs"));
+        assert!(glob_match("src/generated/**", "src/generated/schema.rs"));
+        assert!(glob_match("./tests/*.rs", "tests/e2e.rs"));
+        assert!(!glob_match("tests/*.rs", "tests/e2e/mod.rs"));
+    }
+
+    #[test]
+    fn test_defaults_by_project_type() {
+        let temp = TempDir::new().unwrap();
+        write_lines(temp.path(), "src/ok.rs", 900);
+        write_lines(temp.path(), "src/soft.rs", 1200);
+        write_lines(temp.path(), "src/hard.rs", 1600);
+        write_lines(temp.path(), "target/debug/gen.rs", 5000);
+        write_lines(temp.path(), "notes.md", 5000);
+
+        let report = check_file_sizes(temp.path(), ProjectType::Rust);
+        assert_eq!(report.checked, 3);
+        assert!(!report.passed());
+        let hard: Vec<&str> = report.hard().map(|v| v.file.as_str()).collect();
+        let soft: Vec<&str> = report.soft().map(|v| v.file.as_str()).collect();
+        assert_eq!(hard, vec!["src/hard.rs"]);
+        assert_eq!(soft, vec!["src/soft.rs"]);
+        assert_eq!(report.violations[0].limit, 1500);
+    }
+
+    #[test]
+    fn test_docs_projects_only_warn() {
+        let temp = TempDir::new().unwrap();
+        write_lines(temp.path(), "guide.md", 2000);
+        let report = check_file_sizes(temp.path(), ProjectType::Docs);
+        assert!(report.passed());
+        assert_eq!(report.soft().count(), 1);
+    }
+
+    #[test]
+    fn test_globs_and_exemptions() {
+        let temp = TempDir::new().unwrap();
+        write_project(
+            temp.path(),
+            "      soft_limit: 100\n      hard_limit: 200\n      limits:\n        - glob: \"tests/**/*.rs\"\n          hard_limit: 500\n        - glob: \"*.md\"\n          soft_limit: 10\n      exempt:\n        - \"src/generated/**\"\n",
+        );
+        write_lines(temp.path(), "src/main.rs", 150);
+        write_lines(temp.path(), "src/generated/schema.rs", 9000);
+        write_lines(temp.path(), "tests/e2e/big.rs", 400);
+        write_lines(temp.path(), "README.md", 20);
+
+        let report = check_file_sizes(temp.path(), ProjectType::Rust);
+        assert!(report.passed());
+        assert_eq!(report.exempt, 1);
+        assert_eq!(report.checked, 3);
+        let soft: Vec<(&str, usize)> = report.soft().map(|v| (v.file.as_str(), v.limit)).collect();
+        assert_eq!(soft, vec![("README.md", 10), ("src/main.rs", 100)]);
+    }
+
+    #[test]
+    fn test_soft_only_config_keeps_default_hard_limit() {
+        let temp = TempDir::new().unwrap();
+        std::fs::create_dir_all(temp.path().join(".asimov")).unwrap();
+        let config = |file_size: &str| {
+            std::fs::write(
+                temp.path().join(".asimov/project.yaml"),
+                format!(
+                    "identity:\n  name: x\n  type: rust\ncoding_standards:\n  file_size:\n    soft_limit: 10\n{}",
+                    file_size
+                ),
+            )
+            .unwrap();
+        };
+        write_lines(temp.path(), "src/main.rs", 5000);
+
+        config("");
+        let report = check_file_sizes(temp.path(), ProjectType::Rust);
+        assert!(!report.passed());
+        assert_eq!(report.violations[0].level, LimitLevel::Hard);
+        assert_eq!(report.violations[0].limit, 1500);
+
+        // Only an explicit null or 0 turns hard enforcement off
+        for disabled in ["    hard_limit: null\n", "    hard_limit: 0\n"] {
+            config(disabled);
+            let report = check_file_sizes(temp.path(), ProjectType::Rust);
+            assert!(report.passed(), "{}", disabled);
+            assert_eq!(report.soft().next().unwrap().limit, 10);
+        }
+    }
+}
@@ -0,0 +1,513 @@
+//! Git hook manager (v12.4.0)
+//!
+//! Installs asimov's pre-commit, commit-msg and pre-push hooks into the
+//! directory git actually runs hooks from (`core.hooksPath`, else the common
+//! git dir's `hooks/`, so linked worktrees share them).
+//!
+//! A hook that asimov did not write (husky, pre-commit, lefthook, hand-written)
+//! is never replaced: it is moved to `asimov-chained/<hook>` and asimov's hook
+//! runs it first with the same arguments. The chained hook keeps its file
+//! name, and shell hooks are sourced with asimov's `$0`, so dispatchers such
+//! as husky's `_/h` (which use `basename "$0"` and `dirname "$0"`) keep working.
+//! `uninstall` removes asimov's hooks and moves chained hooks back.
+
+use crate::quality::load_quality_config;
+use crate::templates::{
+    commit_msg_hook_template, is_generated_hook, prepush_hook_template, ProjectType,
+};
+use crate::workspace::precommit_hook_for_dir;
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+/// Directory, inside the hooks dir, holding pre-existing hooks asimov's hooks chain to
+pub const CHAINED_DIR: &str = "asimov-chained";
+
+/// Shell inserted after the shebang of asimov's hooks to run the chained hook.
+/// Shell scripts are sourced in their own interpreter with this hook's `$0`;
+/// anything else is executed from `asimov-chained/`, keeping its basename.
+pub const CHAIN_SCRIPT: &str = r#"# === CHAINED HOOK (v12.4.0) ===
+# The hook that was here before asimov runs first (asimov hooks uninstall restores it)
+chained_hook="$(dirname "$0")/asimov-chained/$(basename "$0")"
+if [ -x "$chained_hook" ]; then
+  IFS= read -r chained_shebang < "$chained_hook" || true
+  chained_shell="${chained_shebang#\#!}"
+  case "${chained_shell##*/}" in
+    sh|sh\ *|bash|bash\ *|dash|dash\ *|"env sh"|"env bash")
+      $chained_shell -c 'asimov_chained_hook="$1"; shift; . "$asimov_chained_hook"' "$0" "$chained_hook" "$@" || exit $?
+      ;;
+    *)
+      "$chained_hook" "$@" || exit $?
+      ;;
+  esac
+fi
+"#;
+
+/// Git hooks asimov manages
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GitHook {
+    /// Quality gates on staged changes
+    PreCommit,
+    /// Commit message policy
+    CommitMsg,
+    /// Full test suite
+    PrePush,
+}
+
+impl GitHook {
+    pub const ALL: [GitHook; 3] = [GitHook::PreCommit, GitHook::CommitMsg, GitHook::PrePush];
+
+    /// File name git looks for
+    pub fn name(&self) -> &'static str {
+        match self {
+            GitHook::PreCommit => "pre-commit",
+            GitHook::CommitMsg => "commit-msg",
+            GitHook::PrePush => "pre-push",
+        }
+    }
+
+    /// asimov's script for this hook, chaining to any pre-existing one
+    pub fn render(&self, dir: &Path, project_type: ProjectType) -> String {
+        let script = match self {
+            GitHook::PreCommit => precommit_hook_for_dir(dir, project_type),
+            GitHook::CommitMsg => commit_msg_hook_template(),
+            GitHook::PrePush => {
+                prepush_hook_template(project_type, &load_quality_config(dir).gates())
+            }
+        };
+        with_chain(&script)
+    }
+}
+
+impl std::str::FromStr for GitHook {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        GitHook::ALL
+            .into_iter()
+            .find(|hook| hook.name() == s.trim().to_lowercase())
+            .ok_or_else(|| {
+                format!(
+                    "Unknown git hook '{}' (expected pre-commit, commit-msg or pre-push)",
+                    s
+                )
+            })
+    }
+}
+
+impl fmt::Display for GitHook {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// Insert the call to `asimov-chained/<hook>` right after the shebang
+fn with_chain(script: &str) -> String {
+    let (shebang, rest) = script.split_once('\n').unwrap_or((script, ""));
+    format!("{shebang}\n{CHAIN_SCRIPT}{rest}")
+}
+
+/// The git dir shared by all worktrees of the repository at `dir`
+fn common_git_dir(dir: &Path) -> Result<PathBuf, String> {
+    let dot_git = dir.join(".git");
+    let git_dir = if dot_git.is_dir() {
+        dot_git
+    } else if dot_git.is_file() {
+        // Linked worktree or submodule: "gitdir: <path>"
+        let content = std::fs::read_to_string(&dot_git)
+            .map_err(|e| format!("Cannot read {}: {}", dot_git.display(), e))?;
+        let target = content
+            .trim()
+            .strip_prefix("gitdir:")
+            .ok_or_else(|| format!("Unexpected {}", dot_git.display()))?;
+        dir.join(target.trim())
+    } else {
+        return Err("Not a git repository".to_string());
+    };
+
+    // Worktrees point at the main git dir through `commondir`
+    match std::fs::read_to_string(git_dir.join("commondir")) {
+        Ok(common) => Ok(git_dir.join(common.trim())),
+        Err(_) => Ok(git_dir),
+    }
+}
+
+/// Directory git runs hooks from: `core.hooksPath`, else `<git dir>/hooks`
+pub fn hooks_dir(dir: &Path) -> Result<PathBuf, String> {
+    let git_dir = common_git_dir(dir)?;
+    let hooks_path = std::process::Command::new("git")
+        .arg("--git-dir")
+        .arg(&git_dir)
+        .args(["config", "--path", "--get", "core.hooksPath"])
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string())
+        .filter(|path| !path.is_empty());
+    Ok(match hooks_path {
+        // Relative paths are relative to the worktree root
+        Some(path) => dir.join(path),
+        None => git_dir.join("hooks"),
+    })
+}
+
+/// What happened to one hook
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HookAction {
+    /// Written where there was none
+    Installed,
+    /// asimov's hook regenerated with new content
+    Updated,
+    /// Already up to date
+    Unchanged,
+    /// A foreign hook was moved aside and is now run by asimov's hook
+    Chained,
+    /// Foreign hook left alone (refresh never chains on its own)
+    Kept,
+    /// asimov's hook deleted
+    Removed,
+    /// asimov's hook deleted and the chained hook moved back
+    Restored,
+}
+
+/// One hook touched by install, refresh or uninstall
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GitHookChange {
+    pub hook: GitHook,
+    pub path: PathBuf,
+    pub action: HookAction,
+}
+
+fn chained_path(path: &Path) -> PathBuf {
+    let name = path.file_name().unwrap_or_default();
+    path.with_file_name(CHAINED_DIR).join(name)
+}
+
+fn write_hook(path: &Path, content: &str) -> Result<(), String> {
+    std::fs::write(path, content)
+        .map_err(|e| format!("Failed to write {}: {}", path.display(), e))?;
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        std::fs::set_permissions(path, std::fs::Permissions::from_mode(0o755))
+            .map_err(|e| format!("Failed to set permissions on {}: {}", path.display(), e))?;
+    }
+    Ok(())
+}
+
+/// Install `hooks`, chaining to any foreign hook already in place
+pub fn install_git_hooks(
+    dir: &Path,
+    project_type: ProjectType,
+    hooks: &[GitHook],
+) -> Result<Vec<GitHookChange>, String> {
+    sync_git_hooks(dir, project_type, hooks, true)
+}
+
+/// Regenerate asimov's hooks; install a missing pre-commit, keep foreign ones
+pub fn refresh_git_hooks(
+    dir: &Path,
+    project_type: ProjectType,
+) -> Result<Vec<GitHookChange>, String> {
+    let hooks_dir = hooks_dir(dir)?;
+    let installed: Vec<GitHook> = GitHook::ALL
+        .into_iter()
+        .filter(|hook| {
+            *hook == GitHook::PreCommit
+                || std::fs::read_to_string(hooks_dir.join(hook.name()))
+                    .is_ok_and(|content| is_generated_hook(&content))
+        })
+        .collect();
+    sync_git_hooks(dir, project_type, &installed, false)
+}
+
+fn sync_git_hooks(
+    dir: &Path,
+    project_type: ProjectType,
+    hooks: &[GitHook],
+    chain: bool,
+) -> Result<Vec<GitHookChange>, String> {
+    let hooks_dir = hooks_dir(dir)?;
+    std::fs::create_dir_all(&hooks_dir)
+        .map_err(|e| format!("Failed to create {}: {}", hooks_dir.display(), e))?;
+
+    let mut changes = Vec::new();
+    for &hook in hooks {
+        let path = hooks_dir.join(hook.name());
+        let content = hook.render(dir, project_type);
+        let action = match std::fs::read_to_string(&path) {
+            Err(_) => HookAction::Installed,
+            Ok(existing) if existing == content => HookAction::Unchanged,
+            Ok(existing) if is_generated_hook(&existing) => HookAction::Updated,
+            Ok(_) if !chain => HookAction::Kept,
+            Ok(_) => {
+                let chained = chained_path(&path);
+                if chained.exists() {
+                    return Err(format!(
+                        "{} is not asimov's and {} already exists; merge them by hand",
+                        path.display(),
+                        chained.display()
+                    ));
+                }
+                std::fs::create_dir_all(hooks_dir.join(CHAINED_DIR))
+                    .map_err(|e| format!("Failed to create {}: {}", CHAINED_DIR, e))?;
+                std::fs::rename(&path, &chained)
+                    .map_err(|e| format!("Failed to move {}: {}", path.display(), e))?;
+                HookAction::Chained
+            }
+        };
+        if !matches!(action, HookAction::Unchanged | HookAction::Kept) {
+            write_hook(&path, &content)?;
+        }
+        changes.push(GitHookChange { hook, path, action });
+    }
+    Ok(changes)
+}
+
+/// Remove asimov's hooks and put chained hooks back
+pub fn uninstall_git_hooks(dir: &Path) -> Result<Vec<GitHookChange>, String> {
+    let hooks_dir = hooks_dir(dir)?;
+    let mut changes = Vec::new();
+    for hook in GitHook::ALL {
+        let path = hooks_dir.join(hook.name());
+        match std::fs::read_to_string(&path) {
+            Ok(content) if is_generated_hook(&content) => {}
+            _ => continue,
+        }
+        std::fs::remove_file(&path)
+            .map_err(|e| format!("Failed to remove {}: {}", path.display(), e))?;
+        let chained = chained_path(&path);
+        let action = if chained.exists() {
+            std::fs::rename(&chained, &path)
+                .map_err(|e| format!("Failed to restore {}: {}", path.display(), e))?;
+            // Only succeeds once the last chained hook is back
+            let _ = std::fs::remove_dir(hooks_dir.join(CHAINED_DIR));
+            HookAction::Restored
+        } else {
+            HookAction::Removed
+        };
+        changes.push(GitHookChange { hook, path, action });
+    }
+    Ok(changes)
+}
+
+/// Who owns a hook
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GitHookState {
+    Missing,
+    /// asimov's hook, and whether it chains to a previous one
+    Asimov {
+        chained: bool,
+    },
+    /// Another tool's or hand-written
+    Foreign,
+}
+
+/// State of each managed hook in `hooks_dir(dir)`
+pub fn git_hooks_status(dir: &Path) -> Result<(PathBuf, Vec<(GitHook, GitHookState)>), String> {
+    let hooks_dir = hooks_dir(dir)?;
+    let states = GitHook::ALL
+        .into_iter()
+        .map(|hook| {
+            let path = hooks_dir.join(hook.name());
+            let state = match std::fs::read_to_string(&path) {
+                Err(_) => GitHookState::Missing,
+                Ok(content) if is_generated_hook(&content) => GitHookState::Asimov {
+                    chained: chained_path(&path).exists(),
+                },
+                Ok(_) => GitHookState::Foreign,
+            };
+            (hook, state)
+        })
+        .collect();
+    Ok((hooks_dir, states))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn repo() -> TempDir {
+        let temp = TempDir::new().unwrap();
+        std::fs::create_dir_all(temp.path().join(".git/hooks")).unwrap();
+        temp
+    }
+
+    fn hook(temp: &TempDir, name: &str) -> String {
+        std::fs::read_to_string(temp.path().join(".git/hooks").join(name)).unwrap()
+    }
+
+    #[test]
+    fn test_git_hook_parse_and_display() {
+        assert_eq!("commit-msg".parse::<GitHook>().unwrap(), GitHook::CommitMsg);
+        assert_eq!(GitHook::PrePush.to_string(), "pre-push");
+        assert!("post-merge".parse::<GitHook>().is_err());
+    }
+
+    #[test]
+    fn test_with_chain_after_shebang() {
+        let script = with_chain("#!/bin/bash\necho hi\n");
+        assert!(script.starts_with("#!/bin/bash\n# === CHAINED HOOK"));
+        assert!(
+            script.contains(r#"chained_hook="$(dirname "$0")/asimov-chained/$(basename "$0")""#)
+        );
+        assert!(script.ends_with("fi\necho hi\n"));
+    }
+
+    #[test]
+    fn test_install_chains_and_uninstall_restores() {
+        let temp = repo();
+        let husky = "#!/bin/sh\nnpx lint-staged\n";
+        std::fs::write(temp.path().join(".git/hooks/pre-commit"), husky).unwrap();
+
+        let changes = install_git_hooks(temp.path(), ProjectType::Generic, &GitHook::ALL).unwrap();
+        let actions: Vec<HookAction> = changes.iter().map(|c| c.action).collect();
+        assert_eq!(
+            actions,
+            vec![
+                HookAction::Chained,
+                HookAction::Installed,
+                HookAction::Installed
+            ]
+        );
+        assert_eq!(hook(&temp, "asimov-chained/pre-commit"), husky);
+        assert!(is_generated_hook(&hook(&temp, "pre-commit")));
+        assert!(hook(&temp, "commit-msg").contains("asimov lint-commit"));
+
+        // Idempotent: the chained hook is not chained again
+        let again = install_git_hooks(temp.path(), ProjectType::Generic, &GitHook::ALL).unwrap();
+        assert!(again.iter().all(|c| c.action == HookAction::Unchanged));
+
+        let (_, status) = git_hooks_status(temp.path()).unwrap();
+        assert_eq!(status[0].1, GitHookState::Asimov { chained: true });
+        assert_eq!(status[2].1, GitHookState::Asimov { chained: false });
+
+        let removed = uninstall_git_hooks(temp.path()).unwrap();
+        let actions: Vec<HookAction> = removed.iter().map(|c| c.action).collect();
+        assert_eq!(
+            actions,
+            vec![
+                HookAction::Restored,
+                HookAction::Removed,
+                HookAction::Removed
+            ]
+        );
+        assert_eq!(hook(&temp, "pre-commit"), husky);
+        assert!(!temp.path().join(".git/hooks/commit-msg").exists());
+        assert!(!temp.path().join(".git/hooks/asimov-chained").exists());
+    }
+
+    #[cfg(unix)]
+    fn write_executable(path: &Path, content: &str) {
+        use std::os::unix::fs::PermissionsExt;
+        std::fs::write(path, content).unwrap();
+        std::fs::set_permissions(path, std::fs::Permissions::from_mode(0o755)).unwrap();
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_chained_hooks_keep_basename_dispatch() {
+        // husky v9: core.hooksPath is .husky/_, every hook there sources
+        // `_/h`, which runs .husky/<basename of $0>
+        let temp = TempDir::new().unwrap();
+        let husky = temp.path().join(".husky");
+        std::fs::create_dir_all(husky.join("_")).unwrap();
+        write_executable(
+            &husky.join("_/h"),
+            "#!/usr/bin/env sh\nn=$(basename \"$0\")\ns=$(dirname \"$(dirname \"$0\")\")/$n\n[ ! -f \"$s\" ] && exit 0\nsh -e \"$s\" \"$@\"\n",
+        );
+        write_executable(
+            &husky.join("_/pre-commit"),
+            "#!/usr/bin/env sh\n. \"$(dirname \"$0\")/h\"\n",
+        );
+        std::fs::write(husky.join("pre-commit"), "echo \"husky $1\" >> ran\n").unwrap();
+        // A non-shell dispatcher only sees its own path
+        write_executable(
+            &husky.join("_/pre-push"),
+            "#!/usr/bin/env -S sh\necho \"$(basename \"$0\") $1\" >> ran\n",
+        );
+
+        for name in ["pre-commit", "pre-push"] {
+            let path = husky.join("_").join(name);
+            let foreign = std::fs::read_to_string(&path).unwrap();
+            std::fs::create_dir_all(husky.join("_").join(CHAINED_DIR)).unwrap();
+            std::fs::rename(&path, chained_path(&path)).unwrap();
+            write_executable(&path, &with_chain("#!/bin/sh\necho \"asimov $1\" >> ran\n"));
+            assert_eq!(
+                std::fs::read_to_string(chained_path(&path)).unwrap(),
+                foreign
+            );
+
+            let status = std::process::Command::new(&path)
+                .arg("arg")
+                .current_dir(temp.path())
+                .status()
+                .unwrap();
+            assert!(status.success());
+        }
+        assert_eq!(
+            std::fs::read_to_string(temp.path().join("ran")).unwrap(),
+            "husky arg\nasimov arg\npre-push arg\nasimov arg\n"
+        );
+    }
+
+    #[test]
+    fn test_refresh_keeps_foreign_and_updates_own() {
+        let temp = repo();
+        std::fs::write(
+            temp.path().join(".git/hooks/pre-commit"),
+            "#!/bin/sh\nmine\n",
+        )
+        .unwrap();
+        install_git_hooks(temp.path(), ProjectType::Generic, &[GitHook::PrePush]).unwrap();
+        std::fs::write(
+            temp.path().join(".git/hooks/pre-push"),
+            hook(&temp, "pre-push").replace("Running pre-push", "Old pre-push"),
+        )
+        .unwrap();
+
+        let changes = refresh_git_hooks(temp.path(), ProjectType::Generic).unwrap();
+        assert_eq!(changes.len(), 2, "commit-msg was never installed");
+        assert_eq!(changes[0].action, HookAction::Kept);
+        assert_eq!(changes[1].action, HookAction::Updated);
+        assert_eq!(hook(&temp, "pre-commit"), "#!/bin/sh\nmine\n");
+    }
+
+    #[test]
+    fn test_install_refuses_second_foreign_hook() {
+        let temp = repo();
+        std::fs::write(temp.path().join(".git/hooks/pre-push"), "#!/bin/sh\na\n").unwrap();
+        std::fs::create_dir_all(temp.path().join(".git/hooks/asimov-chained")).unwrap();
+        std::fs::write(
+            temp.path().join(".git/hooks/asimov-chained/pre-push"),
+            "#!/bin/sh\nb\n",
+        )
+        .unwrap();
+        let err =
+            install_git_hooks(temp.path(), ProjectType::Generic, &[GitHook::PrePush]).unwrap_err();
+        assert!(err.contains("merge them by hand"), "{}", err);
+    }
+
+    #[test]
+    fn test_hooks_dir_follows_worktree_commondir() {
+        let temp = TempDir::new().unwrap();
+        let main_git = temp.path().join("main/.git");
+        std::fs::create_dir_all(main_git.join("worktrees/wt")).unwrap();
+        std::fs::create_dir_all(main_git.join("hooks")).unwrap();
+        std::fs::write(main_git.join("worktrees/wt/commondir"), "../..\n").unwrap();
+        let worktree = temp.path().join("wt");
+        std::fs::create_dir_all(&worktree).unwrap();
+        std::fs::write(
+            worktree.join(".git"),
+            format!("gitdir: {}\n", main_git.join("worktrees/wt").display()),
+        )
+        .unwrap();
+
+        let dir = hooks_dir(&worktree).unwrap();
+        assert_eq!(
+            dir.canonicalize().unwrap(),
+            main_git.join("hooks").canonicalize().unwrap()
+        );
+        assert!(hooks_dir(temp.path()).is_err());
+    }
+}
@@ -32,18 +32,28 @@
 //! }
 //! ```
 
+pub mod changelog;
 pub mod commands;
+pub mod commit_policy;
+pub mod deps;
 pub mod error;
 pub mod ethics;
+pub mod file_size;
+pub mod git_hooks;
 pub mod green;
 pub mod markdown;
+pub mod paths;
 pub mod protocols;
+pub mod quality;
+pub mod roadmap;
 pub mod schemas;
 pub mod semantic;
+pub mod supervisor;
 pub mod sycophancy;
 pub mod templates;
 pub mod update;
 pub mod validator;
+pub mod workspace;
 
 // Re-export main types
 pub use error::{Error, Result};
@@ -72,6 +82,8 @@ pub use sycophancy::{
 #[allow(deprecated)]
 pub use templates::{
     asimov_template,
+    // v12.4.0: Ranked detection with evidence
+    detect_project_candidates,
     // v10.6.0: Removed claude_* hooks (ADR-060)
     // v8.1.0: Project type detection (ADR-032)
     detect_project_type,
@@ -110,7 +122,7 @@ pub use schemas::{
 };
 
 // Update exports for self-update functionality (v7.8.0)
-pub use update::{check_for_update, perform_update, VersionCheck, CURRENT_VERSION};
+pub use update::{check_for_update, perform_update, UpdateError, VersionCheck, CURRENT_VERSION};
 
 // Protocol exports for enforced loading (v8.0.0 - ADR-031)
 // v8.14.0: Added individual protocol JSON exports
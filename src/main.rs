@@ -5,13 +5,22 @@
 //! is in lib.rs for testability. This file only handles CLI parsing and output.
 
 use clap::{Parser, Subcommand};
-use std::path::PathBuf;
+use royalbit_asimov::commands::{
+    ChangelogOptions, CheckOptions, DepsAuditOptions, ReplayOptions, StatsOptions, UpdateOptions,
+};
+use royalbit_asimov::git_hooks::GitHook;
+use royalbit_asimov::quality::GateProfile;
+use royalbit_asimov::update::Channel;
+use std::path::{Path, PathBuf};
 use std::process::ExitCode;
 
 mod output;
 use output::{
-    cmd_doctor, cmd_init, cmd_launch, cmd_lint_docs, cmd_refresh, cmd_replay, cmd_role, cmd_stats,
-    cmd_update, cmd_validate, cmd_warmup,
+    cmd_changelog, cmd_check, cmd_deps_audit, cmd_detect, cmd_doctor, cmd_git_hooks_install,
+    cmd_git_hooks_uninstall, cmd_hooks_install, cmd_hooks_status, cmd_init, cmd_launch,
+    cmd_licenses, cmd_lint_commit, cmd_lint_docs, cmd_refresh, cmd_replay, cmd_role,
+    cmd_sessions_close, cmd_sessions_list, cmd_sessions_show, cmd_stats, cmd_template_install,
+    cmd_template_list, cmd_template_remove, cmd_update, cmd_validate, cmd_warmup,
 };
 
 #[derive(Parser)]
@@ -24,6 +33,9 @@ Licensed under Elastic License 2.0 (ELv2).
 LAUNCHER MODE (AI-agnostic, v10.7.0 ADR-061):
   asimov                             # Auto-detect AI CLI, launch + warmup
   asimov                             # Inside AI session: runs warmup directly
+  asimov --ai gemini                 # Pick a CLI (or set default_ai in config)
+  asimov --autonomy auto             # Let the CLI skip its permission prompts
+  asimov --supervise                 # Re-inject context after compaction
 
 SUPPORTED AI CLIs:
   - Claude Code (claude)             # https://claude.ai/download
@@ -37,7 +49,13 @@ EXAMPLES:
   asimov role eng                    # Switch to Principal Engineer role
   asimov validate                    # Validate roadmap.yaml
   asimov update                      # Update binary
+  asimov update --rollback           # Restore the binary before the last update
   asimov init                        # Initialize new project
+  asimov template list               # List templates and installed packs
+  asimov hooks install               # pre-commit, commit-msg, pre-push (chains existing)
+  asimov hooks install --ai claude   # Re-inject context on session start/compaction
+  asimov lint-commit main..HEAD      # Check commit messages against commit_policy
+  asimov --offline doctor            # No network (same as ASIMOV_OFFLINE=1)
 
 PROTOCOLS (7 total, loaded from .asimov/protocols/ with embedded fallback):
   - asimov     - The Three Laws (do no harm, obey human, self-preserve)
@@ -51,6 +69,22 @@ PROTOCOLS (7 total, loaded from .asimov/protocols/ with embedded fallback):
 Docs: https://github.com/royalbit/asimov")]
 #[command(version)]
 struct Cli {
+    /// No network access: skip update checks (same as ASIMOV_OFFLINE=1)
+    #[arg(long, global = true)]
+    offline: bool,
+
+    /// AI CLI to launch by profile id or name (overrides default_ai)
+    #[arg(long, value_name = "NAME")]
+    ai: Option<String>,
+
+    /// How much the launched AI may do unprompted: supervised (default) or auto
+    #[arg(long, value_name = "LEVEL")]
+    autonomy: Option<royalbit_asimov::commands::Autonomy>,
+
+    /// Run the AI CLI under a supervisor that re-injects context after compaction
+    #[arg(long)]
+    supervise: bool,
+
     #[command(subcommand)]
     command: Option<Commands>,
 }
@@ -62,6 +96,10 @@ enum Commands {
         /// Scan project files for red flag patterns
         #[arg(long)]
         ethics_scan: bool,
+
+        /// Only check source file sizes against project.yaml limits
+        #[arg(long)]
+        sizes: bool,
     },
 
     /// Initialize or migrate an asimov project
@@ -70,12 +108,16 @@ enum Commands {
         #[arg(short, long)]
         name: String,
 
-        /// Template type. Base: rust, python, node, go, flutter, docs, arch, generic.
+        /// Template type. Base: rust, python, node, go, flutter, docs, arch, generic,
+        /// java, kotlin, csharp, cpp, ruby, php, swift.
         /// API: api-rust, api-go, api-fastapi, api-nestjs, api-spring.
         /// Web: web-nextjs, web-react, web-vue, web-angular.
         /// Mono: mono-turbo, mono-nx, mono-pnpm. Other: admin-dashboard.
+        /// Installed packs: see 'asimov template list'.
+        /// Omit to detect the type from the directory and confirm interactively
+        /// (without a terminal on stdin the top candidate is used).
         #[arg(short = 't', long = "type")]
-        project_type: String,
+        project_type: Option<String>,
 
         /// Output directory (defaults to current directory)
         #[arg(short, long, default_value = ".")]
@@ -86,6 +128,25 @@ enum Commands {
         force: bool,
     },
 
+    /// Check commit messages against commit_policy in project.yaml (v12.4.0)
+    #[command(name = "lint-commit")]
+    LintCommit {
+        /// Message file (as passed to the commit-msg hook) or revision range (main..HEAD)
+        #[arg(default_value = "HEAD")]
+        target: String,
+    },
+
+    /// Draft the CHANGELOG.md entry of the released current milestone (v12.4.0)
+    Changelog {
+        /// Insert the entry into CHANGELOG.md instead of printing it
+        #[arg(long)]
+        write: bool,
+
+        /// Release date (YYYY-MM-DD, default today)
+        #[arg(long)]
+        date: Option<String>,
+    },
+
     /// Lint markdown documentation
     #[command(name = "lint-docs")]
     LintDocs {
@@ -126,6 +187,24 @@ enum Commands {
         /// Only check, don't install
         #[arg(long)]
         check: bool,
+
+        /// Install even if the release signature is missing or invalid
+        #[arg(long)]
+        insecure: bool,
+
+        /// Release channel: stable or beta (includes pre-releases)
+        #[arg(long, default_value = "stable")]
+        channel: Channel,
+
+        /// Install a specific release, e.g. 12.4.0 (may be older). Releases
+        /// before 12.4.0 are unsigned and need --insecure
+        #[arg(long, value_name = "X.Y.Z", conflicts_with = "channel")]
+        version: Option<String>,
+
+        /// Restore the binary replaced by the last update (local backup, no
+        /// download; to go further back use --version)
+        #[arg(long, conflicts_with_all = ["check", "channel", "version"])]
+        rollback: bool,
     },
 
     /// Session warmup - display milestone and validate
@@ -140,10 +219,32 @@ enum Commands {
     },
 
     /// Show session statistics
-    Stats,
+    ///
+    /// v12.4.0: Also milestone cycle times, deliverables per week and the
+    /// current milestone's burndown, from the git history of roadmap.yaml
+    /// and CHANGELOG.md
+    Stats {
+        /// Limit the history analytics to this date on (YYYY-MM-DD or a git date: "4 weeks ago")
+        #[arg(long)]
+        since: Option<String>,
 
-    /// Diagnose autonomous mode issues
-    Doctor,
+        /// Output as JSON
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Diagnose autonomous mode issues (read-only unless --fix)
+    Doctor {
+        /// Apply repairs: create missing files, install the pre-commit hook and
+        /// regenerate protocols (missing tools are reported with install
+        /// commands, never installed)
+        #[arg(long)]
+        fix: bool,
+
+        /// Output the report as JSON
+        #[arg(long)]
+        json: bool,
+    },
 
     /// Switch or list available roles (v10.0.0)
     Role {
@@ -165,9 +266,195 @@ enum Commands {
         #[arg(long)]
         since: Option<String>,
 
-        /// Show full diffs
+        /// Show commits until time
+        #[arg(long, conflicts_with = "yesterday")]
+        until: Option<String>,
+
+        /// Only commits by this author (name or email pattern)
+        #[arg(long)]
+        author: Option<String>,
+
+        /// Only commits touching this path (repeatable)
+        #[arg(long = "path", value_name = "PATH")]
+        paths: Vec<String>,
+
+        /// Show changed files and full diffs
         #[arg(short, long)]
         verbose: bool,
+
+        /// Output as JSON
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Run the quality gates declared in project.yaml (v12.4.0)
+    Check {
+        /// Run only this gate (repeatable, e.g. --gate lint --gate links)
+        #[arg(short, long = "gate")]
+        gates: Vec<String>,
+
+        /// Run gates concurrently
+        #[arg(long)]
+        parallel: bool,
+
+        /// Output structured results as JSON
+        #[arg(long)]
+        json: bool,
+
+        /// Skip when nothing is staged (for the pre-commit hook); gates still
+        /// run against the working tree, unstaged edits included
+        #[arg(long)]
+        staged: bool,
+
+        /// Gate profile: fast or full (default: hook.profile or $ASIMOV_HOOK_PROFILE)
+        #[arg(long)]
+        profile: Option<GateProfile>,
+
+        /// Ignore cached results and run every gate
+        #[arg(long)]
+        no_cache: bool,
+    },
+
+    /// Detect the project type and show the evidence (v12.4.0)
+    Detect {
+        /// Directory to inspect
+        #[arg(default_value = ".")]
+        path: PathBuf,
+
+        /// Output ranked candidates as JSON
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Dependency health from local lockfiles (v12.4.0, ADR-045)
+    Deps {
+        #[command(subcommand)]
+        action: DepsAction,
+    },
+
+    /// Check dependency licenses against the project.yaml policy (v12.4.0)
+    Licenses {
+        /// Project directory
+        #[arg(default_value = ".")]
+        path: PathBuf,
+
+        /// Output the license report as JSON
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Manage installable template packs (v12.4.0)
+    Template {
+        #[command(subcommand)]
+        action: TemplateAction,
+    },
+
+    /// Manage git hooks and AI CLI session hooks (v12.4.0)
+    Hooks {
+        #[command(subcommand)]
+        action: HooksAction,
+    },
+
+    /// Browse the session journal written by launch and warmup (v12.4.0)
+    Sessions {
+        #[command(subcommand)]
+        action: SessionsAction,
+    },
+}
+
+#[derive(Subcommand)]
+enum SessionsAction {
+    /// List recorded sessions, newest first
+    List {
+        /// Output sessions as JSON
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Show one session: context at start, commits and gate results
+    Show {
+        /// Session id or unique prefix ("last" = the newest)
+        #[arg(default_value = "last")]
+        id: String,
+
+        /// Output the session as JSON
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Close an open session, recording its commits and last check results
+    Close {
+        /// Session id or unique prefix ("last" = the newest)
+        #[arg(default_value = "last")]
+        id: String,
+    },
+}
+
+#[derive(Subcommand)]
+enum HooksAction {
+    /// Install git hooks (pre-commit, commit-msg, pre-push), or AI CLI session hooks with --ai
+    ///
+    /// Honors core.hooksPath. A hook owned by another tool (husky, pre-commit,
+    /// lefthook) is kept as asimov-chained/<hook> and run first.
+    Install {
+        /// AI profile id or name (claude, gemini, ...): render .asimov/hooks
+        /// templates and register them in the AI CLI's settings instead
+        #[arg(long, value_name = "NAME")]
+        ai: Option<String>,
+
+        /// Only install these git hooks (default: all)
+        #[arg(long = "hook", value_name = "HOOK", conflicts_with = "ai")]
+        hooks: Vec<GitHook>,
+    },
+
+    /// Remove asimov's git hooks and restore the hooks they chained to
+    Uninstall,
+
+    /// Show git hooks and which session hooks are registered for each AI CLI
+    Status,
+}
+
+#[derive(Subcommand)]
+enum DepsAction {
+    /// Audit lockfiles offline: duplicates, count, depth, licenses, advisories
+    Audit {
+        /// Project directory
+        #[arg(default_value = ".")]
+        path: PathBuf,
+
+        /// Local advisory database (YAML/JSON; default: .asimov/advisories.yaml)
+        #[arg(long)]
+        advisories: Option<PathBuf>,
+
+        /// Also fail on BLOATED_DEPS and license findings
+        #[arg(long)]
+        strict: bool,
+
+        /// Output the audit as JSON
+        #[arg(long)]
+        json: bool,
+    },
+}
+
+#[derive(Subcommand)]
+enum TemplateAction {
+    /// Install a template pack from a directory or .tar.gz
+    Install {
+        /// Pack directory or archive containing manifest.yaml and template.yaml
+        source: PathBuf,
+
+        /// Replace an installed pack with the same name
+        #[arg(long)]
+        force: bool,
+    },
+
+    /// List built-in, project and installed templates
+    List,
+
+    /// Remove an installed template pack
+    Remove {
+        /// Pack name
+        name: String,
     },
 }
 
@@ -175,15 +462,24 @@ enum Commands {
 fn main() -> ExitCode {
     let cli = Cli::parse();
 
+    // v12.4.0: Via the environment, so launched AIs and hooks inherit it
+    if cli.offline {
+        std::env::set_var(royalbit_asimov::update::OFFLINE_ENV, "1");
+    }
+
     match cli.command {
-        None => cmd_launch(),
-        Some(Commands::Validate { ethics_scan }) => cmd_validate(ethics_scan),
+        None => cmd_launch(cli.ai.as_deref(), cli.autonomy, cli.supervise),
+        Some(Commands::Validate { ethics_scan, sizes }) => cmd_validate(ethics_scan, sizes),
         Some(Commands::Init {
             name,
             project_type,
             output,
             force,
-        }) => cmd_init(&name, &project_type, &output, force),
+        }) => cmd_init(&name, project_type.as_deref(), &output, force),
+        Some(Commands::LintCommit { target }) => cmd_lint_commit(&target),
+        Some(Commands::Changelog { write, date }) => {
+            cmd_changelog(&ChangelogOptions { write, date })
+        }
         Some(Commands::LintDocs {
             path,
             fix,
@@ -195,16 +491,86 @@ fn main() -> ExitCode {
             dry_run,
             json,
         }) => cmd_refresh(verbose, yes, dry_run, json),
-        Some(Commands::Update { check }) => cmd_update(check),
+        Some(Commands::Update {
+            check,
+            insecure,
+            channel,
+            version,
+            rollback,
+        }) => cmd_update(&UpdateOptions {
+            check_only: check,
+            insecure,
+            channel,
+            version,
+            rollback,
+        }),
         Some(Commands::Warmup { path, verbose }) => cmd_warmup(&path, verbose),
-        Some(Commands::Stats) => cmd_stats(),
-        Some(Commands::Doctor) => cmd_doctor(),
+        Some(Commands::Stats { since, json }) => cmd_stats(&StatsOptions { since }, json),
+        Some(Commands::Doctor { fix, json }) => cmd_doctor(fix, json),
         Some(Commands::Role { code }) => cmd_role(code.as_deref()),
         Some(Commands::Replay {
             commits,
             yesterday,
             since,
-            verbose: _,
-        }) => cmd_replay(commits, yesterday, since),
+            until,
+            author,
+            paths,
+            verbose,
+            json,
+        }) => {
+            let options = ReplayOptions {
+                commits,
+                yesterday,
+                since,
+                until,
+                author,
+                paths,
+                diffs: verbose,
+            };
+            cmd_replay(&options, json)
+        }
+        Some(Commands::Check {
+            gates,
+            parallel,
+            json,
+            staged,
+            profile,
+            no_cache,
+        }) => {
+            let options = CheckOptions {
+                gates,
+                parallel,
+                staged,
+                profile,
+                no_cache,
+            };
+            cmd_check(Path::new("."), &options, json)
+        }
+        Some(Commands::Detect { path, json }) => cmd_detect(&path, json),
+        Some(Commands::Deps { action }) => match action {
+            DepsAction::Audit {
+                path,
+                advisories,
+                strict,
+                json,
+            } => cmd_deps_audit(&path, &DepsAuditOptions { advisories, strict }, json),
+        },
+        Some(Commands::Licenses { path, json }) => cmd_licenses(&path, json),
+        Some(Commands::Template { action }) => match action {
+            TemplateAction::Install { source, force } => cmd_template_install(&source, force),
+            TemplateAction::List => cmd_template_list(),
+            TemplateAction::Remove { name } => cmd_template_remove(&name),
+        },
+        Some(Commands::Hooks { action }) => match action {
+            HooksAction::Install { ai: Some(ai), .. } => cmd_hooks_install(&ai),
+            HooksAction::Install { ai: None, hooks } => cmd_git_hooks_install(&hooks),
+            HooksAction::Uninstall => cmd_git_hooks_uninstall(),
+            HooksAction::Status => cmd_hooks_status(),
+        },
+        Some(Commands::Sessions { action }) => match action {
+            SessionsAction::List { json } => cmd_sessions_list(json),
+            SessionsAction::Show { id, json } => cmd_sessions_show(&id, json),
+            SessionsAction::Close { id } => cmd_sessions_close(&id),
+        },
     }
 }
@@ -0,0 +1,39 @@
+//! Changelog output (v12.4.0)
+
+use colored::Colorize;
+use royalbit_asimov::commands::{run_changelog, ChangelogOptions};
+use std::path::Path;
+use std::process::ExitCode;
+
+/// Prints the draft on stdout (pipeable) and notes on stderr, or writes it
+#[cfg_attr(feature = "coverage", coverage(off))]
+pub(crate) fn cmd_changelog(options: &ChangelogOptions) -> ExitCode {
+    let draft = match run_changelog(Path::new("."), options) {
+        Ok(draft) => draft,
+        Err(e) => {
+            eprintln!("{} {}", "Error:".bold().red(), e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let source = match &draft.previous_tag {
+        Some(tag) => format!("{} commit(s) since {}", draft.commits, tag),
+        None => format!("{} commit(s), no previous tag", draft.commits),
+    };
+    if draft.written {
+        println!(
+            "{} Added [{}] to {} ({})",
+            "✓".bright_green(),
+            draft.version,
+            draft.path.display(),
+            source
+        );
+    } else {
+        print!("{}", draft.entry);
+        eprintln!(
+            "{}",
+            format!("Draft from {}; `asimov changelog --write` adds it", source).dimmed()
+        );
+    }
+    ExitCode::SUCCESS
+}
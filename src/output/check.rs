@@ -0,0 +1,101 @@
+//! Quality gate check output (v12.4.0)
+
+use colored::Colorize;
+use royalbit_asimov::commands::{record_check, run_check, CheckOptions, GateRun, GateStatus};
+use std::path::Path;
+use std::process::ExitCode;
+
+/// Lines of captured output shown for a failed gate
+const FAILURE_TAIL_LINES: usize = 20;
+
+#[cfg_attr(feature = "coverage", coverage(off))]
+pub(crate) fn cmd_check(dir: &Path, options: &CheckOptions, json: bool) -> ExitCode {
+    let result = match run_check(dir, options) {
+        Ok(result) => result,
+        Err(e) => {
+            eprintln!("{} {}", "Error:".bold().red(), e);
+            return ExitCode::FAILURE;
+        }
+    };
+    // v12.4.0: The session this runs in reports these results when it closes
+    if let Err(e) = record_check(dir, &result) {
+        eprintln!("{} Session not recorded: {}", "Warning:".yellow(), e);
+    }
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&result).unwrap());
+    } else {
+        println!(
+            "{} (profile: {})",
+            "RoyalBit Asimov - CHECK".bold().green(),
+            result.profile
+        );
+        println!();
+        if let Some(note) = &result.note {
+            println!("  {}", note.dimmed());
+        }
+        for gate in &result.gates {
+            print_gate(gate);
+        }
+        println!();
+        if result.success {
+            println!("{} All gates passed", "Success:".bold().green());
+        } else {
+            let failed: Vec<&str> = result.failed().iter().map(|g| g.name.as_str()).collect();
+            println!("{} {} failed", "Error:".bold().red(), failed.join(", "));
+        }
+    }
+
+    if result.success {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+#[cfg_attr(feature = "coverage", coverage(off))]
+fn print_gate(gate: &GateRun) {
+    let seconds = format!("{:.1}s", gate.duration_ms as f64 / 1000.0);
+    match gate.status {
+        GateStatus::Passed => println!(
+            "  {} {:<8} {}  {}",
+            "✓".bright_green(),
+            gate.name,
+            seconds.dimmed(),
+            gate.command.dimmed()
+        ),
+        GateStatus::Cached => println!(
+            "  {} {:<8} {}  {}",
+            "✓".bright_green(),
+            gate.name,
+            "cached".bright_blue(),
+            gate.command.dimmed()
+        ),
+        GateStatus::Skipped => println!(
+            "  {} {:<8} {}",
+            "-".dimmed(),
+            gate.name,
+            "skipped (full profile)".dimmed()
+        ),
+        GateStatus::Failed | GateStatus::TimedOut => {
+            let reason = match (gate.status, gate.exit_code) {
+                (GateStatus::TimedOut, _) => "timed out".to_string(),
+                (_, Some(code)) => format!("exit {}", code),
+                _ => "killed".to_string(),
+            };
+            println!(
+                "  {} {:<8} {}  {} ({})",
+                "✗".bright_red(),
+                gate.name,
+                seconds,
+                gate.command,
+                reason.red()
+            );
+            let output = format!("{}{}", gate.stdout, gate.stderr);
+            let lines: Vec<&str> = output.lines().collect();
+            for line in &lines[lines.len().saturating_sub(FAILURE_TAIL_LINES)..] {
+                println!("      {}", line);
+            }
+        }
+    }
+}
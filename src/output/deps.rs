@@ -0,0 +1,159 @@
+//! Dependency audit output (v12.4.0, ADR-045)
+
+use colored::Colorize;
+use royalbit_asimov::commands::{run_deps_audit, DepsAuditOptions};
+use std::path::Path;
+use std::process::ExitCode;
+
+/// Duplicates listed before the output is summarized
+const MAX_DUPLICATES_SHOWN: usize = 15;
+
+#[cfg_attr(feature = "coverage", coverage(off))]
+pub(crate) fn cmd_deps_audit(dir: &Path, options: &DepsAuditOptions, json: bool) -> ExitCode {
+    let result = match run_deps_audit(dir, options) {
+        Ok(result) => result,
+        Err(e) => {
+            eprintln!("{} {}", "Error:".bold().red(), e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&result).unwrap());
+        return if result.success {
+            ExitCode::SUCCESS
+        } else {
+            ExitCode::FAILURE
+        };
+    }
+
+    let audit = &result.audit;
+    println!("{}", "RoyalBit Asimov - DEPS AUDIT".bold().green());
+    println!();
+
+    println!("{}", "LOCKFILES".bold());
+    for lockfile in &audit.lockfiles {
+        let depth = lockfile
+            .depth
+            .map(|d| format!(", depth {}", d))
+            .unwrap_or_default();
+        println!(
+            "  {} {} ({}): {} packages, {} direct{}",
+            "✓".green(),
+            lockfile.path,
+            lockfile.ecosystem,
+            lockfile.packages,
+            lockfile.direct,
+            depth
+        );
+    }
+
+    if !audit.duplicates.is_empty() {
+        println!();
+        println!("{}", "DUPLICATE VERSIONS".bold());
+        for dup in audit.duplicates.iter().take(MAX_DUPLICATES_SHOWN) {
+            println!(
+                "  {} {} ({}): {}",
+                "⚠".yellow(),
+                dup.name,
+                dup.ecosystem,
+                dup.versions.join(", ")
+            );
+        }
+        if audit.duplicates.len() > MAX_DUPLICATES_SHOWN {
+            println!(
+                "  {}",
+                format!(
+                    "... and {} more (use --json)",
+                    audit.duplicates.len() - MAX_DUPLICATES_SHOWN
+                )
+                .dimmed()
+            );
+        }
+    }
+
+    println!();
+    println!("{}", "GREEN (BLOATED_DEPS)".bold());
+    if audit.bloat.is_empty() {
+        println!("  {} Within limits", "✓".green());
+    }
+    for finding in &audit.bloat {
+        println!("  {} {}", "⚠".yellow(), finding.message);
+    }
+
+    println!();
+    println!("{}", "LICENSES".bold());
+    println!(
+        "  Project: {}",
+        result.project_license.as_deref().unwrap_or("unknown")
+    );
+    if !audit.licenses.is_empty() {
+        let mix: Vec<String> = audit
+            .licenses
+            .iter()
+            .map(|(license, count)| format!("{} ({})", license, count))
+            .collect();
+        println!("  Dependencies: {}", mix.join(", "));
+    }
+    if audit.unknown_licenses > 0 {
+        println!(
+            "  {}",
+            format!(
+                "{} package(s) without license data in the lockfile",
+                audit.unknown_licenses
+            )
+            .dimmed()
+        );
+    }
+    for conflict in &result.license_conflicts {
+        println!("  {} {}", "⚠".yellow(), conflict);
+    }
+
+    println!();
+    println!("{}", "ADVISORIES".bold());
+    match &result.advisory_db {
+        None => println!(
+            "  {}",
+            "No advisory database (add .asimov/advisories.yaml or pass --advisories)".dimmed()
+        ),
+        Some(db) if audit.vulnerabilities.is_empty() => {
+            println!("  {} No known advisories ({})", "✓".green(), db)
+        }
+        Some(_) => {
+            for m in &audit.vulnerabilities {
+                println!(
+                    "  {} {} {} {} [{}]{}",
+                    "✗".red(),
+                    m.advisory.package,
+                    m.version,
+                    m.advisory.id.bold(),
+                    m.advisory.severity.as_deref().unwrap_or("unknown"),
+                    m.advisory
+                        .summary
+                        .as_ref()
+                        .map(|s| format!(" - {}", s))
+                        .unwrap_or_default()
+                );
+            }
+        }
+    }
+
+    println!();
+    if result.success {
+        println!("{} Dependency audit passed", "Success:".bold().green());
+        ExitCode::SUCCESS
+    } else if !audit.vulnerabilities.is_empty() {
+        println!(
+            "{} {} vulnerable package(s)",
+            "Error:".bold().red(),
+            audit.vulnerabilities.len()
+        );
+        ExitCode::FAILURE
+    } else {
+        println!(
+            "{} Dependency health findings (--strict)",
+            "Error:".bold().red()
+        );
+        ExitCode::FAILURE
+    }
+}
@@ -0,0 +1,70 @@
+//! Project type detection output (v12.4.0)
+
+use colored::Colorize;
+use royalbit_asimov::commands::run_detect;
+use std::path::Path;
+use std::process::ExitCode;
+
+#[cfg_attr(feature = "coverage", coverage(off))]
+pub(crate) fn cmd_detect(path: &Path, json: bool) -> ExitCode {
+    let report = match run_detect(path) {
+        Ok(report) => report,
+        Err(e) => {
+            eprintln!("{} {}", "Error:".bold().red(), e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&report).unwrap());
+        return ExitCode::SUCCESS;
+    }
+
+    println!("{}", "RoyalBit Asimov - DETECT".bold().green());
+    println!();
+    println!(
+        "  {} {}",
+        "Project type:".bold(),
+        report.primary().to_string().bright_cyan().bold()
+    );
+    println!();
+
+    if report.candidates.is_empty() {
+        println!("  {}", "No project markers found (generic)".dimmed());
+    }
+    for (i, c) in report.candidates.iter().enumerate() {
+        let line = format!(
+            "{:>3.0}%  {} (score {})",
+            c.confidence * 100.0,
+            c.project_type,
+            c.score
+        );
+        if i == 0 {
+            println!("  {}", line.bold());
+        } else {
+            println!("  {}", line);
+        }
+        for e in &c.evidence {
+            println!(
+                "         {} {} {}",
+                format!("{:<12}", e.kind).dimmed(),
+                e.detail,
+                format!("+{}", e.weight).dimmed()
+            );
+        }
+    }
+
+    if !report.file_counts.is_empty() {
+        println!();
+        let counts: Vec<String> = report
+            .file_counts
+            .iter()
+            .map(|(lang, n)| format!("{} {}", lang, n))
+            .collect();
+        println!("  {} {}", "Files:".bold(), counts.join(", "));
+    }
+    if !report.lockfiles.is_empty() {
+        println!("  {} {}", "Lockfiles:".bold(), report.lockfiles.join(", "));
+    }
+    ExitCode::SUCCESS
+}
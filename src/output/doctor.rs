@@ -0,0 +1,78 @@
+//! Doctor output (v12.4.0: severities, custom checks, --fix and --json)
+
+use colored::Colorize;
+use royalbit_asimov::commands::{run_doctor_with_options, CheckSeverity, DoctorOptions};
+use std::process::ExitCode;
+
+#[cfg_attr(feature = "coverage", coverage(off))]
+pub(crate) fn cmd_doctor(fix: bool, json: bool) -> ExitCode {
+    let options = DoctorOptions { fix };
+    let result = run_doctor_with_options(std::path::Path::new("."), &options);
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&result).unwrap());
+    } else {
+        println!("{}", "RoyalBit ASIMOV - DOCTOR".bold().green());
+        println!();
+
+        // v9.8.0: Display detected license (ADR-045)
+        if let Some(ref license) = result.license {
+            println!("{}", "DEPENDENCY HEALTH".bold());
+            println!("  License: {} (detected)", license.bright_blue());
+            println!();
+        }
+
+        for check in &result.checks {
+            let icon = match (check.passed, check.severity) {
+                (true, _) => "✓".green(),
+                (false, CheckSeverity::Error) => "✗".red(),
+                (false, CheckSeverity::Warn) => "⚠".yellow(),
+                (false, CheckSeverity::Info) => "ℹ".blue(),
+            };
+            println!("  {} {}: {}", icon, check.name, check.message);
+            if check.fixed {
+                if let Some(ref repair) = check.fix {
+                    println!("      {} Fixed: {}", "→".yellow(), repair);
+                }
+            }
+        }
+
+        print_list("Issues:".bold().red(), &result.issues);
+        print_list("Warnings:".bold().yellow(), &result.warnings);
+        print_list("Notes:".bold().blue(), &result.notes);
+
+        println!();
+        let passed = result.checks.iter().filter(|c| c.passed).count();
+        let total = result.checks.len();
+        println!("{} {}/{} checks passed", "Result:".bold(), passed, total);
+
+        // v12.4.0: Repairs are opt-in
+        if !result.fixes_available.is_empty() {
+            println!();
+            println!(
+                "{} 'asimov doctor --fix' would apply:",
+                "Fixes:".bold().yellow()
+            );
+            for repair in &result.fixes_available {
+                println!("  • {}", repair);
+            }
+        }
+    }
+
+    if result.healthy() {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+fn print_list(title: colored::ColoredString, items: &[String]) {
+    if items.is_empty() {
+        return;
+    }
+    println!();
+    println!("{}", title);
+    for item in items {
+        println!("  • {}", item);
+    }
+}
@@ -0,0 +1,180 @@
+//! Git hook and AI CLI session hook output (v12.4.0)
+
+use colored::Colorize;
+use royalbit_asimov::commands::{
+    run_git_hooks_install, run_git_hooks_status, run_git_hooks_uninstall, run_hooks_install,
+    run_hooks_status,
+};
+use royalbit_asimov::git_hooks::{GitHook, GitHookChange, GitHookState, HookAction, CHAINED_DIR};
+use std::path::Path;
+use std::process::ExitCode;
+
+#[cfg_attr(feature = "coverage", coverage(off))]
+pub(crate) fn cmd_hooks_install(ai: &str) -> ExitCode {
+    let result = match run_hooks_install(Path::new("."), ai) {
+        Ok(result) => result,
+        Err(e) => {
+            eprintln!("{} {}", "Error:".bold().red(), e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    println!("{}", "RoyalBit Asimov - HOOKS".bold().green());
+    println!();
+    println!("  AI: {}", result.profile.name.bright_cyan().bold());
+    for script in &result.scripts {
+        println!("  {} Rendered {}", "✓".bright_green(), script.display());
+    }
+    for script in &result.kept {
+        println!(
+            "  {} Kept {} (not written by asimov)",
+            "⚠".yellow(),
+            script.display()
+        );
+    }
+    for event in &result.added {
+        println!(
+            "  {} Registered {} in {}",
+            "✓".bright_green(),
+            event.bold(),
+            result.settings.display()
+        );
+    }
+    for event in &result.unchanged {
+        println!("  {} {} already registered", "✓".dimmed(), event);
+    }
+    ExitCode::SUCCESS
+}
+
+#[cfg_attr(feature = "coverage", coverage(off))]
+pub(crate) fn cmd_git_hooks_install(hooks: &[GitHook]) -> ExitCode {
+    print_git_hook_changes(run_git_hooks_install(Path::new("."), hooks))
+}
+
+#[cfg_attr(feature = "coverage", coverage(off))]
+pub(crate) fn cmd_git_hooks_uninstall() -> ExitCode {
+    print_git_hook_changes(run_git_hooks_uninstall(Path::new(".")))
+}
+
+#[cfg_attr(feature = "coverage", coverage(off))]
+fn print_git_hook_changes(changes: Result<Vec<GitHookChange>, String>) -> ExitCode {
+    let changes = match changes {
+        Ok(changes) => changes,
+        Err(e) => {
+            eprintln!("{} {}", "Error:".bold().red(), e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    println!("{}", "RoyalBit Asimov - HOOKS".bold().green());
+    println!();
+    if changes.is_empty() {
+        println!("  No asimov git hooks installed");
+    }
+    for change in &changes {
+        let path = change.path.display();
+        match change.action {
+            HookAction::Installed => println!("  {} Installed {}", "✓".bright_green(), path),
+            HookAction::Updated => println!("  {} Updated {}", "✓".bright_green(), path),
+            HookAction::Unchanged => println!("  {} {} up to date", "✓".dimmed(), path),
+            HookAction::Chained => println!(
+                "  {} Installed {} (runs the existing hook first, kept as {}/{})",
+                "✓".bright_green(),
+                path,
+                CHAINED_DIR,
+                change.hook.name()
+            ),
+            HookAction::Kept => {
+                println!("  {} Kept {} (not generated by asimov)", "⚠".yellow(), path)
+            }
+            HookAction::Removed => println!("  {} Removed {}", "✓".bright_green(), path),
+            HookAction::Restored => println!(
+                "  {} Removed asimov's hook, restored the previous {}",
+                "✓".bright_green(),
+                path
+            ),
+        }
+    }
+    ExitCode::SUCCESS
+}
+
+#[cfg_attr(feature = "coverage", coverage(off))]
+pub(crate) fn cmd_hooks_status() -> ExitCode {
+    let statuses = match run_hooks_status(Path::new(".")) {
+        Ok(statuses) => statuses,
+        Err(e) => {
+            eprintln!("{} {}", "Error:".bold().red(), e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    println!("{}", "RoyalBit Asimov - HOOKS".bold().green());
+    if let Some(git) = run_git_hooks_status(Path::new(".")) {
+        println!();
+        println!("{} ({})", "git".bold(), git.hooks_dir.display());
+        for (hook, state) in &git.hooks {
+            match state {
+                GitHookState::Asimov { chained: false } => {
+                    println!("  {} {}", "✓".bright_green(), hook)
+                }
+                GitHookState::Asimov { chained: true } => println!(
+                    "  {} {} (chains to {}/{})",
+                    "✓".bright_green(),
+                    hook,
+                    CHAINED_DIR,
+                    hook
+                ),
+                GitHookState::Foreign => {
+                    println!("  {} {} (not generated by asimov)", "⚠".yellow(), hook)
+                }
+                GitHookState::Missing => println!("  {} {} (not installed)", "-".dimmed(), hook),
+            }
+        }
+        if git
+            .hooks
+            .iter()
+            .any(|(_, state)| !matches!(state, GitHookState::Asimov { .. }))
+        {
+            println!("  Install: {}", "asimov hooks install".bold());
+        }
+    }
+    for status in &statuses {
+        println!();
+        let Some(settings) = &status.settings else {
+            println!(
+                "{} {}",
+                status.profile.name.bold(),
+                "no native hooks (use asimov --supervise)".dimmed()
+            );
+            continue;
+        };
+        println!("{} ({})", status.profile.name.bold(), settings.display());
+        if let Some(error) = &status.error {
+            println!("  {} {}", "✗".red(), error);
+        }
+        for event in &status.events {
+            let mark = match (event.registered, event.script_ok) {
+                (true, true) => "✓".bright_green(),
+                (true, false) => "✗".red(),
+                (false, _) => "-".dimmed(),
+            };
+            let mut line = format!("  {} {} -> {}", mark, event.event, event.hook.name());
+            if event.registered && !event.script_ok {
+                line.push_str(" (script missing or not executable)");
+            } else if !event.registered {
+                line.push_str(" (not installed)");
+            }
+            if event.other_hooks > 0 {
+                line.push_str(&format!(", {} other hook(s)", event.other_hooks));
+            }
+            println!("{}", line);
+        }
+        if status.events.iter().any(|e| !e.registered) {
+            println!(
+                "  Install: {}",
+                format!("asimov hooks install --ai {}", status.profile.id).bold()
+            );
+        }
+    }
+    ExitCode::SUCCESS
+}
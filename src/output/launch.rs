@@ -0,0 +1,268 @@
+//! Launcher output: pick an AI CLI and start it with the warmup context
+//!
+//! v12.4.0: Moved out of output/mod.rs; `--supervise` runs the CLI under
+//! the session supervisor, which re-injects `refresh --json` context.
+
+use colored::Colorize;
+use royalbit_asimov::commands::{
+    check_launch_conditions, close_session, load_launch_config, run_warmup, start_session,
+    AiProfile, Autonomy, LaunchResult, NewSession, PromptMode, SessionSource, SESSION_ENV,
+};
+use royalbit_asimov::supervisor::{supervise, SupervisedCommand, DEFAULT_QUIET};
+use std::io::{self, IsTerminal as _, Write as _};
+use std::process::ExitCode;
+
+/// Prompt user to select an AI CLI when multiple are available
+#[cfg_attr(feature = "coverage", coverage(off))]
+fn prompt_ai_selection(profiles: &[AiProfile]) -> Option<AiProfile> {
+    println!("{}", "Multiple AI CLIs detected:".bold().yellow());
+    println!();
+    for (i, profile) in profiles.iter().enumerate() {
+        println!("  {}. {} ({})", i + 1, profile.name, profile.binary);
+    }
+    println!();
+    print!("Select AI to launch [1-{}]: ", profiles.len());
+    io::stdout().flush().ok()?;
+
+    let mut input = String::new();
+    io::stdin().read_line(&mut input).ok()?;
+    let choice: usize = input.trim().parse().ok()?;
+
+    if choice >= 1 && choice <= profiles.len() {
+        Some(profiles[choice - 1].clone())
+    } else {
+        None
+    }
+}
+
+/// Launch an AI CLI with minimal warmup context (v12.2.0)
+#[cfg_attr(feature = "coverage", coverage(off))]
+fn launch_ai(profile: &AiProfile, autonomy: Autonomy, supervise: bool) -> ExitCode {
+    println!(
+        "{}",
+        format!("Launching {} ({})...", profile.name, autonomy).bright_cyan()
+    );
+
+    // Get warmup content directly
+    let warmup_result = run_warmup(std::path::Path::new("."), false);
+    if warmup_result.error.is_some() {
+        eprintln!(
+            "{} Failed to generate warmup context",
+            "Error:".bold().red()
+        );
+        return ExitCode::FAILURE;
+    }
+
+    // Pass warmup as prompt argument (not stdin - breaks terminal raw mode)
+    // v12.4.0: or as a file, per the profile's prompt mode
+    let prompt = super::warmup_json(&warmup_result).to_string();
+    let prompt_file = match profile.prompt {
+        PromptMode::File => match write_prompt_file(&prompt) {
+            Ok(path) => Some(path),
+            Err(e) => {
+                eprintln!(
+                    "{} Failed to write the warmup file: {}",
+                    "Error:".bold().red(),
+                    e
+                );
+                return ExitCode::FAILURE;
+            }
+        },
+        _ => None,
+    };
+
+    // v12.4.0: Journal the session; the AI's own warmup/role calls join it
+    let dir = std::path::Path::new(".");
+    let session = if dir.join(".asimov").is_dir() {
+        let new = NewSession {
+            source: SessionSource::Launch,
+            ai: Some(profile.id.clone()),
+            autonomy: Some(autonomy.to_string()),
+            supervisor: supervise,
+        };
+        match start_session(dir, &new) {
+            Ok(start) => {
+                std::env::set_var(SESSION_ENV, &start.id);
+                Some(start)
+            }
+            Err(e) => {
+                eprintln!("{} Session not recorded: {}", "Warning:".yellow(), e);
+                None
+            }
+        }
+    } else {
+        None
+    };
+
+    let mut args = profile.launch_args(autonomy);
+    args.extend(profile.prompt_args(
+        &prompt,
+        prompt_file.as_deref().unwrap_or(std::path::Path::new("")),
+    ));
+    let exit_code = if supervise {
+        run_supervised(profile, args)
+    } else {
+        run_direct(profile, args)
+    };
+    drop(prompt_file);
+
+    if let Some(start) = session {
+        match close_session(dir, &start.id, exit_code) {
+            Ok(end) => println!(
+                "{} Session {} recorded: {} commit(s), {}",
+                "✓".green(),
+                start.id,
+                end.commits.len(),
+                super::sessions::gate_summary(&end.gates)
+            ),
+            Err(e) => eprintln!("{} Session not closed: {}", "Warning:".yellow(), e),
+        }
+    }
+
+    match exit_code {
+        Some(0) => ExitCode::SUCCESS,
+        Some(code) => ExitCode::from(u8::try_from(code).unwrap_or(1)),
+        None => ExitCode::FAILURE,
+    }
+}
+
+/// The warmup JSON in a new private temp file, removed when dropped
+fn write_prompt_file(prompt: &str) -> std::io::Result<tempfile::TempPath> {
+    use std::io::Write;
+    let mut file = tempfile::Builder::new()
+        .prefix("asimov-warmup-")
+        .suffix(".json")
+        .tempfile()?;
+    file.write_all(prompt.as_bytes())?;
+    Ok(file.into_temp_path())
+}
+
+/// Run the AI CLI attached to the terminal; None if it could not start
+#[cfg_attr(feature = "coverage", coverage(off))]
+fn run_direct(profile: &AiProfile, args: Vec<String>) -> Option<i32> {
+    match std::process::Command::new(&profile.binary)
+        .args(args)
+        .status()
+    {
+        // Killed by a signal: no code
+        Ok(status) => Some(status.code().unwrap_or(1)),
+        Err(e) => {
+            eprintln!(
+                "{} Failed to start {}: {}",
+                "Error:".bold().red(),
+                profile.name,
+                e
+            );
+            None
+        }
+    }
+}
+
+#[cfg_attr(feature = "coverage", coverage(off))]
+pub(crate) fn cmd_launch(
+    ai: Option<&str>,
+    autonomy: Option<Autonomy>,
+    supervise: bool,
+) -> ExitCode {
+    let config = match load_launch_config(std::path::Path::new(".")) {
+        Ok(config) => config,
+        Err(e) => {
+            eprintln!("{} {}", "Error:".bold().red(), e);
+            return ExitCode::FAILURE;
+        }
+    };
+    // v12.4.0: --ai beats default_ai; --autonomy beats the user config
+    let preferred = ai.or(config.default_ai.as_deref());
+    let autonomy = autonomy.unwrap_or(config.autonomy);
+    match check_launch_conditions(&config.profiles, preferred) {
+        LaunchResult::InsideAi(name) => {
+            // Inside an AI session - run warmup directly
+            if std::env::var("ASIMOV_DEBUG").is_ok() {
+                eprintln!("{} Inside {} session", "Debug:".dimmed(), name);
+            }
+            super::cmd_warmup(std::path::Path::new("."), false)
+        }
+        LaunchResult::NoAiFound => {
+            eprintln!("{} No AI CLI found in PATH", "Error:".bold().red());
+            eprintln!();
+            eprintln!("Install one of:");
+            eprintln!("  Claude Code: https://claude.ai/download");
+            eprintln!("  Gemini CLI:  https://cloud.google.com/gemini-cli");
+            eprintln!("  Codex CLI:   https://github.com/openai/codex");
+            eprintln!();
+            eprintln!("Or add your own under ai_profiles in ~/.config/asimov/config.yaml");
+            ExitCode::FAILURE
+        }
+        LaunchResult::UnknownAi(name) => {
+            let ids: Vec<&str> = config.profiles.iter().map(|p| p.id.as_str()).collect();
+            eprintln!(
+                "{} Unknown AI '{}' (profiles: {})",
+                "Error:".bold().red(),
+                name,
+                ids.join(", ")
+            );
+            ExitCode::FAILURE
+        }
+        LaunchResult::NotInstalled(profile) => {
+            eprintln!(
+                "{} {} is not installed ('{}' not found in PATH)",
+                "Error:".bold().red(),
+                profile.name,
+                profile.binary
+            );
+            ExitCode::FAILURE
+        }
+        LaunchResult::Launching(profile) => launch_ai(&profile, autonomy, supervise),
+        // v12.4.0: Never block on stdin in scripts or tmux send-keys pipelines
+        LaunchResult::MultipleFound(profiles) if !io::stdin().is_terminal() => {
+            let ids: Vec<&str> = profiles.iter().map(|p| p.id.as_str()).collect();
+            eprintln!(
+                "{} Multiple AI CLIs found ({}); use --ai <id> or set default_ai",
+                "Error:".bold().red(),
+                ids.join(", ")
+            );
+            ExitCode::FAILURE
+        }
+        LaunchResult::MultipleFound(profiles) => match prompt_ai_selection(&profiles) {
+            Some(profile) => launch_ai(&profile, autonomy, supervise),
+            None => {
+                eprintln!("{} Invalid selection", "Error:".bold().red());
+                ExitCode::FAILURE
+            }
+        },
+    }
+}
+
+/// Run the AI CLI under the session supervisor (v12.4.0); None if it
+/// could not start
+#[cfg_attr(feature = "coverage", coverage(off))]
+fn run_supervised(profile: &AiProfile, args: Vec<String>) -> Option<i32> {
+    let command = SupervisedCommand {
+        program: profile.binary.clone(),
+        args,
+        markers: profile.markers.clone(),
+        quiet: DEFAULT_QUIET,
+    };
+    // Outside an asimov project there is nothing to re-inject
+    let mut context = |_event| {
+        std::path::Path::new(".asimov")
+            .is_dir()
+            .then(|| super::self_heal_json().to_string())
+    };
+    match supervise(&command, &mut context) {
+        Ok(report) => {
+            if std::env::var("ASIMOV_DEBUG").is_ok() {
+                eprintln!(
+                    "{} Re-injected context {} time(s)",
+                    "Debug:".dimmed(),
+                    report.injected.len()
+                );
+            }
+            Some(i32::try_from(report.exit_code).unwrap_or(1))
+        }
+        Err(e) => {
+            eprintln!("{} {}", "Error:".bold().red(), e);
+            None
+        }
+    }
+}
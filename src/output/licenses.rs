@@ -0,0 +1,98 @@
+//! Dependency license output (v12.4.0, ADR-045)
+
+use colored::Colorize;
+use royalbit_asimov::commands::run_licenses;
+use std::path::Path;
+use std::process::ExitCode;
+
+/// Unknown-license packages listed before the output is summarized
+const MAX_UNKNOWN_SHOWN: usize = 10;
+
+#[cfg_attr(feature = "coverage", coverage(off))]
+pub(crate) fn cmd_licenses(dir: &Path, json: bool) -> ExitCode {
+    let result = match run_licenses(dir) {
+        Ok(result) => result,
+        Err(e) => {
+            eprintln!("{} {}", "Error:".bold().red(), e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&result).unwrap());
+    } else {
+        println!("{}", "RoyalBit Asimov - LICENSES".bold().green());
+        println!();
+        println!(
+            "  {} {}",
+            "Project license:".bold(),
+            result.project_license.as_deref().unwrap_or("unknown")
+        );
+        println!(
+            "  {} {} ({})",
+            "Policy:".bold(),
+            result.policy_source,
+            result.lockfiles.join(", ")
+        );
+        if !result.policy.allow.is_empty() {
+            println!("    allow: {}", result.policy.allow.join(", "));
+        }
+        if !result.policy.deny.is_empty() {
+            println!("    deny:  {}", result.policy.deny.join(", "));
+        }
+        println!();
+
+        for v in &result.check.violations {
+            println!(
+                "  {} {} {} ({}): {}",
+                "✗".red(),
+                v.name,
+                v.version,
+                v.license.as_deref().unwrap_or("unknown"),
+                v.reason
+            );
+        }
+        if !result.check.unknown.is_empty() {
+            let shown: Vec<&str> = result
+                .check
+                .unknown
+                .iter()
+                .take(MAX_UNKNOWN_SHOWN)
+                .map(String::as_str)
+                .collect();
+            let more = result.check.unknown.len().saturating_sub(MAX_UNKNOWN_SHOWN);
+            println!(
+                "  {} {} package(s) without local license metadata: {}{}",
+                "⚠".yellow(),
+                result.check.unknown.len(),
+                shown.join(", "),
+                if more > 0 {
+                    format!(" (+{} more)", more)
+                } else {
+                    String::new()
+                }
+            );
+        }
+
+        println!();
+        if result.success {
+            println!(
+                "{} {} package(s) comply with the license policy",
+                "Success:".bold().green(),
+                result.check.checked
+            );
+        } else {
+            println!(
+                "{} {} license violation(s)",
+                "Error:".bold().red(),
+                result.check.violations.len()
+            );
+        }
+    }
+
+    if result.success {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
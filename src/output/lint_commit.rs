@@ -0,0 +1,48 @@
+//! Commit message lint output (v12.4.0)
+
+use colored::Colorize;
+use royalbit_asimov::commands::run_lint_commit;
+use std::path::Path;
+use std::process::ExitCode;
+
+#[cfg_attr(feature = "coverage", coverage(off))]
+pub(crate) fn cmd_lint_commit(target: &str) -> ExitCode {
+    let result = match run_lint_commit(Path::new("."), target) {
+        Ok(result) => result,
+        Err(e) => {
+            eprintln!("{} {}", "Error:".bold().red(), e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    for commit in &result.commits {
+        let mark = if commit.violations.is_empty() {
+            "✓".bright_green()
+        } else {
+            "✗".red()
+        };
+        let mut line = format!("{} ", mark);
+        if let Some(hash) = &commit.commit {
+            line.push_str(&format!("{} ", hash.yellow()));
+        }
+        line.push_str(&commit.subject);
+        if let Some(milestone) = &commit.milestone {
+            line.push_str(&format!(" {}", format!("(v{})", milestone).dimmed()));
+        }
+        println!("{}", line);
+        for violation in &commit.violations {
+            println!("    {}", violation);
+        }
+    }
+
+    if result.is_ok() {
+        ExitCode::SUCCESS
+    } else {
+        println!();
+        println!(
+            "{} Commit message policy violated (see commit_policy in .asimov/project.yaml)",
+            "Error:".bold().red()
+        );
+        ExitCode::FAILURE
+    }
+}
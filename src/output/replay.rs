@@ -0,0 +1,100 @@
+//! Replay output (v12.4.0: per-commit stats, diffs and JSON)
+
+use colored::Colorize;
+use royalbit_asimov::commands::{run_replay, ReplayOptions};
+use std::process::ExitCode;
+
+#[cfg_attr(feature = "coverage", coverage(off))]
+pub(crate) fn cmd_replay(options: &ReplayOptions, json: bool) -> ExitCode {
+    let result = run_replay(std::path::Path::new("."), options);
+
+    if !result.is_git_repo {
+        eprintln!("{} Not a git repository", "Error:".bold().red());
+        return ExitCode::FAILURE;
+    }
+
+    if let Some(ref err) = result.error {
+        eprintln!("{} {}", "Error:".bold().red(), err);
+        return ExitCode::FAILURE;
+    }
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&result).unwrap());
+        return ExitCode::SUCCESS;
+    }
+
+    println!("{}", "RoyalBit Asimov Replay".bold().green());
+    println!();
+    println!("  Range: {}", result.range_description.bright_blue());
+    println!("  Commits: {}", result.commits.len());
+    println!("  Files changed: {}", result.total_files_changed);
+    println!(
+        "  Insertions: +{}",
+        result.total_insertions.to_string().green()
+    );
+    println!("  Deletions: -{}", result.total_deletions.to_string().red());
+    println!();
+
+    // Dates only matter when the range spans several days
+    let multi_day = result
+        .commits
+        .iter()
+        .any(|c| c.date != result.commits[0].date);
+    for commit in &result.commits {
+        let when = if multi_day {
+            format!("{} {}", commit.date, commit.time)
+        } else {
+            commit.time.clone()
+        };
+        let milestone = commit
+            .milestone
+            .as_ref()
+            .map(|m| format!(" {}", format!("(v{})", m).dimmed()))
+            .unwrap_or_default();
+        println!(
+            "  {} {} {}{} {} {}",
+            commit.hash.bright_yellow(),
+            when.dimmed(),
+            commit.message,
+            milestone,
+            format!("+{}", commit.insertions).green(),
+            format!("-{}", commit.deletions).red()
+        );
+
+        if options.diffs {
+            println!("      {}", commit.author.dimmed());
+            for file in &commit.files {
+                if file.binary {
+                    println!("      {} {}", "bin".dimmed(), file.path);
+                } else {
+                    println!(
+                        "      {} {} {}",
+                        format!("+{}", file.insertions).green(),
+                        format!("-{}", file.deletions).red(),
+                        file.path
+                    );
+                }
+            }
+            if let Some(diff) = &commit.diff {
+                println!();
+                for line in diff.lines() {
+                    let line = if line.starts_with('+') && !line.starts_with("+++") {
+                        line.green().to_string()
+                    } else if line.starts_with('-') && !line.starts_with("---") {
+                        line.red().to_string()
+                    } else {
+                        line.to_string()
+                    };
+                    println!("      {}", line);
+                }
+                println!();
+            }
+        }
+    }
+
+    if result.success {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
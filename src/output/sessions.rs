@@ -0,0 +1,178 @@
+//! Session journal output (v12.4.0)
+
+use colored::Colorize;
+use royalbit_asimov::commands::{
+    close_session, find_session, load_sessions, GateStatus, Session, SessionGate,
+};
+use std::path::Path;
+use std::process::ExitCode;
+
+#[cfg_attr(feature = "coverage", coverage(off))]
+pub(crate) fn gate_summary(gates: &[SessionGate]) -> String {
+    let ran = gates
+        .iter()
+        .filter(|g| g.status != GateStatus::Skipped)
+        .count();
+    let failed = gates
+        .iter()
+        .filter(|g| matches!(g.status, GateStatus::Failed | GateStatus::TimedOut))
+        .count();
+    match (ran, failed) {
+        (0, _) => "no check run".to_string(),
+        (ran, 0) => format!("{} gate(s) green", ran),
+        (ran, failed) => format!("{}/{} gate(s) failed", failed, ran),
+    }
+}
+
+#[cfg_attr(feature = "coverage", coverage(off))]
+fn session_status(session: &Session) -> String {
+    match &session.end {
+        None => "open".yellow().to_string(),
+        Some(end) => {
+            let exit = match end.exit_code {
+                Some(0) => "exit 0".green().to_string(),
+                Some(code) => format!("exit {}", code).red().to_string(),
+                None => "closed".dimmed().to_string(),
+            };
+            format!(
+                "{}, {} commit(s), {}",
+                exit,
+                end.commits.len(),
+                gate_summary(&end.gates)
+            )
+        }
+    }
+}
+
+#[cfg_attr(feature = "coverage", coverage(off))]
+pub(crate) fn cmd_sessions_list(json: bool) -> ExitCode {
+    let sessions = load_sessions(Path::new("."));
+    if json {
+        println!("{}", serde_json::to_string_pretty(&sessions).unwrap());
+        return ExitCode::SUCCESS;
+    }
+
+    println!("{}", "RoyalBit Asimov - SESSIONS".bold().green());
+    println!();
+    if sessions.is_empty() {
+        println!("  No sessions recorded (asimov launch and asimov warmup record them)");
+        return ExitCode::SUCCESS;
+    }
+    for session in &sessions {
+        let focus = match (&session.start.milestone, &session.start.wip) {
+            (Some(milestone), Some(wip)) => format!("v{} [{}]", milestone, wip),
+            (Some(milestone), None) => format!("v{}", milestone),
+            (None, Some(wip)) => format!("[{}]", wip),
+            (None, None) => "-".to_string(),
+        };
+        println!(
+            "  {} {} {} {} {}",
+            session.id().bright_yellow(),
+            session.start.ai.as_deref().unwrap_or("-").bright_cyan(),
+            session.role().unwrap_or("-"),
+            focus.dimmed(),
+            session_status(session)
+        );
+    }
+    ExitCode::SUCCESS
+}
+
+#[cfg_attr(feature = "coverage", coverage(off))]
+pub(crate) fn cmd_sessions_show(id: &str, json: bool) -> ExitCode {
+    let session = match find_session(Path::new("."), id) {
+        Ok(session) => session,
+        Err(e) => {
+            eprintln!("{} {}", "Error:".bold().red(), e);
+            return ExitCode::FAILURE;
+        }
+    };
+    if json {
+        println!("{}", serde_json::to_string_pretty(&session).unwrap());
+        return ExitCode::SUCCESS;
+    }
+
+    let start = &session.start;
+    let none = || "-".to_string();
+    println!(
+        "{} {}",
+        "RoyalBit Asimov - SESSION".bold().green(),
+        start.id.bright_yellow()
+    );
+    println!();
+    println!("  Started:   {} ({})", start.time, start.source);
+    println!(
+        "  AI:        {}{}",
+        start.ai.clone().unwrap_or_else(none),
+        start
+            .autonomy
+            .as_ref()
+            .map(|a| format!(
+                " ({}{})",
+                a,
+                if start.supervisor { ", supervisor" } else { "" }
+            ))
+            .unwrap_or_default()
+    );
+    println!(
+        "  Role:      {}",
+        session.role().map(str::to_string).unwrap_or_else(none)
+    );
+    println!(
+        "  Milestone: {}",
+        start.milestone.clone().unwrap_or_else(none)
+    );
+    println!("  WIP:       {}", start.wip.clone().unwrap_or_else(none));
+    println!("  Protocols: {}", start.protocol_hash.dimmed());
+    println!(
+        "  Start:     {} (asimov {})",
+        start.head.clone().unwrap_or_else(none),
+        start.asimov_version
+    );
+    println!("  Warmups:   {}", session.warmups);
+
+    let Some(end) = &session.end else {
+        println!();
+        println!("  {}", "Open".yellow());
+        return ExitCode::SUCCESS;
+    };
+    println!("  Ended:     {} ({})", end.time, session_status(&session));
+    if !end.commits.is_empty() {
+        println!();
+        println!("{}", "COMMITS:".bold());
+        for commit in &end.commits {
+            println!("  {} {}", commit.hash.bright_yellow(), commit.subject);
+        }
+    }
+    if !end.gates.is_empty() {
+        println!();
+        println!("{}", "GATES:".bold());
+        for gate in &end.gates {
+            let status = match gate.status {
+                GateStatus::Passed | GateStatus::Cached => "ok".green(),
+                GateStatus::Failed | GateStatus::TimedOut => "FAIL".red(),
+                GateStatus::Skipped => "skip".dimmed(),
+            };
+            println!("  {} {}", status, gate.name);
+        }
+    }
+    ExitCode::SUCCESS
+}
+
+#[cfg_attr(feature = "coverage", coverage(off))]
+pub(crate) fn cmd_sessions_close(id: &str) -> ExitCode {
+    match close_session(Path::new("."), id, None) {
+        Ok(end) => {
+            println!(
+                "{} Closed session: {} commit(s), {}",
+                "✓".green(),
+                end.commits.len(),
+                gate_summary(&end.gates)
+            );
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("{} {}", "Error:".bold().red(), e);
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,164 @@
+//! Stats output (v12.4.0: milestone cycle times, weekly throughput,
+//! burndown and JSON)
+
+use colored::Colorize;
+use royalbit_asimov::commands::{run_stats_with_options, MilestoneCycle, StatsOptions};
+use std::process::ExitCode;
+
+/// Weeks shown as text without --since (JSON has them all)
+const RECENT_WEEKS: usize = 12;
+
+const BAR_WIDTH: usize = 20;
+
+#[cfg_attr(feature = "coverage", coverage(off))]
+fn bar(value: usize, max: usize) -> String {
+    if max == 0 {
+        return String::new();
+    }
+    "█".repeat((value * BAR_WIDTH).div_ceil(max))
+}
+
+/// Mean cycle time of released milestones, with how many it averages
+#[cfg_attr(feature = "coverage", coverage(off))]
+fn average_days<'a>(cycles: impl Iterator<Item = &'a MilestoneCycle>) -> Option<(f64, usize)> {
+    let days: Vec<i64> = cycles.filter_map(|c| c.days).collect();
+    (!days.is_empty()).then(|| {
+        (
+            days.iter().sum::<i64>() as f64 / days.len() as f64,
+            days.len(),
+        )
+    })
+}
+
+#[cfg_attr(feature = "coverage", coverage(off))]
+pub(crate) fn cmd_stats(options: &StatsOptions, json: bool) -> ExitCode {
+    let result = match run_stats_with_options(std::path::Path::new("."), options) {
+        Ok(result) => result,
+        Err(e) => {
+            eprintln!("{} {}", "Error:".bold().red(), e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&result).unwrap());
+        return ExitCode::SUCCESS;
+    }
+
+    println!("{}", "RoyalBit Asimov Stats".bold().green());
+    println!();
+    println!("  Session: {}", result.session_date.bright_blue());
+    println!("  Commits today: {}", result.today_commits);
+    println!("  Asimov commits: {}", result.asimov_commits);
+    println!("  Total commits: {}", result.total_commits);
+
+    if let Some(ref ver) = result.milestone_version {
+        println!();
+        println!("  Milestone: v{}", ver.bright_yellow());
+        if let Some(ref summary) = result.milestone_summary {
+            println!("  Summary: {}", summary);
+        }
+        println!("  Milestone commits: {}", result.milestone_commits);
+    }
+
+    let history = &result.history;
+    let window = result
+        .since
+        .as_ref()
+        .map(|since| format!(" since {}", since))
+        .unwrap_or_default();
+
+    if !history.milestones.is_empty() {
+        println!();
+        println!("{}", format!("MILESTONE CYCLES{}:", window).bold());
+        for cycle in &history.milestones {
+            let days = match cycle.days {
+                Some(days) => format!("{} day(s)", days),
+                None if cycle.released.is_none() => "open".yellow().to_string(),
+                None => "-".to_string(),
+            };
+            let sessions = if cycle.sessions > 0 {
+                format!(
+                    "  {} session(s), {} autonomous",
+                    cycle.sessions, cycle.autonomous_sessions
+                )
+                .dimmed()
+                .to_string()
+            } else {
+                String::new()
+            };
+            println!(
+                "  {} {} → {}  {}{}",
+                format!("v{:<10}", cycle.version).bright_yellow(),
+                cycle.started.as_deref().unwrap_or("?"),
+                cycle.released.as_deref().unwrap_or("…"),
+                days,
+                sessions
+            );
+        }
+        if let Some((average, count)) = average_days(history.milestones.iter()) {
+            println!(
+                "  Average: {:.1} day(s) over {} released milestone(s)",
+                average, count
+            );
+        }
+        // Only comparable once the journal has autonomous sessions
+        if history.milestones.iter().any(|c| c.autonomous_sessions > 0) {
+            let describe = |average: Option<(f64, usize)>| match average {
+                Some((days, count)) => format!("{:.1} day(s) ({})", days, count),
+                None => "-".to_string(),
+            };
+            println!(
+                "  With autonomous sessions: {}; without: {}",
+                describe(average_days(
+                    history
+                        .milestones
+                        .iter()
+                        .filter(|c| c.autonomous_sessions > 0)
+                )),
+                describe(average_days(
+                    history
+                        .milestones
+                        .iter()
+                        .filter(|c| c.autonomous_sessions == 0)
+                ))
+            );
+        }
+    }
+
+    if history.weekly.iter().any(|w| w.completed > 0) {
+        let weeks = if result.since.is_some() {
+            &history.weekly[..]
+        } else {
+            &history.weekly[history.weekly.len().saturating_sub(RECENT_WEEKS)..]
+        };
+        let max = weeks.iter().map(|w| w.completed).max().unwrap_or(0);
+        println!();
+        println!("{}", format!("DELIVERABLES PER WEEK{}:", window).bold());
+        for week in weeks {
+            println!(
+                "  {} {:>3} {}",
+                week.week,
+                week.completed,
+                bar(week.completed, max).green()
+            );
+        }
+    }
+
+    let max = history.burndown.iter().map(|p| p.total).max().unwrap_or(0);
+    if let (Some(ver), true) = (&result.milestone_version, max > 0) {
+        println!();
+        println!("{}", format!("BURNDOWN v{}:", ver).bold());
+        for point in &history.burndown {
+            println!(
+                "  {} {} {}/{} remaining",
+                point.date,
+                format!("{:<width$}", bar(point.remaining, max), width = BAR_WIDTH).yellow(),
+                point.remaining,
+                point.total
+            );
+        }
+    }
+
+    ExitCode::SUCCESS
+}
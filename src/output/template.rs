@@ -0,0 +1,92 @@
+//! Template pack command output (v12.4.0)
+
+use colored::Colorize;
+use royalbit_asimov::commands::{run_template_install, run_template_list, run_template_remove};
+use std::path::Path;
+use std::process::ExitCode;
+
+#[cfg_attr(feature = "coverage", coverage(off))]
+pub(crate) fn cmd_template_install(source: &Path, force: bool) -> ExitCode {
+    match run_template_install(source, force) {
+        Ok(pack) => {
+            println!(
+                "{} Installed template pack {} v{} (base: {})",
+                "✓".bright_green(),
+                pack.name.bright_cyan().bold(),
+                pack.version,
+                pack.project_type()
+            );
+            println!(
+                "  Use: {}",
+                format!("asimov init -n <name> -t {}", pack.name).bold()
+            );
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("{} {}", "Error:".bold().red(), e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+#[cfg_attr(feature = "coverage", coverage(off))]
+pub(crate) fn cmd_template_list() -> ExitCode {
+    let result = run_template_list();
+
+    println!("{}", "RoyalBit Asimov - TEMPLATES".bold().green());
+    println!();
+    println!("{}", "BUILT-IN:".bold());
+    println!("  {}", result.builtin.join(", "));
+
+    if !result.local.is_empty() {
+        println!();
+        println!("{} (.asimov/templates/)", "PROJECT:".bold());
+        println!("  {}", result.local.join(", "));
+    }
+
+    println!();
+    match &result.packs_dir {
+        Some(dir) => println!("{} ({})", "INSTALLED PACKS:".bold(), dir.display()),
+        None => println!("{}", "INSTALLED PACKS:".bold()),
+    }
+    if result.packs.is_empty() {
+        println!("  {}", "none".dimmed());
+        println!(
+            "  Install one with {}",
+            "asimov template install <dir|pack.tar.gz>".bold()
+        );
+    }
+    for pack in &result.packs {
+        println!(
+            "  {} v{} ({}) {}",
+            pack.name.bright_cyan().bold(),
+            pack.version,
+            pack.project_type(),
+            pack.description.dimmed()
+        );
+    }
+    ExitCode::SUCCESS
+}
+
+#[cfg_attr(feature = "coverage", coverage(off))]
+pub(crate) fn cmd_template_remove(name: &str) -> ExitCode {
+    match run_template_remove(name) {
+        Ok(pack) => {
+            println!(
+                "{} Removed template pack {} v{}",
+                "✓".bright_green(),
+                pack.name.bright_cyan().bold(),
+                pack.version
+            );
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("{} {}", "Error:".bold().red(), e);
+            eprintln!(
+                "  Use {} to see installed packs",
+                "asimov template list".bold()
+            );
+            ExitCode::FAILURE
+        }
+    }
+}
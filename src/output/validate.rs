@@ -0,0 +1,116 @@
+//! Validate output (v12.4.0: split from mod.rs, adds file size limits)
+
+use colored::Colorize;
+use royalbit_asimov::commands::run_validate;
+use royalbit_asimov::file_size::{check_project_file_sizes, FileSizeReport};
+use std::path::Path;
+use std::process::ExitCode;
+
+#[cfg_attr(feature = "coverage", coverage(off))]
+pub(crate) fn cmd_validate(ethics_scan: bool, sizes: bool) -> ExitCode {
+    // v12.4.0: --sizes runs only the file size check (used by the pre-commit hook)
+    if sizes {
+        let report = check_project_file_sizes(Path::new("."));
+        print_file_sizes(&report);
+        println!();
+        return if report.passed() {
+            println!("{} File sizes within limits", "Success:".bold().green());
+            ExitCode::SUCCESS
+        } else {
+            println!("{} File size limit exceeded", "Error:".bold().red());
+            ExitCode::FAILURE
+        };
+    }
+
+    let result = run_validate(Path::new("."), ethics_scan);
+
+    println!("{}", "RoyalBit Asimov Validate".bold().green());
+    println!();
+
+    // Show roadmap validation
+    if result.roadmap.is_some() || result.project.is_some() {
+        println!("{}", "ROADMAP & PROJECT".bold());
+    }
+    if let Some(ref r) = result.roadmap {
+        if r.valid {
+            println!("  {} roadmap.yaml", "✓".green());
+        } else {
+            println!("  {} roadmap.yaml", "✗".red());
+            for e in &r.errors {
+                println!("      {}", e.red());
+            }
+        }
+    }
+
+    // Show project validation
+    if let Some(ref p) = result.project {
+        if p.valid {
+            println!("  {} project.yaml", "✓".green());
+        } else {
+            println!("  {} project.yaml", "✗".red());
+            for e in &p.errors {
+                println!("      {}", e.red());
+            }
+        }
+    }
+
+    // Show ethics scan results
+    if let Some(ref scan) = result.ethics_scan {
+        println!();
+        println!("{}", "Ethics Scan".bold());
+        if scan.red_flags_found > 0 {
+            println!(
+                "  {} {} red flag(s) found",
+                "⚠".yellow(),
+                scan.red_flags_found
+            );
+            for m in &scan.matches {
+                println!("      {}:{} - {}", m.file, m.line, m.pattern);
+            }
+        } else {
+            println!("  {} No red flags found", "✓".green());
+        }
+    }
+
+    println!();
+    print_file_sizes(&result.file_sizes);
+
+    println!();
+    if result.success {
+        println!("{} All validations passed", "Success:".bold().green());
+        ExitCode::SUCCESS
+    } else {
+        println!("{} Validation failed", "Error:".bold().red());
+        ExitCode::FAILURE
+    }
+}
+
+/// Print the FILE SIZES section: hard violations fail, soft ones warn
+#[cfg_attr(feature = "coverage", coverage(off))]
+fn print_file_sizes(report: &FileSizeReport) {
+    println!("{}", "FILE SIZES".bold());
+    for v in report.hard() {
+        println!(
+            "  {} {} has {} lines (hard limit: {})",
+            "✗".red(),
+            v.file,
+            v.lines,
+            v.limit
+        );
+    }
+    for v in report.soft() {
+        println!(
+            "  {} {} has {} lines (soft limit: {})",
+            "⚠".yellow(),
+            v.file,
+            v.lines,
+            v.limit
+        );
+    }
+    if report.violations.is_empty() {
+        println!("  {} {} file(s) within limits", "✓".green(), report.checked);
+    }
+    if report.exempt > 0 {
+        println!("  {} file(s) exempt", report.exempt.to_string().dimmed());
+    }
+}
@@ -0,0 +1,104 @@
+//! User-level directories for asimov (v12.4.0)
+//!
+//! Project data lives in `.asimov/`. Anything shared across projects
+//! (installed template packs, caches, user config) lives in the user's
+//! platform config directory instead.
+
+use std::path::PathBuf;
+
+/// Environment variable that overrides the user-level asimov directory
+pub const ASIMOV_HOME_ENV: &str = "ASIMOV_HOME";
+
+/// User-level asimov directory
+///
+/// Resolution order:
+/// 1. `$ASIMOV_HOME`
+/// 2. `$XDG_CONFIG_HOME/asimov`
+/// 3. `%APPDATA%\asimov` (Windows) or `~/.config/asimov`
+pub fn user_config_dir() -> Option<PathBuf> {
+    if let Some(dir) = non_empty_env(ASIMOV_HOME_ENV) {
+        return Some(PathBuf::from(dir));
+    }
+    if let Some(dir) = non_empty_env("XDG_CONFIG_HOME") {
+        return Some(PathBuf::from(dir).join("asimov"));
+    }
+
+    #[cfg(windows)]
+    {
+        non_empty_env("APPDATA").map(|dir| PathBuf::from(dir).join("asimov"))
+    }
+
+    #[cfg(not(windows))]
+    {
+        non_empty_env("HOME").map(|dir| PathBuf::from(dir).join(".config").join("asimov"))
+    }
+}
+
+/// The user's home directory (`$HOME`, or `%USERPROFILE%` on Windows)
+pub fn home_dir() -> Option<PathBuf> {
+    #[cfg(windows)]
+    {
+        non_empty_env("USERPROFILE").map(PathBuf::from)
+    }
+
+    #[cfg(not(windows))]
+    {
+        non_empty_env("HOME").map(PathBuf::from)
+    }
+}
+
+/// User-level cache directory (v12.4.0)
+///
+/// Resolution order:
+/// 1. `$ASIMOV_HOME/cache`
+/// 2. `$XDG_CACHE_HOME/asimov`
+/// 3. `%LOCALAPPDATA%\asimov\cache` (Windows) or `~/.cache/asimov`
+pub fn user_cache_dir() -> Option<PathBuf> {
+    if let Some(dir) = non_empty_env(ASIMOV_HOME_ENV) {
+        return Some(PathBuf::from(dir).join("cache"));
+    }
+    if let Some(dir) = non_empty_env("XDG_CACHE_HOME") {
+        return Some(PathBuf::from(dir).join("asimov"));
+    }
+
+    #[cfg(windows)]
+    {
+        non_empty_env("LOCALAPPDATA").map(|dir| PathBuf::from(dir).join("asimov").join("cache"))
+    }
+
+    #[cfg(not(windows))]
+    {
+        home_dir().map(|dir| dir.join(".cache").join("asimov"))
+    }
+}
+
+/// User config file (`config.yaml` in the user config directory)
+pub fn user_config_file() -> Option<PathBuf> {
+    user_config_dir().map(|dir| dir.join("config.yaml"))
+}
+
+/// Directory holding installed template packs
+pub fn user_templates_dir() -> Option<PathBuf> {
+    user_config_dir().map(|dir| dir.join("templates"))
+}
+
+fn non_empty_env(name: &str) -> Option<String> {
+    std::env::var(name).ok().filter(|v| !v.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_user_templates_dir_under_config_dir() {
+        if let (Some(config), Some(templates)) = (user_config_dir(), user_templates_dir()) {
+            assert_eq!(templates, config.join("templates"));
+        }
+    }
+
+    #[test]
+    fn test_non_empty_env_missing() {
+        assert!(non_empty_env("ASIMOV_TEST_DEFINITELY_UNSET_VAR").is_none());
+    }
+}
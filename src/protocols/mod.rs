@@ -205,6 +205,36 @@ pub fn compile_protocols() -> CompiledProtocols {
     }
 }
 
+// ========== Protocol Hash (v12.4.0) ==========
+
+/// Protocol sources in load order, paired with their embedded fallback
+const EMBEDDED_PROTOCOLS: &[(&str, &str)] = &[
+    ("warmup", WARMUP_JSON),
+    ("asimov", ASIMOV_JSON),
+    ("freshness", FRESHNESS_JSON),
+    ("sycophancy", SYCOPHANCY_JSON),
+    ("green", GREEN_JSON),
+    ("sprint", SPRINT_JSON),
+    ("coding-standards", CODING_STANDARDS_JSON),
+];
+
+/// SHA-256 over the protocol sources a session in `dir` runs under
+/// (`.asimov/protocols/*.json` overrides, else the embedded copies), so
+/// session records show which protocol version an AI was working with
+pub fn protocol_hash(dir: &std::path::Path) -> String {
+    use sha2::{Digest, Sha256};
+    let mut hasher = Sha256::new();
+    for (name, embedded) in EMBEDDED_PROTOCOLS {
+        let path = dir.join(".asimov/protocols").join(format!("{}.json", name));
+        let content = std::fs::read_to_string(path).unwrap_or_else(|_| embedded.to_string());
+        hasher.update(name.as_bytes());
+        hasher.update([0]);
+        hasher.update(content.as_bytes());
+        hasher.update([0]);
+    }
+    format!("sha256:{:x}", hasher.finalize())
+}
+
 // ========== Individual Protocol Loaders (External + Fallback) ==========
 
 fn load_asimov_protocol() -> AsimovProtocol {
@@ -368,6 +398,18 @@ pub const PROTOCOL_FILES: &[(&str, fn() -> String)] = &[
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_protocol_hash_tracks_overrides() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let embedded = protocol_hash(temp.path());
+        assert!(embedded.starts_with("sha256:"));
+        assert_eq!(embedded, protocol_hash(temp.path()));
+
+        std::fs::create_dir_all(temp.path().join(".asimov/protocols")).unwrap();
+        std::fs::write(temp.path().join(".asimov/protocols/green.json"), "{}").unwrap();
+        assert_ne!(embedded, protocol_hash(temp.path()));
+    }
+
     #[test]
     fn test_date_injection() {
         let template = "Today is {TODAY}, year {YEAR}";
@@ -0,0 +1,363 @@
+//! Declared quality gates (v12.4.0)
+//!
+//! The pre-commit hook runs the `quality` commands from `project.yaml`
+//! instead of hardcoded per-language snippets. A gate is either a plain
+//! command or a map with a timeout and profile:
+//!
+//! ```yaml
+//! quality:
+//!   format: "cargo fmt --check"
+//!   lint: "cargo clippy --all-targets -- -D warnings"
+//!   test:
+//!     command: "cargo nextest run"
+//!     timeout: 600
+//!     profile: full
+//!
+//! hook:
+//!   profile: fast            # fast | full (override: ASIMOV_HOOK_PROFILE=full)
+//!   order: [format, lint, test]
+//!   timeout: 300             # default per-gate timeout in seconds
+//! ```
+//!
+//! `fast` gates run in both profiles, `full` gates only in the full profile.
+//! Without `hook.order`, the standard gates run in [`QUALITY_GATE_ORDER`].
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::Path;
+
+/// Standard gates, in the order they run when `hook.order` is not set
+pub const QUALITY_GATE_ORDER: &[&str] = &["format", "lint", "types", "vet", "build", "test"];
+
+/// Environment variable that overrides the hook profile at commit time
+pub const HOOK_PROFILE_ENV: &str = "ASIMOV_HOOK_PROFILE";
+
+/// When a gate runs
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum GateProfile {
+    /// Runs on every commit
+    Fast,
+    /// Runs only in the full profile
+    #[default]
+    Full,
+}
+
+impl GateProfile {
+    /// Default profile for a standard gate: checks are fast, builds and tests full
+    pub fn for_gate(name: &str) -> Self {
+        match name {
+            "format" | "lint" | "types" | "vet" => GateProfile::Fast,
+            _ => GateProfile::Full,
+        }
+    }
+
+    /// True if a gate with this profile runs under the `active` profile
+    pub fn runs_in(self, active: GateProfile) -> bool {
+        self == GateProfile::Fast || active == GateProfile::Full
+    }
+}
+
+impl std::str::FromStr for GateProfile {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "fast" => Ok(GateProfile::Fast),
+            "full" => Ok(GateProfile::Full),
+            other => Err(format!(
+                "Unknown profile '{}' (expected fast or full)",
+                other
+            )),
+        }
+    }
+}
+
+impl fmt::Display for GateProfile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GateProfile::Fast => write!(f, "fast"),
+            GateProfile::Full => write!(f, "full"),
+        }
+    }
+}
+
+/// A `quality` entry as written in project.yaml
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(untagged)]
+pub enum GateSpec {
+    Command(String),
+    Detailed {
+        command: String,
+        #[serde(default)]
+        timeout: Option<u64>,
+        #[serde(default)]
+        profile: Option<GateProfile>,
+    },
+}
+
+impl GateSpec {
+    pub fn command(&self) -> &str {
+        match self {
+            GateSpec::Command(cmd) | GateSpec::Detailed { command: cmd, .. } => cmd.trim(),
+        }
+    }
+
+    /// Placeholders like "# Add your test command" are not gates
+    pub fn is_placeholder(&self) -> bool {
+        let cmd = self.command();
+        cmd.is_empty() || cmd.starts_with('#')
+    }
+}
+
+impl From<&str> for GateSpec {
+    fn from(cmd: &str) -> Self {
+        GateSpec::Command(cmd.to_string())
+    }
+}
+
+/// A resolved gate, ready to render into the hook
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QualityGate {
+    pub name: String,
+    pub command: String,
+    /// Seconds; None or 0 means no limit
+    pub timeout: Option<u64>,
+    pub profile: GateProfile,
+}
+
+/// The `hook:` section of project.yaml
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+pub struct HookConfig {
+    /// Default profile baked into the hook (full if omitted)
+    #[serde(default)]
+    pub profile: Option<GateProfile>,
+    /// Gates to run, in order (may name custom gates such as `e2e`)
+    #[serde(default)]
+    pub order: Vec<String>,
+    /// Default per-gate timeout in seconds
+    #[serde(default)]
+    pub timeout: Option<u64>,
+}
+
+impl HookConfig {
+    pub fn profile(&self) -> GateProfile {
+        self.profile.unwrap_or(GateProfile::Full)
+    }
+
+    /// Profile in effect now: $ASIMOV_HOOK_PROFILE, else the configured default
+    pub fn active_profile(&self) -> GateProfile {
+        std::env::var(HOOK_PROFILE_ENV)
+            .ok()
+            .and_then(|p| p.parse().ok())
+            .unwrap_or_else(|| self.profile())
+    }
+}
+
+/// Quality gates and hook settings declared in a project.yaml
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct QualityConfig {
+    pub quality: BTreeMap<String, GateSpec>,
+    pub hook: HookConfig,
+}
+
+impl QualityConfig {
+    /// Gates the pre-commit hook runs, in order
+    pub fn gates(&self) -> Vec<QualityGate> {
+        resolve_gates(&self.quality, &self.hook)
+    }
+}
+
+/// Load `quality` and `hook` from `<dir>/.asimov/project.yaml`
+pub fn load_quality_config(dir: &Path) -> QualityConfig {
+    std::fs::read_to_string(dir.join(".asimov").join("project.yaml"))
+        .ok()
+        .and_then(|c| serde_yaml_ng::from_str::<serde_yaml_ng::Value>(&c).ok())
+        .map(|yaml| QualityConfig {
+            quality: parse_quality(yaml.get("quality")),
+            hook: yaml
+                .get("hook")
+                .and_then(|h| serde_yaml_ng::from_value(h.clone()).ok())
+                .unwrap_or_default(),
+        })
+        .unwrap_or_default()
+}
+
+/// Parse a `quality` map, skipping entries that are neither a command nor a gate map
+pub fn parse_quality(value: Option<&serde_yaml_ng::Value>) -> BTreeMap<String, GateSpec> {
+    value
+        .and_then(|q| q.as_mapping())
+        .map(|map| {
+            map.iter()
+                .filter_map(|(k, v)| {
+                    let spec = serde_yaml_ng::from_value(v.clone()).ok()?;
+                    Some((k.as_str()?.to_string(), spec))
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Resolve the named gates (any declared gate, including custom ones and `links`)
+pub fn select_gates(
+    quality: &BTreeMap<String, GateSpec>,
+    hook: &HookConfig,
+    names: &[String],
+) -> Result<Vec<QualityGate>, String> {
+    if let Some(unknown) = names
+        .iter()
+        .find(|n| quality.get(n.as_str()).is_none_or(GateSpec::is_placeholder))
+    {
+        let declared: Vec<&str> = quality
+            .iter()
+            .filter(|(_, s)| !s.is_placeholder())
+            .map(|(n, _)| n.as_str())
+            .collect();
+        return Err(format!(
+            "Gate '{}' is not declared in project.yaml quality (declared: {})",
+            unknown,
+            if declared.is_empty() {
+                "none".to_string()
+            } else {
+                declared.join(", ")
+            }
+        ));
+    }
+    let selection = HookConfig {
+        order: names.to_vec(),
+        ..hook.clone()
+    };
+    Ok(resolve_gates(quality, &selection))
+}
+
+/// Order and resolve declared gates.
+/// With `hook.order`, exactly those gates run; otherwise the standard gates.
+pub fn resolve_gates(quality: &BTreeMap<String, GateSpec>, hook: &HookConfig) -> Vec<QualityGate> {
+    let names: Vec<&str> = if hook.order.is_empty() {
+        QUALITY_GATE_ORDER.to_vec()
+    } else {
+        hook.order.iter().map(String::as_str).collect()
+    };
+
+    names
+        .into_iter()
+        .filter_map(|name| {
+            let spec = quality.get(name).filter(|s| !s.is_placeholder())?;
+            let (timeout, profile) = match spec {
+                GateSpec::Command(_) => (None, None),
+                GateSpec::Detailed {
+                    timeout, profile, ..
+                } => (*timeout, *profile),
+            };
+            Some(QualityGate {
+                name: name.to_string(),
+                command: spec.command().to_string(),
+                timeout: timeout.or(hook.timeout).filter(|t| *t > 0),
+                profile: profile.unwrap_or_else(|| GateProfile::for_gate(name)),
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn write_project(dir: &Path, content: &str) {
+        std::fs::create_dir_all(dir.join(".asimov")).unwrap();
+        std::fs::write(dir.join(".asimov/project.yaml"), content).unwrap();
+    }
+
+    #[test]
+    fn test_missing_project_yaml_has_no_gates() {
+        let temp = TempDir::new().unwrap();
+        let config = load_quality_config(temp.path());
+        assert!(config.gates().is_empty());
+        assert_eq!(config.hook.profile(), GateProfile::Full);
+    }
+
+    #[test]
+    fn test_standard_order_and_default_profiles() {
+        let temp = TempDir::new().unwrap();
+        write_project(
+            temp.path(),
+            r#"
+quality:
+  test: "cargo nextest run"
+  fix: "cargo fmt"
+  lint: "./scripts/lint.sh"
+  format: "cargo fmt --check"
+  e2e: "cargo test --test e2e"
+"#,
+        );
+        let gates = load_quality_config(temp.path()).gates();
+        let names: Vec<&str> = gates.iter().map(|g| g.name.as_str()).collect();
+        // fix and custom gates are not run unless listed in hook.order
+        assert_eq!(names, vec!["format", "lint", "test"]);
+        assert_eq!(gates[0].profile, GateProfile::Fast);
+        assert_eq!(gates[2].profile, GateProfile::Full);
+        assert_eq!(gates[2].command, "cargo nextest run");
+        assert_eq!(gates[2].timeout, None);
+    }
+
+    #[test]
+    fn test_detailed_gates_and_hook_section() {
+        let temp = TempDir::new().unwrap();
+        write_project(
+            temp.path(),
+            r##"
+quality:
+  test:
+    command: "cargo nextest run"
+    timeout: 600
+  lint: "cargo clippy -- -D warnings"
+  e2e:
+    command: "cargo test --test e2e"
+    profile: full
+  format: "# Add your format command"
+hook:
+  profile: fast
+  order: [e2e, lint, format, test]
+  timeout: 120
+"##,
+        );
+        let config = load_quality_config(temp.path());
+        assert_eq!(config.hook.profile(), GateProfile::Fast);
+
+        let gates = config.gates();
+        let names: Vec<&str> = gates.iter().map(|g| g.name.as_str()).collect();
+        assert_eq!(names, vec!["e2e", "lint", "test"]);
+        assert_eq!(gates[0].profile, GateProfile::Full);
+        assert_eq!(gates[1].timeout, Some(120));
+        assert_eq!(gates[2].timeout, Some(600));
+    }
+
+    #[test]
+    fn test_invalid_entries_are_skipped() {
+        let yaml: serde_yaml_ng::Value =
+            serde_yaml_ng::from_str("lint: 42\ntest: [a, b]\nformat: \"fmt\"\n").unwrap();
+        let quality = parse_quality(Some(&yaml));
+        assert_eq!(quality.len(), 1);
+        assert_eq!(quality["format"], GateSpec::from("fmt"));
+    }
+
+    #[test]
+    fn test_zero_timeout_means_unlimited() {
+        let mut quality = BTreeMap::new();
+        quality.insert(
+            "test".to_string(),
+            GateSpec::Detailed {
+                command: "make test".to_string(),
+                timeout: Some(0),
+                profile: None,
+            },
+        );
+        let hook = HookConfig {
+            timeout: Some(60),
+            ..Default::default()
+        };
+        assert_eq!(resolve_gates(&quality, &hook)[0].timeout, None);
+    }
+}
@@ -0,0 +1,257 @@
+//! Roadmap deliverables (v12.4.0)
+//!
+//! Deliverables are checklist strings or maps with an `id`, which commits
+//! reference as `[id]` so replay and stats can attribute them to a milestone:
+//!
+//! ```yaml
+//! current:
+//!   version: "1.2.0"
+//!   deliverables:
+//!     - "[ ] Plain checklist item"          # no id, cannot be referenced
+//!     - id: hook-manager
+//!       summary: "Git hook manager"
+//!       status: wip                         # todo | wip | done
+//! next:
+//!   - version: "1.3.0"
+//!     deliverables:
+//!       - id: lint-commit
+//! ```
+
+use crate::resolve_protocol_dir;
+use std::path::Path;
+
+/// A roadmap deliverable that has an id
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Deliverable {
+    pub id: String,
+    /// Version of the milestone it belongs to
+    pub milestone: String,
+    pub summary: Option<String>,
+    pub status: Option<String>,
+}
+
+fn load_roadmap(dir: &Path) -> Option<serde_yaml_ng::Value> {
+    std::fs::read_to_string(resolve_protocol_dir(dir).join("roadmap.yaml"))
+        .ok()
+        .and_then(|content| serde_yaml_ng::from_str(&content).ok())
+}
+
+/// Deliverables with an id from `current` and `next` in roadmap.yaml
+pub fn load_deliverables(dir: &Path) -> Vec<Deliverable> {
+    load_roadmap(dir)
+        .map(|roadmap| parse_deliverables(&roadmap))
+        .unwrap_or_default()
+}
+
+/// Version of the `current` milestone in roadmap.yaml
+pub fn current_milestone(dir: &Path) -> Option<String> {
+    load_roadmap(dir)?
+        .get("current")?
+        .get("version")?
+        .as_str()
+        .map(str::to_string)
+}
+
+/// Milestones of roadmap.yaml (empty without one)
+pub fn load_milestones(dir: &Path) -> Vec<Milestone> {
+    load_roadmap(dir)
+        .map(|roadmap| parse_milestones(&roadmap))
+        .unwrap_or_default()
+}
+
+/// Deliverables with an id from a parsed roadmap
+pub fn parse_deliverables(roadmap: &serde_yaml_ng::Value) -> Vec<Deliverable> {
+    let next = roadmap
+        .get("next")
+        .and_then(|n| n.as_sequence())
+        .into_iter()
+        .flatten();
+    roadmap
+        .get("current")
+        .into_iter()
+        .chain(next)
+        .flat_map(milestone_deliverables)
+        .collect()
+}
+
+/// A milestone as a roadmap snapshot lists it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Milestone {
+    pub version: String,
+    pub status: Option<String>,
+    pub summary: Option<String>,
+    /// The `current` milestone (else one of `next`)
+    pub current: bool,
+    /// Every deliverable, with or without an id
+    pub items: Vec<ChecklistItem>,
+}
+
+/// A deliverable of any form: `"[x] text"` strings or maps with a status
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChecklistItem {
+    /// The id, else the text without its checkbox
+    pub key: String,
+    pub done: bool,
+}
+
+/// `current` and `next` milestones of a parsed roadmap (with a version)
+pub fn parse_milestones(roadmap: &serde_yaml_ng::Value) -> Vec<Milestone> {
+    let next = roadmap
+        .get("next")
+        .and_then(|n| n.as_sequence())
+        .into_iter()
+        .flatten()
+        .map(|m| (m, false));
+    roadmap
+        .get("current")
+        .map(|m| (m, true))
+        .into_iter()
+        .chain(next)
+        .filter_map(|(milestone, current)| {
+            let text = |key: &str| milestone.get(key).and_then(|v| v.as_str());
+            Some(Milestone {
+                version: text("version")?.to_string(),
+                status: text("status").map(str::to_string),
+                summary: text("summary").map(str::to_string),
+                current,
+                items: milestone
+                    .get("deliverables")
+                    .and_then(|d| d.as_sequence())
+                    .into_iter()
+                    .flatten()
+                    .filter_map(checklist_item)
+                    .collect(),
+            })
+        })
+        .collect()
+}
+
+fn checklist_item(item: &serde_yaml_ng::Value) -> Option<ChecklistItem> {
+    if let Some(text) = item.as_str() {
+        let text = text.trim();
+        let (done, key) = match text.get(..3) {
+            Some("[x]" | "[X]") => (true, &text[3..]),
+            Some("[ ]") => (false, &text[3..]),
+            _ => (false, text),
+        };
+        return Some(ChecklistItem {
+            key: key.trim().to_string(),
+            done,
+        });
+    }
+    let text = |key: &str| item.get(key).and_then(|v| v.as_str());
+    Some(ChecklistItem {
+        key: text("id").or_else(|| text("summary"))?.to_string(),
+        done: text("status") == Some("done"),
+    })
+}
+
+fn milestone_deliverables(milestone: &serde_yaml_ng::Value) -> Vec<Deliverable> {
+    let text = |value: &serde_yaml_ng::Value, key: &str| {
+        value.get(key).and_then(|v| v.as_str()).map(str::to_string)
+    };
+    let version = text(milestone, "version").unwrap_or_default();
+    milestone
+        .get("deliverables")
+        .and_then(|d| d.as_sequence())
+        .into_iter()
+        .flatten()
+        .filter_map(|item| {
+            Some(Deliverable {
+                id: text(item, "id").filter(|id| !id.trim().is_empty())?,
+                milestone: version.clone(),
+                summary: text(item, "summary"),
+                status: text(item, "status"),
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_load_deliverables() {
+        let temp = TempDir::new().unwrap();
+        std::fs::create_dir_all(temp.path().join(".asimov")).unwrap();
+        std::fs::write(
+            temp.path().join(".asimov/roadmap.yaml"),
+            r#"
+current:
+  version: "1.2.0"
+  status: in_progress
+  summary: "Hooks"
+  deliverables:
+    - "[ ] Plain item"
+    - id: hook-manager
+      summary: "Git hook manager"
+      status: wip
+next:
+  - version: "1.3.0"
+    summary: "Policy"
+    deliverables:
+      - id: lint-commit
+"#,
+        )
+        .unwrap();
+
+        let deliverables = load_deliverables(temp.path());
+        assert_eq!(deliverables.len(), 2);
+        assert_eq!(deliverables[0].id, "hook-manager");
+        assert_eq!(deliverables[0].milestone, "1.2.0");
+        assert_eq!(deliverables[0].status.as_deref(), Some("wip"));
+        assert_eq!(deliverables[1].id, "lint-commit");
+        assert_eq!(deliverables[1].milestone, "1.3.0");
+        assert_eq!(deliverables[1].summary, None);
+        assert_eq!(current_milestone(temp.path()).as_deref(), Some("1.2.0"));
+
+        assert!(load_deliverables(&temp.path().join("missing")).is_empty());
+        assert_eq!(current_milestone(&temp.path().join("missing")), None);
+    }
+
+    #[test]
+    fn test_parse_milestones() {
+        let roadmap: serde_yaml_ng::Value = serde_yaml_ng::from_str(
+            r#"
+current:
+  version: "1.2.0"
+  status: in_progress
+  summary: Hooks
+  deliverables:
+    - "[x] Done item"
+    - "[ ] Open item"
+    - id: hook-manager
+      status: done
+    - id: lint
+next:
+  - version: "1.3.0"
+  - summary: "No version"
+"#,
+        )
+        .unwrap();
+
+        let milestones = parse_milestones(&roadmap);
+        assert_eq!(milestones.len(), 2);
+        assert!(milestones[0].current);
+        assert_eq!(milestones[0].status.as_deref(), Some("in_progress"));
+        assert_eq!(milestones[0].summary.as_deref(), Some("Hooks"));
+        let items: Vec<(&str, bool)> = milestones[0]
+            .items
+            .iter()
+            .map(|i| (i.key.as_str(), i.done))
+            .collect();
+        assert_eq!(
+            items,
+            vec![
+                ("Done item", true),
+                ("Open item", false),
+                ("hook-manager", true),
+                ("lint", false)
+            ]
+        );
+        assert!(!milestones[1].current);
+        assert!(milestones[1].items.is_empty());
+    }
+}
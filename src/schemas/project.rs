@@ -3,7 +3,7 @@
 //! Project context file - contains project-specific configuration.
 //! Created by ADR-032 to separate project data from hardcoded behavior protocols.
 
-pub const PROJECT_SCHEMA: &str = r#"{
+pub const PROJECT_SCHEMA: &str = r##"{
   "$schema": "http://json-schema.org/draft-07/schema#",
   "$id": "https://github.com/royalbit/asimov/schemas/project.json",
   "title": "RoyalBit Asimov - project.yaml",
@@ -23,7 +23,7 @@ pub const PROJECT_SCHEMA: &str = r#"{
         },
         "type": {
           "type": "string",
-          "enum": ["rust", "python", "node", "go", "flutter", "docs", "generic", "migration"],
+          "enum": ["rust", "python", "node", "go", "flutter", "docs", "generic", "migration", "arch", "java", "kotlin", "csharp", "cpp", "ruby", "php", "swift"],
           "description": "Project type"
         },
         "version": {
@@ -40,44 +40,241 @@ pub const PROJECT_SCHEMA: &str = r#"{
         }
       }
     },
+    "min_asimov_version": {
+      "type": "string",
+      "pattern": "^v?[0-9]+\\.[0-9]+\\.[0-9]+(-[0-9A-Za-z.-]+)?$",
+      "description": "Oldest asimov binary this project supports; warmup and validate fail on older ones (v12.4.0)"
+    },
+    "default_ai": {
+      "type": "string",
+      "description": "AI profile id or name launched when several AI CLIs are installed; --ai overrides (v12.4.0)"
+    },
+    "ai_profiles": {
+      "type": "array",
+      "description": "AI CLI profiles for asimov launch; an existing id overrides name, env, model and markers, a new id adds a profile whose binary must be in allowed_binaries in the user config (v12.4.0)",
+      "items": {
+        "type": "object",
+        "required": ["id"],
+        "additionalProperties": false,
+        "properties": {
+          "id": { "type": "string", "description": "Profile id (claude, gemini, codex or a new one)" },
+          "name": { "type": "string", "description": "Display name" },
+          "binary": { "type": "string", "description": "Command to launch (required for new profiles, which the user config must allow-list)" },
+          "env": {
+            "type": "array",
+            "items": { "type": "string" },
+            "description": "Environment variables set inside a session of this AI"
+          },
+          "args": {
+            "type": "array",
+            "items": { "type": "string" },
+            "description": "Arguments always passed before the warmup prompt"
+          },
+          "model_flag": { "type": "string", "description": "Flag that selects the model, e.g. --model (user config only for built-ins)" },
+          "model": {
+            "type": "string",
+            "pattern": "^[A-Za-z0-9._:/@][A-Za-z0-9._:/@-]*$",
+            "description": "Model passed after model_flag; the launch argument project.yaml may override"
+          },
+          "auto_args": {
+            "type": "array",
+            "items": { "type": "string" },
+            "description": "Extra arguments passed only with --autonomy auto (e.g. skip permission prompts)"
+          },
+          "prompt": {
+            "type": "string",
+            "enum": ["arg", "file", "none"],
+            "description": "How the warmup prompt is passed (default arg)"
+          },
+          "prompt_flag": { "type": "string", "description": "Flag placed before the prompt or prompt file" },
+          "compact_markers": {
+            "type": "array",
+            "items": { "type": "string" },
+            "description": "Output text meaning compaction; asimov --supervise re-injects context after it"
+          },
+          "session_start_markers": {
+            "type": "array",
+            "items": { "type": "string" },
+            "description": "Output text meaning a session started, resumed or was cleared"
+          },
+          "hooks": {
+            "type": "object",
+            "description": "Native session hooks for asimov hooks install (v12.4.0)",
+            "required": ["settings"],
+            "additionalProperties": false,
+            "properties": {
+              "settings": { "type": "string", "description": "Settings JSON with a hooks section, relative to the project root" },
+              "session_start": { "type": "string", "description": "Event run on session start/resume/clear" },
+              "compact": { "type": "string", "description": "Event run before compaction" },
+              "project_dir_env": { "type": "string", "description": "Variable holding the project root when hooks run" }
+            }
+          }
+        }
+      }
+    },
     "quality": {
       "type": "object",
-      "description": "Quality gate commands",
+      "description": "Quality gate commands: a command string or {command, timeout, profile} (v12.4.0)",
       "properties": {
         "test": {
-          "type": "string",
+          "$ref": "#/definitions/gate",
           "description": "Test command"
         },
         "lint": {
-          "type": "string",
+          "$ref": "#/definitions/gate",
           "description": "Lint command"
         },
         "format": {
-          "type": "string",
+          "$ref": "#/definitions/gate",
           "description": "Format check command"
         },
         "build": {
-          "type": "string",
+          "$ref": "#/definitions/gate",
           "description": "Build command"
         },
         "types": {
-          "type": "string",
+          "$ref": "#/definitions/gate",
           "description": "Type check command (optional)"
         },
         "vet": {
-          "type": "string",
+          "$ref": "#/definitions/gate",
           "description": "Vet command (Go)"
         },
         "fix": {
-          "type": "string",
-          "description": "Auto-fix command"
+          "$ref": "#/definitions/gate",
+          "description": "Auto-fix command (run by the hook only if listed in hook.order)"
         },
         "links": {
-          "type": "string",
+          "$ref": "#/definitions/gate",
           "description": "Link check command"
         }
       }
     },
+    "hook": {
+      "type": "object",
+      "description": "Pre-commit hook generated from quality gates (v12.4.0)",
+      "properties": {
+        "profile": {
+          "type": "string",
+          "enum": ["fast", "full"],
+          "description": "Default profile (override with ASIMOV_HOOK_PROFILE)"
+        },
+        "order": {
+          "type": "array",
+          "items": { "type": "string" },
+          "description": "Gates to run, in order (defaults to format, lint, types, vet, build, test)"
+        },
+        "timeout": {
+          "type": "integer",
+          "minimum": 0,
+          "description": "Default per-gate timeout in seconds (0 = none)"
+        }
+      }
+    },
+    "commit_policy": {
+      "type": "object",
+      "description": "Commit message policy for the commit-msg hook and asimov lint-commit (v12.4.0)",
+      "additionalProperties": false,
+      "properties": {
+        "types": {
+          "type": "array",
+          "items": { "type": "string", "pattern": "^[a-z]+$" },
+          "description": "Allowed conventional-commit types, e.g. [feat, fix, docs] (empty: any subject)"
+        },
+        "require_deliverable": {
+          "type": "boolean",
+          "description": "Subjects must reference a roadmap deliverable id as [id]"
+        },
+        "validate_references": {
+          "type": "boolean",
+          "description": "[id] references must name a roadmap deliverable (default true; false allows any [tag])"
+        },
+        "banned_phrases": {
+          "type": "array",
+          "items": { "type": "string" },
+          "description": "Phrases rejected anywhere in the message (case-insensitive)"
+        }
+      }
+    },
+    "deps": {
+      "type": "object",
+      "description": "Dependency audit thresholds for asimov deps audit (v12.4.0, ADR-045)",
+      "properties": {
+        "max_dependencies": {
+          "type": "integer",
+          "minimum": 0,
+          "description": "Locked packages above this count are BLOATED_DEPS (default 300)"
+        },
+        "max_depth": {
+          "type": "integer",
+          "minimum": 0,
+          "description": "Dependency depth above this is BLOATED_DEPS (default 12)"
+        },
+        "advisories": {
+          "type": "string",
+          "description": "Local advisory database (default .asimov/advisories.yaml)"
+        }
+      }
+    },
+    "doctor": {
+      "type": "object",
+      "description": "Project-specific checks asimov doctor runs after the built-in ones (v12.4.0)",
+      "properties": {
+        "checks": {
+          "type": "array",
+          "items": {
+            "type": "object",
+            "required": ["id", "command"],
+            "additionalProperties": false,
+            "properties": {
+              "id": { "type": "string", "description": "Unique check id" },
+              "name": { "type": "string", "description": "Display name (defaults to id)" },
+              "command": { "type": "string", "description": "Shell command, run in the project directory" },
+              "exit_code": { "type": "integer", "description": "Expected exit code (default 0)" },
+              "output": { "type": "string", "description": "Regex stdout or stderr must match" },
+              "severity": {
+                "type": "string",
+                "enum": ["info", "warn", "error"],
+                "description": "Severity when the check fails (default warn)"
+              },
+              "fix": { "type": "string", "description": "Shell command asimov doctor --fix runs on failure" },
+              "hint": { "type": "string", "description": "Shown when the check fails" },
+              "timeout": {
+                "type": "integer",
+                "minimum": 0,
+                "description": "Seconds (default 60, 0 = none)"
+              }
+            }
+          }
+        }
+      }
+    },
+    "licenses": {
+      "type": "object",
+      "description": "Dependency license policy for asimov licenses and doctor (v12.4.0)",
+      "properties": {
+        "allow": {
+          "type": "array",
+          "items": { "type": "string" },
+          "description": "SPDX ids (wildcards allowed); if set, every dependency needs one"
+        },
+        "deny": {
+          "type": "array",
+          "items": { "type": "string" },
+          "description": "SPDX ids (wildcards allowed) that are never acceptable"
+        },
+        "exceptions": {
+          "type": "array",
+          "items": { "type": "string" },
+          "description": "Package names exempt from the policy"
+        },
+        "unknown": {
+          "type": "string",
+          "enum": ["allow", "warn", "deny"],
+          "description": "Dependencies without license metadata (default warn)"
+        }
+      }
+    },
     "files": {
       "type": "object",
       "description": "Project file structure",
@@ -114,6 +311,30 @@ pub const PROJECT_SCHEMA: &str = r#"{
       "items": { "type": "string" },
       "description": "Language-specific best practices"
     },
+    "packages": {
+      "type": "array",
+      "description": "Workspace packages, each checked only when it has staged changes (v12.4.0)",
+      "items": {
+        "type": "object",
+        "required": ["path"],
+        "properties": {
+          "path": {
+            "type": "string",
+            "minLength": 1,
+            "description": "Package directory relative to the repository root"
+          },
+          "type": {
+            "type": "string",
+            "description": "Package project type (detected from markers if omitted)"
+          },
+          "quality": {
+            "type": "object",
+            "additionalProperties": { "$ref": "#/definitions/gate" },
+            "description": "Package quality gate commands (built-in checks if omitted)"
+          }
+        }
+      }
+    },
     "release": {
       "type": "object",
       "description": "Release configuration",
@@ -179,5 +400,29 @@ pub const PROJECT_SCHEMA: &str = r#"{
       }
     }
   },
-  "additionalProperties": true
-}"#;
+  "additionalProperties": true,
+  "definitions": {
+    "gate": {
+      "oneOf": [
+        { "type": "string" },
+        {
+          "type": "object",
+          "required": ["command"],
+          "properties": {
+            "command": { "type": "string", "description": "Shell command" },
+            "timeout": {
+              "type": "integer",
+              "minimum": 0,
+              "description": "Timeout in seconds (0 = none)"
+            },
+            "profile": {
+              "type": "string",
+              "enum": ["fast", "full"],
+              "description": "fast: every commit, full: full profile only"
+            }
+          }
+        }
+      ]
+    }
+  }
+}"##;
@@ -1,6 +1,6 @@
 //! JSON Schema for roadmap.yaml
 
-pub const ROADMAP_SCHEMA: &str = r#"
+pub const ROADMAP_SCHEMA: &str = r##"
 {
   "$schema": "http://json-schema.org/draft-07/schema#",
   "$id": "https://github.com/royalbit/asimov/schemas/roadmap.json",
@@ -34,11 +34,7 @@ pub const ROADMAP_SCHEMA: &str = r#"
           "description": "Path to ADR file (e.g., docs/adr/015-anti-sycophancy-protocol.md)"
         },
         "deliverables": {
-          "type": "array",
-          "description": "Checklist of deliverables",
-          "items": {
-            "type": "string"
-          }
+          "$ref": "#/definitions/deliverables"
         }
       },
       "required": ["version", "status", "summary"]
@@ -64,6 +60,9 @@ pub const ROADMAP_SCHEMA: &str = r#"
           "adr": {
             "type": "string",
             "description": "Path to ADR file (optional)"
+          },
+          "deliverables": {
+            "$ref": "#/definitions/deliverables"
           }
         },
         "required": ["version", "summary"]
@@ -77,6 +76,34 @@ pub const ROADMAP_SCHEMA: &str = r#"
       }
     }
   },
-  "required": ["current"]
+  "required": ["current"],
+  "definitions": {
+    "deliverables": {
+      "type": "array",
+      "description": "Checklist of deliverables; items with an id can be referenced from commits as [id] (v12.4.0)",
+      "items": {
+        "oneOf": [
+          { "type": "string" },
+          {
+            "type": "object",
+            "required": ["id"],
+            "properties": {
+              "id": {
+                "type": "string",
+                "pattern": "^[A-Za-z0-9][A-Za-z0-9._-]*$",
+                "description": "Deliverable id, referenced from commit messages as [id]"
+              },
+              "summary": { "type": "string", "description": "One-line summary" },
+              "status": {
+                "type": "string",
+                "enum": ["todo", "wip", "done"],
+                "description": "Progress (wip survives context compaction, ADR-047)"
+              }
+            }
+          }
+        ]
+      }
+    }
+  }
 }
-"#;
+"##;
@@ -4,8 +4,12 @@
 //! - Version consistency across files
 //! - Deprecated pattern detection
 //! - Cross-reference validation
+//! - CHANGELOG.md against roadmap.yaml and the package version (v12.4.0)
 
+use crate::changelog::{parse_changelog, CHANGELOG_FILE};
 use crate::markdown::find_markdown_files;
+use crate::resolve_protocol_dir;
+use crate::roadmap::load_milestones;
 use regex::Regex;
 use std::fs;
 use std::path::{Path, PathBuf};
@@ -56,6 +60,8 @@ pub enum IssueCategory {
     VersionMismatch,
     DeprecatedPattern,
     HelpDocMismatch,
+    /// v12.4.0: CHANGELOG.md out of step with roadmap.yaml or the manifest
+    ChangelogMismatch,
 }
 
 impl std::fmt::Display for IssueCategory {
@@ -64,6 +70,7 @@ impl std::fmt::Display for IssueCategory {
             IssueCategory::VersionMismatch => write!(f, "version"),
             IssueCategory::DeprecatedPattern => write!(f, "deprecated"),
             IssueCategory::HelpDocMismatch => write!(f, "help-doc"),
+            IssueCategory::ChangelogMismatch => write!(f, "changelog"),
         }
     }
 }
@@ -84,6 +91,8 @@ pub struct SemanticConfig {
     pub expected_version: Option<String>,
     /// Check --help output against docs
     pub check_help: bool,
+    /// v12.4.0: Check CHANGELOG.md against roadmap.yaml and Cargo.toml/package.json
+    pub check_changelog: bool,
 }
 
 /// A deprecated pattern to detect
@@ -142,6 +151,11 @@ pub fn check_semantic(dir: &Path, config: &SemanticConfig) -> SemanticResult {
         check_help_doc_consistency(dir, &mut result);
     }
 
+    // 4. CHANGELOG/roadmap/manifest consistency
+    if config.check_changelog {
+        check_changelog_consistency(dir, &mut result);
+    }
+
     result
 }
 
@@ -287,6 +301,79 @@ fn check_help_doc_consistency(_dir: &Path, _result: &mut SemanticResult) {
     // For now, version consistency is handled by check_version_consistency
 }
 
+/// Released roadmap versions need a CHANGELOG.md entry, and the newest entry
+/// should be the version the manifest declares (skipped without a CHANGELOG.md)
+fn check_changelog_consistency(dir: &Path, result: &mut SemanticResult) {
+    let changelog_path = dir.join(CHANGELOG_FILE);
+    let Ok(content) = fs::read_to_string(&changelog_path) else {
+        return;
+    };
+    let entries = parse_changelog(&content);
+
+    let roadmap_path = resolve_protocol_dir(dir).join("roadmap.yaml");
+    let roadmap = fs::read_to_string(&roadmap_path).unwrap_or_default();
+    for milestone in load_milestones(dir) {
+        if milestone.status.as_deref() != Some("released")
+            || entries.iter().any(|e| e.version == milestone.version)
+        {
+            continue;
+        }
+        let line = roadmap
+            .lines()
+            .position(|l| l.contains("version") && l.contains(milestone.version.as_str()))
+            .map(|i| i + 1);
+        result.issues.push(SemanticIssue {
+            file: roadmap_path.clone(),
+            line,
+            category: IssueCategory::ChangelogMismatch,
+            severity: Severity::Error,
+            message: format!(
+                "Released version {} has no {} entry (draft one with `asimov changelog`)",
+                milestone.version, CHANGELOG_FILE
+            ),
+            context: None,
+        });
+    }
+
+    let Some(top) = entries.first() else {
+        return;
+    };
+    for (manifest, version) in package_versions(dir) {
+        if version != top.version {
+            result.issues.push(SemanticIssue {
+                file: changelog_path.clone(),
+                line: Some(top.line),
+                category: IssueCategory::ChangelogMismatch,
+                severity: Severity::Warning,
+                message: format!(
+                    "Top {} version {} differs from {} version {}",
+                    CHANGELOG_FILE, top.version, manifest, version
+                ),
+                context: Some(top.heading.clone()),
+            });
+        }
+    }
+}
+
+/// Versions declared by Cargo.toml and package.json
+fn package_versions(dir: &Path) -> Vec<(&'static str, String)> {
+    let mut versions = Vec::new();
+    if let Some(version) = get_cargo_version(dir) {
+        versions.push(("Cargo.toml", version));
+    }
+    let package = fs::read_to_string(dir.join("package.json"))
+        .ok()
+        .and_then(|content| serde_json::from_str::<serde_json::Value>(&content).ok());
+    if let Some(version) = package
+        .as_ref()
+        .and_then(|p| p.get("version"))
+        .and_then(|v| v.as_str())
+    {
+        versions.push(("package.json", version.to_string()));
+    }
+    versions
+}
+
 /// Load deprecated patterns from a config file or warmup.yaml
 pub fn load_deprecated_patterns(dir: &Path) -> Vec<DeprecatedPattern> {
     let mut patterns = Vec::new();
@@ -700,4 +787,60 @@ mod tests {
         // The issue should contain replacement info
         assert!(result.issues.iter().any(|i| i.message.contains("new_api")));
     }
+
+    #[test]
+    fn test_changelog_consistency() {
+        let temp = TempDir::new().unwrap();
+        let dir = temp.path();
+        fs::create_dir_all(dir.join(".asimov")).unwrap();
+        fs::write(
+            dir.join(".asimov/roadmap.yaml"),
+            "current:\n  version: \"1.1.0\"\n  status: released\nnext:\n  - version: \"1.2.0\"\n    status: planned\n",
+        )
+        .unwrap();
+        fs::write(
+            dir.join("Cargo.toml"),
+            "[package]\nname = \"x\"\nversion = \"1.2.0\"\n",
+        )
+        .unwrap();
+        fs::write(dir.join("package.json"), r#"{"version": "1.0.0"}"#).unwrap();
+        let config = SemanticConfig {
+            check_changelog: true,
+            ..Default::default()
+        };
+
+        // Nothing to compare without a CHANGELOG.md
+        assert!(check_semantic(dir, &config).is_ok());
+
+        fs::write(
+            dir.join(CHANGELOG_FILE),
+            "# Changelog\n\n## [Unreleased]\n\n## [1.0.0] - 2026-01-01\n",
+        )
+        .unwrap();
+        let result = check_semantic(dir, &config);
+        assert_eq!(result.error_count(), 1);
+        let missing = &result.issues[0];
+        assert_eq!(missing.category, IssueCategory::ChangelogMismatch);
+        assert_eq!(missing.line, Some(2));
+        assert!(missing.message.contains("Released version 1.1.0"));
+        assert_eq!(result.warning_count(), 1);
+        assert!(result.issues[1]
+            .message
+            .contains("Cargo.toml version 1.2.0"));
+        assert_eq!(result.issues[1].line, Some(5));
+
+        fs::write(
+            dir.join(CHANGELOG_FILE),
+            "## [1.1.0] - 2026-02-01\n\n## [1.0.0] - 2026-01-01\n",
+        )
+        .unwrap();
+        fs::write(dir.join("package.json"), r#"{"version": "1.1.0"}"#).unwrap();
+        fs::write(
+            dir.join("Cargo.toml"),
+            "[package]\nname = \"x\"\nversion = \"1.1.0\"\n",
+        )
+        .unwrap();
+        assert!(check_semantic(dir, &config).is_ok());
+        assert_eq!(IssueCategory::ChangelogMismatch.to_string(), "changelog");
+    }
 }
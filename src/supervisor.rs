@@ -0,0 +1,574 @@
+//! Session supervisor (v12.4.0)
+//!
+//! `asimov --supervise` runs the AI CLI under a pseudo-terminal instead of
+//! handing over the real one. Output passes through unchanged while the
+//! supervisor watches for compaction and session-start events; once the CLI
+//! goes quiet it types the `refresh --json` context back into the session,
+//! so compaction survival no longer depends on the AI remembering to warm up.
+//!
+//! Events arrive two ways:
+//! - Output markers: the profile's `compact_markers` / `session_start_markers`,
+//!   matched case-insensitively with ANSI escape sequences stripped
+//! - Hooks: the child gets `ASIMOV_SUPERVISOR_EVENTS=<file>` and the
+//!   `.asimov/hooks/*.sh.tpl` hooks append `compact` or `session-start` to it
+
+use portable_pty::{native_pty_system, CommandBuilder, PtySize};
+use std::fmt;
+use std::io::{Read, Seek, SeekFrom, Write};
+use std::path::Path;
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// Environment variable naming the event file hooks append to
+pub const EVENTS_ENV: &str = "ASIMOV_SUPERVISOR_EVENTS";
+
+/// How long the CLI must be silent before context is injected
+pub const DEFAULT_QUIET: Duration = Duration::from_millis(1500);
+
+/// How often the event file, window size and child are polled
+const POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// How long to drain output after the child exits
+const DRAIN_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// Something that wipes or resets the AI's context
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SessionEvent {
+    /// The conversation was (or is being) compacted
+    Compact,
+    /// A new, resumed or cleared session started
+    SessionStart,
+}
+
+impl std::str::FromStr for SessionEvent {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().replace('_', "-").as_str() {
+            "compact" | "pre-compact" => Ok(SessionEvent::Compact),
+            "session-start" | "start" => Ok(SessionEvent::SessionStart),
+            other => Err(format!(
+                "Unknown session event '{}' (expected compact or session-start)",
+                other
+            )),
+        }
+    }
+}
+
+impl fmt::Display for SessionEvent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SessionEvent::Compact => write!(f, "compact"),
+            SessionEvent::SessionStart => write!(f, "session-start"),
+        }
+    }
+}
+
+/// Output markers per event, usually from an AI profile
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Markers {
+    pub compact: Vec<String>,
+    pub session_start: Vec<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum EscapeState {
+    Text,
+    Escape,
+    /// `ESC [` ... final byte
+    Csi,
+    /// `ESC ]` ... BEL or `ESC \`
+    Osc,
+    OscEscape,
+}
+
+/// Finds markers in a terminal output stream split into arbitrary chunks
+#[derive(Debug, Clone)]
+pub struct MarkerScanner {
+    markers: Vec<(Vec<u8>, SessionEvent)>,
+    longest: usize,
+    state: EscapeState,
+    tail: Vec<u8>,
+}
+
+impl MarkerScanner {
+    pub fn new(markers: &Markers) -> Self {
+        let markers: Vec<(Vec<u8>, SessionEvent)> = markers
+            .compact
+            .iter()
+            .map(|m| (m, SessionEvent::Compact))
+            .chain(
+                markers
+                    .session_start
+                    .iter()
+                    .map(|m| (m, SessionEvent::SessionStart)),
+            )
+            .filter(|(m, _)| !m.trim().is_empty())
+            .map(|(m, event)| (m.to_lowercase().into_bytes(), event))
+            .collect();
+        let longest = markers.iter().map(|(m, _)| m.len()).max().unwrap_or(0);
+        Self {
+            markers,
+            longest,
+            state: EscapeState::Text,
+            tail: Vec::new(),
+        }
+    }
+
+    /// Feed raw output; returns the events whose markers completed in it
+    pub fn feed(&mut self, bytes: &[u8]) -> Vec<SessionEvent> {
+        if self.markers.is_empty() {
+            return Vec::new();
+        }
+        for &byte in bytes {
+            self.state = match (self.state, byte) {
+                (EscapeState::Text, 0x1b) => EscapeState::Escape,
+                (EscapeState::Text, b) => {
+                    if b >= 0x20 || b == b'\n' {
+                        self.tail.push(b.to_ascii_lowercase());
+                    }
+                    EscapeState::Text
+                }
+                (EscapeState::Escape, b'[') => EscapeState::Csi,
+                (EscapeState::Escape, b']') => EscapeState::Osc,
+                (EscapeState::Escape, _) => EscapeState::Text,
+                (EscapeState::Csi, 0x40..=0x7e) => EscapeState::Text,
+                (EscapeState::Csi, _) => EscapeState::Csi,
+                (EscapeState::Osc, 0x07) => EscapeState::Text,
+                (EscapeState::Osc, 0x1b) => EscapeState::OscEscape,
+                (EscapeState::Osc, _) => EscapeState::Osc,
+                (EscapeState::OscEscape, _) => EscapeState::Text,
+            };
+        }
+
+        let mut events = Vec::new();
+        let mut consumed = 0;
+        for (marker, event) in &self.markers {
+            if let Some(pos) = find(&self.tail, marker) {
+                if !events.contains(event) {
+                    events.push(*event);
+                }
+                consumed = consumed.max(pos + marker.len());
+            }
+        }
+        // Forget matched text, and keep only what a marker could still finish
+        self.tail.drain(..consumed);
+        let keep = self.longest.saturating_sub(1);
+        if self.tail.len() > keep {
+            self.tail.drain(..self.tail.len() - keep);
+        }
+        events
+    }
+}
+
+fn find(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    haystack
+        .windows(needle.len())
+        .position(|window| window == needle)
+}
+
+/// The file hooks append events to, read incrementally; removed when dropped
+#[derive(Debug)]
+pub struct EventLog {
+    path: tempfile::TempPath,
+    offset: u64,
+}
+
+impl EventLog {
+    /// Create a new event file in the temp directory
+    pub fn create() -> std::io::Result<Self> {
+        Self::create_in(&std::env::temp_dir())
+    }
+
+    /// Create a new event file in `dir`: randomly named, never an existing
+    /// file or symlink, readable by the current user only
+    pub fn create_in(dir: &Path) -> std::io::Result<Self> {
+        let path = tempfile::Builder::new()
+            .prefix("asimov-events-")
+            .suffix(".log")
+            .tempfile_in(dir)?
+            .into_temp_path();
+        Ok(Self { path, offset: 0 })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Events from complete lines appended since the last poll
+    pub fn poll(&mut self) -> Vec<SessionEvent> {
+        let mut content = Vec::new();
+        let read = std::fs::File::open(&self.path).and_then(|mut file| {
+            file.seek(SeekFrom::Start(self.offset))?;
+            file.read_to_end(&mut content)
+        });
+        if read.is_err() {
+            return Vec::new();
+        }
+        let Some(end) = content.iter().rposition(|&b| b == b'\n') else {
+            return Vec::new();
+        };
+        self.offset += end as u64 + 1;
+        String::from_utf8_lossy(&content[..end])
+            .lines()
+            .filter_map(|line| line.parse().ok())
+            .collect()
+    }
+}
+
+/// The AI CLI to run under the supervisor
+#[derive(Debug, Clone)]
+pub struct SupervisedCommand {
+    pub program: String,
+    pub args: Vec<String>,
+    pub markers: Markers,
+    /// Silence required before injecting context (see `DEFAULT_QUIET`)
+    pub quiet: Duration,
+}
+
+/// What happened during a supervised session
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SupervisorReport {
+    pub exit_code: u32,
+    /// Events that led to a context injection, in order
+    pub injected: Vec<SessionEvent>,
+}
+
+enum Output {
+    Data,
+    Events(Vec<SessionEvent>),
+    Closed,
+}
+
+/// Run `command` under a PTY, injecting `context(event)` after each event
+///
+/// Blocks until the CLI exits. `context` returning `None` skips that event.
+pub fn supervise(
+    command: &SupervisedCommand,
+    context: &mut dyn FnMut(SessionEvent) -> Option<String>,
+) -> Result<SupervisorReport, String> {
+    let cwd = std::env::current_dir().map_err(|e| format!("No working directory: {}", e))?;
+    let mut event_log =
+        EventLog::create().map_err(|e| format!("Failed to create the event file: {}", e))?;
+
+    let pty_system = native_pty_system();
+    let mut size = terminal::size();
+    let pair = pty_system
+        .openpty(size)
+        .map_err(|e| format!("Failed to open a pseudo-terminal: {}", e))?;
+
+    let mut cmd = CommandBuilder::new(&command.program);
+    cmd.args(&command.args);
+    cmd.cwd(&cwd);
+    cmd.env(EVENTS_ENV, event_log.path());
+    let mut child = pair
+        .slave
+        .spawn_command(cmd)
+        .map_err(|e| format!("Failed to start {}: {}", command.program, e))?;
+    // The reader only sees EOF once no slave handle is left open here
+    drop(pair.slave);
+
+    let mut reader = pair
+        .master
+        .try_clone_reader()
+        .map_err(|e| format!("Failed to read from the pseudo-terminal: {}", e))?;
+    let writer =
+        Arc::new(Mutex::new(pair.master.take_writer().map_err(|e| {
+            format!("Failed to write to the pseudo-terminal: {}", e)
+        })?));
+
+    let _raw_mode = terminal::RawMode::enable();
+
+    let (tx, rx) = mpsc::channel();
+    let mut scanner = MarkerScanner::new(&command.markers);
+    std::thread::spawn(move || {
+        let mut stdout = std::io::stdout();
+        let mut buf = [0u8; 8192];
+        loop {
+            match reader.read(&mut buf) {
+                Ok(0) | Err(_) => break,
+                Ok(n) => {
+                    let _ = stdout.write_all(&buf[..n]);
+                    let _ = stdout.flush();
+                    let events = scanner.feed(&buf[..n]);
+                    let message = if events.is_empty() {
+                        Output::Data
+                    } else {
+                        Output::Events(events)
+                    };
+                    if tx.send(message).is_err() {
+                        break;
+                    }
+                }
+            }
+        }
+        let _ = tx.send(Output::Closed);
+    });
+
+    let stdin_writer = Arc::clone(&writer);
+    std::thread::spawn(move || {
+        let mut stdin = std::io::stdin();
+        let mut buf = [0u8; 1024];
+        while let Ok(n) = stdin.read(&mut buf) {
+            if n == 0 {
+                break;
+            }
+            let Ok(mut writer) = stdin_writer.lock() else {
+                break;
+            };
+            if writer
+                .write_all(&buf[..n])
+                .and_then(|_| writer.flush())
+                .is_err()
+            {
+                break;
+            }
+        }
+    });
+
+    let mut pending: Vec<SessionEvent> = Vec::new();
+    let mut injected = Vec::new();
+    let mut last_output = Instant::now();
+    let mut exit_status = None;
+    let mut exited_at = None;
+
+    loop {
+        match rx.recv_timeout(POLL_INTERVAL) {
+            Ok(Output::Data) => last_output = Instant::now(),
+            Ok(Output::Events(events)) => {
+                last_output = Instant::now();
+                queue(&mut pending, events);
+            }
+            Ok(Output::Closed) | Err(RecvTimeoutError::Disconnected) => {
+                if exit_status.is_none() {
+                    exit_status = Some(child.wait().map_err(|e| e.to_string())?);
+                }
+                break;
+            }
+            Err(RecvTimeoutError::Timeout) => {}
+        }
+
+        queue(&mut pending, event_log.poll());
+
+        if let Some(status) = child.try_wait().map_err(|e| e.to_string())? {
+            exit_status.get_or_insert(status);
+            // A grandchild may keep the terminal open; stop waiting for it
+            if exited_at.get_or_insert_with(Instant::now).elapsed() >= DRAIN_TIMEOUT {
+                break;
+            }
+            continue;
+        }
+
+        let current = terminal::size();
+        if (current.rows, current.cols) != (size.rows, size.cols) {
+            size = current;
+            let _ = pair.master.resize(size);
+        }
+
+        if !pending.is_empty() && last_output.elapsed() >= command.quiet {
+            // Several events in one quiet period need a single refresh
+            let event = pending[0];
+            pending.clear();
+            if let Some(text) = context(event) {
+                let mut writer = writer
+                    .lock()
+                    .map_err(|_| "Pseudo-terminal writer poisoned".to_string())?;
+                writer
+                    .write_all(text.trim_end().as_bytes())
+                    .and_then(|_| writer.write_all(b"\r"))
+                    .and_then(|_| writer.flush())
+                    .map_err(|e| format!("Failed to inject context: {}", e))?;
+                injected.push(event);
+            }
+        }
+    }
+
+    let exit_code = exit_status.map(|s| s.exit_code()).unwrap_or(1);
+    Ok(SupervisorReport {
+        exit_code,
+        injected,
+    })
+}
+
+fn queue(pending: &mut Vec<SessionEvent>, events: Vec<SessionEvent>) {
+    for event in events {
+        if !pending.contains(&event) {
+            pending.push(event);
+        }
+    }
+}
+
+/// The real terminal: size and raw mode
+#[cfg(unix)]
+mod terminal {
+    use portable_pty::PtySize;
+    use std::io::IsTerminal;
+
+    /// Window size of stdout, or 80x24 when it is not a terminal
+    pub fn size() -> PtySize {
+        let mut ws: libc::winsize = unsafe { std::mem::zeroed() };
+        // SAFETY: TIOCGWINSZ only writes a winsize into the pointer we pass
+        let ok = unsafe { libc::ioctl(libc::STDOUT_FILENO, libc::TIOCGWINSZ, &mut ws) } == 0;
+        if ok && ws.ws_row > 0 && ws.ws_col > 0 {
+            PtySize {
+                rows: ws.ws_row,
+                cols: ws.ws_col,
+                pixel_width: ws.ws_xpixel,
+                pixel_height: ws.ws_ypixel,
+            }
+        } else {
+            super::default_size()
+        }
+    }
+
+    /// Raw mode on stdin for as long as this lives (no-op if not a terminal)
+    pub struct RawMode(Option<libc::termios>);
+
+    impl RawMode {
+        pub fn enable() -> Self {
+            if !std::io::stdin().is_terminal() {
+                return Self(None);
+            }
+            let mut original: libc::termios = unsafe { std::mem::zeroed() };
+            // SAFETY: tcgetattr/tcsetattr read and write the termios we own
+            unsafe {
+                if libc::tcgetattr(libc::STDIN_FILENO, &mut original) != 0 {
+                    return Self(None);
+                }
+                let mut raw = original;
+                libc::cfmakeraw(&mut raw);
+                if libc::tcsetattr(libc::STDIN_FILENO, libc::TCSANOW, &raw) != 0 {
+                    return Self(None);
+                }
+            }
+            Self(Some(original))
+        }
+    }
+
+    impl Drop for RawMode {
+        fn drop(&mut self) {
+            if let Some(original) = self.0 {
+                // SAFETY: restores the settings captured in enable()
+                unsafe {
+                    libc::tcsetattr(libc::STDIN_FILENO, libc::TCSANOW, &original);
+                }
+            }
+        }
+    }
+}
+
+/// The real terminal: fixed size, no raw mode (ConPTY handles input)
+#[cfg(not(unix))]
+mod terminal {
+    use portable_pty::PtySize;
+
+    pub fn size() -> PtySize {
+        super::default_size()
+    }
+
+    pub struct RawMode;
+
+    impl RawMode {
+        pub fn enable() -> Self {
+            RawMode
+        }
+    }
+}
+
+fn default_size() -> PtySize {
+    PtySize {
+        rows: 24,
+        cols: 80,
+        pixel_width: 0,
+        pixel_height: 0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn markers() -> Markers {
+        Markers {
+            compact: vec!["Compacting conversation".to_string()],
+            session_start: vec!["Session resumed".to_string()],
+        }
+    }
+
+    #[test]
+    fn test_session_event_parse_and_display() {
+        assert_eq!(
+            "pre-compact".parse::<SessionEvent>().unwrap(),
+            SessionEvent::Compact
+        );
+        assert_eq!(
+            "SESSION_START".parse::<SessionEvent>().unwrap(),
+            SessionEvent::SessionStart
+        );
+        assert_eq!(SessionEvent::SessionStart.to_string(), "session-start");
+        assert!("lunch".parse::<SessionEvent>().is_err());
+    }
+
+    #[test]
+    fn test_scanner_strips_escapes_and_spans_chunks() {
+        let mut scanner = MarkerScanner::new(&markers());
+        assert!(scanner.feed(b"\x1b[1;33m\xe2\x9c\xbb Compact").is_empty());
+        assert_eq!(
+            scanner.feed(b"ing \x1b[0mconver\x1b]0;title\x07sation\xe2\x80\xa6"),
+            vec![SessionEvent::Compact]
+        );
+        // Matched text is consumed, so it is not reported twice
+        assert!(scanner.feed(b" still working").is_empty());
+        assert_eq!(
+            scanner.feed(b"SESSION RESUMED\r\n"),
+            vec![SessionEvent::SessionStart]
+        );
+    }
+
+    #[test]
+    fn test_scanner_without_markers() {
+        let mut scanner = MarkerScanner::new(&Markers::default());
+        assert!(scanner.feed(b"Compacting conversation").is_empty());
+    }
+
+    #[test]
+    fn test_event_log_reads_complete_lines() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let mut log = EventLog::create_in(temp.path()).unwrap();
+        let path = log.path().to_path_buf();
+        assert!(log.poll().is_empty());
+
+        let mut file = std::fs::OpenOptions::new()
+            .append(true)
+            .open(&path)
+            .unwrap();
+        file.write_all(b"compact\nbogus\nsession-st").unwrap();
+        assert_eq!(log.poll(), vec![SessionEvent::Compact]);
+        file.write_all(b"art\n").unwrap();
+        assert_eq!(log.poll(), vec![SessionEvent::SessionStart]);
+        assert!(log.poll().is_empty());
+
+        drop(log);
+        assert!(!path.exists(), "event file is removed with the log");
+    }
+
+    #[test]
+    fn test_event_log_files_are_private_and_unique() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let first = EventLog::create_in(temp.path()).unwrap();
+        let second = EventLog::create_in(temp.path()).unwrap();
+        assert_ne!(first.path(), second.path());
+        let name = first.path().file_name().unwrap().to_string_lossy();
+        assert!(name.starts_with("asimov-events-") && name.ends_with(".log"));
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mode = std::fs::metadata(first.path())
+                .unwrap()
+                .permissions()
+                .mode();
+            assert_eq!(mode & 0o077, 0, "event file is readable by others");
+        }
+    }
+}
@@ -0,0 +1,467 @@
+//! Confidence-scored project type detection (v12.4.0)
+//!
+//! Instead of stopping at the first marker file, every project type is scored
+//! from the evidence found in the directory:
+//!
+//! - build manifests (`Cargo.toml`, `pyproject.toml`, `package.json`, ...)
+//! - lockfiles (`Cargo.lock`, `poetry.lock`, `pnpm-lock.yaml`, ...)
+//! - source file counts by language
+//!
+//! A Python project that keeps a `package.json` for docs tooling therefore
+//! ranks Python first: it has the manifest, the lockfile and the sources.
+//! Ties fall back to the historical marker priority (ADR-032).
+
+use super::{has_architecture_file, has_file_with_extension, is_kotlin_project, ProjectType};
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::path::Path;
+
+/// Score for the first build manifest of a type
+const MANIFEST_WEIGHT: u32 = 50;
+/// Score for each additional manifest of the same type
+const EXTRA_MANIFEST_WEIGHT: u32 = 10;
+/// Score for a lockfile
+const LOCKFILE_WEIGHT: u32 = 20;
+/// Maximum score from source files (scaled by share of all source files)
+const SOURCE_WEIGHT: u32 = 40;
+/// Arch/docs markers stay below a bare manifest so code projects win,
+/// but above stray sources (scripts in a docs repo)
+const ARCH_WEIGHT: u32 = 45;
+const EXTRA_ARCH_WEIGHT: u32 = 2;
+const DOCS_WEIGHT: u32 = 45;
+
+/// Stop counting after this many files (keeps detection fast on huge trees)
+const MAX_FILES_SCANNED: usize = 5000;
+const MAX_SCAN_DEPTH: usize = 8;
+
+/// Directories skipped when counting source files
+const SKIPPED_DIRS: &[&str] = &[
+    "node_modules",
+    "target",
+    "vendor",
+    "build",
+    "dist",
+    "out",
+    "bin",
+    "obj",
+    "venv",
+    "__pycache__",
+];
+
+/// Tie-break order: the marker priority of the original detector
+const PRIORITY: &[ProjectType] = &[
+    ProjectType::Flutter,
+    ProjectType::Rust,
+    ProjectType::Go,
+    ProjectType::Python,
+    ProjectType::Kotlin,
+    ProjectType::Java,
+    ProjectType::CSharp,
+    ProjectType::Swift,
+    ProjectType::Ruby,
+    ProjectType::Php,
+    ProjectType::Node,
+    ProjectType::Cpp,
+    ProjectType::Arch,
+    ProjectType::Docs,
+];
+
+/// Build manifests per type (top-level file names)
+const MANIFESTS: &[(ProjectType, &[&str])] = &[
+    (ProjectType::Flutter, &["pubspec.yaml"]),
+    (ProjectType::Rust, &["Cargo.toml"]),
+    (ProjectType::Go, &["go.mod", "go.work"]),
+    (
+        ProjectType::Python,
+        &[
+            "pyproject.toml",
+            "setup.py",
+            "setup.cfg",
+            "requirements.txt",
+        ],
+    ),
+    (ProjectType::Swift, &["Package.swift"]),
+    (ProjectType::Ruby, &["Gemfile"]),
+    (ProjectType::Php, &["composer.json"]),
+    (ProjectType::Node, &["package.json"]),
+    (ProjectType::Cpp, &["CMakeLists.txt", "meson.build"]),
+];
+
+/// Lockfiles per type
+const LOCKFILES: &[(ProjectType, &[&str])] = &[
+    (ProjectType::Flutter, &["pubspec.lock"]),
+    (ProjectType::Rust, &["Cargo.lock"]),
+    (ProjectType::Go, &["go.sum"]),
+    (
+        ProjectType::Python,
+        &["poetry.lock", "uv.lock", "Pipfile.lock", "pdm.lock"],
+    ),
+    (ProjectType::Java, &["gradle.lockfile"]),
+    (ProjectType::Kotlin, &["gradle.lockfile"]),
+    (ProjectType::CSharp, &["packages.lock.json"]),
+    (ProjectType::Swift, &["Package.resolved"]),
+    (ProjectType::Ruby, &["Gemfile.lock"]),
+    (ProjectType::Php, &["composer.lock"]),
+    (
+        ProjectType::Node,
+        &[
+            "package-lock.json",
+            "yarn.lock",
+            "pnpm-lock.yaml",
+            "bun.lock",
+            "bun.lockb",
+        ],
+    ),
+    (ProjectType::Cpp, &["conan.lock", "vcpkg.json"]),
+];
+
+/// Source extensions per type
+const SOURCE_EXTENSIONS: &[(ProjectType, &[&str])] = &[
+    (ProjectType::Rust, &["rs"]),
+    (ProjectType::Python, &["py", "pyi"]),
+    (
+        ProjectType::Node,
+        &["js", "jsx", "ts", "tsx", "mjs", "cjs", "vue", "svelte"],
+    ),
+    (ProjectType::Go, &["go"]),
+    (ProjectType::Flutter, &["dart"]),
+    (ProjectType::Java, &["java"]),
+    (ProjectType::Kotlin, &["kt", "kts"]),
+    (ProjectType::CSharp, &["cs"]),
+    (
+        ProjectType::Cpp,
+        &["c", "cc", "cpp", "cxx", "h", "hh", "hpp"],
+    ),
+    (ProjectType::Ruby, &["rb"]),
+    (ProjectType::Php, &["php"]),
+    (ProjectType::Swift, &["swift"]),
+];
+
+/// One piece of evidence behind a candidate
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct DetectionEvidence {
+    /// marker, lockfile, source_files or directory
+    pub kind: &'static str,
+    pub detail: String,
+    pub weight: u32,
+}
+
+/// A ranked project type candidate
+#[derive(Debug, Clone, Serialize)]
+pub struct DetectionCandidate {
+    pub project_type: ProjectType,
+    pub score: u32,
+    /// Share of the total score across all candidates (0.0 - 1.0)
+    pub confidence: f64,
+    pub evidence: Vec<DetectionEvidence>,
+}
+
+impl DetectionCandidate {
+    /// True if a build manifest (not just sources) backs this candidate
+    pub fn has_marker(&self) -> bool {
+        self.evidence.iter().any(|e| e.kind == "marker")
+    }
+}
+
+/// Ranked detection result
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct DetectionReport {
+    /// Candidates, best first
+    pub candidates: Vec<DetectionCandidate>,
+    /// Source file counts by language (plus markdown)
+    pub file_counts: BTreeMap<String, usize>,
+    /// Lockfiles found at the top level
+    pub lockfiles: Vec<String>,
+}
+
+impl DetectionReport {
+    /// Best candidate, or Generic when nothing matched
+    pub fn primary(&self) -> ProjectType {
+        self.candidates
+            .first()
+            .map(|c| c.project_type)
+            .unwrap_or_default()
+    }
+}
+
+/// Rank every project type by the evidence found in `dir`
+pub fn detect_project_candidates(dir: &Path) -> DetectionReport {
+    let mut report = DetectionReport::default();
+    let mut evidence: BTreeMap<usize, Vec<DetectionEvidence>> = BTreeMap::new();
+    let mut push = |pt: ProjectType, kind: &'static str, detail: String, weight: u32| {
+        let idx = priority_index(pt);
+        evidence.entry(idx).or_default().push(DetectionEvidence {
+            kind,
+            detail,
+            weight,
+        });
+    };
+
+    // Build manifests
+    let kotlin = is_kotlin_project(dir);
+    let mut manifests: Vec<(ProjectType, String)> = MANIFESTS
+        .iter()
+        .flat_map(|(pt, files)| files.iter().map(move |f| (*pt, *f)))
+        .filter(|(_, f)| dir.join(f).exists())
+        .map(|(pt, f)| (pt, f.to_string()))
+        .collect();
+    for gradle in ["pom.xml", "build.gradle", "build.gradle.kts"] {
+        if dir.join(gradle).exists() {
+            let pt = if kotlin && gradle != "pom.xml" {
+                ProjectType::Kotlin
+            } else {
+                ProjectType::Java
+            };
+            manifests.push((pt, gradle.to_string()));
+        }
+    }
+    if kotlin && dir.join("src/main/kotlin").is_dir() {
+        manifests.push((ProjectType::Kotlin, "src/main/kotlin/".to_string()));
+    }
+    if has_file_with_extension(dir, &["csproj", "sln"]) {
+        manifests.push((ProjectType::CSharp, "*.csproj / *.sln".to_string()));
+    }
+    let mut seen: Vec<ProjectType> = Vec::new();
+    for (pt, file) in manifests {
+        let weight = if seen.contains(&pt) {
+            EXTRA_MANIFEST_WEIGHT
+        } else {
+            seen.push(pt);
+            MANIFEST_WEIGHT
+        };
+        push(pt, "marker", file, weight);
+    }
+
+    // Lockfiles
+    for (pt, files) in LOCKFILES {
+        for file in files.iter().filter(|f| dir.join(f).exists()) {
+            if (*pt == ProjectType::Kotlin) != kotlin && *file == "gradle.lockfile" {
+                continue;
+            }
+            push(*pt, "lockfile", file.to_string(), LOCKFILE_WEIGHT);
+            if !report.lockfiles.contains(&file.to_string()) {
+                report.lockfiles.push(file.to_string());
+            }
+        }
+    }
+
+    // Source files by language
+    report.file_counts = count_source_files(dir);
+    let total_code: usize = report
+        .file_counts
+        .iter()
+        .filter(|(lang, _)| lang.as_str() != "markdown")
+        .map(|(_, n)| n)
+        .sum();
+    for (pt, _) in SOURCE_EXTENSIONS {
+        let count = report
+            .file_counts
+            .get(&pt.to_string())
+            .copied()
+            .unwrap_or(0);
+        let Some(share) = (SOURCE_WEIGHT as usize * count).checked_div(total_code) else {
+            continue;
+        };
+        if count == 0 {
+            continue;
+        }
+        push(
+            *pt,
+            "source_files",
+            format!("{} of {} source files", count, total_code),
+            share.max(1) as u32,
+        );
+    }
+
+    // Architecture project (ADR-041)
+    let mut arch_dirs: Vec<String> = ["c4-models", "decisions"]
+        .iter()
+        .filter(|d| dir.join(d).is_dir())
+        .map(|d| format!("{}/", d))
+        .collect();
+    if dir.join("diagrams").is_dir() && has_architecture_file(dir) {
+        arch_dirs.push("diagrams/ + ARCHITECTURE*.md".to_string());
+    }
+    for (i, detail) in arch_dirs.into_iter().enumerate() {
+        let weight = if i == 0 {
+            ARCH_WEIGHT
+        } else {
+            EXTRA_ARCH_WEIGHT
+        };
+        push(ProjectType::Arch, "directory", detail, weight);
+    }
+
+    // Docs project: docs/ or README.md without code directories
+    let has_code_dirs = ["src", "lib", "cmd", "pkg"]
+        .iter()
+        .any(|d| dir.join(d).is_dir());
+    if !has_code_dirs {
+        if dir.join("docs").is_dir() {
+            push(
+                ProjectType::Docs,
+                "directory",
+                "docs/".to_string(),
+                DOCS_WEIGHT,
+            );
+        } else if dir.join("README.md").exists() {
+            push(
+                ProjectType::Docs,
+                "marker",
+                "README.md".to_string(),
+                DOCS_WEIGHT,
+            );
+        }
+    }
+
+    let total: u32 = evidence.values().flatten().map(|e| e.weight).sum();
+    report.candidates = evidence
+        .into_iter()
+        .map(|(idx, evidence)| {
+            let score = evidence.iter().map(|e| e.weight).sum();
+            DetectionCandidate {
+                project_type: PRIORITY[idx],
+                score,
+                confidence: (f64::from(score) / f64::from(total.max(1)) * 100.0).round() / 100.0,
+                evidence,
+            }
+        })
+        .collect();
+    // Stable sort keeps priority order (BTreeMap key) for equal scores
+    report
+        .candidates
+        .sort_by_key(|c| std::cmp::Reverse(c.score));
+    report
+}
+
+fn priority_index(pt: ProjectType) -> usize {
+    PRIORITY
+        .iter()
+        .position(|p| *p == pt)
+        .unwrap_or(PRIORITY.len() - 1)
+}
+
+fn count_source_files(dir: &Path) -> BTreeMap<String, usize> {
+    let mut counts: BTreeMap<String, usize> = BTreeMap::new();
+    let walker = walkdir::WalkDir::new(dir)
+        .max_depth(MAX_SCAN_DEPTH)
+        .into_iter()
+        .filter_entry(|e| {
+            let name = e.file_name().to_string_lossy();
+            e.depth() == 0 || !(name.starts_with('.') || SKIPPED_DIRS.contains(&name.as_ref()))
+        });
+
+    for entry in walker
+        .flatten()
+        .filter(|e| e.file_type().is_file())
+        .take(MAX_FILES_SCANNED)
+    {
+        let Some(ext) = entry.path().extension().and_then(|e| e.to_str()) else {
+            continue;
+        };
+        let lang = if ext == "md" {
+            Some("markdown".to_string())
+        } else {
+            SOURCE_EXTENSIONS
+                .iter()
+                .find(|(_, exts)| exts.contains(&ext))
+                .map(|(pt, _)| pt.to_string())
+        };
+        if let Some(lang) = lang {
+            *counts.entry(lang).or_default() += 1;
+        }
+    }
+    counts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn write(root: &Path, rel: &str, content: &str) {
+        let path = root.join(rel);
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, content).unwrap();
+    }
+
+    #[test]
+    fn test_empty_dir_is_generic() {
+        let temp = TempDir::new().unwrap();
+        let report = detect_project_candidates(temp.path());
+        assert!(report.candidates.is_empty());
+        assert_eq!(report.primary(), ProjectType::Generic);
+    }
+
+    #[test]
+    fn test_python_with_tooling_package_json() {
+        let temp = TempDir::new().unwrap();
+        write(temp.path(), "pyproject.toml", "[project]\nname = \"x\"\n");
+        write(temp.path(), "uv.lock", "");
+        write(temp.path(), "package.json", "{\"devDependencies\": {}}");
+        write(temp.path(), "src/app/__init__.py", "");
+        write(temp.path(), "src/app/main.py", "");
+
+        let report = detect_project_candidates(temp.path());
+        assert_eq!(report.primary(), ProjectType::Python);
+        assert_eq!(report.candidates[1].project_type, ProjectType::Node);
+        assert!(report.candidates[0].confidence > report.candidates[1].confidence);
+        assert_eq!(report.file_counts.get("python"), Some(&2));
+        assert_eq!(report.lockfiles, vec!["uv.lock"]);
+    }
+
+    #[test]
+    fn test_sources_outweigh_check_order() {
+        // Marker order alone would say Node; the sources say TypeScript app
+        let temp = TempDir::new().unwrap();
+        write(temp.path(), "package.json", "{}");
+        write(temp.path(), "pnpm-lock.yaml", "");
+        write(temp.path(), "CMakeLists.txt", "");
+        write(temp.path(), "src/index.ts", "");
+        let report = detect_project_candidates(temp.path());
+        assert_eq!(report.primary(), ProjectType::Node);
+
+        let evidence = &report.candidates[0].evidence;
+        assert!(evidence
+            .iter()
+            .any(|e| e.kind == "marker" && e.detail == "package.json"));
+        assert!(evidence.iter().any(|e| e.kind == "lockfile"));
+        assert!(evidence.iter().any(|e| e.kind == "source_files"));
+    }
+
+    #[test]
+    fn test_ties_use_marker_priority() {
+        let temp = TempDir::new().unwrap();
+        write(temp.path(), "pubspec.yaml", "name: x");
+        write(temp.path(), "package.json", "{}");
+        let report = detect_project_candidates(temp.path());
+        assert_eq!(report.primary(), ProjectType::Flutter);
+        assert_eq!(report.candidates[0].score, report.candidates[1].score);
+    }
+
+    #[test]
+    fn test_skips_vendored_sources() {
+        let temp = TempDir::new().unwrap();
+        write(temp.path(), "go.mod", "module x");
+        write(temp.path(), "main.go", "");
+        write(temp.path(), "node_modules/a/index.js", "");
+        write(temp.path(), ".venv/lib/site.py", "");
+        let report = detect_project_candidates(temp.path());
+        assert_eq!(report.file_counts.get("go"), Some(&1));
+        assert!(!report.file_counts.contains_key("node"));
+        assert!(!report.file_counts.contains_key("python"));
+    }
+
+    #[test]
+    fn test_confidence_sums_to_one() {
+        let temp = TempDir::new().unwrap();
+        write(temp.path(), "Cargo.toml", "");
+        write(temp.path(), "Cargo.lock", "");
+        write(temp.path(), "src/main.rs", "");
+        write(temp.path(), "scripts/gen.py", "");
+        let report = detect_project_candidates(temp.path());
+        let sum: f64 = report.candidates.iter().map(|c| c.confidence).sum();
+        assert!((sum - 1.0).abs() < 0.02);
+        assert!(report.candidates[0].has_marker());
+        assert!(!report.candidates[1].has_marker());
+    }
+}
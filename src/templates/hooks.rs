@@ -1,14 +1,222 @@
 //! Hook template generators for git and Claude Code
 //! v9.6.0: Direct coding standards enforcement (ADR-043)
 //! v9.8.0: Dependency health audit - STRICT (ADR-045)
+//! v12.4.0: Per-package hooks for workspaces
+//! v12.4.0: Gates generated from project.yaml `quality` (built-ins as fallback)
 
 use super::ProjectType;
+use crate::file_size::default_limits;
+use crate::quality::{GateProfile, HookConfig, QualityGate, HOOK_PROFILE_ENV};
+use crate::workspace::WorkspacePackage;
+
+/// Marker line identifying hooks written by asimov (others are never overwritten)
+pub const GENERATED_HOOK_MARKER: &str = "# Generated by: asimov init / asimov refresh";
+
+/// True if a hook was written by asimov (safe to regenerate)
+pub fn is_generated_hook(content: &str) -> bool {
+    content.contains(GENERATED_HOOK_MARKER)
+}
 
 /// Generate pre-commit hook for RoyalBit Asimov
 /// v9.6.0: Direct tool calls, asimov is optional (no SPOF)
 pub fn precommit_hook_template(project_type: ProjectType) -> String {
-    let (checks, file_ext, max_lines, exclude_dirs) = match project_type {
-        ProjectType::Rust => (
+    let checks = builtin_quality_checks(project_type);
+
+    // v9.8.0: Dependency health checks (ADR-045)
+    let dep_health_check = dependency_health_check(project_type);
+
+    hook_script(&format!(
+        "{}\n{}\n{}",
+        checks,
+        dep_health_check,
+        file_size_check(project_type)
+    ))
+}
+
+/// Generate pre-commit hook from declared quality gates (v12.4.0)
+/// Falls back to the built-in checks when no gates are declared.
+pub fn quality_precommit_hook_template(
+    project_type: ProjectType,
+    gates: &[QualityGate],
+    hook: &HookConfig,
+) -> String {
+    if gates.is_empty() {
+        return precommit_hook_template(project_type);
+    }
+
+    let fallback: Vec<String> = declared_gate_checks(gates)
+        .lines()
+        .map(|line| format!("  {}", line))
+        .collect();
+
+    hook_script(&format!(
+        r#"{}
+# Native runner (cached, timed) when asimov is installed; shell gates otherwise (ADR-043)
+if command -v asimov &>/dev/null && asimov check --help &>/dev/null; then
+  asimov check --staged || exit 1
+else
+{}
+fi
+{}
+{}"#,
+        gate_runner(hook.profile()),
+        fallback.join("\n"),
+        dependency_health_check(project_type),
+        file_size_check(project_type)
+    ))
+}
+
+/// Generate pre-commit hook for a workspace (v12.4.0)
+/// Each package's checks run in its directory, only when it has staged changes.
+pub fn workspace_precommit_hook_template(
+    packages: &[WorkspacePackage],
+    hook: &HookConfig,
+) -> String {
+    let nested: Vec<&str> = packages
+        .iter()
+        .filter(|p| p.path != ".")
+        .map(|p| p.path.as_str())
+        .collect();
+
+    let mut body = gate_runner(hook.profile());
+    body.push_str(
+        r#"
+# === STAGED PACKAGES (v12.4.0 workspace) ===
+staged_files=$(git diff --cached --name-only --diff-filter=ACMRD)
+"#,
+    );
+
+    for package in packages {
+        let declared = package.quality_gates(hook);
+        let checks = if declared.is_empty() {
+            builtin_quality_checks(package.project_type).to_string()
+        } else {
+            declared_gate_checks(&declared)
+        };
+        let label = format!("{} ({})", package.path, package.project_type);
+
+        body.push_str(&format!(
+            r#"
+# === PACKAGE: {label} ===
+if {changed}; then
+  echo ""
+  echo {banner}
+  (
+    cd {dir}
+{checks}
+{deps}
+{size}
+  ) || exit 1
+fi
+"#,
+            label = label.replace(['\n', '\r'], " "),
+            changed = package_changed_check(&package.path, &nested),
+            banner = shell_quote(&format!("▶ {}", label)),
+            dir = shell_quote(&package.path),
+            checks = checks,
+            deps = dependency_health_check(package.project_type),
+            size = file_size_check(package.project_type),
+        ));
+    }
+
+    hook_script(&body)
+}
+
+/// Shell test that `$staged_files` touches the package at `path`
+fn package_changed_check(path: &str, nested: &[&str]) -> String {
+    if path != "." {
+        let pattern = format!("^{}/", ere_escape(path));
+        return format!(
+            "echo \"$staged_files\" | grep -qE {}",
+            shell_quote(&pattern)
+        );
+    }
+    // Root owns every staged file outside the other packages
+    if nested.is_empty() {
+        return "[ -n \"$staged_files\" ]".to_string();
+    }
+    let escaped: Vec<String> = nested.iter().map(|p| ere_escape(p)).collect();
+    let pattern = format!("^({})/", escaped.join("|"));
+    format!(
+        "[ -n \"$staged_files\" ] && echo \"$staged_files\" | grep -vqE {}",
+        shell_quote(&pattern)
+    )
+}
+
+/// Escape a literal for a POSIX extended regex
+fn ere_escape(literal: &str) -> String {
+    let mut escaped = String::with_capacity(literal.len());
+    for c in literal.chars() {
+        if r".[]()*+?{}|^$\".contains(c) {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+/// Shell helper that runs one gate with its profile and timeout
+fn gate_runner(default_profile: GateProfile) -> String {
+    format!(
+        r#"# === QUALITY GATES (v12.4.0: project.yaml quality) ===
+# Profile: fast runs fast gates only, full runs all (override: {env}=fast|full)
+hook_profile="${{{env}:-{profile}}}"
+echo "Profile: $hook_profile"
+
+run_gate() {{
+  gate_name="$1"; gate_profile="$2"; gate_timeout="$3"; gate_cmd="$4"
+  if [ "$gate_profile" = "full" ] && [ "$hook_profile" = "fast" ]; then
+    echo "Skipping $gate_name (full profile)"
+    return 0
+  fi
+  echo "Running $gate_name: $gate_cmd"
+  gate_status=0
+  if [ "$gate_timeout" -gt 0 ] && command -v timeout &>/dev/null; then
+    timeout "$gate_timeout" sh -c "$gate_cmd" || gate_status=$?
+  else
+    sh -c "$gate_cmd" || gate_status=$?
+  fi
+  if [ "$gate_status" -eq 124 ] && [ "$gate_timeout" -gt 0 ]; then
+    echo ""; echo "❌ $gate_name timed out after ${{gate_timeout}}s"
+    return 1
+  fi
+  if [ "$gate_status" -ne 0 ]; then
+    echo ""; echo "❌ $gate_name failed: $gate_cmd"
+    return 1
+  fi
+}}
+"#,
+        env = HOOK_PROFILE_ENV,
+        profile = default_profile,
+    )
+}
+
+/// One `run_gate` call per declared gate, in order
+fn declared_gate_checks(gates: &[QualityGate]) -> String {
+    gates
+        .iter()
+        .map(|gate| {
+            format!(
+                "run_gate {} {} {} {} || exit 1",
+                shell_quote(&gate.name),
+                gate.profile,
+                gate.timeout.unwrap_or(0),
+                shell_quote(&gate.command)
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Single-quote a string for bash
+fn shell_quote(value: &str) -> String {
+    format!("'{}'", value.replace('\'', r"'\''"))
+}
+
+/// Built-in quality checks (shell snippet) for a project type
+fn builtin_quality_checks(project_type: ProjectType) -> &'static str {
+    match project_type {
+        ProjectType::Rust => {
             r#"# === QUALITY CHECKS (independent, no asimov) ===
 echo "Checking formatting..."
 if [ -f "cli/Cargo.toml" ]; then
@@ -33,12 +241,9 @@ if [ -f "cli/Cargo.toml" ]; then
   (cd cli && cargo test) || exit 1
 elif [ -f "Cargo.toml" ]; then
   cargo test || exit 1
-fi"#,
-            "rs",
-            1500,
-            "target cli/target",
-        ),
-        ProjectType::Python => (
+fi"#
+        }
+        ProjectType::Python => {
             r#"# === QUALITY CHECKS (independent, no asimov) ===
 echo "Checking formatting..."
 if command -v ruff &>/dev/null; then
@@ -59,12 +264,9 @@ if command -v pytest &>/dev/null; then
   pytest || exit 1
 elif [ -f "setup.py" ] || [ -f "pyproject.toml" ]; then
   python -m pytest || true
-fi"#,
-            "py",
-            1000,
-            "venv __pycache__ .venv",
-        ),
-        ProjectType::Node => (
+fi"#
+        }
+        ProjectType::Node => {
             r#"# === QUALITY CHECKS (independent, no asimov) ===
 echo "Checking formatting..."
 if [ -f "package.json" ]; then
@@ -81,12 +283,9 @@ fi
 echo "Running tests..."
 if [ -f "package.json" ]; then
   npm test 2>/dev/null || npx jest 2>/dev/null || true
-fi"#,
-            "ts js tsx jsx",
-            800,
-            "node_modules dist build",
-        ),
-        ProjectType::Go => (
+fi"#
+        }
+        ProjectType::Go => {
             r#"# === QUALITY CHECKS (independent, no asimov) ===
 echo "Checking formatting..."
 if command -v gofmt &>/dev/null; then
@@ -107,12 +306,9 @@ fi
 echo "Running tests..."
 if command -v go &>/dev/null; then
   go test ./... || exit 1
-fi"#,
-            "go",
-            1000,
-            "vendor",
-        ),
-        ProjectType::Flutter => (
+fi"#
+        }
+        ProjectType::Flutter => {
             r#"# === QUALITY CHECKS (independent, no asimov) ===
 echo "Checking formatting..."
 if command -v dart &>/dev/null; then
@@ -131,95 +327,223 @@ fi
 echo "Running tests..."
 if command -v flutter &>/dev/null; then
   flutter test || exit 1
-fi"#,
-            "dart",
-            800,
-            ".dart_tool build",
-        ),
-        ProjectType::Docs | ProjectType::Arch | ProjectType::Generic | ProjectType::Migration => (
+fi"#
+        }
+        // v12.4.0: Maven or Gradle, wrapper preferred over system install
+        ProjectType::Java => {
+            r#"# === QUALITY CHECKS (independent, no asimov) ===
+if [ -f "pom.xml" ]; then
+  mvn_cmd="mvn"
+  [ -x "./mvnw" ] && mvn_cmd="./mvnw"
+  if grep -q "spotless" pom.xml 2>/dev/null; then
+    echo "Checking formatting..."
+    $mvn_cmd -q spotless:check || { echo ""; echo "❌ Run: $mvn_cmd spotless:apply"; exit 1; }
+  fi
+
+  echo "Running tests..."
+  $mvn_cmd -q verify || exit 1
+elif [ -f "build.gradle" ] || [ -f "build.gradle.kts" ]; then
+  gradle_cmd="gradle"
+  [ -x "./gradlew" ] && gradle_cmd="./gradlew"
+  if grep -q "spotless" build.gradle* 2>/dev/null; then
+    echo "Checking formatting..."
+    $gradle_cmd -q spotlessCheck || { echo ""; echo "❌ Run: $gradle_cmd spotlessApply"; exit 1; }
+  fi
+
+  echo "Running tests..."
+  $gradle_cmd -q check || exit 1
+fi"#
+        }
+        ProjectType::Kotlin => {
+            r#"# === QUALITY CHECKS (independent, no asimov) ===
+gradle_cmd="gradle"
+[ -x "./gradlew" ] && gradle_cmd="./gradlew"
+
+echo "Checking formatting..."
+if command -v ktlint &>/dev/null; then
+  ktlint "src/**/*.kt" || { echo ""; echo "❌ Run: ktlint --format"; exit 1; }
+fi
+
+echo "Running tests..."
+if [ -f "build.gradle.kts" ] || [ -f "build.gradle" ]; then
+  $gradle_cmd -q check || exit 1
+elif [ -f "pom.xml" ]; then
+  mvn -q verify || exit 1
+fi"#
+        }
+        ProjectType::CSharp => {
+            r#"# === QUALITY CHECKS (independent, no asimov) ===
+if command -v dotnet &>/dev/null; then
+  echo "Checking formatting..."
+  dotnet format --verify-no-changes || {
+    echo ""; echo "❌ Run: dotnet format"; exit 1
+  }
+
+  echo "Building (warnings as errors)..."
+  dotnet build -warnaserror --nologo -v q || exit 1
+
+  echo "Running tests..."
+  dotnet test --no-build --nologo -v q || exit 1
+fi"#
+        }
+        ProjectType::Cpp => {
+            r#"# === QUALITY CHECKS (independent, no asimov) ===
+echo "Checking formatting..."
+if command -v clang-format &>/dev/null && [ -f ".clang-format" ]; then
+  sources=$(git diff --cached --name-only --diff-filter=ACM | grep -E '\.(c|cc|cpp|cxx|h|hh|hpp)$' || true)
+  if [ -n "$sources" ]; then
+    echo "$sources" | xargs clang-format --dry-run --Werror || {
+      echo ""; echo "❌ Run: clang-format -i <files>"; exit 1
+    }
+  fi
+fi
+
+echo "Building..."
+if [ -f "CMakeLists.txt" ] && command -v cmake &>/dev/null; then
+  cmake -S . -B build -DCMAKE_EXPORT_COMPILE_COMMANDS=ON >/dev/null || exit 1
+  cmake --build build || exit 1
+
+  echo "Running tests..."
+  if command -v ctest &>/dev/null; then
+    ctest --test-dir build --output-on-failure || exit 1
+  fi
+elif [ -f "meson.build" ] && command -v meson &>/dev/null; then
+  [ -d "build" ] || meson setup build >/dev/null || exit 1
+  meson compile -C build || exit 1
+
+  echo "Running tests..."
+  meson test -C build || exit 1
+fi"#
+        }
+        ProjectType::Ruby => {
+            r#"# === QUALITY CHECKS (independent, no asimov) ===
+echo "Running linter..."
+if [ -f "Gemfile" ] && command -v bundle &>/dev/null; then
+  bundle exec rubocop 2>/dev/null || {
+    echo ""; echo "❌ Run: bundle exec rubocop -a"; exit 1
+  }
+elif command -v rubocop &>/dev/null; then
+  rubocop || exit 1
+fi
+
+echo "Running tests..."
+if [ -d "spec" ] && command -v bundle &>/dev/null; then
+  bundle exec rspec || exit 1
+elif [ -d "test" ] && command -v bundle &>/dev/null; then
+  bundle exec rake test || exit 1
+fi"#
+        }
+        ProjectType::Php => {
+            r#"# === QUALITY CHECKS (independent, no asimov) ===
+echo "Checking formatting..."
+if [ -x "vendor/bin/php-cs-fixer" ]; then
+  vendor/bin/php-cs-fixer fix --dry-run --diff || {
+    echo ""; echo "❌ Run: vendor/bin/php-cs-fixer fix"; exit 1
+  }
+elif [ -x "vendor/bin/phpcs" ]; then
+  vendor/bin/phpcs || { echo ""; echo "❌ Run: vendor/bin/phpcbf"; exit 1; }
+fi
+
+echo "Running static analysis..."
+if [ -x "vendor/bin/phpstan" ]; then
+  vendor/bin/phpstan analyse --no-progress || exit 1
+fi
+
+echo "Running tests..."
+if [ -x "vendor/bin/phpunit" ]; then
+  vendor/bin/phpunit || exit 1
+fi"#
+        }
+        ProjectType::Swift => {
+            r#"# === QUALITY CHECKS (independent, no asimov) ===
+echo "Checking formatting..."
+if command -v swift-format &>/dev/null; then
+  swift-format lint --strict -r Sources Tests 2>/dev/null || {
+    echo ""; echo "❌ Run: swift-format -i -r Sources Tests"; exit 1
+  }
+fi
+
+echo "Running linter..."
+if command -v swiftlint &>/dev/null; then
+  swiftlint --strict || exit 1
+fi
+
+echo "Running tests..."
+if [ -f "Package.swift" ] && command -v swift &>/dev/null; then
+  swift test || exit 1
+fi"#
+        }
+        ProjectType::Docs | ProjectType::Arch | ProjectType::Generic | ProjectType::Migration => {
             r#"# === QUALITY CHECKS ===
 echo "Checking documentation..."
-# No code-specific checks for docs/arch/generic projects"#,
-            "md",
-            800,
-            "node_modules",
+# No code-specific checks for docs/arch/generic projects"#
+        }
+    }
+}
+
+/// File size check: native `asimov validate --sizes` (project.yaml globs and
+/// exemptions), or an inline loop over the type's default limits (v12.4.0)
+fn file_size_check(project_type: ProjectType) -> String {
+    let limits = default_limits(project_type);
+    let find_patterns = limits
+        .extensions
+        .iter()
+        .map(|ext| format!("-name '*.{ext}'"))
+        .collect::<Vec<_>>()
+        .join(" -o ");
+    let exclude_pattern = limits
+        .exclude_dirs
+        .iter()
+        .map(|d| format!("-not -path './{d}/*'"))
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    // Types without a hard limit only warn
+    let (max_lines, over, after) = match (limits.hard, limits.soft) {
+        (Some(hard), _) => (
+            hard,
+            r#"echo "❌ $f exceeds $max_lines lines ($lines)""#,
+            "echo \"Split large files to improve maintainability\"\n    exit 1",
+        ),
+        (None, soft) => (
+            soft.unwrap_or(usize::MAX),
+            r#"echo "⚠️  $f has $lines lines (limit: $max_lines)""#,
+            "echo \"Consider splitting large files\"",
         ),
     };
 
-    // Build file size check for code files
-    let file_size_check = if matches!(
-        project_type,
-        ProjectType::Docs | ProjectType::Arch | ProjectType::Generic | ProjectType::Migration
-    ) {
-        // For docs projects, check markdown files
-        format!(
-            r#"
-# === FILE SIZE CHECK (inline, no deps) ===
-echo "Checking file sizes..."
-max_lines={}
-found_large=0
-for f in $(find . -name '*.md' {} 2>/dev/null); do
-  lines=$(wc -l < "$f" | tr -d ' ')
-  if [ "$lines" -gt "$max_lines" ]; then
-    echo "⚠️  $f has $lines lines (limit: $max_lines)"
-    found_large=1
-  fi
-done
-if [ "$found_large" -eq 1 ]; then
-  echo "Consider splitting large files"
-fi"#,
-            max_lines,
-            exclude_dirs
-                .split_whitespace()
-                .map(|d| format!("-not -path './{d}/*'"))
-                .collect::<Vec<_>>()
-                .join(" ")
-        )
-    } else {
-        // For code projects, check source files
-        let extensions: Vec<&str> = file_ext.split_whitespace().collect();
-        let find_patterns: String = extensions
-            .iter()
-            .map(|ext| format!("-name '*.{ext}'"))
-            .collect::<Vec<_>>()
-            .join(" -o ");
-        let exclude_pattern: String = exclude_dirs
-            .split_whitespace()
-            .map(|d| format!("-not -path './{d}/*'"))
-            .collect::<Vec<_>>()
-            .join(" ");
-
-        format!(
-            r#"
-# === FILE SIZE CHECK (inline, no deps) ===
+    format!(
+        r#"
+# === FILE SIZE CHECK (native when available, inline fallback) ===
 echo "Checking file sizes..."
-max_lines={}
-found_large=0
-for f in $(find . \( {} \) {} 2>/dev/null); do
-  lines=$(wc -l < "$f" | tr -d ' ')
-  if [ "$lines" -gt "$max_lines" ]; then
-    echo "❌ $f exceeds $max_lines lines ($lines)"
-    found_large=1
+if command -v asimov &>/dev/null && asimov validate --help 2>/dev/null | grep -q -- --sizes; then
+  asimov validate --sizes || exit 1
+else
+  max_lines={max_lines}
+  found_large=0
+  for f in $(find . \( {find_patterns} \) {exclude_pattern} 2>/dev/null); do
+    lines=$(wc -l < "$f" | tr -d ' ')
+    if [ "$lines" -gt "$max_lines" ]; then
+      {over}
+      found_large=1
+    fi
+  done
+  if [ "$found_large" -eq 1 ]; then
+    {after}
   fi
-done
-if [ "$found_large" -eq 1 ]; then
-  echo "Split large files to improve maintainability"
-  exit 1
-fi"#,
-            max_lines, find_patterns, exclude_pattern
-        )
-    };
-
-    // v9.8.0: Dependency health checks (ADR-045)
-    let dep_health_check = dependency_health_check(project_type);
+fi"#
+    )
+}
 
+/// Wrap hook body with the shared header, markdown lint and self-heal footer
+fn hook_script(body: &str) -> String {
     format!(
         r#"#!/bin/bash
 # ═══════════════════════════════════════════════════════════════════════════════
 # Pre-commit hook - Direct Coding Standards Enforcement (v9.6.0)
 # Dependency Health Audit - STRICT (v9.8.0)
 # ═══════════════════════════════════════════════════════════════════════════════
-# Generated by: asimov init / asimov refresh
+{}
 # Architecture: ADR-043 (No SPOF), ADR-045 (GOOD CODE = Fresh deps, zero vulns)
 # ═══════════════════════════════════════════════════════════════════════════════
 
@@ -227,8 +551,6 @@ set -e
 
 echo "Running pre-commit checks..."
 
-{}
-{}
 {}
 
 # === DOCUMENTATION CHECK (if markdownlint-cli2 available) ===
@@ -254,7 +576,7 @@ fi
 echo ""
 echo "✅ Pre-commit checks passed!"
 "#,
-        checks, dep_health_check, file_size_check
+        GENERATED_HOOK_MARKER, body
     )
 }
 
@@ -421,41 +743,231 @@ if command -v dart &>/dev/null; then
 fi"#
         .to_string(),
 
+        ProjectType::CSharp => r#"
+# === DEPENDENCY HEALTH (v12.4.0 ADR-045 - STRICT) ===
+# Security audit - FAIL on ANY vulnerable package
+if command -v dotnet &>/dev/null; then
+  echo "Security audit..."
+  if dotnet list package --vulnerable --include-transitive 2>/dev/null | grep -q "has the following vulnerable packages"; then
+    echo ""
+    echo "❌ SECURITY: Vulnerabilities found!"
+    dotnet list package --vulnerable --include-transitive 2>/dev/null || true
+    exit 1
+  fi
+
+  # Freshness check - FAIL on outdated top-level packages
+  echo "Freshness check..."
+  if dotnet list package --outdated 2>/dev/null | grep -q "^   >"; then
+    echo ""
+    echo "❌ OUTDATED: Dependencies need updates!"
+    dotnet list package --outdated 2>/dev/null || true
+    exit 1
+  fi
+fi"#
+        .to_string(),
+
+        ProjectType::Ruby => r#"
+# === DEPENDENCY HEALTH (v12.4.0 ADR-045 - STRICT) ===
+# Security audit - FAIL on ANY vulnerability
+if command -v bundle-audit &>/dev/null; then
+  echo "Security audit..."
+  bundle-audit check --update 2>/dev/null || {
+    echo ""
+    echo "❌ SECURITY: Vulnerabilities found!"
+    echo "Run: bundle update <gem>"
+    exit 1
+  }
+fi
+
+# Freshness check - FAIL on outdated direct dependencies
+if [ -f "Gemfile.lock" ] && command -v bundle &>/dev/null; then
+  echo "Freshness check..."
+  bundle outdated --only-explicit --parseable >/dev/null 2>&1 || {
+    echo ""
+    echo "❌ OUTDATED: Dependencies need updates!"
+    bundle outdated --only-explicit 2>/dev/null || true
+    echo ""
+    echo "Run: bundle update"
+    exit 1
+  }
+fi"#
+        .to_string(),
+
+        ProjectType::Php => r#"
+# === DEPENDENCY HEALTH (v12.4.0 ADR-045 - STRICT) ===
+if [ -f "composer.lock" ] && command -v composer &>/dev/null; then
+  # Security audit - FAIL on ANY vulnerability
+  echo "Security audit..."
+  composer audit --no-interaction 2>/dev/null || {
+    echo ""
+    echo "❌ SECURITY: Vulnerabilities found!"
+    echo "Run: composer update <package>"
+    exit 1
+  }
+
+  # Freshness check - FAIL on outdated direct dependencies
+  echo "Freshness check..."
+  composer outdated --direct --strict >/dev/null 2>&1 || {
+    echo ""
+    echo "❌ OUTDATED: Dependencies need updates!"
+    composer outdated --direct 2>/dev/null || true
+    echo ""
+    echo "Run: composer update"
+    exit 1
+  }
+fi"#
+        .to_string(),
+
+        // No first-party audit tooling: fall back to osv-scanner when available
+        ProjectType::Java | ProjectType::Kotlin | ProjectType::Cpp | ProjectType::Swift => r#"
+# === DEPENDENCY HEALTH (v12.4.0 ADR-045 - STRICT) ===
+# Security audit - FAIL on ANY vulnerability
+if command -v osv-scanner &>/dev/null; then
+  echo "Security audit..."
+  osv-scanner scan source -r . >/dev/null 2>&1 || {
+    echo ""
+    echo "❌ SECURITY: Vulnerabilities found!"
+    echo "Run: osv-scanner scan source -r ."
+    exit 1
+  }
+fi"#
+        .to_string(),
+
         // Docs/Arch/Generic/Migration don't have package dependencies
         _ => String::new(),
     }
 }
 
+/// Generate pre-push hook: every gate at the full profile (v12.4.0)
+/// Falls back to the built-in checks when no gates are declared.
+pub fn prepush_hook_template(project_type: ProjectType, gates: &[QualityGate]) -> String {
+    let checks = if gates.is_empty() {
+        builtin_quality_checks(project_type).to_string()
+    } else {
+        let fallback: Vec<String> = declared_gate_checks(gates)
+            .lines()
+            .map(|line| format!("  {}", line))
+            .collect();
+        format!(
+            r#"{}
+if command -v asimov &>/dev/null && asimov check --help &>/dev/null; then
+  asimov check --profile full --no-cache || exit 1
+else
+{}
+fi"#,
+            gate_runner(GateProfile::Full),
+            fallback.join("\n")
+        )
+    };
+
+    format!(
+        r#"#!/bin/bash
+# ═══════════════════════════════════════════════════════════════════════════════
+# Pre-push hook - Full test suite (v12.4.0)
+# ═══════════════════════════════════════════════════════════════════════════════
+{}
+# Skip once with: git push --no-verify
+# ═══════════════════════════════════════════════════════════════════════════════
+
+set -e
+
+echo "Running pre-push checks (full profile)..."
+export {}=full
+
+{}
+
+echo ""
+echo "✅ Pre-push checks passed!"
+"#,
+        GENERATED_HOOK_MARKER, HOOK_PROFILE_ENV, checks
+    )
+}
+
+/// Generate commit-msg hook: the project.yaml commit message policy (v12.4.0)
+/// A no-op when asimov (or its lint-commit command) is not installed.
+pub fn commit_msg_hook_template() -> String {
+    format!(
+        r#"#!/bin/bash
+# ═══════════════════════════════════════════════════════════════════════════════
+# Commit-msg hook - Commit message policy (v12.4.0)
+# ═══════════════════════════════════════════════════════════════════════════════
+{}
+# ═══════════════════════════════════════════════════════════════════════════════
+
+set -e
+
+if command -v asimov &>/dev/null && asimov lint-commit --help &>/dev/null; then
+  asimov lint-commit "$1" || exit 1
+fi
+"#,
+        GENERATED_HOOK_MARKER
+    )
+}
+
 /// Generate hook installer script
+/// v12.4.0: Prefers `asimov hooks install`; the fallback copies every hook in
+/// .hooks/ into git's real hooks dir (core.hooksPath aware) and chains to
+/// hooks owned by other tools instead of replacing them
 pub fn hook_installer_template() -> String {
-    r#"#!/bin/bash
+    format!(
+        r#"#!/bin/bash
 # Install git hooks for RoyalBit Asimov
 # Generated by asimov init
 
 set -e
 
-HOOK_DIR=".git/hooks"
-SRC_DIR=".hooks"
-
-if [ ! -d ".git" ]; then
+if [ ! -e ".git" ]; then
     echo "Error: Not a git repository"
     exit 1
 fi
 
+if command -v asimov &>/dev/null; then
+    exec asimov hooks install
+fi
+
+# Fallback without asimov: honors core.hooksPath (defaults to .git/hooks)
+HOOK_DIR="$(git rev-parse --git-path hooks)"
+SRC_DIR=".hooks"
+MARKER="{marker}"
+
 mkdir -p "$HOOK_DIR"
 
-if [ -f "$SRC_DIR/pre-commit" ]; then
-    cp "$SRC_DIR/pre-commit" "$HOOK_DIR/pre-commit"
-    chmod +x "$HOOK_DIR/pre-commit"
-    echo "✓ Installed pre-commit hook"
-else
-    echo "Error: $SRC_DIR/pre-commit not found"
+installed=0
+for hook in pre-commit commit-msg pre-push; do
+    [ -f "$SRC_DIR/$hook" ] || continue
+    dest="$HOOK_DIR/$hook"
+    chained="$HOOK_DIR/{chained_dir}/$hook"
+    if [ -f "$dest" ] && ! grep -qF "$MARKER" "$dest"; then
+        if [ -e "$chained" ]; then
+            echo "Error: $dest is not asimov's and $chained already exists"
+            exit 1
+        fi
+        mkdir -p "$HOOK_DIR/{chained_dir}"
+        mv "$dest" "$chained"
+        echo "✓ Chained existing $hook hook"
+    fi
+    {{
+        head -n 1 "$SRC_DIR/$hook"
+        cat <<'ASIMOV_CHAIN'
+{chain}ASIMOV_CHAIN
+        tail -n +2 "$SRC_DIR/$hook"
+    }} > "$dest"
+    chmod +x "$dest"
+    echo "✓ Installed $hook hook"
+    installed=$((installed + 1))
+done
+
+if [ "$installed" -eq 0 ]; then
+    echo "Error: no hooks found in $SRC_DIR"
     exit 1
 fi
 
 echo "Hooks installed successfully!"
-"#
-    .to_string()
+"#,
+        marker = GENERATED_HOOK_MARKER,
+        chained_dir = crate::git_hooks::CHAINED_DIR,
+        chain = crate::git_hooks::CHAIN_SCRIPT,
+    )
 }
 
 /// Returns true if project type uses cargo-husky (Rust projects)
@@ -475,6 +987,7 @@ pub fn git_precommit_hook() -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::path::Path;
 
     #[test]
     fn test_uses_cargo_husky() {
@@ -515,6 +1028,21 @@ mod tests {
         assert!(hook.contains("*.py"));
     }
 
+    #[test]
+    fn test_file_size_check_native_with_fallback() {
+        let hook = precommit_hook_template(ProjectType::Rust);
+        assert!(hook.contains("asimov validate --sizes || exit 1"));
+        // Fallback uses the hard limit from the type's defaults
+        assert!(hook.contains("max_lines=1500"));
+        assert!(hook.contains("-not -path './target/*'"));
+
+        // Docs projects have no hard limit: the fallback only warns
+        let docs = precommit_hook_template(ProjectType::Docs);
+        assert!(docs.contains("max_lines=800"));
+        assert!(docs.contains("Consider splitting large files"));
+        assert!(!docs.contains("Split large files to improve maintainability"));
+    }
+
     #[test]
     fn test_precommit_hook_node() {
         let hook = precommit_hook_template(ProjectType::Node);
@@ -539,6 +1067,34 @@ mod tests {
         assert!(hook.contains("FILE SIZE CHECK"));
     }
 
+    #[test]
+    fn test_precommit_hook_new_ecosystems() {
+        let expected = [
+            (ProjectType::Java, "mvn", "*.java"),
+            (ProjectType::Kotlin, "gradle", "*.kt"),
+            (ProjectType::CSharp, "dotnet format", "*.cs"),
+            (ProjectType::Cpp, "ctest", "*.cpp"),
+            (ProjectType::Ruby, "rubocop", "*.rb"),
+            (ProjectType::Php, "phpunit", "*.php"),
+            (ProjectType::Swift, "swift test", "*.swift"),
+        ];
+        for (pt, check, pattern) in expected {
+            let hook = precommit_hook_template(pt);
+            assert!(hook.contains(check), "{:?} hook should run {}", pt, check);
+            assert!(
+                hook.contains(pattern),
+                "{:?} hook should size-check {}",
+                pt,
+                pattern
+            );
+            assert!(
+                hook.contains("DEPENDENCY HEALTH"),
+                "{:?} hook audits deps",
+                pt
+            );
+        }
+    }
+
     #[test]
     fn test_precommit_hook_docs() {
         let hook = precommit_hook_template(ProjectType::Docs);
@@ -547,11 +1103,82 @@ mod tests {
         assert!(hook.contains("asimov refresh --json || true")); // v12.3.0: Self-heal JSON
     }
 
+    #[test]
+    fn test_prepush_hook_template() {
+        let builtin = prepush_hook_template(ProjectType::Rust, &[]);
+        assert!(is_generated_hook(&builtin));
+        assert!(builtin.contains("cargo test"));
+        assert!(builtin.contains("ASIMOV_HOOK_PROFILE=full"));
+
+        let gates = vec![QualityGate {
+            name: "test".to_string(),
+            command: "make it's-tested".to_string(),
+            timeout: Some(600),
+            profile: GateProfile::Full,
+        }];
+        let declared = prepush_hook_template(ProjectType::Rust, &gates);
+        assert!(declared.contains("asimov check --profile full --no-cache"));
+        assert!(declared.contains(r"run_gate 'test' full 600 'make it'\''s-tested'"));
+        assert!(!declared.contains("cargo test"));
+    }
+
+    #[test]
+    fn test_commit_msg_hook_template() {
+        let hook = commit_msg_hook_template();
+        assert!(is_generated_hook(&hook));
+        assert!(hook.contains(r#"asimov lint-commit "$1""#));
+    }
+
+    #[test]
+    fn test_ere_escape() {
+        assert_eq!(ere_escape("web"), "web");
+        assert_eq!(ere_escape("a.b+c/(d)"), r"a\.b\+c/\(d\)");
+        assert_eq!(ere_escape("x-y_z"), "x-y_z");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_package_changed_check() {
+        let nested = ["web.app", "it's", "a+b"];
+        let changed = |path: &str, staged: &str| {
+            let script = format!(
+                "staged_files=\"$1\"\nif {}; then exit 0; else exit 1; fi\n",
+                package_changed_check(path, &nested)
+            );
+            std::process::Command::new("sh")
+                .args(["-c", &script, "sh", staged])
+                .status()
+                .unwrap()
+                .success()
+        };
+
+        // Nothing staged: no package changed, root included
+        assert!(!changed(".", ""));
+        assert!(!changed("web.app", ""));
+
+        // Paths are literal: `.` and `+` are not regex operators, `'` is quoted
+        assert!(changed("web.app", "web.app/index.js"));
+        assert!(!changed("web.app", "webXapp/index.js"));
+        assert!(changed("it's", "it's/x"));
+        assert!(changed("a+b", "a+b/x"));
+        assert!(!changed("a+b", "aab/x"));
+
+        // Root owns what no nested package does
+        assert!(!changed(".", "web.app/x\nit's/y"));
+        assert!(changed(".", "web.app/x\nREADME.md"));
+        assert!(changed(".", "webXapp/x"));
+        assert!(changed(".", "README.md"));
+    }
+
     #[test]
     fn test_hook_installer_template() {
         let installer = hook_installer_template();
         assert!(installer.contains("#!/bin/bash"));
-        assert!(installer.contains(".git/hooks"));
+        assert!(installer.contains("exec asimov hooks install"));
+        assert!(installer.contains("git rev-parse --git-path hooks"));
+        assert!(installer.contains("for hook in pre-commit commit-msg pre-push"));
+        assert!(installer.contains(r#"chained="$HOOK_DIR/asimov-chained/$hook""#));
+        assert!(installer.contains(crate::git_hooks::CHAIN_SCRIPT));
     }
 
     // v10.6.0: Removed test_claude_settings_json, test_claude_session_start_hook,
@@ -569,6 +1196,13 @@ mod tests {
             ProjectType::Generic,
             ProjectType::Migration,
             ProjectType::Arch,
+            ProjectType::Java,
+            ProjectType::Kotlin,
+            ProjectType::CSharp,
+            ProjectType::Cpp,
+            ProjectType::Ruby,
+            ProjectType::Php,
+            ProjectType::Swift,
         ];
         for pt in types {
             let hook = precommit_hook_template(pt);
@@ -613,4 +1247,86 @@ mod tests {
             );
         }
     }
+
+    fn gate(name: &str, command: &str, timeout: Option<u64>, profile: GateProfile) -> QualityGate {
+        QualityGate {
+            name: name.to_string(),
+            command: command.to_string(),
+            timeout,
+            profile,
+        }
+    }
+
+    #[test]
+    fn test_quality_hook_falls_back_to_builtin() {
+        let hook = quality_precommit_hook_template(ProjectType::Rust, &[], &HookConfig::default());
+        assert_eq!(hook, precommit_hook_template(ProjectType::Rust));
+        assert!(is_generated_hook(&hook));
+    }
+
+    #[test]
+    fn test_quality_hook_renders_declared_gates() {
+        let gates = vec![
+            gate("lint", "./lint.sh --strict", None, GateProfile::Fast),
+            gate("test", "echo \"it's fine\"", Some(600), GateProfile::Full),
+        ];
+        let hook = quality_precommit_hook_template(
+            ProjectType::Rust,
+            &gates,
+            &HookConfig {
+                profile: Some(GateProfile::Fast),
+                ..Default::default()
+            },
+        );
+        assert!(hook.contains("hook_profile=\"${ASIMOV_HOOK_PROFILE:-fast}\""));
+        assert!(hook.contains("run_gate 'lint' fast 0 './lint.sh --strict' || exit 1"));
+        assert!(hook.contains(r#"run_gate 'test' full 600 'echo "it'\''s fine"' || exit 1"#));
+        // Built-in cargo snippets are replaced, dependency health is kept
+        assert!(!hook.contains("cargo test"));
+        assert!(hook.contains("DEPENDENCY HEALTH"));
+        assert!(hook.find("run_gate 'lint'").unwrap() < hook.find("run_gate 'test'").unwrap());
+    }
+
+    #[test]
+    fn test_is_generated_hook() {
+        assert!(is_generated_hook(&git_precommit_hook()));
+        assert!(!is_generated_hook("#!/bin/sh\ncargo nextest run\n"));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_quality_hook_profiles_and_timeouts_run() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let run = |gates: &[QualityGate], profile: &str| {
+            let hook = quality_precommit_hook_template(
+                ProjectType::Generic,
+                gates,
+                &HookConfig::default(),
+            );
+            std::process::Command::new("bash")
+                .arg("-c")
+                .arg(hook)
+                .current_dir(temp.path())
+                .env(HOOK_PROFILE_ENV, profile)
+                .env("PATH", "/usr/bin:/bin")
+                .output()
+                .unwrap()
+        };
+
+        let gates = vec![
+            gate("lint", "true", None, GateProfile::Fast),
+            gate("test", "exit 3", None, GateProfile::Full),
+        ];
+        assert!(run(&gates, "fast").status.success());
+        let output = run(&gates, "full");
+        assert!(!output.status.success());
+        assert!(String::from_utf8_lossy(&output.stdout).contains("❌ test failed: exit 3"));
+
+        if Path::new("/usr/bin/timeout").exists() {
+            let gates = vec![gate("test", "sleep 5", Some(1), GateProfile::Fast)];
+            let output = run(&gates, "full");
+            assert!(!output.status.success());
+            assert!(String::from_utf8_lossy(&output.stdout).contains("timed out after 1s"));
+        }
+    }
 }
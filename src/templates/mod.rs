@@ -1,6 +1,8 @@
 //! Template generators for RoyalBit Asimov files
 
+mod detect;
 mod hooks;
+pub mod packs;
 mod project;
 mod protocols;
 mod warmup;
@@ -9,6 +11,7 @@ use std::fmt;
 use std::path::Path;
 
 // Re-export all public items
+pub use detect::*;
 pub use hooks::*;
 pub use project::*;
 pub use protocols::*;
@@ -27,6 +30,24 @@ pub enum ProjectType {
     Docs,
     Migration,
     Arch,
+    // v12.4.0: JVM, .NET, native and scripting ecosystems
+    Java,
+    Kotlin,
+    CSharp,
+    Cpp,
+    Ruby,
+    Php,
+    Swift,
+}
+
+impl ProjectType {
+    /// True for programming-language projects (not docs/arch/generic/migration)
+    pub fn is_code(self) -> bool {
+        !matches!(
+            self,
+            ProjectType::Generic | ProjectType::Docs | ProjectType::Migration | ProjectType::Arch
+        )
+    }
 }
 
 impl fmt::Display for ProjectType {
@@ -41,10 +62,24 @@ impl fmt::Display for ProjectType {
             ProjectType::Docs => write!(f, "docs"),
             ProjectType::Migration => write!(f, "migration"),
             ProjectType::Arch => write!(f, "arch"),
+            ProjectType::Java => write!(f, "java"),
+            ProjectType::Kotlin => write!(f, "kotlin"),
+            ProjectType::CSharp => write!(f, "csharp"),
+            ProjectType::Cpp => write!(f, "cpp"),
+            ProjectType::Ruby => write!(f, "ruby"),
+            ProjectType::Php => write!(f, "php"),
+            ProjectType::Swift => write!(f, "swift"),
         }
     }
 }
 
+// v12.4.0: Serialized by name, same as project.yaml identity.type
+impl serde::Serialize for ProjectType {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
 impl std::str::FromStr for ProjectType {
     type Err = String;
 
@@ -59,16 +94,26 @@ impl std::str::FromStr for ProjectType {
             "docs" | "documentation" => Ok(ProjectType::Docs),
             "migration" | "migrations" => Ok(ProjectType::Migration),
             "arch" | "architecture" => Ok(ProjectType::Arch),
+            "java" | "jvm" => Ok(ProjectType::Java),
+            "kotlin" | "kt" => Ok(ProjectType::Kotlin),
+            "csharp" | "c#" | "cs" | "dotnet" | ".net" => Ok(ProjectType::CSharp),
+            "cpp" | "c++" | "cxx" | "c" => Ok(ProjectType::Cpp),
+            "ruby" | "rb" => Ok(ProjectType::Ruby),
+            "php" => Ok(ProjectType::Php),
+            "swift" => Ok(ProjectType::Swift),
             _ => Err(format!(
-                "Unknown template: '{}'. Use --help to see all 21 available templates",
+                "Unknown template: '{}'. Use --help to see all 28 available templates",
                 s
             )),
         }
     }
 }
 
-/// Detect project type from marker files in the given directory (ADR-032)
-/// Returns the detected project type or Generic if no markers found
+/// Detect project type in the given directory (ADR-032)
+/// from the first marker file found, in priority order.
+/// Cheap (no directory walk): doctor, refresh, hooks and file size checks
+/// call it on every run. `asimov detect` and the init/refresh prompt use
+/// the ranked `detect_project_candidates` instead (v12.4.0).
 pub fn detect_project_type(dir: &Path) -> ProjectType {
     // Check for marker files in priority order
     // Flutter/Dart before Node (pubspec.yaml is more specific)
@@ -84,9 +129,35 @@ pub fn detect_project_type(dir: &Path) -> ProjectType {
     if dir.join("pyproject.toml").exists() || dir.join("setup.py").exists() {
         return ProjectType::Python;
     }
+    // v12.4.0: Build manifests before package.json (Rails/Laravel/Spring ship one too)
+    if is_kotlin_project(dir) {
+        return ProjectType::Kotlin;
+    }
+    if dir.join("pom.xml").exists()
+        || dir.join("build.gradle").exists()
+        || dir.join("build.gradle.kts").exists()
+    {
+        return ProjectType::Java;
+    }
+    if has_file_with_extension(dir, &["csproj", "sln"]) {
+        return ProjectType::CSharp;
+    }
+    if dir.join("Package.swift").exists() {
+        return ProjectType::Swift;
+    }
+    if dir.join("Gemfile").exists() {
+        return ProjectType::Ruby;
+    }
+    if dir.join("composer.json").exists() {
+        return ProjectType::Php;
+    }
     if dir.join("package.json").exists() {
         return ProjectType::Node;
     }
+    // C/C++ after Node: node-gyp addons ship a CMakeLists.txt alongside package.json
+    if dir.join("CMakeLists.txt").exists() || dir.join("meson.build").exists() {
+        return ProjectType::Cpp;
+    }
     // Check for arch project (ADR-041)
     // c4-models/ OR decisions/ OR (diagrams/ AND ARCHITECTURE*.md)
     if dir.join("c4-models").is_dir() || dir.join("decisions").is_dir() {
@@ -109,6 +180,32 @@ pub fn detect_project_type(dir: &Path) -> ProjectType {
     ProjectType::Generic
 }
 
+/// Gradle project using Kotlin sources or the Kotlin plugin
+fn is_kotlin_project(dir: &Path) -> bool {
+    if dir.join("src/main/kotlin").is_dir() {
+        return true;
+    }
+    ["build.gradle.kts", "build.gradle"].iter().any(|name| {
+        std::fs::read_to_string(dir.join(name))
+            .map(|content| content.contains("kotlin(") || content.contains("org.jetbrains.kotlin"))
+            .unwrap_or(false)
+    })
+}
+
+/// Check if directory has a file with any of the given extensions (top level only)
+fn has_file_with_extension(dir: &Path, extensions: &[&str]) -> bool {
+    std::fs::read_dir(dir)
+        .map(|entries| {
+            entries.flatten().any(|entry| {
+                entry
+                    .path()
+                    .extension()
+                    .is_some_and(|ext| extensions.iter().any(|e| ext == *e))
+            })
+        })
+        .unwrap_or(false)
+}
+
 /// Check if directory has an ARCHITECTURE*.md file
 fn has_architecture_file(dir: &Path) -> bool {
     if let Ok(entries) = std::fs::read_dir(dir) {
@@ -225,6 +322,15 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_detect_project_type_ignores_weak_evidence() {
+        // Only the ranked candidates count lock files and source extensions
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        std::fs::write(temp_dir.path().join("requirements.txt"), "requests").unwrap();
+        std::fs::write(temp_dir.path().join("main.py"), "print()").unwrap();
+        assert_eq!(detect_project_type(temp_dir.path()), ProjectType::Generic);
+    }
+
     #[test]
     fn test_detect_project_type_python() {
         let temp_dir = tempfile::TempDir::new().unwrap();
@@ -379,6 +485,76 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_detect_project_type_new_ecosystems() {
+        let cases = [
+            ("pom.xml", "<project/>", ProjectType::Java),
+            ("build.gradle", "plugins { id 'java' }", ProjectType::Java),
+            (
+                "build.gradle.kts",
+                "plugins { kotlin(\"jvm\") }",
+                ProjectType::Kotlin,
+            ),
+            ("App.csproj", "<Project/>", ProjectType::CSharp),
+            ("App.sln", "", ProjectType::CSharp),
+            ("CMakeLists.txt", "project(app)", ProjectType::Cpp),
+            (
+                "Gemfile",
+                "source 'https://rubygems.org'",
+                ProjectType::Ruby,
+            ),
+            ("composer.json", "{}", ProjectType::Php),
+            (
+                "Package.swift",
+                "// swift-tools-version:5.9",
+                ProjectType::Swift,
+            ),
+        ];
+        for (marker, content, expected) in cases {
+            let temp_dir = tempfile::TempDir::new().unwrap();
+            std::fs::write(temp_dir.path().join(marker), content).unwrap();
+            assert_eq!(
+                detect_project_type(temp_dir.path()),
+                expected,
+                "marker: {}",
+                marker
+            );
+        }
+    }
+
+    #[test]
+    fn test_detect_project_type_manifest_before_package_json() {
+        // Rails and Laravel apps ship a package.json for assets
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        std::fs::write(temp_dir.path().join("Gemfile"), "").unwrap();
+        std::fs::write(temp_dir.path().join("package.json"), "{}").unwrap();
+        assert_eq!(detect_project_type(temp_dir.path()), ProjectType::Ruby);
+
+        // node-gyp addons ship a CMakeLists.txt
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        std::fs::write(temp_dir.path().join("CMakeLists.txt"), "").unwrap();
+        std::fs::write(temp_dir.path().join("package.json"), "{}").unwrap();
+        assert_eq!(detect_project_type(temp_dir.path()), ProjectType::Node);
+    }
+
+    #[test]
+    fn test_project_type_new_ecosystems_roundtrip() {
+        for pt in [
+            ProjectType::Java,
+            ProjectType::Kotlin,
+            ProjectType::CSharp,
+            ProjectType::Cpp,
+            ProjectType::Ruby,
+            ProjectType::Php,
+            ProjectType::Swift,
+        ] {
+            assert_eq!(pt.to_string().parse::<ProjectType>(), Ok(pt));
+        }
+        assert_eq!("c#".parse::<ProjectType>(), Ok(ProjectType::CSharp));
+        assert_eq!("c++".parse::<ProjectType>(), Ok(ProjectType::Cpp));
+        assert_eq!("rb".parse::<ProjectType>(), Ok(ProjectType::Ruby));
+    }
+
     #[test]
     fn test_has_architecture_file_true() {
         let temp_dir = tempfile::TempDir::new().unwrap();
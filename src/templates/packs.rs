@@ -0,0 +1,333 @@
+//! Installable template packs (v12.4.0)
+//!
+//! A template pack is a directory (or `.tar.gz` of one) containing:
+//!
+//! ```text
+//! my-pack/
+//!   manifest.yaml   # name, version, description, base
+//!   template.yaml   # project.yaml template ({PROJECT_NAME}, {PROJECT_TAGLINE})
+//! ```
+//!
+//! Packs are installed into the user-level templates directory so that
+//! `asimov init -t <name>` finds them from any directory, without network access.
+
+use super::ProjectType;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+/// Manifest file name inside a pack
+pub const PACK_MANIFEST: &str = "manifest.yaml";
+
+/// Template file name inside a pack
+pub const PACK_TEMPLATE: &str = "template.yaml";
+
+/// Template pack manifest
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TemplateManifest {
+    pub name: String,
+    pub version: String,
+    #[serde(default)]
+    pub description: String,
+    /// Base project type used for hooks and dev dependencies
+    pub base: String,
+}
+
+impl TemplateManifest {
+    /// Base `ProjectType` of the pack (Generic if unrecognized)
+    pub fn project_type(&self) -> ProjectType {
+        self.base.parse().unwrap_or_default()
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        let valid_name = !self.name.is_empty()
+            && self
+                .name
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-' || c == '_');
+        if !valid_name {
+            return Err(format!(
+                "Invalid pack name '{}': use lowercase letters, digits, '-' or '_'",
+                self.name
+            ));
+        }
+        if self.version.trim().is_empty() {
+            return Err("Pack manifest is missing 'version'".to_string());
+        }
+        self.base
+            .parse::<ProjectType>()
+            .map_err(|_| format!("Unknown base project type '{}'", self.base))?;
+        Ok(())
+    }
+}
+
+/// Read and validate a pack manifest from a pack directory
+pub fn read_manifest(pack_dir: &Path) -> Result<TemplateManifest, String> {
+    let path = pack_dir.join(PACK_MANIFEST);
+    let content = std::fs::read_to_string(&path)
+        .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+    let manifest: TemplateManifest = serde_yaml_ng::from_str(&content)
+        .map_err(|e| format!("Invalid {}: {}", PACK_MANIFEST, e))?;
+    manifest.validate()?;
+    Ok(manifest)
+}
+
+/// Install a pack from a directory or `.tar.gz` into `templates_root`
+///
+/// Returns the installed manifest. Refuses to overwrite an installed pack
+/// with the same name unless `force` is set.
+pub fn install_pack(
+    source: &Path,
+    templates_root: &Path,
+    force: bool,
+) -> Result<TemplateManifest, String> {
+    if source.is_dir() {
+        return install_from_dir(source, templates_root, force);
+    }
+
+    let name = source
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_default();
+    if !source.is_file() {
+        return Err(format!("Template pack not found: {}", source.display()));
+    }
+    if !(name.ends_with(".tar.gz") || name.ends_with(".tgz")) {
+        return Err(format!(
+            "Unsupported pack format: {} (expected a directory or .tar.gz)",
+            name
+        ));
+    }
+
+    // Private (0700), unpredictable, and removed on drop
+    let staging = tempfile::Builder::new()
+        .prefix("asimov-pack-")
+        .tempdir()
+        .map_err(|e| format!("Failed to create staging directory: {}", e))?;
+
+    crate::update::extract_archive(source, staging.path()).map_err(|e| e.to_string())?;
+    let root = find_pack_root(staging.path())?;
+    install_from_dir(&root, templates_root, force)
+}
+
+/// Locate the pack root inside an extracted archive (root or single top-level dir)
+fn find_pack_root(extracted: &Path) -> Result<PathBuf, String> {
+    if extracted.join(PACK_MANIFEST).exists() {
+        return Ok(extracted.to_path_buf());
+    }
+    let entries = std::fs::read_dir(extracted)
+        .map_err(|e| format!("Failed to read archive contents: {}", e))?;
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.is_dir() && path.join(PACK_MANIFEST).exists() {
+            return Ok(path);
+        }
+    }
+    Err(format!("Archive does not contain {}", PACK_MANIFEST))
+}
+
+fn install_from_dir(
+    pack_dir: &Path,
+    templates_root: &Path,
+    force: bool,
+) -> Result<TemplateManifest, String> {
+    let manifest = read_manifest(pack_dir)?;
+
+    let template_src = pack_dir.join(PACK_TEMPLATE);
+    let template = std::fs::read_to_string(&template_src)
+        .map_err(|e| format!("Failed to read {}: {}", template_src.display(), e))?;
+    serde_yaml_ng::from_str::<serde_yaml_ng::Value>(&template)
+        .map_err(|e| format!("Invalid {}: {}", PACK_TEMPLATE, e))?;
+
+    let dest = templates_root.join(&manifest.name);
+    if dest.exists() {
+        if !force {
+            let installed = read_manifest(&dest)
+                .map(|m| format!(" (v{})", m.version))
+                .unwrap_or_default();
+            return Err(format!(
+                "Template pack '{}'{} is already installed. Use --force to replace it",
+                manifest.name, installed
+            ));
+        }
+        std::fs::remove_dir_all(&dest)
+            .map_err(|e| format!("Failed to remove existing pack: {}", e))?;
+    }
+
+    std::fs::create_dir_all(&dest)
+        .map_err(|e| format!("Failed to create {}: {}", dest.display(), e))?;
+    std::fs::copy(pack_dir.join(PACK_MANIFEST), dest.join(PACK_MANIFEST))
+        .map_err(|e| format!("Failed to install {}: {}", PACK_MANIFEST, e))?;
+    std::fs::write(dest.join(PACK_TEMPLATE), template)
+        .map_err(|e| format!("Failed to install {}: {}", PACK_TEMPLATE, e))?;
+
+    Ok(manifest)
+}
+
+/// List installed packs in `templates_root`, sorted by name
+pub fn list_packs(templates_root: &Path) -> Vec<TemplateManifest> {
+    let mut packs: Vec<TemplateManifest> = std::fs::read_dir(templates_root)
+        .map(|entries| {
+            entries
+                .flatten()
+                .filter(|e| e.path().is_dir())
+                .filter_map(|e| read_manifest(&e.path()).ok())
+                .collect()
+        })
+        .unwrap_or_default();
+    packs.sort_by(|a, b| a.name.cmp(&b.name));
+    packs
+}
+
+/// Find an installed pack by name
+pub fn find_pack(templates_root: &Path, name: &str) -> Option<TemplateManifest> {
+    read_manifest(&templates_root.join(name))
+        .ok()
+        .filter(|m| m.name == name)
+}
+
+/// Read the template of an installed pack
+pub fn read_pack_template(templates_root: &Path, name: &str) -> Option<String> {
+    find_pack(templates_root, name)?;
+    std::fs::read_to_string(templates_root.join(name).join(PACK_TEMPLATE)).ok()
+}
+
+/// Remove an installed pack, returning its manifest
+pub fn remove_pack(templates_root: &Path, name: &str) -> Result<TemplateManifest, String> {
+    let manifest = find_pack(templates_root, name)
+        .ok_or_else(|| format!("Template pack '{}' is not installed", name))?;
+    std::fs::remove_dir_all(templates_root.join(&manifest.name))
+        .map_err(|e| format!("Failed to remove pack '{}': {}", name, e))?;
+    Ok(manifest)
+}
+
+/// Find an installed pack in the user-level templates directory
+pub fn find_user_pack(name: &str) -> Option<TemplateManifest> {
+    crate::paths::user_templates_dir().and_then(|root| find_pack(&root, name))
+}
+
+/// List packs installed in the user-level templates directory
+pub fn list_user_packs() -> Vec<TemplateManifest> {
+    crate::paths::user_templates_dir()
+        .map(|root| list_packs(&root))
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn write_pack(dir: &Path, name: &str, base: &str) {
+        std::fs::create_dir_all(dir).unwrap();
+        std::fs::write(
+            dir.join(PACK_MANIFEST),
+            format!(
+                "name: {}\nversion: \"1.2.0\"\ndescription: Company template\nbase: {}\n",
+                name, base
+            ),
+        )
+        .unwrap();
+        std::fs::write(
+            dir.join(PACK_TEMPLATE),
+            "identity:\n  name: \"{PROJECT_NAME}\"\n  type: rust\n",
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_install_from_directory() {
+        let temp = TempDir::new().unwrap();
+        let src = temp.path().join("src-pack");
+        let root = temp.path().join("installed");
+        write_pack(&src, "acme-svc", "rust");
+
+        let manifest = install_pack(&src, &root, false).unwrap();
+        assert_eq!(manifest.name, "acme-svc");
+        assert_eq!(manifest.project_type(), ProjectType::Rust);
+        assert!(root.join("acme-svc").join(PACK_TEMPLATE).exists());
+        assert!(read_pack_template(&root, "acme-svc")
+            .unwrap()
+            .contains("{PROJECT_NAME}"));
+    }
+
+    #[test]
+    fn test_install_refuses_overwrite_without_force() {
+        let temp = TempDir::new().unwrap();
+        let src = temp.path().join("src-pack");
+        let root = temp.path().join("installed");
+        write_pack(&src, "acme-svc", "rust");
+
+        install_pack(&src, &root, false).unwrap();
+        let err = install_pack(&src, &root, false).unwrap_err();
+        assert!(err.contains("already installed"));
+        assert!(install_pack(&src, &root, true).is_ok());
+    }
+
+    #[test]
+    fn test_install_from_tarball() {
+        let temp = TempDir::new().unwrap();
+        let src = temp.path().join("acme-web");
+        let root = temp.path().join("installed");
+        write_pack(&src, "acme-web", "node");
+
+        let archive = temp.path().join("acme-web.tar.gz");
+        let status = std::process::Command::new("tar")
+            .args(["-czf", archive.to_str().unwrap(), "-C"])
+            .arg(temp.path())
+            .arg("acme-web")
+            .status();
+        if !status.map(|s| s.success()).unwrap_or(false) {
+            return; // tar not available on this platform
+        }
+
+        let manifest = install_pack(&archive, &root, false).unwrap();
+        assert_eq!(manifest.project_type(), ProjectType::Node);
+        assert!(find_pack(&root, "acme-web").is_some());
+    }
+
+    #[test]
+    fn test_install_rejects_invalid_manifest() {
+        let temp = TempDir::new().unwrap();
+        let src = temp.path().join("bad");
+        write_pack(&src, "Bad Name", "rust");
+        let err = install_pack(&src, &temp.path().join("installed"), false).unwrap_err();
+        assert!(err.contains("Invalid pack name"));
+
+        write_pack(&src, "bad", "cobol");
+        let err = install_pack(&src, &temp.path().join("installed"), false).unwrap_err();
+        assert!(err.contains("Unknown base project type"));
+    }
+
+    #[test]
+    fn test_install_rejects_unsupported_file() {
+        let temp = TempDir::new().unwrap();
+        let file = temp.path().join("pack.zip");
+        std::fs::write(&file, "zip").unwrap();
+        let err = install_pack(&file, temp.path(), false).unwrap_err();
+        assert!(err.contains("Unsupported pack format"));
+    }
+
+    #[test]
+    fn test_list_and_remove_packs() {
+        let temp = TempDir::new().unwrap();
+        let root = temp.path().join("installed");
+        write_pack(&temp.path().join("b"), "zeta", "go");
+        write_pack(&temp.path().join("a"), "alpha", "python");
+        install_pack(&temp.path().join("b"), &root, false).unwrap();
+        install_pack(&temp.path().join("a"), &root, false).unwrap();
+
+        let names: Vec<String> = list_packs(&root).into_iter().map(|m| m.name).collect();
+        assert_eq!(names, vec!["alpha", "zeta"]);
+
+        let removed = remove_pack(&root, "alpha").unwrap();
+        assert_eq!(removed.name, "alpha");
+        assert!(find_pack(&root, "alpha").is_none());
+        assert!(remove_pack(&root, "alpha").is_err());
+    }
+
+    #[test]
+    fn test_list_packs_missing_root() {
+        let temp = TempDir::new().unwrap();
+        assert!(list_packs(&temp.path().join("nope")).is_empty());
+    }
+}
@@ -17,6 +17,14 @@ const TEMPLATE_FLUTTER: &str = include_str!("../../templates/flutter.yaml");
 const TEMPLATE_DOCS: &str = include_str!("../../templates/docs.yaml");
 const TEMPLATE_GENERIC: &str = include_str!("../../templates/generic.yaml");
 const TEMPLATE_ARCH: &str = include_str!("../../templates/arch.yaml");
+// v12.4.0: JVM, .NET, native and scripting ecosystems
+const TEMPLATE_JAVA: &str = include_str!("../../templates/java.yaml");
+const TEMPLATE_KOTLIN: &str = include_str!("../../templates/kotlin.yaml");
+const TEMPLATE_CSHARP: &str = include_str!("../../templates/csharp.yaml");
+const TEMPLATE_CPP: &str = include_str!("../../templates/cpp.yaml");
+const TEMPLATE_RUBY: &str = include_str!("../../templates/ruby.yaml");
+const TEMPLATE_PHP: &str = include_str!("../../templates/php.yaml");
+const TEMPLATE_SWIFT: &str = include_str!("../../templates/swift.yaml");
 
 // API templates
 const TEMPLATE_API_RUST: &str = include_str!("../../templates/api-rust.yaml");
@@ -60,6 +68,13 @@ fn get_template(project_type: ProjectType) -> String {
         ProjectType::Flutter => "flutter",
         ProjectType::Docs => "docs",
         ProjectType::Arch => "arch",
+        ProjectType::Java => "java",
+        ProjectType::Kotlin => "kotlin",
+        ProjectType::CSharp => "csharp",
+        ProjectType::Cpp => "cpp",
+        ProjectType::Ruby => "ruby",
+        ProjectType::Php => "php",
+        ProjectType::Swift => "swift",
         ProjectType::Generic | ProjectType::Migration => "generic",
     };
 
@@ -77,6 +92,13 @@ fn get_template(project_type: ProjectType) -> String {
         ProjectType::Flutter => TEMPLATE_FLUTTER.to_string(),
         ProjectType::Docs => TEMPLATE_DOCS.to_string(),
         ProjectType::Arch => TEMPLATE_ARCH.to_string(),
+        ProjectType::Java => TEMPLATE_JAVA.to_string(),
+        ProjectType::Kotlin => TEMPLATE_KOTLIN.to_string(),
+        ProjectType::CSharp => TEMPLATE_CSHARP.to_string(),
+        ProjectType::Cpp => TEMPLATE_CPP.to_string(),
+        ProjectType::Ruby => TEMPLATE_RUBY.to_string(),
+        ProjectType::Php => TEMPLATE_PHP.to_string(),
+        ProjectType::Swift => TEMPLATE_SWIFT.to_string(),
         ProjectType::Generic | ProjectType::Migration => TEMPLATE_GENERIC.to_string(),
     }
 }
@@ -100,12 +122,20 @@ const EXTENDED_TEMPLATES: &[&str] = &[
 
 /// Get any template by name (tries external first, falls back to embedded)
 /// v10.3.1: Unified template lookup for all template types
+/// v12.4.0: Project-local > installed pack > embedded
 pub fn get_template_by_name(name: &str) -> Option<String> {
     // Try external file first
     if let Some(content) = try_read_template(name) {
         return Some(content);
     }
 
+    // Then user-installed template packs
+    if let Some(content) = crate::paths::user_templates_dir()
+        .and_then(|root| super::packs::read_pack_template(&root, name))
+    {
+        return Some(content);
+    }
+
     // Fall back to embedded template
     match name {
         // Base project templates
@@ -117,6 +147,13 @@ pub fn get_template_by_name(name: &str) -> Option<String> {
         "docs" => Some(TEMPLATE_DOCS.to_string()),
         "arch" => Some(TEMPLATE_ARCH.to_string()),
         "generic" => Some(TEMPLATE_GENERIC.to_string()),
+        "java" => Some(TEMPLATE_JAVA.to_string()),
+        "kotlin" => Some(TEMPLATE_KOTLIN.to_string()),
+        "csharp" => Some(TEMPLATE_CSHARP.to_string()),
+        "cpp" => Some(TEMPLATE_CPP.to_string()),
+        "ruby" => Some(TEMPLATE_RUBY.to_string()),
+        "php" => Some(TEMPLATE_PHP.to_string()),
+        "swift" => Some(TEMPLATE_SWIFT.to_string()),
         // API templates
         "api-rust" => Some(TEMPLATE_API_RUST.to_string()),
         "api-go" => Some(TEMPLATE_API_GO.to_string()),
@@ -147,7 +184,8 @@ pub fn get_enterprise_template(name: &str) -> Option<String> {
 /// List all available templates (external + embedded)
 pub fn list_templates() -> Vec<String> {
     let mut templates = vec![
-        "rust", "python", "node", "go", "flutter", "docs", "arch", "generic",
+        "rust", "python", "node", "go", "flutter", "docs", "arch", "generic", "java", "kotlin",
+        "csharp", "cpp", "ruby", "php", "swift",
     ]
     .into_iter()
     .map(|s| s.to_string())
@@ -156,6 +194,13 @@ pub fn list_templates() -> Vec<String> {
     // Add extended templates (api-*, web-*, mono-*, admin-dashboard)
     templates.extend(EXTENDED_TEMPLATES.iter().map(|s| s.to_string()));
 
+    // v12.4.0: Add installed template packs
+    for pack in super::packs::list_user_packs() {
+        if !templates.contains(&pack.name) {
+            templates.push(pack.name);
+        }
+    }
+
     // Add custom templates from .asimov/templates/ if they exist
     if let Ok(entries) = std::fs::read_dir(templates_dir()) {
         for entry in entries.flatten() {
@@ -230,6 +275,62 @@ flutter test                  # Run tests
 dart analyze lib/             # Analyze (must pass)
 dart format lib/ test/        # Format code
 flutter build apk             # Build Android
+```"#
+        }
+        ProjectType::Java => {
+            r#"```bash
+./mvnw test                   # Run tests (or ./gradlew test)
+./mvnw checkstyle:check       # Lint
+./mvnw spotless:apply         # Format code
+./mvnw package                # Build
+```"#
+        }
+        ProjectType::Kotlin => {
+            r#"```bash
+./gradlew test                # Run tests
+./gradlew ktlintCheck         # Lint (must pass)
+ktlint --format               # Format code
+./gradlew build               # Build
+```"#
+        }
+        ProjectType::CSharp => {
+            r#"```bash
+dotnet test                   # Run tests
+dotnet build -warnaserror     # Build, warnings as errors
+dotnet format                 # Format code
+dotnet build -c Release       # Release build
+```"#
+        }
+        ProjectType::Cpp => {
+            r#"```bash
+cmake -S . -B build           # Configure
+cmake --build build           # Build
+ctest --test-dir build        # Run tests
+clang-format -i src/*.cpp     # Format code
+```"#
+        }
+        ProjectType::Ruby => {
+            r#"```bash
+bundle exec rspec             # Run tests
+bundle exec rubocop           # Lint (must pass)
+bundle exec rubocop -a        # Autocorrect
+bundle install                # Install dependencies
+```"#
+        }
+        ProjectType::Php => {
+            r#"```bash
+vendor/bin/phpunit            # Run tests
+vendor/bin/phpstan analyse    # Static analysis (must pass)
+vendor/bin/php-cs-fixer fix   # Format code
+composer install              # Install dependencies
+```"#
+        }
+        ProjectType::Swift => {
+            r#"```bash
+swift test                    # Run tests
+swiftlint                     # Lint (must pass)
+swift-format -i -r Sources    # Format code
+swift build -c release        # Release build
 ```"#
         }
         ProjectType::Docs => {
@@ -305,6 +406,13 @@ mod tests {
             ProjectType::Generic,
             ProjectType::Migration,
             ProjectType::Arch,
+            ProjectType::Java,
+            ProjectType::Kotlin,
+            ProjectType::CSharp,
+            ProjectType::Cpp,
+            ProjectType::Ruby,
+            ProjectType::Php,
+            ProjectType::Swift,
         ];
         for pt in types {
             let template = project_template("test", "tagline", pt);
@@ -331,6 +439,13 @@ mod tests {
             ProjectType::Generic,
             ProjectType::Migration,
             ProjectType::Arch,
+            ProjectType::Java,
+            ProjectType::Kotlin,
+            ProjectType::CSharp,
+            ProjectType::Cpp,
+            ProjectType::Ruby,
+            ProjectType::Php,
+            ProjectType::Swift,
         ];
         for pt in types {
             let template = claude_md_template("test", pt);
@@ -364,6 +479,25 @@ mod tests {
         assert!(templates.contains(&"admin-dashboard".to_string()));
     }
 
+    #[test]
+    fn test_new_base_templates_match_schema_type() {
+        for pt in [
+            ProjectType::Java,
+            ProjectType::Kotlin,
+            ProjectType::CSharp,
+            ProjectType::Cpp,
+            ProjectType::Ruby,
+            ProjectType::Php,
+            ProjectType::Swift,
+        ] {
+            let template = project_template("test", "tagline", pt);
+            let yaml: serde_yaml_ng::Value = serde_yaml_ng::from_str(&template).unwrap();
+            let declared = yaml["identity"]["type"].as_str().unwrap();
+            assert_eq!(declared, pt.to_string());
+            assert!(yaml["quality"]["test"].as_str().is_some());
+        }
+    }
+
     #[test]
     fn test_get_template_by_name_unknown() {
         assert!(get_template_by_name("nonexistent").is_none());
@@ -5,7 +5,16 @@ use super::ProjectType;
 /// Generate a starter warmup.yaml template
 pub fn warmup_template(project_name: &str, project_type: ProjectType) -> String {
     match project_type {
-        ProjectType::Generic | ProjectType::Migration => warmup_generic(project_name),
+        // v12.4.0: Newer ecosystems use project.yaml quality commands, not warmup.yaml
+        ProjectType::Generic
+        | ProjectType::Migration
+        | ProjectType::Java
+        | ProjectType::Kotlin
+        | ProjectType::CSharp
+        | ProjectType::Cpp
+        | ProjectType::Ruby
+        | ProjectType::Php
+        | ProjectType::Swift => warmup_generic(project_name),
         ProjectType::Rust => warmup_rust(project_name),
         ProjectType::Python => warmup_python(project_name),
         ProjectType::Node => warmup_node(project_name),
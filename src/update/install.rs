@@ -0,0 +1,872 @@
+//! Downloading, verifying and installing a release (v12.4.0)
+//!
+//! Everything runs in-process: HTTP through the offline-aware client in
+//! the parent module, SHA-256, minisign signatures and tar.gz/zip
+//! extraction. The archive is staged in a private temp directory and read
+//! into memory once, so the bytes that were verified are the ones extracted.
+
+use super::{http_get, UpdateError, RELEASE_PUBLIC_KEY, SIGNATURE_SUFFIX};
+use sha2::{Digest, Sha256};
+use std::env;
+use std::fs;
+use std::io::{self, Read, Seek};
+use std::path::{Path, PathBuf};
+
+/// Parse checksums file and find the expected checksum for an asset
+pub fn parse_checksums(checksums_content: &str, asset_name: &str) -> Option<String> {
+    checksums_content
+        .lines()
+        .find(|line| line.contains(asset_name))
+        .and_then(|line| line.split_whitespace().next())
+        .map(|s| s.to_string())
+}
+
+/// Calculate SHA256 checksum of a file
+pub fn calculate_checksum(file_path: &Path) -> Result<String, UpdateError> {
+    let mut file = fs::File::open(file_path).map_err(UpdateError::io(format!(
+        "Failed to open {}",
+        file_path.display()
+    )))?;
+    let mut hasher = Sha256::new();
+    io::copy(&mut file, &mut hasher).map_err(UpdateError::io(format!(
+        "Failed to read {}",
+        file_path.display()
+    )))?;
+    Ok(format!("{:x}", hasher.finalize()))
+}
+
+/// Verify checksum matches expected
+pub fn verify_checksum_match(expected: &str, actual: &str) -> Result<(), UpdateError> {
+    if !actual.eq_ignore_ascii_case(expected) {
+        Err(UpdateError::ChecksumMismatch {
+            expected: expected.to_string(),
+            actual: actual.to_string(),
+        })
+    } else {
+        Ok(())
+    }
+}
+
+/// Download and install the update with optional checksum verification (v8.4.0)
+///
+/// v12.4.0: The archive must carry a valid signature from
+/// `RELEASE_PUBLIC_KEY`; `insecure` skips that check. Without a compiled-in
+/// key nothing is downloaded unless `insecure` is set.
+#[cfg_attr(feature = "coverage", coverage(off))]
+pub fn perform_update(
+    download_url: &str,
+    checksums_url: Option<&str>,
+    insecure: bool,
+) -> Result<(), UpdateError> {
+    let current_exe = env::current_exe().map_err(UpdateError::io(
+        "Could not determine current executable path",
+    ))?;
+
+    let public_key = match (insecure, RELEASE_PUBLIC_KEY) {
+        (true, _) => None,
+        (false, Some(key)) => Some(key),
+        (false, None) => return Err(UpdateError::NoReleaseKey),
+    };
+
+    println!("  Downloading update...");
+
+    // Download into a new directory only this user can read, removed on drop
+    let staging = tempfile::Builder::new()
+        .prefix("asimov-update-")
+        .tempdir()
+        .map_err(UpdateError::io("Failed to create a staging directory"))?;
+    let result = install_from(
+        download_url,
+        checksums_url,
+        public_key,
+        staging.path(),
+        &current_exe,
+    );
+    drop(staging);
+    result
+}
+
+#[cfg_attr(feature = "coverage", coverage(off))]
+fn install_from(
+    download_url: &str,
+    checksums_url: Option<&str>,
+    public_key: Option<&str>,
+    staging: &Path,
+    current_exe: &Path,
+) -> Result<(), UpdateError> {
+    let asset_name = download_url
+        .rsplit('/')
+        .next()
+        .filter(|name| !name.is_empty())
+        .unwrap_or("asimov_update.tar.gz");
+    let archive = staging.join(asset_name);
+
+    download_file(download_url, &archive)?;
+    // Read once: the checksum, the signature and extraction all see these bytes
+    let data = fs::read(&archive).map_err(UpdateError::io(format!(
+        "Failed to read {}",
+        archive.display()
+    )))?;
+
+    // Verify checksum if available (v8.4.0)
+    if let Some(checksums_url) = checksums_url {
+        println!("  Verifying checksum...");
+        verify_checksum(&data, checksums_url, asset_name)?;
+    }
+
+    match public_key {
+        Some(public_key) => {
+            println!("  Verifying signature...");
+            let signature_url = format!("{}{}", download_url, SIGNATURE_SUFFIX);
+            let signature = download_signature(&signature_url)?;
+            verify_signature(&data, asset_name, &signature, public_key)?;
+        }
+        None => println!("  Skipping signature verification (--insecure)"),
+    }
+
+    println!("  Extracting...");
+
+    let extract_dir = staging.join("extract");
+    unpack(io::Cursor::new(data), &archive, &extract_dir)?;
+
+    let binary = extract_dir.join(format!("asimov{}", env::consts::EXE_SUFFIX));
+    if !binary.is_file() {
+        return Err(UpdateError::BinaryNotFound(binary));
+    }
+
+    println!("  Installing...");
+
+    replace_binary(&binary, current_exe)
+}
+
+/// Download a file from URL to local path, following redirects
+///
+/// Fails with `Truncated` when the body is shorter than Content-Length;
+/// a partial file is removed.
+#[cfg_attr(feature = "coverage", coverage(off))]
+pub fn download_file(url: &str, dest: &Path) -> Result<(), UpdateError> {
+    let mut response = http_get(url)?;
+    let expected = response.content_length();
+
+    let mut file = fs::File::create(dest).map_err(UpdateError::io(format!(
+        "Failed to create {}",
+        dest.display()
+    )))?;
+    let copied = io::copy(&mut response, &mut file);
+    let written = file.metadata().map(|m| m.len()).unwrap_or(0);
+    drop(file);
+
+    let result = match (copied, expected) {
+        (Ok(_), Some(expected)) if written != expected => Err(UpdateError::Truncated {
+            expected,
+            actual: written,
+        }),
+        (Ok(_), _) => Ok(()),
+        // The connection closed early: report it against the announced length
+        (Err(_), Some(expected)) if written < expected => Err(UpdateError::Truncated {
+            expected,
+            actual: written,
+        }),
+        (Err(e), _) => Err(UpdateError::io(format!("Failed to download {}", url))(e)),
+    };
+    if result.is_err() {
+        let _ = fs::remove_file(dest);
+    }
+    result
+}
+
+/// Extract a `.zip` or `.tar.gz` archive into a directory (created if missing)
+///
+/// Entries that would land outside `dest_dir` are rejected.
+pub fn extract_archive(archive: &Path, dest_dir: &Path) -> Result<(), UpdateError> {
+    let file = fs::File::open(archive).map_err(UpdateError::io(format!(
+        "Failed to open {}",
+        archive.display()
+    )))?;
+    unpack(file, archive, dest_dir)
+}
+
+/// `extract_archive` over an open reader; `archive` names the format and errors
+fn unpack<R: Read + Seek>(file: R, archive: &Path, dest_dir: &Path) -> Result<(), UpdateError> {
+    let archive_error = |reason: String| UpdateError::Archive {
+        path: archive.to_path_buf(),
+        reason,
+    };
+    fs::create_dir_all(dest_dir).map_err(UpdateError::io(format!(
+        "Failed to create {}",
+        dest_dir.display()
+    )))?;
+
+    let is_zip = archive
+        .extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("zip"));
+    if is_zip {
+        zip::ZipArchive::new(file)
+            .and_then(|mut zip| zip.extract(dest_dir))
+            .map_err(|e| archive_error(e.to_string()))
+    } else {
+        let mut tar = tar::Archive::new(flate2::read::GzDecoder::new(file));
+        tar.set_preserve_permissions(true);
+        tar.unpack(dest_dir)
+            .map_err(|e| archive_error(e.to_string()))
+    }
+}
+
+/// Replace the current binary with a new one
+#[cfg_attr(feature = "coverage", coverage(off))]
+///
+/// v12.4.0: The replaced binary is kept at `backup_path` for `--rollback`.
+pub fn replace_binary(new_binary: &Path, current_exe: &Path) -> Result<(), UpdateError> {
+    let backup_path = backup_path(current_exe);
+
+    // Remove old backup if exists
+    let _ = fs::remove_file(&backup_path);
+
+    // Rename current to backup
+    fs::rename(current_exe, &backup_path)
+        .map_err(UpdateError::io("Failed to backup current binary"))?;
+
+    // Copy new binary to current location
+    if let Err(e) = fs::copy(new_binary, current_exe) {
+        // Put the old binary back rather than leave nothing installed
+        let _ = fs::rename(&backup_path, current_exe);
+        return Err(UpdateError::io("Failed to install new binary")(e));
+    }
+
+    // Set executable permissions on Unix
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        fs::set_permissions(current_exe, fs::Permissions::from_mode(0o755))
+            .map_err(UpdateError::io("Failed to set permissions"))?;
+    }
+
+    Ok(())
+}
+
+/// Where `replace_binary` keeps the previous executable (`asimov.old`)
+pub fn backup_path(current_exe: &Path) -> PathBuf {
+    current_exe.with_extension("old")
+}
+
+/// Swap the executable with its backup (v12.4.0)
+///
+/// The binary being rolled back becomes the new backup, so a second
+/// rollback undoes the first.
+pub fn rollback_binary(current_exe: &Path) -> Result<(), UpdateError> {
+    let backup = backup_path(current_exe);
+    if !backup.is_file() {
+        return Err(UpdateError::NoBackup(backup));
+    }
+    let swap = current_exe.with_extension("rollback");
+    let _ = fs::remove_file(&swap);
+
+    fs::rename(current_exe, &swap).map_err(UpdateError::io("Failed to move current binary"))?;
+    if let Err(e) = fs::rename(&backup, current_exe) {
+        let _ = fs::rename(&swap, current_exe);
+        return Err(UpdateError::io("Failed to restore previous binary")(e));
+    }
+    fs::rename(&swap, &backup).map_err(UpdateError::io("Failed to keep replaced binary"))
+}
+
+/// Version reported by `<binary> --version` ("asimov 12.3.0" -> "12.3.0")
+pub fn binary_version(binary: &Path) -> Option<String> {
+    let output = std::process::Command::new(binary)
+        .arg("--version")
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    String::from_utf8_lossy(&output.stdout)
+        .split_whitespace()
+        .last()
+        .map(|v| v.trim_start_matches('v').to_string())
+}
+
+/// Fetch a detached minisign signature; a 404 means the release has none
+fn download_signature(url: &str) -> Result<String, UpdateError> {
+    let mut response = match http_get(url) {
+        Err(UpdateError::Http { status: 404, .. }) => {
+            return Err(UpdateError::SignatureMissing(url.to_string()))
+        }
+        other => other?,
+    };
+    let mut signature = String::new();
+    response
+        .read_to_string(&mut signature)
+        .map_err(UpdateError::io(format!("Failed to download {}", url)))?;
+    Ok(signature)
+}
+
+/// Verify a minisign signature over an archive (v12.4.0)
+///
+/// Only pre-hashed (current minisign) signatures are accepted. When the
+/// trusted comment names a file, it must be `asset_name`, so a validly
+/// signed archive for another platform or release cannot be swapped in.
+pub fn verify_signature(
+    data: &[u8],
+    asset_name: &str,
+    signature: &str,
+    public_key: &str,
+) -> Result<(), UpdateError> {
+    let invalid = |reason: String| UpdateError::SignatureInvalid {
+        path: PathBuf::from(asset_name),
+        reason,
+    };
+    let public_key = minisign_verify::PublicKey::from_base64(public_key)
+        .map_err(|e| invalid(format!("bad public key: {}", e)))?;
+    let signature = minisign_verify::Signature::decode(signature)
+        .map_err(|e| invalid(format!("malformed signature: {}", e)))?;
+
+    public_key
+        .verify(data, &signature, false)
+        .map_err(|e| invalid(e.to_string()))?;
+
+    let signed_file = signature
+        .trusted_comment()
+        .split_whitespace()
+        .find_map(|field| field.strip_prefix("file:"));
+    match signed_file {
+        Some(file) if file != asset_name => Err(invalid(format!(
+            "signature is for {}, not {}",
+            file, asset_name
+        ))),
+        _ => Ok(()),
+    }
+}
+
+/// Verify SHA256 checksum of downloaded archive bytes (v8.4.0)
+#[cfg_attr(feature = "coverage", coverage(off))]
+fn verify_checksum(data: &[u8], checksums_url: &str, asset_name: &str) -> Result<(), UpdateError> {
+    // Download checksums.txt
+    let mut checksums = String::new();
+    http_get(checksums_url)?
+        .read_to_string(&mut checksums)
+        .map_err(UpdateError::io(format!(
+            "Failed to download {}",
+            checksums_url
+        )))?;
+
+    // Find the expected checksum for our asset
+    let expected_checksum = parse_checksums(&checksums, asset_name)
+        .ok_or_else(|| UpdateError::ChecksumNotFound(asset_name.to_string()))?;
+
+    // Calculate actual checksum
+    let actual_checksum = format!("{:x}", Sha256::digest(data));
+
+    verify_checksum_match(&expected_checksum, &actual_checksum)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_parse_checksums() {
+        let checksums = r#"abc123def456  asimov-x86_64-unknown-linux-musl.tar.gz
+789xyz000111  asimov-aarch64-apple-darwin.tar.gz"#;
+
+        let checksum = parse_checksums(checksums, "asimov-x86_64-unknown-linux-musl.tar.gz");
+        assert_eq!(checksum, Some("abc123def456".to_string()));
+
+        let checksum2 = parse_checksums(checksums, "asimov-aarch64-apple-darwin.tar.gz");
+        assert_eq!(checksum2, Some("789xyz000111".to_string()));
+
+        let missing = parse_checksums(checksums, "nonexistent.tar.gz");
+        assert!(missing.is_none());
+    }
+
+    #[test]
+    fn test_verify_checksum_match_success() {
+        let result = verify_checksum_match("abc123", "abc123");
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_verify_checksum_match_failure() {
+        let result = verify_checksum_match("abc123", "xyz789");
+        assert!(result.is_err());
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("Checksum mismatch"));
+    }
+
+    #[test]
+    fn test_calculate_checksum() {
+        let temp_dir = TempDir::new().unwrap();
+        let test_file = temp_dir.path().join("test.txt");
+        let mut file = fs::File::create(&test_file).unwrap();
+        file.write_all(b"test content").unwrap();
+
+        let result = calculate_checksum(&test_file);
+        assert!(result.is_ok());
+        let checksum = result.unwrap();
+        // SHA256 is always 64 hex characters
+        assert_eq!(checksum.len(), 64);
+    }
+
+    #[test]
+    fn test_calculate_checksum_missing_file() {
+        let result = calculate_checksum(Path::new("/nonexistent/file.txt"));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_extract_archive_missing_file() {
+        let temp_dir = TempDir::new().unwrap();
+        let result = extract_archive(Path::new("/nonexistent.tar.gz"), temp_dir.path());
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_download_file_invalid_url() {
+        let temp_dir = TempDir::new().unwrap();
+        let dest = temp_dir.path().join("download.tar.gz");
+        // This will fail because it's not a valid URL that returns a file
+        let result = download_file("http://localhost:99999/nonexistent", &dest);
+        // Either fails to connect or gets empty/error response
+        assert!(result.is_err() || !dest.exists() || fs::metadata(&dest).unwrap().len() == 0);
+    }
+
+    #[test]
+    fn test_replace_binary() {
+        let temp_dir = TempDir::new().unwrap();
+
+        // Create "new" binary
+        let new_binary = temp_dir.path().join("new_binary");
+        let mut f = fs::File::create(&new_binary).unwrap();
+        f.write_all(b"new binary content").unwrap();
+
+        // Create "current" binary
+        let current_exe = temp_dir.path().join("current_binary");
+        let mut f = fs::File::create(&current_exe).unwrap();
+        f.write_all(b"old binary content").unwrap();
+
+        let result = replace_binary(&new_binary, &current_exe);
+        assert!(result.is_ok());
+
+        // Verify the current exe now has new content
+        let content = fs::read_to_string(&current_exe).unwrap();
+        assert_eq!(content, "new binary content");
+
+        // v12.4.0: the previous binary is kept for --rollback
+        let backup = fs::read_to_string(backup_path(&current_exe)).unwrap();
+        assert_eq!(backup, "old binary content");
+    }
+
+    #[test]
+    fn test_rollback_binary_swaps_with_backup() {
+        let temp_dir = TempDir::new().unwrap();
+        let current_exe = temp_dir.path().join("asimov");
+        fs::write(&current_exe, "v2").unwrap();
+
+        let err = rollback_binary(&current_exe).unwrap_err();
+        assert!(matches!(err, UpdateError::NoBackup(_)));
+        assert_eq!(fs::read_to_string(&current_exe).unwrap(), "v2");
+
+        fs::write(backup_path(&current_exe), "v1").unwrap();
+        rollback_binary(&current_exe).unwrap();
+        assert_eq!(fs::read_to_string(&current_exe).unwrap(), "v1");
+        assert_eq!(fs::read_to_string(backup_path(&current_exe)).unwrap(), "v2");
+
+        // Rolling back again undoes the rollback
+        rollback_binary(&current_exe).unwrap();
+        assert_eq!(fs::read_to_string(&current_exe).unwrap(), "v2");
+        assert!(!current_exe.with_extension("rollback").exists());
+    }
+
+    #[test]
+    fn test_replace_binary_missing_source() {
+        let temp_dir = TempDir::new().unwrap();
+        let current_exe = temp_dir.path().join("current");
+        fs::write(&current_exe, "content").unwrap();
+
+        let result = replace_binary(Path::new("/nonexistent"), &current_exe);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_perform_update_invalid_url() {
+        // Test with invalid URL
+        let result = perform_update("http://localhost:99999/invalid.tar.gz", None, false);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_perform_update_without_release_key() {
+        if RELEASE_PUBLIC_KEY.is_some() {
+            return;
+        }
+        let err = perform_update("http://localhost:99999/invalid.tar.gz", None, false).unwrap_err();
+        assert!(matches!(err, UpdateError::NoReleaseKey), "{}", err);
+        assert!(err.to_string().contains("see SECURITY.md"));
+    }
+
+    #[test]
+    fn test_perform_update_with_checksums() {
+        // Test with invalid URL but with checksums option
+        let result = perform_update(
+            "http://localhost:99999/invalid.tar.gz",
+            Some("http://localhost:99999/checksums.txt"),
+            false,
+        );
+        assert!(result.is_err());
+    }
+
+    /// Build a tar.gz in-process with the given (path, content) entries
+    fn tar_gz(path: &Path, entries: &[(&str, &[u8])]) {
+        let encoder = flate2::write::GzEncoder::new(
+            fs::File::create(path).unwrap(),
+            flate2::Compression::default(),
+        );
+        let mut builder = tar::Builder::new(encoder);
+        for (name, content) in entries {
+            let mut header = tar::Header::new_gnu();
+            header.set_size(content.len() as u64);
+            header.set_mode(0o755);
+            header.set_cksum();
+            builder.append_data(&mut header, name, *content).unwrap();
+        }
+        builder.into_inner().unwrap().finish().unwrap();
+    }
+
+    #[test]
+    fn test_extract_archive_valid() {
+        let temp_dir = TempDir::new().unwrap();
+        let archive_path = temp_dir.path().join("test.tar.gz");
+        tar_gz(
+            &archive_path,
+            &[("asimov", b"binary"), ("docs/README", b"hi")],
+        );
+
+        let extract_dir = temp_dir.path().join("extract");
+        extract_archive(&archive_path, &extract_dir).unwrap();
+        assert_eq!(fs::read(extract_dir.join("asimov")).unwrap(), b"binary");
+        assert_eq!(fs::read(extract_dir.join("docs/README")).unwrap(), b"hi");
+    }
+
+    #[test]
+    fn test_extract_archive_zip() {
+        let temp_dir = TempDir::new().unwrap();
+        let archive_path = temp_dir.path().join("test.zip");
+        let mut zip = zip::ZipWriter::new(fs::File::create(&archive_path).unwrap());
+        zip.start_file("asimov.exe", zip::write::SimpleFileOptions::default())
+            .unwrap();
+        zip.write_all(b"windows binary").unwrap();
+        zip.finish().unwrap();
+
+        let extract_dir = temp_dir.path().join("extract");
+        extract_archive(&archive_path, &extract_dir).unwrap();
+        assert_eq!(
+            fs::read(extract_dir.join("asimov.exe")).unwrap(),
+            b"windows binary"
+        );
+    }
+
+    #[test]
+    fn test_extract_archive_corrupt() {
+        let temp_dir = TempDir::new().unwrap();
+        let archive_path = temp_dir.path().join("broken.tar.gz");
+        fs::write(&archive_path, b"not gzip").unwrap();
+        let err = extract_archive(&archive_path, &temp_dir.path().join("out")).unwrap_err();
+        assert!(matches!(err, UpdateError::Archive { .. }));
+    }
+
+    #[test]
+    fn test_verify_checksum_match_empty() {
+        let result = verify_checksum_match("", "");
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_download_file_to_temp() {
+        // Test downloading to a temp directory (will fail due to invalid URL)
+        let temp_dir = TempDir::new().unwrap();
+        let dest = temp_dir.path().join("download.tar.gz");
+        let result = download_file("http://localhost:99999/file.tar.gz", &dest);
+        // Should fail - no server running
+        assert!(result.is_err() || !dest.exists());
+    }
+
+    #[test]
+    fn test_extract_archive_nonexistent_src() {
+        let temp_dir = TempDir::new().unwrap();
+        let result = extract_archive(Path::new("/nonexistent/archive.tar.gz"), temp_dir.path());
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_replace_binary_target_dir_missing() {
+        let temp_dir = TempDir::new().unwrap();
+        let new_binary = temp_dir.path().join("new");
+        fs::write(&new_binary, "content").unwrap();
+
+        let result = replace_binary(&new_binary, Path::new("/nonexistent/dir/binary"));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_calculate_checksum_known_digest() {
+        let temp_dir = TempDir::new().unwrap();
+        let file = temp_dir.path().join("abc");
+        fs::write(&file, "abc").unwrap();
+        assert_eq!(
+            calculate_checksum(&file).unwrap(),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+    }
+
+    #[test]
+    fn test_download_follows_redirects() {
+        let mut server = mockito::Server::new();
+        let _redirect = server
+            .mock("GET", "/download/asimov.tar.gz")
+            .with_status(302)
+            .with_header("location", &format!("{}/cdn/asimov.tar.gz", server.url()))
+            .create();
+        let _asset = server
+            .mock("GET", "/cdn/asimov.tar.gz")
+            .with_body("archive bytes")
+            .create();
+
+        let temp_dir = TempDir::new().unwrap();
+        let dest = temp_dir.path().join("asimov.tar.gz");
+        download_file(&format!("{}/download/asimov.tar.gz", server.url()), &dest).unwrap();
+        assert_eq!(fs::read(&dest).unwrap(), b"archive bytes");
+    }
+
+    #[test]
+    fn test_download_http_error() {
+        let mut server = mockito::Server::new();
+        let _missing = server.mock("GET", "/gone.tar.gz").with_status(404).create();
+
+        let temp_dir = TempDir::new().unwrap();
+        let dest = temp_dir.path().join("gone.tar.gz");
+        let err = download_file(&format!("{}/gone.tar.gz", server.url()), &dest).unwrap_err();
+        assert!(matches!(err, UpdateError::Http { status: 404, .. }));
+        assert!(!dest.exists());
+    }
+
+    #[test]
+    fn test_download_truncated() {
+        let mut server = mockito::Server::new();
+        let _short = server
+            .mock("GET", "/short.tar.gz")
+            .with_header("content-length", "1000")
+            .with_chunked_body(|w| w.write_all(b"only a few bytes"))
+            .create();
+
+        let temp_dir = TempDir::new().unwrap();
+        let dest = temp_dir.path().join("short.tar.gz");
+        let err = download_file(&format!("{}/short.tar.gz", server.url()), &dest).unwrap_err();
+        assert!(
+            matches!(err, UpdateError::Truncated { expected: 1000, .. }),
+            "{:?}",
+            err
+        );
+        assert!(!dest.exists());
+    }
+
+    #[test]
+    fn test_verify_checksum_against_server() {
+        let archive = b"abc";
+
+        let mut server = mockito::Server::new();
+        let _good = server
+            .mock("GET", "/good/checksums.txt")
+            .with_body("ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad  asimov-test.tar.gz\n")
+            .create();
+        let _bad = server
+            .mock("GET", "/bad/checksums.txt")
+            .with_body("0000000000000000000000000000000000000000000000000000000000000000  asimov-test.tar.gz\n")
+            .create();
+
+        let url = |path: &str| format!("{}{}", server.url(), path);
+        verify_checksum(archive, &url("/good/checksums.txt"), "asimov-test.tar.gz").unwrap();
+
+        let err =
+            verify_checksum(archive, &url("/bad/checksums.txt"), "asimov-test.tar.gz").unwrap_err();
+        assert!(matches!(err, UpdateError::ChecksumMismatch { .. }));
+
+        let err =
+            verify_checksum(archive, &url("/good/checksums.txt"), "asimov-other.zip").unwrap_err();
+        assert!(matches!(err, UpdateError::ChecksumNotFound(_)));
+    }
+
+    const TEST_KEY_ID: [u8; 8] = *b"asimovts";
+
+    fn b64(bytes: &[u8]) -> String {
+        use base64::Engine;
+        base64::engine::general_purpose::STANDARD.encode(bytes)
+    }
+
+    /// A throwaway minisign keypair: (signing key, base64 public key)
+    fn test_keypair(seed: u8) -> (ed25519_dalek::SigningKey, String) {
+        let key = ed25519_dalek::SigningKey::from_bytes(&[seed; 32]);
+        let mut public = b"Ed".to_vec();
+        public.extend_from_slice(&TEST_KEY_ID);
+        public.extend_from_slice(key.verifying_key().as_bytes());
+        (key, b64(&public))
+    }
+
+    /// Sign `data` the way `minisign -S` does (pre-hashed with BLAKE2b-512)
+    fn minisign(key: &ed25519_dalek::SigningKey, data: &[u8], trusted_comment: &str) -> String {
+        use ed25519_dalek::Signer;
+        let signature = key.sign(&blake2::Blake2b512::digest(data)).to_bytes();
+        let mut bin = b"ED".to_vec();
+        bin.extend_from_slice(&TEST_KEY_ID);
+        bin.extend_from_slice(&signature);
+        let mut global = signature.to_vec();
+        global.extend_from_slice(trusted_comment.as_bytes());
+        format!(
+            "untrusted comment: asimov test key\n{}\ntrusted comment: {}\n{}\n",
+            b64(&bin),
+            trusted_comment,
+            b64(&key.sign(&global).to_bytes())
+        )
+    }
+
+    #[test]
+    fn test_verify_signature() {
+        let archive = b"release archive";
+        let (key, public_key) = test_keypair(1);
+        let signature = minisign(&key, b"release archive", "file:asimov-test.tar.gz hashed");
+
+        verify_signature(archive, "asimov-test.tar.gz", &signature, &public_key).unwrap();
+
+        // Tampered archive
+        let err = verify_signature(
+            b"evil archive",
+            "asimov-test.tar.gz",
+            &signature,
+            &public_key,
+        )
+        .unwrap_err();
+        assert!(matches!(err, UpdateError::SignatureInvalid { .. }));
+
+        // Signed by a different key
+        let (other, _) = test_keypair(2);
+        let forged = minisign(&other, b"release archive", "file:asimov-test.tar.gz");
+        assert!(verify_signature(archive, "asimov-test.tar.gz", &forged, &public_key).is_err());
+
+        // Valid signature for another asset
+        let err = verify_signature(archive, "asimov-other.zip", &signature, &public_key)
+            .unwrap_err()
+            .to_string();
+        assert!(
+            err.contains("signature is for asimov-test.tar.gz"),
+            "{}",
+            err
+        );
+
+        // Garbage
+        assert!(verify_signature(archive, "asimov-test.tar.gz", "junk", &public_key).is_err());
+    }
+
+    const ASSET: &str = "asimov-test.tar.gz";
+
+    /// A release archive holding the platform's `asimov` binary
+    fn release_archive() -> Vec<u8> {
+        let temp_dir = TempDir::new().unwrap();
+        let archive_path = temp_dir.path().join(ASSET);
+        let binary = format!("asimov{}", env::consts::EXE_SUFFIX);
+        tar_gz(&archive_path, &[(&binary, b"new binary")]);
+        fs::read(archive_path).unwrap()
+    }
+
+    /// A mock release server: archive, checksums.txt and (optionally) a signature
+    struct MockRelease {
+        server: mockito::ServerGuard,
+        _mocks: Vec<mockito::Mock>,
+    }
+
+    impl MockRelease {
+        fn new(archive: &[u8], signature: Option<String>) -> Self {
+            let checksum = format!("{:x}", Sha256::digest(archive));
+            let mut server = mockito::Server::new();
+            let signature_path = format!("/v99.0.0/{}{}", ASSET, SIGNATURE_SUFFIX);
+            let signature_mock = match signature {
+                Some(body) => server.mock("GET", signature_path.as_str()).with_body(body),
+                None => server.mock("GET", signature_path.as_str()).with_status(404),
+            };
+            let mocks = vec![
+                signature_mock.create(),
+                server
+                    .mock("GET", format!("/v99.0.0/{}", ASSET).as_str())
+                    .with_body(archive)
+                    .create(),
+                server
+                    .mock("GET", "/v99.0.0/checksums.txt")
+                    .with_body(format!("{}  {}\n", checksum, ASSET))
+                    .create(),
+            ];
+            MockRelease {
+                server,
+                _mocks: mocks,
+            }
+        }
+
+        /// Install over a fake current executable; returns the result and its content after
+        fn install(&self, public_key: Option<&str>) -> (Result<(), UpdateError>, String) {
+            let temp_dir = TempDir::new().unwrap();
+            let current_exe = temp_dir.path().join("asimov-current");
+            fs::write(&current_exe, "old binary").unwrap();
+            let staging = temp_dir.path().join("staging");
+            fs::create_dir(&staging).unwrap();
+
+            let base = format!("{}/v99.0.0", self.server.url());
+            let result = install_from(
+                &format!("{}/{}", base, ASSET),
+                Some(&format!("{}/checksums.txt", base)),
+                public_key,
+                &staging,
+                &current_exe,
+            );
+            (result, fs::read_to_string(&current_exe).unwrap())
+        }
+    }
+
+    #[test]
+    fn test_install_signed_release() {
+        let (key, public_key) = test_keypair(1);
+        let archive = release_archive();
+        let signature = minisign(&key, &archive, &format!("file:{} hashed", ASSET));
+
+        let release = MockRelease::new(&archive, Some(signature));
+        let (result, content) = release.install(Some(&public_key));
+        result.unwrap();
+        assert_eq!(content, "new binary");
+    }
+
+    #[test]
+    fn test_install_refuses_unsigned_release() {
+        let (_, public_key) = test_keypair(1);
+        let release = MockRelease::new(&release_archive(), None);
+
+        let (result, content) = release.install(Some(&public_key));
+        assert!(matches!(result, Err(UpdateError::SignatureMissing(_))));
+        assert_eq!(content, "old binary");
+
+        // --insecure
+        let (result, content) = release.install(None);
+        result.unwrap();
+        assert_eq!(content, "new binary");
+    }
+
+    #[test]
+    fn test_install_refuses_bad_signature() {
+        let (_, public_key) = test_keypair(1);
+        let (attacker, _) = test_keypair(3);
+        let archive = release_archive();
+        let forged = minisign(&attacker, &archive, ASSET);
+
+        let release = MockRelease::new(&archive, Some(forged));
+        let (result, content) = release.install(Some(&public_key));
+        assert!(matches!(result, Err(UpdateError::SignatureInvalid { .. })));
+        assert_eq!(content, "old binary");
+    }
+}
@@ -0,0 +1,917 @@
+//! Self-update functionality for RoyalBit Asimov CLI
+//!
+//! Checks GitHub Releases for new versions and updates the binary in-place.
+//!
+//! v12.4.0: Every network call goes through `http_client`, which refuses
+//! to run when `ASIMOV_OFFLINE` is set. Passive callers (doctor) use
+//! `cached_check_for_update`, which asks GitHub at most once per TTL.
+//! Downloads, extraction and SHA-256 run in-process (no curl, tar or
+//! sha256sum), so minimal container images can self-update.
+//!
+//! v12.4.0: Release archives carry a detached minisign signature
+//! (`<asset>.minisig`) checked against `RELEASE_PUBLIC_KEY`, which is
+//! compiled in. The binary is only replaced when it verifies, unless the
+//! user passes `--insecure`. Until a maintainer commits the release key
+//! (SECURITY.md, "Setting up release signing") it is `None`, and updates
+//! fail with `NoReleaseKey` instead of installing unverified archives.
+//!
+//! v12.4.0: `release` adds channels, pinned versions, rollback and the
+//! project-level `min_asimov_version`.
+
+mod install;
+mod release;
+
+pub use install::*;
+pub use release::*;
+
+use reqwest::blocking::{Client, Response};
+use reqwest::{header, redirect};
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use std::env;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use thiserror::Error;
+
+/// Environment variable that disables all network access (`--offline` sets it)
+pub const OFFLINE_ENV: &str = "ASIMOV_OFFLINE";
+
+/// Environment variable overriding the version check TTL, in seconds
+pub const VERSION_CHECK_TTL_ENV: &str = "ASIMOV_VERSION_CHECK_TTL";
+
+/// How long a cached version check is trusted (24 hours)
+pub const DEFAULT_VERSION_CHECK_TTL: u64 = 24 * 60 * 60;
+
+/// Version check cache, inside the user cache directory
+pub const VERSION_CACHE_FILE: &str = "version-check.json";
+
+/// Seconds to wait for a connection
+const HTTP_CONNECT_TIMEOUT: u64 = 10;
+
+/// Seconds a single request (including the download body) may take
+const HTTP_TIMEOUT: u64 = 300;
+
+/// GitHub releases page URL (redirects to latest)
+const GITHUB_RELEASES_URL: &str = "https://github.com/royalbit/asimov/releases/latest";
+
+/// minisign public key that signs release archives; must match
+/// `keys/minisign.pub`, which the release workflow verifies against.
+/// None until a maintainer generates the key (see SECURITY.md).
+pub const RELEASE_PUBLIC_KEY: Option<&str> = None;
+
+/// Detached signature published next to each release archive
+pub const SIGNATURE_SUFFIX: &str = ".minisig";
+
+/// Oldest release published with signatures; earlier ones have no `.minisig`
+pub const FIRST_SIGNED_VERSION: &str = "12.4.0";
+
+/// GitHub releases download base URL
+const GITHUB_DOWNLOAD_BASE: &str = "https://github.com/royalbit/asimov/releases/download";
+
+/// Current version from Cargo.toml
+pub const CURRENT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// Result of version check
+#[derive(Debug, Clone)]
+pub struct VersionCheck {
+    pub current: String,
+    pub latest: String,
+    pub update_available: bool,
+    pub download_url: Option<String>,
+    pub checksums_url: Option<String>,
+}
+
+/// Get the appropriate asset name for the current platform
+pub fn get_platform_asset() -> Option<&'static str> {
+    #[cfg(all(target_os = "linux", target_arch = "x86_64"))]
+    return Some("asimov-x86_64-unknown-linux-musl.tar.gz");
+
+    #[cfg(all(target_os = "linux", target_arch = "aarch64"))]
+    return Some("asimov-aarch64-unknown-linux-musl.tar.gz");
+
+    #[cfg(all(target_os = "macos", target_arch = "aarch64"))]
+    return Some("asimov-aarch64-apple-darwin.tar.gz");
+
+    #[cfg(all(target_os = "macos", target_arch = "x86_64"))]
+    return Some("asimov-x86_64-apple-darwin.tar.gz");
+
+    #[cfg(all(target_os = "windows", target_arch = "x86_64"))]
+    return Some("asimov-x86_64-pc-windows-msvc.zip");
+
+    #[cfg(not(any(
+        all(target_os = "linux", target_arch = "x86_64"),
+        all(target_os = "linux", target_arch = "aarch64"),
+        all(target_os = "macos", target_arch = "aarch64"),
+        all(target_os = "macos", target_arch = "x86_64"),
+        all(target_os = "windows", target_arch = "x86_64")
+    )))]
+    return None;
+}
+
+/// Parse GitHub API response and extract version check info
+/// This is the pure logic, separated from HTTP for testability
+pub fn parse_github_response(body: &str, current_version: &str) -> Result<VersionCheck, String> {
+    let latest_version = extract_json_string(body, "tag_name")
+        .ok_or("Could not parse version from GitHub response")?
+        .trim_start_matches('v')
+        .to_string();
+
+    let update_available = is_newer_version(&latest_version, current_version);
+
+    // Find download URL for current platform
+    let download_url = if update_available {
+        get_platform_asset().and_then(|asset_name| find_asset_url(body, asset_name))
+    } else {
+        None
+    };
+
+    // Find checksums.txt URL
+    let checksums_url = if update_available {
+        find_checksums_url(body)
+    } else {
+        None
+    };
+
+    Ok(VersionCheck {
+        current: current_version.to_string(),
+        latest: latest_version,
+        update_available,
+        download_url,
+        checksums_url,
+    })
+}
+
+/// Find the download URL for a specific asset in the GitHub API response
+pub fn find_asset_url(body: &str, asset_name: &str) -> Option<String> {
+    // Try both with and without space after colon (GitHub uses space)
+    let search_with_space = format!("\"name\": \"{}\"", asset_name);
+    let search_no_space = format!("\"name\":\"{}\"", asset_name);
+    let pos = body
+        .find(&search_with_space)
+        .or_else(|| body.find(&search_no_space));
+    if let Some(pos) = pos {
+        // Look for browser_download_url near this position
+        // GitHub API has large uploader objects (~1500 chars) between name and browser_download_url
+        let chunk = &body[pos.saturating_sub(500)..body.len().min(pos + 2500)];
+        extract_json_string(chunk, "browser_download_url").filter(|url| url.contains(asset_name))
+    } else {
+        None
+    }
+}
+
+/// Find the checksums.txt URL in the GitHub API response
+pub fn find_checksums_url(body: &str) -> Option<String> {
+    let pos = body
+        .find("\"name\": \"checksums.txt\"")
+        .or_else(|| body.find("\"name\":\"checksums.txt\""));
+    if let Some(pos) = pos {
+        // GitHub API has large uploader objects (~1500 chars) between name and browser_download_url
+        let chunk = &body[pos.saturating_sub(500)..body.len().min(pos + 2500)];
+        extract_json_string(chunk, "browser_download_url")
+            .filter(|url| url.contains("checksums.txt"))
+    } else {
+        None
+    }
+}
+
+/// True when network access is disabled (`ASIMOV_OFFLINE` set to anything but "", "0" or "false")
+pub fn is_offline() -> bool {
+    env::var(OFFLINE_ENV)
+        .map(|v| {
+            let v = v.trim().to_ascii_lowercase();
+            !(v.is_empty() || v == "0" || v == "false")
+        })
+        .unwrap_or(false)
+}
+
+/// Errors from checking for, downloading and installing an update (v12.4.0)
+#[derive(Debug, Error)]
+pub enum UpdateError {
+    #[error("Network access disabled ({}=1 or --offline)", OFFLINE_ENV)]
+    Offline,
+
+    #[error("Request to {url} failed: {source}")]
+    Network {
+        url: String,
+        #[source]
+        source: reqwest::Error,
+    },
+
+    #[error("{url} returned HTTP {status}")]
+    Http { url: String, status: u16 },
+
+    #[error("{0} did not redirect to a release")]
+    NoRedirect(String),
+
+    #[error("Unexpected response from {url}: {reason}")]
+    BadResponse { url: String, reason: String },
+
+    #[error("Invalid version '{0}' (expected X.Y.Z)")]
+    InvalidVersion(String),
+
+    #[error("No previous binary at {} (nothing to roll back)", .0.display())]
+    NoBackup(PathBuf),
+
+    #[error("Could not parse version from redirect URL: {0}")]
+    BadRedirect(String),
+
+    #[error("Download truncated: expected {expected} bytes, got {actual}")]
+    Truncated { expected: u64, actual: u64 },
+
+    #[error("Checksum not found for {0}")]
+    ChecksumNotFound(String),
+
+    #[error("Checksum mismatch!\n  Expected: {expected}\n  Actual:   {actual}")]
+    ChecksumMismatch { expected: String, actual: String },
+
+    #[error("Failed to extract {}: {reason}", path.display())]
+    Archive { path: PathBuf, reason: String },
+
+    #[error("No signature at {0} (use --insecure to update without one)")]
+    SignatureMissing(String),
+
+    #[error(
+        "asimov {0} predates signed releases (the first is {first}); pass --insecure to install it unverified",
+        first = FIRST_SIGNED_VERSION
+    )]
+    Unsigned(String),
+
+    #[error(
+        "This build has no release signing key (see SECURITY.md), so updates cannot be verified; pass --insecure to install anyway"
+    )]
+    NoReleaseKey,
+
+    #[error("Signature verification failed for {}: {reason}", path.display())]
+    SignatureInvalid { path: PathBuf, reason: String },
+
+    #[error("Extracted binary not found at {}", .0.display())]
+    BinaryNotFound(PathBuf),
+
+    #[error("{context}: {source}")]
+    Io {
+        context: String,
+        #[source]
+        source: io::Error,
+    },
+}
+
+impl UpdateError {
+    fn io(context: impl Into<String>) -> impl FnOnce(io::Error) -> Self {
+        let context = context.into();
+        move |source| UpdateError::Io { context, source }
+    }
+}
+
+/// The single gate for network access: an HTTP client, refused when offline
+fn http_client(follow_redirects: bool) -> Result<Client, UpdateError> {
+    if is_offline() {
+        return Err(UpdateError::Offline);
+    }
+    let redirects = if follow_redirects {
+        redirect::Policy::limited(10)
+    } else {
+        redirect::Policy::none()
+    };
+    Client::builder()
+        .user_agent(concat!("asimov/", env!("CARGO_PKG_VERSION")))
+        .redirect(redirects)
+        .connect_timeout(Duration::from_secs(HTTP_CONNECT_TIMEOUT))
+        .timeout(Duration::from_secs(HTTP_TIMEOUT))
+        .build()
+        .map_err(|source| UpdateError::Network {
+            url: "HTTP client".to_string(),
+            source,
+        })
+}
+
+/// GET a URL, following redirects, and fail on a non-success status
+fn http_get(url: &str) -> Result<Response, UpdateError> {
+    let network = |source| UpdateError::Network {
+        url: url.to_string(),
+        source,
+    };
+    let response = http_client(true)?.get(url).send().map_err(network)?;
+    if !response.status().is_success() {
+        return Err(UpdateError::Http {
+            url: url.to_string(),
+            status: response.status().as_u16(),
+        });
+    }
+    Ok(response)
+}
+
+#[cfg_attr(feature = "coverage", coverage(off))]
+/// Get latest version by following GitHub releases redirect
+/// This avoids API rate limits entirely
+fn get_latest_version_from_redirect(url: &str) -> Result<String, UpdateError> {
+    let response = http_client(false)?
+        .head(url)
+        .send()
+        .map_err(|source| UpdateError::Network {
+            url: url.to_string(),
+            source,
+        })?;
+
+    if !response.status().is_redirection() {
+        return Err(UpdateError::NoRedirect(url.to_string()));
+    }
+    let redirect_url = response
+        .headers()
+        .get(header::LOCATION)
+        .and_then(|v| v.to_str().ok())
+        .ok_or_else(|| UpdateError::NoRedirect(url.to_string()))?;
+
+    // Extract version from URL like: https://github.com/royalbit/asimov/releases/tag/v9.5.0
+    redirect_url
+        .trim_end_matches('/')
+        .rsplit('/')
+        .next()
+        .map(|v| v.trim_start_matches('v').to_string())
+        .filter(|v| !v.is_empty() && v.starts_with(|c: char| c.is_ascii_digit()))
+        .ok_or_else(|| UpdateError::BadRedirect(redirect_url.to_string()))
+}
+
+/// Check for updates by following GitHub releases redirect (no API, no rate limits)
+/// v12.4.0: A successful check refreshes the version cache
+pub fn check_for_update() -> Result<VersionCheck, UpdateError> {
+    let check = check_for_update_from_url(GITHUB_RELEASES_URL)?;
+    if let Some(path) = version_cache_path() {
+        let cache = VersionCache {
+            latest: Some(check.latest.clone()),
+            checked_at: unix_now(),
+            error: None,
+        };
+        let _ = write_version_cache(&path, &cache);
+    }
+    Ok(check)
+}
+
+/// Check for updates from a custom URL (for testing)
+pub fn check_for_update_from_url(url: &str) -> Result<VersionCheck, UpdateError> {
+    let latest_version = get_latest_version_from_redirect(url)?;
+    Ok(version_check_for(&latest_version))
+}
+
+/// Last known latest release and when it was last checked (v12.4.0)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VersionCache {
+    /// Latest release from the last successful check, if any
+    #[serde(default)]
+    pub latest: Option<String>,
+    /// Unix seconds of the last attempt, successful or not
+    pub checked_at: u64,
+    /// Why the last attempt failed; None when it succeeded
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// `<user cache dir>/version-check.json`
+pub fn version_cache_path() -> Option<std::path::PathBuf> {
+    crate::paths::user_cache_dir().map(|dir| dir.join(VERSION_CACHE_FILE))
+}
+
+/// TTL from `ASIMOV_VERSION_CHECK_TTL`, else 24 hours
+pub fn version_check_ttl() -> u64 {
+    env::var(VERSION_CHECK_TTL_ENV)
+        .ok()
+        .and_then(|v| v.trim().parse().ok())
+        .unwrap_or(DEFAULT_VERSION_CHECK_TTL)
+}
+
+pub fn read_version_cache(path: &Path) -> Option<VersionCache> {
+    serde_json::from_str(&fs::read_to_string(path).ok()?).ok()
+}
+
+pub fn write_version_cache(path: &Path, cache: &VersionCache) -> Result<(), String> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .map_err(|e| format!("Failed to create {}: {}", parent.display(), e))?;
+    }
+    fs::write(path, serde_json::to_string(cache).unwrap())
+        .map_err(|e| format!("Failed to write {}: {}", path.display(), e))
+}
+
+/// Version check for passive callers such as doctor (v12.4.0)
+///
+/// Uses the cache while it is younger than the TTL and only then asks
+/// GitHub. Failed requests are recorded too, so an unreachable GitHub is
+/// asked at most once per TTL. Offline, or when the request fails, the last
+/// known result is returned however old it is; None if there is none.
+pub fn cached_check_for_update() -> Option<VersionCheck> {
+    let path = version_cache_path()?;
+    cached_check_with(&path, version_check_ttl(), unix_now(), || {
+        get_latest_version_from_redirect(GITHUB_RELEASES_URL).map_err(|e| e.to_string())
+    })
+}
+
+/// Cache logic behind `cached_check_for_update`, with the fetch injected
+pub fn cached_check_with(
+    cache_path: &Path,
+    ttl: u64,
+    now: u64,
+    fetch: impl FnOnce() -> Result<String, String>,
+) -> Option<VersionCheck> {
+    let cached = read_version_cache(cache_path);
+    let last_known = cached.as_ref().and_then(|c| c.latest.clone());
+    let fresh = cached
+        .as_ref()
+        .is_some_and(|c| now.saturating_sub(c.checked_at) < ttl);
+    if fresh || is_offline() {
+        return last_known.map(|latest| version_check_for(&latest));
+    }
+    let (cache, check) = match fetch() {
+        Ok(latest) => {
+            let check = version_check_for(&latest);
+            let cache = VersionCache {
+                latest: Some(latest),
+                checked_at: now,
+                error: None,
+            };
+            (cache, Some(check))
+        }
+        Err(e) => {
+            let check = last_known.as_deref().map(version_check_for);
+            let cache = VersionCache {
+                latest: last_known,
+                checked_at: now,
+                error: Some(e),
+            };
+            (cache, check)
+        }
+    };
+    let _ = write_version_cache(cache_path, &cache);
+    check
+}
+
+fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+/// Compare a latest release against the running binary and build its URLs
+fn version_check_for(latest_version: &str) -> VersionCheck {
+    release_check(
+        latest_version,
+        is_newer_version(latest_version, CURRENT_VERSION),
+    )
+}
+
+/// A check for `version`, with download URLs when it should be installed
+fn release_check(latest_version: &str, update_available: bool) -> VersionCheck {
+    let latest_version = latest_version.to_string();
+
+    // Build download URLs directly (no API needed)
+    let download_url = if update_available {
+        get_platform_asset()
+            .map(|asset| format!("{}/v{}/{}", GITHUB_DOWNLOAD_BASE, latest_version, asset))
+    } else {
+        None
+    };
+
+    let checksums_url = if update_available {
+        Some(format!(
+            "{}/v{}/checksums.txt",
+            GITHUB_DOWNLOAD_BASE, latest_version
+        ))
+    } else {
+        None
+    };
+
+    VersionCheck {
+        current: CURRENT_VERSION.to_string(),
+        latest: latest_version,
+        update_available,
+        download_url,
+        checksums_url,
+    }
+}
+
+/// Simple JSON string extraction (avoids adding serde_json dependency)
+pub fn extract_json_string(json: &str, key: &str) -> Option<String> {
+    // Try with space after colon first (GitHub style), then without
+    let search_with_space = format!("\"{}\": \"", key);
+    let search_no_space = format!("\"{}\":\"", key);
+
+    let (start, search_len) = json
+        .find(&search_with_space)
+        .map(|pos| (pos, search_with_space.len()))
+        .or_else(|| {
+            json.find(&search_no_space)
+                .map(|pos| (pos, search_no_space.len()))
+        })?;
+
+    let value_start = start + search_len;
+    let end = json[value_start..].find('"')?;
+    Some(json[value_start..value_start + end].to_string())
+}
+
+/// Compare semantic versions (returns true if latest > current)
+pub fn is_newer_version(latest: &str, current: &str) -> bool {
+    compare_versions(latest, current) == Ordering::Greater
+}
+
+/// Semver precedence of two `X.Y.Z[-pre]` versions (v12.4.0)
+///
+/// A leading `v` and `+build` metadata are ignored; a pre-release
+/// (`12.5.0-beta.1`) sorts before its release.
+pub fn compare_versions(a: &str, b: &str) -> Ordering {
+    fn split(v: &str) -> ([u64; 3], Option<&str>) {
+        let v = v.trim().trim_start_matches('v');
+        let v = v.split('+').next().unwrap_or(v);
+        let (core, pre) = match v.split_once('-') {
+            Some((core, pre)) => (core, Some(pre)),
+            None => (v, None),
+        };
+        let mut parts = [0; 3];
+        for (slot, part) in parts.iter_mut().zip(core.split('.')) {
+            *slot = part.parse().unwrap_or(0);
+        }
+        (parts, pre)
+    }
+
+    let (a_core, a_pre) = split(a);
+    let (b_core, b_pre) = split(b);
+    a_core.cmp(&b_core).then_with(|| match (a_pre, b_pre) {
+        (None, None) => Ordering::Equal,
+        (None, Some(_)) => Ordering::Greater,
+        (Some(_), None) => Ordering::Less,
+        (Some(a), Some(b)) => {
+            let ident = |id: &str| (id.parse::<u64>().ok(), id.to_string());
+            for (x, y) in a.split('.').map(ident).zip(b.split('.').map(ident)) {
+                let order = match (x, y) {
+                    ((Some(x), _), (Some(y), _)) => x.cmp(&y),
+                    ((Some(_), _), (None, _)) => Ordering::Less,
+                    ((None, _), (Some(_), _)) => Ordering::Greater,
+                    ((None, x), (None, y)) => x.cmp(&y),
+                };
+                if order != Ordering::Equal {
+                    return order;
+                }
+            }
+            a.split('.').count().cmp(&b.split('.').count())
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_version_comparison() {
+        assert!(is_newer_version("7.8.0", "7.7.0"));
+        assert!(is_newer_version("8.0.0", "7.9.9"));
+        assert!(is_newer_version("7.7.1", "7.7.0"));
+        assert!(!is_newer_version("7.7.0", "7.7.0"));
+        assert!(!is_newer_version("7.6.0", "7.7.0"));
+        assert!(!is_newer_version("7.7.0", "7.8.0"));
+    }
+
+    #[test]
+    fn test_extract_json_string() {
+        // Without spaces (compact JSON)
+        let json = r#"{"tag_name":"v7.8.0","name":"Release 7.8.0"}"#;
+        assert_eq!(
+            extract_json_string(json, "tag_name"),
+            Some("v7.8.0".to_string())
+        );
+        assert_eq!(
+            extract_json_string(json, "name"),
+            Some("Release 7.8.0".to_string())
+        );
+        assert_eq!(extract_json_string(json, "missing"), None);
+
+        // With spaces (GitHub API style)
+        let json_spaced = r#"{"tag_name": "v8.16.1", "name": "Release 8.16.1"}"#;
+        assert_eq!(
+            extract_json_string(json_spaced, "tag_name"),
+            Some("v8.16.1".to_string())
+        );
+        assert_eq!(
+            extract_json_string(json_spaced, "name"),
+            Some("Release 8.16.1".to_string())
+        );
+    }
+
+    #[test]
+    fn test_current_version_set() {
+        assert!(CURRENT_VERSION.contains('.'));
+    }
+
+    #[test]
+    fn test_get_platform_asset() {
+        let asset = get_platform_asset();
+        #[cfg(any(
+            all(target_os = "linux", target_arch = "x86_64"),
+            all(target_os = "linux", target_arch = "aarch64"),
+            all(target_os = "macos", target_arch = "aarch64"),
+            all(target_os = "macos", target_arch = "x86_64"),
+            all(target_os = "windows", target_arch = "x86_64")
+        ))]
+        {
+            assert!(asset.is_some());
+            let name = asset.unwrap();
+            assert!(name.starts_with("asimov-"));
+        }
+        #[cfg(not(any(
+            all(target_os = "linux", target_arch = "x86_64"),
+            all(target_os = "linux", target_arch = "aarch64"),
+            all(target_os = "macos", target_arch = "aarch64"),
+            all(target_os = "macos", target_arch = "x86_64"),
+            all(target_os = "windows", target_arch = "x86_64")
+        )))]
+        {
+            assert!(asset.is_none());
+        }
+    }
+
+    #[test]
+    fn test_version_check_struct() {
+        let check = VersionCheck {
+            current: "1.0.0".to_string(),
+            latest: "1.1.0".to_string(),
+            update_available: true,
+            download_url: Some("https://example.com/file.tar.gz".to_string()),
+            checksums_url: Some("https://example.com/checksums.txt".to_string()),
+        };
+        assert!(check.update_available);
+        assert!(check.download_url.is_some());
+        assert!(check.checksums_url.is_some());
+    }
+
+    #[test]
+    fn test_parse_github_response_update_available() {
+        let response = r#"{
+            "tag_name": "v9.0.0",
+            "name": "Release 9.0.0",
+            "assets": [
+                {
+                    "name": "asimov-x86_64-unknown-linux-musl.tar.gz",
+                    "browser_download_url": "https://github.com/royalbit/asimov/releases/download/v9.0.0/asimov-x86_64-unknown-linux-musl.tar.gz"
+                },
+                {
+                    "name": "checksums.txt",
+                    "browser_download_url": "https://github.com/royalbit/asimov/releases/download/v9.0.0/checksums.txt"
+                }
+            ]
+        }"#;
+
+        let result = parse_github_response(response, "1.0.0").unwrap();
+        assert_eq!(result.latest, "9.0.0");
+        assert!(result.update_available);
+    }
+
+    #[test]
+    fn test_parse_github_response_no_update() {
+        let response = r#"{"tag_name": "v1.0.0", "name": "Release 1.0.0"}"#;
+        let result = parse_github_response(response, "1.0.0").unwrap();
+        assert_eq!(result.latest, "1.0.0");
+        assert!(!result.update_available);
+        assert!(result.download_url.is_none());
+        assert!(result.checksums_url.is_none());
+    }
+
+    #[test]
+    fn test_parse_github_response_invalid() {
+        let response = r#"{"error": "not found"}"#;
+        let result = parse_github_response(response, "1.0.0");
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_find_asset_url() {
+        let response = r#"{
+            "assets": [
+                {
+                    "name": "asimov-x86_64-unknown-linux-musl.tar.gz",
+                    "browser_download_url": "https://github.com/royalbit/asimov/releases/download/v9.0.0/asimov-x86_64-unknown-linux-musl.tar.gz"
+                }
+            ]
+        }"#;
+        let url = find_asset_url(response, "asimov-x86_64-unknown-linux-musl.tar.gz");
+        assert!(url.is_some());
+        assert!(url
+            .unwrap()
+            .contains("asimov-x86_64-unknown-linux-musl.tar.gz"));
+    }
+
+    #[test]
+    fn test_find_asset_url_not_found() {
+        let response = r#"{"assets": []}"#;
+        let url = find_asset_url(response, "nonexistent.tar.gz");
+        assert!(url.is_none());
+    }
+
+    #[test]
+    fn test_find_checksums_url() {
+        let response = r#"{
+            "assets": [
+                {
+                    "name": "checksums.txt",
+                    "browser_download_url": "https://github.com/royalbit/asimov/releases/download/v9.0.0/checksums.txt"
+                }
+            ]
+        }"#;
+        let url = find_checksums_url(response);
+        assert!(url.is_some());
+        assert!(url.unwrap().contains("checksums.txt"));
+    }
+
+    #[test]
+    fn test_find_checksums_url_not_found() {
+        let response = r#"{"assets": []}"#;
+        let url = find_checksums_url(response);
+        assert!(url.is_none());
+    }
+
+    fn success(latest: &str, checked_at: u64) -> VersionCache {
+        VersionCache {
+            latest: Some(latest.to_string()),
+            checked_at,
+            error: None,
+        }
+    }
+
+    #[test]
+    fn test_cached_check_uses_fresh_cache() {
+        let temp = TempDir::new().unwrap();
+        let cache = temp.path().join("cache").join(VERSION_CACHE_FILE);
+        write_version_cache(&cache, &success("999.0.0", 1_000)).unwrap();
+
+        let check = cached_check_with(&cache, 3_600, 2_000, || {
+            panic!("fresh cache must not hit the network")
+        })
+        .unwrap();
+        assert_eq!(check.latest, "999.0.0");
+        assert!(check.update_available);
+    }
+
+    #[test]
+    fn test_cached_check_refreshes_stale_cache() {
+        let temp = TempDir::new().unwrap();
+        let cache = temp.path().join(VERSION_CACHE_FILE);
+        write_version_cache(&cache, &success("1.0.0", 1_000)).unwrap();
+
+        let check = cached_check_with(&cache, 60, 5_000, || Ok(CURRENT_VERSION.to_string()));
+        assert!(!check.unwrap().update_available);
+        assert_eq!(
+            read_version_cache(&cache),
+            Some(success(CURRENT_VERSION, 5_000))
+        );
+
+        // A failed refresh falls back to the last known result and is recorded
+        let check = cached_check_with(&cache, 60, 9_000, || Err("offline".to_string()));
+        assert_eq!(check.unwrap().latest, CURRENT_VERSION);
+        assert_eq!(
+            read_version_cache(&cache),
+            Some(VersionCache {
+                latest: Some(CURRENT_VERSION.to_string()),
+                checked_at: 9_000,
+                error: Some("offline".to_string()),
+            })
+        );
+
+        // ...so the failure is not retried until the TTL runs out
+        let check = cached_check_with(&cache, 60, 9_030, || {
+            panic!("a recent failure must not hit the network")
+        });
+        assert_eq!(check.unwrap().latest, CURRENT_VERSION);
+        let check = cached_check_with(&cache, 60, 9_060, || Ok("999.0.0".to_string()));
+        assert_eq!(check.unwrap().latest, "999.0.0");
+        assert_eq!(read_version_cache(&cache), Some(success("999.0.0", 9_060)));
+    }
+
+    #[test]
+    fn test_cached_check_without_cache_or_network() {
+        let temp = TempDir::new().unwrap();
+        let cache = temp.path().join(VERSION_CACHE_FILE);
+        assert!(cached_check_with(&cache, 60, 0, || Err("no route".to_string())).is_none());
+        assert_eq!(
+            read_version_cache(&cache),
+            Some(VersionCache {
+                latest: None,
+                checked_at: 0,
+                error: Some("no route".to_string()),
+            })
+        );
+        assert!(cached_check_with(&cache, 60, 30, || panic!("within the TTL")).is_none());
+    }
+
+    #[test]
+    fn test_version_cache_reads_files_without_error() {
+        let temp = TempDir::new().unwrap();
+        let cache = temp.path().join(VERSION_CACHE_FILE);
+        std::fs::write(&cache, r#"{"latest":"1.2.3","checked_at":7}"#).unwrap();
+        assert_eq!(read_version_cache(&cache), Some(success("1.2.3", 7)));
+    }
+
+    #[test]
+    fn test_check_for_update_integration() {
+        // Integration test - actually calls GitHub API
+        // May fail if network unavailable, that's expected
+        let result = check_for_update();
+        // Just verify it doesn't panic, result may be Ok or Err depending on network
+        let _ = result;
+    }
+
+    #[test]
+    fn test_version_check_no_update() {
+        let check = VersionCheck {
+            current: "1.0.0".to_string(),
+            latest: "1.0.0".to_string(),
+            update_available: false,
+            download_url: None,
+            checksums_url: None,
+        };
+        assert!(!check.update_available);
+        assert!(check.download_url.is_none());
+    }
+
+    #[test]
+    fn test_parse_github_response_with_checksums_url() {
+        let response = r#"{
+            "tag_name": "v99.0.0",
+            "assets": [
+                {
+                    "name": "checksums.txt",
+                    "browser_download_url": "https://example.com/checksums.txt"
+                }
+            ]
+        }"#;
+
+        let result = parse_github_response(response, "1.0.0").unwrap();
+        assert!(result.update_available);
+        assert!(result.checksums_url.is_some());
+        assert_eq!(
+            result.checksums_url.unwrap(),
+            "https://example.com/checksums.txt"
+        );
+    }
+
+    #[test]
+    fn test_check_for_update_from_invalid_url() {
+        let result = check_for_update_from_url("http://localhost:99999/invalid");
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_parse_github_response_with_download_url() {
+        let response = r#"{
+            "tag_name": "v99.0.0",
+            "assets": [
+                {
+                    "name": "asimov-x86_64-unknown-linux-musl.tar.gz",
+                    "browser_download_url": "https://example.com/asimov.tar.gz"
+                }
+            ]
+        }"#;
+
+        let result = parse_github_response(response, "1.0.0").unwrap();
+        assert!(result.update_available);
+        // download_url depends on platform
+    }
+
+    #[test]
+    fn test_latest_version_from_redirect() {
+        let mut server = mockito::Server::new();
+        let _latest = server
+            .mock("HEAD", "/releases/latest")
+            .with_status(302)
+            .with_header("location", "https://example.com/releases/tag/v99.1.0")
+            .create();
+        let _missing = server.mock("HEAD", "/empty").with_status(200).create();
+
+        let check =
+            check_for_update_from_url(&format!("{}/releases/latest", server.url())).unwrap();
+        assert_eq!(check.latest, "99.1.0");
+        assert!(check.update_available);
+        assert_eq!(
+            check.checksums_url.as_deref(),
+            Some("https://github.com/royalbit/asimov/releases/download/v99.1.0/checksums.txt")
+        );
+
+        let err = check_for_update_from_url(&format!("{}/empty", server.url())).unwrap_err();
+        assert!(matches!(err, UpdateError::NoRedirect(_)));
+    }
+
+    #[test]
+    fn test_release_public_key_matches_key_file() {
+        let file = Path::new(env!("CARGO_MANIFEST_DIR")).join("keys/minisign.pub");
+        match RELEASE_PUBLIC_KEY {
+            Some(key) => {
+                assert!(minisign_verify::PublicKey::from_base64(key).is_ok());
+                let content = fs::read_to_string(&file).expect("keys/minisign.pub");
+                assert_eq!(content.lines().nth(1), Some(key));
+            }
+            None => assert!(
+                !file.exists(),
+                "keys/minisign.pub without RELEASE_PUBLIC_KEY"
+            ),
+        }
+    }
+}
@@ -0,0 +1,268 @@
+//! Release selection and project version requirements (v12.4.0)
+//!
+//! `asimov update` follows a channel (stable: GitHub's latest release;
+//! beta: the newest release, pre-releases included) or installs a pinned
+//! version. Projects can require a minimum binary with `min_asimov_version`
+//! in project.yaml, which warmup and validate enforce.
+//!
+//! Releases older than `FIRST_SIGNED_VERSION` have no signatures, so pinning
+//! one fails up front unless `--insecure` is passed.
+
+use super::{
+    check_for_update, compare_versions, http_get, release_check, version_check_for, UpdateError,
+    VersionCheck, CURRENT_VERSION, FIRST_SIGNED_VERSION,
+};
+use serde::Deserialize;
+use std::cmp::Ordering;
+use std::fmt;
+use std::io::Read;
+use std::path::Path;
+
+/// GitHub API listing of recent releases, pre-releases included
+const GITHUB_API_RELEASES_URL: &str =
+    "https://api.github.com/repos/royalbit/asimov/releases?per_page=30";
+
+/// Which releases `asimov update` follows
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Channel {
+    /// Latest non-pre-release
+    #[default]
+    Stable,
+    /// Newest release, including pre-releases
+    Beta,
+}
+
+impl std::str::FromStr for Channel {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "stable" => Ok(Channel::Stable),
+            "beta" => Ok(Channel::Beta),
+            other => Err(format!(
+                "Unknown channel '{}' (expected stable or beta)",
+                other
+            )),
+        }
+    }
+}
+
+impl fmt::Display for Channel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Channel::Stable => write!(f, "stable"),
+            Channel::Beta => write!(f, "beta"),
+        }
+    }
+}
+
+/// Check the newest release on a channel
+pub fn check_channel(channel: Channel) -> Result<VersionCheck, UpdateError> {
+    match channel {
+        Channel::Stable => check_for_update(),
+        Channel::Beta => check_for_update_from_api(GITHUB_API_RELEASES_URL),
+    }
+}
+
+/// Check a GitHub releases listing for the newest release (for testing)
+pub fn check_for_update_from_api(url: &str) -> Result<VersionCheck, UpdateError> {
+    let mut body = String::new();
+    http_get(url)?
+        .read_to_string(&mut body)
+        .map_err(UpdateError::io(format!("Failed to read {}", url)))?;
+    let latest = newest_release(&body).map_err(|reason| UpdateError::BadResponse {
+        url: url.to_string(),
+        reason,
+    })?;
+    Ok(version_check_for(&latest))
+}
+
+#[derive(Deserialize)]
+struct ReleaseEntry {
+    tag_name: String,
+    #[serde(default)]
+    draft: bool,
+}
+
+/// Highest published version in a GitHub releases listing
+pub fn newest_release(body: &str) -> Result<String, String> {
+    let releases: Vec<ReleaseEntry> = serde_json::from_str(body).map_err(|e| e.to_string())?;
+    releases
+        .into_iter()
+        .filter(|release| !release.draft)
+        .map(|release| release.tag_name.trim_start_matches('v').to_string())
+        .filter(|version| is_version(version))
+        .max_by(|a, b| compare_versions(a, b))
+        .ok_or_else(|| "no published releases".to_string())
+}
+
+/// A check that installs exactly `version`, older or newer than the running one
+pub fn pinned_version_check(version: &str) -> Result<VersionCheck, UpdateError> {
+    let version = version.trim().trim_start_matches('v');
+    if !is_version(version) {
+        return Err(UpdateError::InvalidVersion(version.to_string()));
+    }
+    let differs = compare_versions(version, CURRENT_VERSION) != Ordering::Equal;
+    Ok(release_check(version, differs))
+}
+
+/// Err for releases published before signing started
+pub fn require_signed_release(version: &str) -> Result<(), UpdateError> {
+    if compare_versions(version, FIRST_SIGNED_VERSION) == Ordering::Less {
+        return Err(UpdateError::Unsigned(version.to_string()));
+    }
+    Ok(())
+}
+
+/// True for `X.Y.Z` with an optional `-pre` suffix
+pub fn is_version(version: &str) -> bool {
+    let core = version.split(['-', '+']).next().unwrap_or_default();
+    let parts: Vec<&str> = core.split('.').collect();
+    parts.len() == 3
+        && parts
+            .iter()
+            .all(|p| !p.is_empty() && p.bytes().all(|b| b.is_ascii_digit()))
+}
+
+/// `min_asimov_version` from `<dir>/.asimov/project.yaml`, if declared
+pub fn min_asimov_version(dir: &Path) -> Option<String> {
+    let content = std::fs::read_to_string(dir.join(".asimov").join("project.yaml")).ok()?;
+    let yaml: serde_yaml_ng::Value = serde_yaml_ng::from_str(&content).ok()?;
+    match yaml.get("min_asimov_version")? {
+        serde_yaml_ng::Value::String(v) => Some(v.trim().to_string()),
+        // An unquoted 12.4 parses as a number
+        other => serde_yaml_ng::to_string(other)
+            .ok()
+            .map(|v| v.trim().to_string()),
+    }
+}
+
+/// Err when the running binary is older than the project's `min_asimov_version`
+pub fn check_min_asimov_version(dir: &Path) -> Result<(), String> {
+    check_min_version_against(dir, CURRENT_VERSION)
+}
+
+/// `check_min_asimov_version` with the running version injected
+pub fn check_min_version_against(dir: &Path, running: &str) -> Result<(), String> {
+    let Some(min) = min_asimov_version(dir) else {
+        return Ok(());
+    };
+    if compare_versions(running, &min) == Ordering::Less {
+        return Err(format!(
+            "This project requires asimov {} or newer (running {}). Run: asimov update",
+            min.trim_start_matches('v'),
+            running
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_compare_versions_with_prereleases() {
+        assert_eq!(compare_versions("12.4.0", "v12.4.0"), Ordering::Equal);
+        assert!(compare_versions("12.5.0-beta.1", "12.4.0").is_gt());
+        assert!(compare_versions("12.5.0-beta.1", "12.5.0").is_lt());
+        assert!(compare_versions("12.5.0-beta.2", "12.5.0-beta.10").is_lt());
+        assert!(compare_versions("12.5.0-alpha", "12.5.0-beta").is_lt());
+        assert!(compare_versions("12.5.0-beta", "12.5.0-beta.1").is_lt());
+        assert!(compare_versions("12.5.0+build.7", "12.5.0").is_eq());
+    }
+
+    #[test]
+    fn test_channel_parse_and_display() {
+        assert_eq!("Beta".parse::<Channel>().unwrap(), Channel::Beta);
+        assert_eq!(Channel::default().to_string(), "stable");
+        assert!("nightly".parse::<Channel>().is_err());
+    }
+
+    #[test]
+    fn test_newest_release_includes_prereleases() {
+        let body = r#"[
+            {"tag_name": "v12.4.0", "prerelease": false},
+            {"tag_name": "v12.5.0-beta.2", "prerelease": true},
+            {"tag_name": "v13.0.0", "draft": true},
+            {"tag_name": "nightly", "prerelease": true},
+            {"tag_name": "v12.5.0-beta.1", "prerelease": true}
+        ]"#;
+        assert_eq!(newest_release(body).unwrap(), "12.5.0-beta.2");
+        assert!(newest_release("[]").is_err());
+        assert!(newest_release("not json").is_err());
+    }
+
+    #[test]
+    fn test_beta_channel_from_api() {
+        let mut server = mockito::Server::new();
+        let _releases = server
+            .mock("GET", "/releases")
+            .with_body(r#"[{"tag_name": "v99.1.0-beta.1"}, {"tag_name": "v99.0.0"}]"#)
+            .create();
+
+        let check = check_for_update_from_api(&format!("{}/releases", server.url())).unwrap();
+        assert_eq!(check.latest, "99.1.0-beta.1");
+        assert!(check.update_available);
+        assert!(check
+            .checksums_url
+            .unwrap()
+            .contains("/v99.1.0-beta.1/checksums.txt"));
+    }
+
+    #[test]
+    fn test_pinned_version_check() {
+        let older = pinned_version_check("v1.0.0").unwrap();
+        assert_eq!(older.latest, "1.0.0");
+        assert!(older.update_available, "pinning allows downgrades");
+        assert!(older.checksums_url.unwrap().contains("/v1.0.0/"));
+
+        let same = pinned_version_check(CURRENT_VERSION).unwrap();
+        assert!(!same.update_available);
+
+        assert!(matches!(
+            pinned_version_check("latest"),
+            Err(UpdateError::InvalidVersion(_))
+        ));
+    }
+
+    #[test]
+    fn test_require_signed_release() {
+        assert!(require_signed_release("12.4.0").is_ok());
+        assert!(require_signed_release("13.0.0").is_ok());
+        let err = require_signed_release("12.3.0").unwrap_err();
+        assert!(matches!(err, UpdateError::Unsigned(_)));
+        assert!(err
+            .to_string()
+            .contains("asimov 12.3.0 predates signed releases (the first is 12.4.0)"));
+        assert!(require_signed_release("12.4.0-beta.1").is_err());
+    }
+
+    fn project(min: Option<&str>) -> TempDir {
+        let temp = TempDir::new().unwrap();
+        std::fs::create_dir_all(temp.path().join(".asimov")).unwrap();
+        let mut yaml = "identity:\n  name: x\n  type: rust\n".to_string();
+        if let Some(min) = min {
+            yaml.push_str(&format!("min_asimov_version: {}\n", min));
+        }
+        std::fs::write(temp.path().join(".asimov/project.yaml"), yaml).unwrap();
+        temp
+    }
+
+    #[test]
+    fn test_min_asimov_version() {
+        assert!(check_min_version_against(project(None).path(), "1.0.0").is_ok());
+        assert!(check_min_version_against(TempDir::new().unwrap().path(), "1.0.0").is_ok());
+
+        let temp = project(Some("\"12.4.0\""));
+        assert_eq!(min_asimov_version(temp.path()).as_deref(), Some("12.4.0"));
+        assert!(check_min_version_against(temp.path(), "12.4.0").is_ok());
+        assert!(check_min_version_against(temp.path(), "13.0.0").is_ok());
+        let err = check_min_version_against(temp.path(), "12.3.0").unwrap_err();
+        assert!(err.contains("requires asimov 12.4.0 or newer (running 12.3.0)"));
+
+        // Pre-releases of the minimum are older than it
+        assert!(check_min_version_against(temp.path(), "12.4.0-beta.1").is_err());
+    }
+}
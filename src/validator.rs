@@ -753,6 +753,35 @@ backlog:
         assert!(result.is_valid, "Errors: {:?}", result.errors);
     }
 
+    #[test]
+    fn test_roadmap_deliverable_ids() {
+        let valid = r#"
+current:
+  version: "1.0.0"
+  status: in_progress
+  summary: "Current milestone"
+  deliverables:
+    - "[ ] Plain item"
+    - id: api-auth
+      summary: "Token auth"
+      status: wip
+next:
+  - version: "1.1.0"
+    summary: "Next milestone"
+    deliverables:
+      - id: billing
+"#;
+        let mut file = NamedTempFile::with_suffix("_roadmap.yaml").unwrap();
+        write!(file, "{}", valid).unwrap();
+        let result = validate_file(file.path()).unwrap();
+        assert!(result.is_valid, "Errors: {:?}", result.errors);
+
+        let invalid = valid.replace("status: wip", "status: started");
+        let mut file = NamedTempFile::with_suffix("_roadmap.yaml").unwrap();
+        write!(file, "{}", invalid).unwrap();
+        assert!(!validate_file(file.path()).unwrap().is_valid);
+    }
+
     #[test]
     fn test_valid_roadmap_all_statuses() {
         for status in ["pending", "planned", "in_progress", "released"] {
@@ -0,0 +1,802 @@
+//! Monorepo / workspace awareness (v12.4.0)
+//!
+//! A repository may hold several projects: Cargo workspaces, pnpm/npm/yarn
+//! workspaces, Go work files, or simply nested projects (`Cargo.toml` at the
+//! root plus `web/package.json`). Each package gets its own type and quality
+//! commands so the pre-commit hook only checks packages with staged changes.
+//!
+//! Packages come from `project.yaml`:
+//!
+//! ```yaml
+//! packages:
+//!   - path: "."
+//!     type: rust
+//!   - path: web
+//!     type: node
+//!     quality:
+//!       lint: "npm run lint"
+//!       test: "npm test"
+//! ```
+//!
+//! When `packages:` is absent they are detected from workspace manifests.
+//! A package may also carry its own `<path>/.asimov/project.yaml`, whose
+//! `identity.type` and `quality` fill in anything the root entry leaves out.
+
+use crate::quality::{
+    load_quality_config, parse_quality, resolve_gates, GateSpec, HookConfig, QualityGate,
+};
+use crate::templates::{
+    detect_project_type, quality_precommit_hook_template, workspace_precommit_hook_template,
+    ProjectType,
+};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::Path;
+
+/// Directories never treated as (or searched for) packages
+const IGNORED_DIRS: &[&str] = &[
+    "node_modules",
+    "target",
+    "vendor",
+    "third_party",
+    "build",
+    "dist",
+    "out",
+    "bin",
+    "obj",
+    "examples",
+    "fixtures",
+    "testdata",
+    "test",
+    "tests",
+    "__tests__",
+];
+
+/// How deep to look for nested projects below the root
+const NESTED_SCAN_DEPTH: usize = 2;
+
+/// Workspace manifest that contributed packages
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WorkspaceKind {
+    Cargo,
+    Pnpm,
+    Npm,
+    Yarn,
+    GoWork,
+    Nested,
+}
+
+impl fmt::Display for WorkspaceKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WorkspaceKind::Cargo => write!(f, "cargo"),
+            WorkspaceKind::Pnpm => write!(f, "pnpm"),
+            WorkspaceKind::Npm => write!(f, "npm"),
+            WorkspaceKind::Yarn => write!(f, "yarn"),
+            WorkspaceKind::GoWork => write!(f, "go.work"),
+            WorkspaceKind::Nested => write!(f, "nested"),
+        }
+    }
+}
+
+/// A package inside the repository
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WorkspacePackage {
+    /// Path relative to the repository root, `/`-separated ("." for the root)
+    pub path: String,
+    pub project_type: ProjectType,
+    /// Declared quality gates (gate name -> command)
+    pub quality: BTreeMap<String, GateSpec>,
+}
+
+impl WorkspacePackage {
+    fn new(path: String, project_type: ProjectType) -> Self {
+        Self {
+            path,
+            project_type,
+            quality: BTreeMap::new(),
+        }
+    }
+
+    /// Declared quality gates in execution order, skipping placeholders
+    pub fn quality_gates(&self, hook: &HookConfig) -> Vec<QualityGate> {
+        resolve_gates(&self.quality, hook)
+    }
+}
+
+/// Detected workspace layout
+#[derive(Debug, Clone, Default)]
+pub struct Workspace {
+    pub kinds: Vec<WorkspaceKind>,
+    pub packages: Vec<WorkspacePackage>,
+}
+
+impl Workspace {
+    /// True when the repo holds more than a single root project
+    pub fn is_multi_package(&self) -> bool {
+        self.packages.iter().any(|p| p.path != ".")
+    }
+
+    fn add(&mut self, kind: WorkspaceKind, package: WorkspacePackage) {
+        if !self.kinds.contains(&kind) {
+            self.kinds.push(kind);
+        }
+        if !self.packages.iter().any(|p| p.path == package.path) {
+            self.packages.push(package);
+        }
+    }
+}
+
+/// `packages:` entry in project.yaml
+#[derive(Debug, Deserialize)]
+struct PackageEntry {
+    path: String,
+    #[serde(rename = "type")]
+    kind: Option<String>,
+    #[serde(default)]
+    quality: Option<serde_yaml_ng::Value>,
+}
+
+/// Detect workspace packages from manifests and nested project markers
+pub fn detect_workspace(dir: &Path) -> Workspace {
+    let mut ws = Workspace::default();
+    // Paths a manifest explicitly excludes must not come back via the nested scan
+    let mut excluded: Vec<String> = Vec::new();
+
+    let root_type = detect_project_type(dir);
+    if root_type.is_code() {
+        ws.packages
+            .push(WorkspacePackage::new(".".to_string(), root_type));
+    }
+
+    if let Ok(content) = std::fs::read_to_string(dir.join("Cargo.toml")) {
+        if let Some((members, excludes)) = cargo_workspace_members(&content) {
+            excluded.extend(expand_all(dir, &excludes));
+            for path in expand_patterns(dir, &members, &excludes) {
+                let pt = member_type(dir, &path, ProjectType::Rust);
+                ws.add(WorkspaceKind::Cargo, WorkspacePackage::new(path, pt));
+            }
+        }
+    }
+
+    if let Ok(content) = std::fs::read_to_string(dir.join("pnpm-workspace.yaml")) {
+        let (includes, excludes) = split_negations(pnpm_workspace_patterns(&content));
+        excluded.extend(expand_all(dir, &excludes));
+        for path in expand_patterns(dir, &includes, &excludes) {
+            let pt = member_type(dir, &path, ProjectType::Node);
+            ws.add(WorkspaceKind::Pnpm, WorkspacePackage::new(path, pt));
+        }
+    }
+
+    if let Ok(content) = std::fs::read_to_string(dir.join("package.json")) {
+        let patterns = package_json_workspaces(&content);
+        if !patterns.is_empty() {
+            let kind = if dir.join("yarn.lock").exists() {
+                WorkspaceKind::Yarn
+            } else {
+                WorkspaceKind::Npm
+            };
+            let (includes, excludes) = split_negations(patterns);
+            excluded.extend(expand_all(dir, &excludes));
+            for path in expand_patterns(dir, &includes, &excludes) {
+                let pt = member_type(dir, &path, ProjectType::Node);
+                ws.add(kind, WorkspacePackage::new(path, pt));
+            }
+        }
+    }
+
+    if let Ok(content) = std::fs::read_to_string(dir.join("go.work")) {
+        for path in go_work_uses(&content) {
+            if dir.join(&path).is_dir() {
+                let pt = member_type(dir, &path, ProjectType::Go);
+                ws.add(WorkspaceKind::GoWork, WorkspacePackage::new(path, pt));
+            }
+        }
+    }
+
+    for path in scan_nested_projects(dir)
+        .into_iter()
+        .filter(|p| !excluded.contains(p))
+    {
+        let pt = detect_project_type(&dir.join(&path));
+        ws.add(WorkspaceKind::Nested, WorkspacePackage::new(path, pt));
+    }
+
+    ws.packages.sort_by(|a, b| a.path.cmp(&b.path));
+    ws
+}
+
+/// Packages declared in `.asimov/project.yaml` (None if no `packages:` key)
+pub fn load_declared_packages(dir: &Path) -> Option<Vec<WorkspacePackage>> {
+    let content = std::fs::read_to_string(dir.join(".asimov").join("project.yaml")).ok()?;
+    let yaml: serde_yaml_ng::Value = serde_yaml_ng::from_str(&content).ok()?;
+    let entries: Vec<PackageEntry> =
+        serde_yaml_ng::from_value(yaml.get("packages")?.clone()).ok()?;
+
+    Some(
+        entries
+            .into_iter()
+            .map(|entry| {
+                let path = normalize_path(&entry.path);
+                let project_type = entry
+                    .kind
+                    .and_then(|k| k.parse().ok())
+                    .unwrap_or_else(|| detect_project_type(&dir.join(&path)));
+                WorkspacePackage {
+                    path,
+                    project_type,
+                    quality: parse_quality(entry.quality.as_ref()),
+                }
+            })
+            .collect(),
+    )
+}
+
+/// Declared packages if present, otherwise detected ones, with per-package
+/// `.asimov/project.yaml` filling in missing quality commands
+pub fn resolve_packages(dir: &Path) -> Vec<WorkspacePackage> {
+    let mut packages =
+        load_declared_packages(dir).unwrap_or_else(|| detect_workspace(dir).packages);
+
+    for package in packages.iter_mut().filter(|p| p.path != ".") {
+        let local = dir.join(&package.path).join(".asimov").join("project.yaml");
+        let Some(yaml) = std::fs::read_to_string(local)
+            .ok()
+            .and_then(|c| serde_yaml_ng::from_str::<serde_yaml_ng::Value>(&c).ok())
+        else {
+            continue;
+        };
+        if let Some(pt) = yaml
+            .get("identity")
+            .and_then(|i| i.get("type"))
+            .and_then(|t| t.as_str())
+            .and_then(|t| t.parse().ok())
+        {
+            if !package.project_type.is_code() {
+                package.project_type = pt;
+            }
+        }
+        for (gate, spec) in parse_quality(yaml.get("quality")) {
+            package.quality.entry(gate).or_insert(spec);
+        }
+    }
+
+    packages
+}
+
+/// Pre-commit hook for a directory: per-package for workspaces, single otherwise.
+/// v12.4.0: Declared `quality` gates replace the built-in checks.
+pub fn precommit_hook_for_dir(dir: &Path, project_type: ProjectType) -> String {
+    let config = load_quality_config(dir);
+    let mut packages = resolve_packages(dir);
+    if packages.iter().any(|p| p.path != ".") {
+        // The root package inherits the root project's gates
+        if let Some(root) = packages.iter_mut().find(|p| p.path == ".") {
+            for (gate, spec) in &config.quality {
+                root.quality
+                    .entry(gate.clone())
+                    .or_insert_with(|| spec.clone());
+            }
+        }
+        workspace_precommit_hook_template(&packages, &config.hook)
+    } else {
+        quality_precommit_hook_template(project_type, &config.gates(), &config.hook)
+    }
+}
+
+/// Render a `packages:` section for project.yaml
+pub fn packages_yaml_section(packages: &[WorkspacePackage]) -> String {
+    let mut out = String::from(
+        "\n# v12.4.0: Workspace packages (pre-commit checks only packages with staged changes)\n\
+         # Add a quality: map per package to override the built-in checks\n\
+         packages:\n",
+    );
+    for package in packages {
+        out.push_str(&format!(
+            "  - path: \"{}\"\n    type: {}\n",
+            package.path, package.project_type
+        ));
+    }
+    out
+}
+
+fn member_type(root: &Path, path: &str, fallback: ProjectType) -> ProjectType {
+    let detected = detect_project_type(&root.join(path));
+    if detected.is_code() {
+        detected
+    } else {
+        fallback
+    }
+}
+
+fn normalize_path(path: &str) -> String {
+    let trimmed = path.trim().trim_start_matches("./").trim_end_matches('/');
+    if trimmed.is_empty() {
+        ".".to_string()
+    } else {
+        trimmed.to_string()
+    }
+}
+
+fn split_negations(patterns: Vec<String>) -> (Vec<String>, Vec<String>) {
+    let (excludes, includes): (Vec<String>, Vec<String>) =
+        patterns.into_iter().partition(|p| p.starts_with('!'));
+    let excludes = excludes
+        .into_iter()
+        .map(|p| p.trim_start_matches('!').to_string())
+        .collect();
+    (includes, excludes)
+}
+
+/// Parse `[workspace] members`/`exclude` from Cargo.toml (line-based, no toml dep)
+fn cargo_workspace_members(content: &str) -> Option<(Vec<String>, Vec<String>)> {
+    let mut in_workspace = false;
+    let mut section = String::new();
+    let mut found = false;
+
+    for line in content.lines() {
+        let trimmed = line.trim();
+        if trimmed.starts_with('[') && !trimmed.starts_with("[[") && trimmed.ends_with(']') {
+            let is_header = !trimmed.contains('"');
+            if is_header {
+                in_workspace = trimmed == "[workspace]";
+                found |= in_workspace;
+                continue;
+            }
+        }
+        if in_workspace {
+            section.push_str(trimmed);
+            section.push('\n');
+        }
+    }
+
+    if !found {
+        return None;
+    }
+    Some((
+        toml_string_array(&section, "members"),
+        toml_string_array(&section, "exclude"),
+    ))
+}
+
+fn toml_string_array(section: &str, key: &str) -> Vec<String> {
+    let re = regex::Regex::new(&format!(r"(?s)(?:^|\n){}\s*=\s*\[(.*?)\]", key)).unwrap();
+    let item = regex::Regex::new(r#""([^"]+)"|'([^']+)'"#).unwrap();
+    re.captures(section)
+        .map(|cap| {
+            item.captures_iter(&cap[1])
+                .filter_map(|c| c.get(1).or_else(|| c.get(2)))
+                .map(|m| m.as_str().to_string())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn pnpm_workspace_patterns(content: &str) -> Vec<String> {
+    serde_yaml_ng::from_str::<serde_yaml_ng::Value>(content)
+        .ok()
+        .and_then(|yaml| yaml.get("packages").and_then(|p| p.as_sequence()).cloned())
+        .map(|seq| {
+            seq.iter()
+                .filter_map(|v| v.as_str().map(|s| s.to_string()))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn package_json_workspaces(content: &str) -> Vec<String> {
+    let Ok(json) = serde_json::from_str::<serde_json::Value>(content) else {
+        return Vec::new();
+    };
+    let workspaces = match json.get("workspaces") {
+        Some(serde_json::Value::Array(items)) => items.clone(),
+        Some(serde_json::Value::Object(obj)) => obj
+            .get("packages")
+            .and_then(|p| p.as_array())
+            .cloned()
+            .unwrap_or_default(),
+        _ => Vec::new(),
+    };
+    workspaces
+        .iter()
+        .filter_map(|v| v.as_str().map(|s| s.to_string()))
+        .collect()
+}
+
+fn go_work_uses(content: &str) -> Vec<String> {
+    let mut uses = Vec::new();
+    let mut in_block = false;
+    for line in content.lines() {
+        let line = line.split("//").next().unwrap_or("").trim();
+        if in_block {
+            if line == ")" {
+                in_block = false;
+            } else if !line.is_empty() {
+                uses.push(normalize_path(line));
+            }
+        } else if let Some(rest) = line.strip_prefix("use") {
+            let rest = rest.trim();
+            if rest == "(" {
+                in_block = true;
+            } else if !rest.is_empty() {
+                uses.push(normalize_path(rest));
+            }
+        }
+    }
+    uses
+}
+
+/// Expand workspace glob patterns (`*`, `?`, `**`) into existing directories
+fn expand_patterns(root: &Path, includes: &[String], excludes: &[String]) -> Vec<String> {
+    let excluded = expand_all(root, excludes);
+    expand_all(root, includes)
+        .into_iter()
+        .filter(|p| p != "." && !excluded.contains(p))
+        .collect()
+}
+
+fn expand_all(root: &Path, patterns: &[String]) -> Vec<String> {
+    let mut paths: Vec<String> = patterns.iter().flat_map(|p| expand_glob(root, p)).collect();
+    paths.sort();
+    paths.dedup();
+    paths
+}
+
+fn expand_glob(root: &Path, pattern: &str) -> Vec<String> {
+    let pattern = normalize_path(pattern);
+    if pattern == "." {
+        return vec![pattern];
+    }
+    let segments: Vec<&str> = pattern.split('/').collect();
+    let mut out = Vec::new();
+    expand_segments(root, "", &segments, &mut out);
+    out
+}
+
+fn expand_segments(root: &Path, prefix: &str, segments: &[&str], out: &mut Vec<String>) {
+    let Some((segment, rest)) = segments.split_first() else {
+        if !prefix.is_empty() {
+            out.push(prefix.to_string());
+        }
+        return;
+    };
+
+    let join = |name: &str| {
+        if prefix.is_empty() {
+            name.to_string()
+        } else {
+            format!("{}/{}", prefix, name)
+        }
+    };
+
+    if !segment.contains(['*', '?']) {
+        let next = join(segment);
+        if root.join(&next).is_dir() {
+            expand_segments(root, &next, rest, out);
+        }
+        return;
+    }
+
+    let current = if prefix.is_empty() {
+        root.to_path_buf()
+    } else {
+        root.join(prefix)
+    };
+    let Ok(entries) = std::fs::read_dir(current) else {
+        return;
+    };
+    let mut names: Vec<String> = entries
+        .flatten()
+        .filter(|e| e.path().is_dir())
+        .map(|e| e.file_name().to_string_lossy().to_string())
+        .filter(|name| !name.starts_with('.') && !IGNORED_DIRS.contains(&name.as_str()))
+        .collect();
+    names.sort();
+
+    if *segment == "**" {
+        // Zero directories, then one more level with `**` still pending
+        expand_segments(root, prefix, rest, out);
+        for name in names {
+            expand_segments(root, &join(&name), segments, out);
+        }
+        return;
+    }
+
+    for name in names.into_iter().filter(|n| wildcard_match(segment, n)) {
+        expand_segments(root, &join(&name), rest, out);
+    }
+}
+
+pub(crate) fn wildcard_match(pattern: &str, name: &str) -> bool {
+    let p: Vec<char> = pattern.chars().collect();
+    let n: Vec<char> = name.chars().collect();
+    let (mut pi, mut ni) = (0, 0);
+    let (mut star, mut mark) = (None, 0);
+    while ni < n.len() {
+        if pi < p.len() && (p[pi] == '?' || p[pi] == n[ni]) {
+            pi += 1;
+            ni += 1;
+        } else if pi < p.len() && p[pi] == '*' {
+            star = Some(pi);
+            mark = ni;
+            pi += 1;
+        } else if let Some(s) = star {
+            pi = s + 1;
+            mark += 1;
+            ni = mark;
+        } else {
+            return false;
+        }
+    }
+    p[pi..].iter().all(|c| *c == '*')
+}
+
+/// Find project directories below the root (not descending into found projects)
+fn scan_nested_projects(root: &Path) -> Vec<String> {
+    let mut found = Vec::new();
+    scan_dir(root, "", 1, &mut found);
+    found
+}
+
+fn scan_dir(root: &Path, prefix: &str, depth: usize, found: &mut Vec<String>) {
+    if depth > NESTED_SCAN_DEPTH {
+        return;
+    }
+    let current = if prefix.is_empty() {
+        root.to_path_buf()
+    } else {
+        root.join(prefix)
+    };
+    let Ok(entries) = std::fs::read_dir(current) else {
+        return;
+    };
+    let mut names: Vec<String> = entries
+        .flatten()
+        .filter(|e| e.path().is_dir())
+        .map(|e| e.file_name().to_string_lossy().to_string())
+        .filter(|name| !name.starts_with('.') && !IGNORED_DIRS.contains(&name.as_str()))
+        .collect();
+    names.sort();
+
+    for name in names {
+        let rel = if prefix.is_empty() {
+            name
+        } else {
+            format!("{}/{}", prefix, name)
+        };
+        if has_project_marker(&root.join(&rel)) {
+            found.push(rel);
+        } else {
+            scan_dir(root, &rel, depth + 1, found);
+        }
+    }
+}
+
+/// True if the directory has a build manifest (docs/README alone don't count)
+fn has_project_marker(dir: &Path) -> bool {
+    detect_project_type(dir).is_code()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn write(root: &Path, rel: &str, content: &str) {
+        let path = root.join(rel);
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, content).unwrap();
+    }
+
+    fn gates(package: &WorkspacePackage) -> Vec<(String, String)> {
+        package
+            .quality_gates(&HookConfig::default())
+            .into_iter()
+            .map(|g| (g.name, g.command))
+            .collect()
+    }
+
+    fn paths(ws: &Workspace) -> Vec<&str> {
+        ws.packages.iter().map(|p| p.path.as_str()).collect()
+    }
+
+    #[test]
+    fn test_single_project_is_not_multi_package() {
+        let temp = TempDir::new().unwrap();
+        write(temp.path(), "Cargo.toml", "[package]\nname = \"a\"\n");
+        let ws = detect_workspace(temp.path());
+        assert_eq!(paths(&ws), vec!["."]);
+        assert!(!ws.is_multi_package());
+    }
+
+    #[test]
+    fn test_detect_cargo_workspace() {
+        let temp = TempDir::new().unwrap();
+        write(
+            temp.path(),
+            "Cargo.toml",
+            "[workspace]\nmembers = [\n  \"crates/*\",\n  \"tools/cli\",\n]\nexclude = [\"crates/old\"]\n\n[workspace.dependencies]\nserde = \"1\"\n",
+        );
+        write(temp.path(), "crates/core/Cargo.toml", "[package]\n");
+        write(temp.path(), "crates/api/Cargo.toml", "[package]\n");
+        write(temp.path(), "crates/old/Cargo.toml", "[package]\n");
+        write(temp.path(), "tools/cli/Cargo.toml", "[package]\n");
+
+        let ws = detect_workspace(temp.path());
+        assert!(ws.kinds.contains(&WorkspaceKind::Cargo));
+        assert_eq!(
+            paths(&ws),
+            vec![".", "crates/api", "crates/core", "tools/cli"]
+        );
+        assert!(ws
+            .packages
+            .iter()
+            .all(|p| p.project_type == ProjectType::Rust));
+    }
+
+    #[test]
+    fn test_detect_pnpm_and_npm_workspaces() {
+        let temp = TempDir::new().unwrap();
+        write(
+            temp.path(),
+            "pnpm-workspace.yaml",
+            "packages:\n  - 'apps/*'\n  - '!apps/legacy'\n",
+        );
+        write(temp.path(), "package.json", "{\"name\": \"root\"}");
+        write(temp.path(), "apps/web/package.json", "{}");
+        write(temp.path(), "apps/legacy/package.json", "{}");
+        let ws = detect_workspace(temp.path());
+        assert!(ws.kinds.contains(&WorkspaceKind::Pnpm));
+        assert_eq!(paths(&ws), vec![".", "apps/web"]);
+
+        let temp = TempDir::new().unwrap();
+        write(
+            temp.path(),
+            "package.json",
+            "{\"workspaces\": {\"packages\": [\"packages/**\"]}}",
+        );
+        write(temp.path(), "yarn.lock", "");
+        write(temp.path(), "packages/ui/package.json", "{}");
+        write(temp.path(), "packages/ui/node_modules/x/package.json", "{}");
+        let ws = detect_workspace(temp.path());
+        assert!(ws.kinds.contains(&WorkspaceKind::Yarn));
+        assert!(paths(&ws).contains(&"packages/ui"));
+        assert!(!paths(&ws).iter().any(|p| p.contains("node_modules")));
+    }
+
+    #[test]
+    fn test_detect_go_work() {
+        let temp = TempDir::new().unwrap();
+        write(
+            temp.path(),
+            "go.work",
+            "go 1.22\n\nuse (\n\t./svc/api // main API\n\t./svc/worker\n)\nuse ./tools\n",
+        );
+        write(temp.path(), "svc/api/go.mod", "module api\n");
+        write(temp.path(), "svc/worker/go.mod", "module worker\n");
+        write(temp.path(), "tools/go.mod", "module tools\n");
+        let ws = detect_workspace(temp.path());
+        assert!(ws.kinds.contains(&WorkspaceKind::GoWork));
+        assert_eq!(paths(&ws), vec!["svc/api", "svc/worker", "tools"]);
+    }
+
+    #[test]
+    fn test_detect_nested_projects() {
+        let temp = TempDir::new().unwrap();
+        write(temp.path(), "Cargo.toml", "[package]\nname = \"a\"\n");
+        write(temp.path(), "web/package.json", "{}");
+        write(temp.path(), "web/packages/inner/package.json", "{}");
+        write(temp.path(), "tests/fixtures/demo/package.json", "{}");
+        write(temp.path(), "docs/README.md", "# Docs");
+
+        let ws = detect_workspace(temp.path());
+        assert!(ws.is_multi_package());
+        assert_eq!(paths(&ws), vec![".", "web"]);
+        assert_eq!(ws.packages[1].project_type, ProjectType::Node);
+    }
+
+    #[test]
+    fn test_declared_packages_override_detection() {
+        let temp = TempDir::new().unwrap();
+        write(temp.path(), "Cargo.toml", "[package]\n");
+        write(temp.path(), "web/package.json", "{}");
+        write(
+            temp.path(),
+            ".asimov/project.yaml",
+            "identity:\n  name: x\n  type: rust\npackages:\n  - path: ./web/\n    quality:\n      test: \"pnpm test\"\n      lint: \"pnpm lint\"\n",
+        );
+
+        let packages = resolve_packages(temp.path());
+        assert_eq!(packages.len(), 1);
+        assert_eq!(packages[0].path, "web");
+        assert_eq!(packages[0].project_type, ProjectType::Node);
+        assert_eq!(
+            gates(&packages[0]),
+            vec![
+                ("lint".to_string(), "pnpm lint".to_string()),
+                ("test".to_string(), "pnpm test".to_string())
+            ]
+        );
+    }
+
+    #[test]
+    fn test_package_local_project_yaml_fills_quality() {
+        let temp = TempDir::new().unwrap();
+        write(temp.path(), "Cargo.toml", "[package]\n");
+        write(temp.path(), "web/package.json", "{}");
+        write(
+            temp.path(),
+            "web/.asimov/project.yaml",
+            "identity:\n  name: web\n  type: node\nquality:\n  test: \"vitest run\"\n  lint: \"# use IDE\"\n",
+        );
+
+        let packages = resolve_packages(temp.path());
+        let web = packages.iter().find(|p| p.path == "web").unwrap();
+        assert_eq!(
+            gates(web),
+            vec![("test".to_string(), "vitest run".to_string())]
+        );
+    }
+
+    #[test]
+    fn test_precommit_hook_for_dir() {
+        let temp = TempDir::new().unwrap();
+        write(temp.path(), "Cargo.toml", "[package]\n");
+        let hook = precommit_hook_for_dir(temp.path(), ProjectType::Rust);
+        assert!(!hook.contains("PACKAGE:"));
+
+        write(temp.path(), "web/package.json", "{}");
+        let hook = precommit_hook_for_dir(temp.path(), ProjectType::Rust);
+        assert!(hook.contains("PACKAGE: web (node)"));
+        assert!(hook.contains("PACKAGE: . (rust)"));
+    }
+
+    #[test]
+    fn test_precommit_hook_for_dir_uses_declared_gates() {
+        let temp = TempDir::new().unwrap();
+        write(temp.path(), "Cargo.toml", "[package]\n");
+        write(
+            temp.path(),
+            ".asimov/project.yaml",
+            "identity:\n  name: x\n  type: rust\nquality:\n  test: \"cargo nextest run\"\n  lint: \"./lint.sh\"\nhook:\n  profile: fast\n",
+        );
+        let hook = precommit_hook_for_dir(temp.path(), ProjectType::Rust);
+        assert!(hook.contains("run_gate 'lint' fast 0 './lint.sh' || exit 1"));
+        assert!(hook.contains("run_gate 'test' full 0 'cargo nextest run' || exit 1"));
+        assert!(hook.contains("hook_profile=\"${ASIMOV_HOOK_PROFILE:-fast}\""));
+        assert!(!hook.contains("cargo test"));
+
+        // Workspace root inherits the root gates
+        write(temp.path(), "web/package.json", "{}");
+        let hook = precommit_hook_for_dir(temp.path(), ProjectType::Rust);
+        assert!(hook.contains("PACKAGE: . (rust)"));
+        assert!(hook.contains("run_gate 'test' full 0 'cargo nextest run'"));
+    }
+
+    #[test]
+    fn test_packages_yaml_section_roundtrip() {
+        let temp = TempDir::new().unwrap();
+        let packages = vec![
+            WorkspacePackage::new(".".to_string(), ProjectType::Rust),
+            WorkspacePackage::new("web".to_string(), ProjectType::Node),
+        ];
+        write(
+            temp.path(),
+            ".asimov/project.yaml",
+            &format!(
+                "identity:\n  name: x\n  type: rust\n{}",
+                packages_yaml_section(&packages)
+            ),
+        );
+        assert_eq!(load_declared_packages(temp.path()).unwrap(), packages);
+    }
+
+    #[test]
+    fn test_wildcard_match() {
+        assert!(wildcard_match("*", "anything"));
+        assert!(wildcard_match("app-*", "app-web"));
+        assert!(wildcard_match("?b", "ab"));
+        assert!(!wildcard_match("app-*", "lib-web"));
+    }
+}
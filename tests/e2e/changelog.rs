@@ -0,0 +1,108 @@
+//! Changelog drafting and consistency tests (v12.4.0)
+
+use super::binary_path;
+use std::fs;
+use std::path::Path;
+use std::process::{Command, Output};
+use tempfile::TempDir;
+
+fn run(dir: &Path, program: &Path, args: &[&str]) -> Output {
+    Command::new(program)
+        .args(args)
+        .current_dir(dir)
+        .env("GIT_AUTHOR_NAME", "Test")
+        .env("GIT_AUTHOR_EMAIL", "test@example.com")
+        .env("GIT_COMMITTER_NAME", "Test")
+        .env("GIT_COMMITTER_EMAIL", "test@example.com")
+        .output()
+        .expect("Failed to execute")
+}
+
+fn git(dir: &Path, args: &[&str]) {
+    let output = run(dir, Path::new("git"), args);
+    assert!(output.status.success(), "{:?}", output);
+}
+
+#[test]
+fn e2e_changelog_draft_write_and_semantic_check() {
+    let temp = TempDir::new().unwrap();
+    let dir = temp.path();
+    fs::create_dir_all(dir.join(".asimov")).unwrap();
+    fs::write(
+        dir.join("Cargo.toml"),
+        "[package]\nname = \"demo\"\nversion = \"2.0.0\"\n",
+    )
+    .unwrap();
+    fs::write(
+        dir.join("CHANGELOG.md"),
+        "# Changelog\n\n## [1.0.0] - 2026-01-01\n\n- First\n\n---\n",
+    )
+    .unwrap();
+    fs::write(
+        dir.join(".asimov/roadmap.yaml"),
+        "current:\n  version: '2.0.0'\n  status: released\n  summary: Second\n  deliverables:\n    - id: sync\n      summary: Sync command\n      status: done\n",
+    )
+    .unwrap();
+    git(dir, &["init", "-q"]);
+    git(dir, &["add", "-A"]);
+    git(dir, &["commit", "-q", "-m", "Release 1.0.0"]);
+    git(dir, &["tag", "v1.0.0"]);
+    git(
+        dir,
+        &["commit", "-q", "--allow-empty", "-m", "feat: [sync] sync"],
+    );
+    git(
+        dir,
+        &["commit", "-q", "--allow-empty", "-m", "fix: lost lines"],
+    );
+
+    let lint = run(dir, &binary_path(), &["lint-docs", "--semantic", "."]);
+    let stdout = String::from_utf8_lossy(&lint.stdout);
+    assert!(!lint.status.success(), "{}", stdout);
+    assert!(
+        stdout.contains("Released version 2.0.0 has no CHANGELOG.md entry"),
+        "{}",
+        stdout
+    );
+    assert!(stdout.contains("Top CHANGELOG.md version 1.0.0 differs from Cargo.toml version 2.0.0"));
+
+    let draft = run(dir, &binary_path(), &["changelog", "--date", "2026-03-01"]);
+    assert!(draft.status.success(), "{:?}", draft);
+    let stdout = String::from_utf8_lossy(&draft.stdout);
+    assert!(
+        stdout.starts_with("## [2.0.0] - 2026-03-01\n\nSecond\n"),
+        "{}",
+        stdout
+    );
+    assert!(
+        stdout.contains("- **Sync command**\n  - Sync\n"),
+        "{}",
+        stdout
+    );
+    assert!(stdout.contains("### Fixed\n\n- Lost lines\n"), "{}", stdout);
+    assert!(!stdout.contains("Release 1.0.0"), "{}", stdout);
+    assert!(String::from_utf8_lossy(&draft.stderr).contains("2 commit(s) since v1.0.0"));
+
+    let write = run(
+        dir,
+        &binary_path(),
+        &["changelog", "--write", "--date", "2026-03-01"],
+    );
+    assert!(write.status.success(), "{:?}", write);
+    let content = fs::read_to_string(dir.join("CHANGELOG.md")).unwrap();
+    assert!(
+        content.contains("---\n\n## [1.0.0] - 2026-01-01"),
+        "{}",
+        content
+    );
+    assert!(content.find("## [2.0.0]") < content.find("## [1.0.0]"));
+
+    let lint = run(dir, &binary_path(), &["lint-docs", "--semantic", "."]);
+    let stdout = String::from_utf8_lossy(&lint.stdout);
+    assert!(!stdout.contains("CHANGELOG.md entry"), "{}", stdout);
+    assert!(!stdout.contains("differs from"), "{}", stdout);
+
+    let again = run(dir, &binary_path(), &["changelog"]);
+    assert!(!again.status.success());
+    assert!(String::from_utf8_lossy(&again.stderr).contains("already has an entry for 2.0.0"));
+}
@@ -0,0 +1,72 @@
+//! Quality gate check e2e tests (v12.4.0)
+
+use super::binary_path;
+use std::fs;
+use std::process::Command;
+use tempfile::TempDir;
+
+fn project(quality: &str) -> TempDir {
+    let temp = TempDir::new().unwrap();
+    fs::create_dir_all(temp.path().join(".asimov")).unwrap();
+    fs::write(
+        temp.path().join(".asimov/project.yaml"),
+        format!("identity:\n  name: x\n  type: generic\n{}", quality),
+    )
+    .unwrap();
+    temp
+}
+
+#[test]
+fn e2e_check_json_reports_gates_and_caches() {
+    let temp = project("quality:\n  lint: \"echo lint-ok\"\n  test: \"echo test-ok\"\n");
+
+    let output = Command::new(binary_path())
+        .args(["check", "--json"])
+        .current_dir(temp.path())
+        .output()
+        .expect("Failed to execute");
+    assert!(output.status.success());
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json["success"], true);
+    assert_eq!(json["gates"][0]["name"], "lint");
+    assert_eq!(json["gates"][0]["status"], "passed");
+    assert_eq!(json["gates"][0]["exit_code"], 0);
+    assert_eq!(json["gates"][0]["stdout"], "lint-ok\n");
+
+    let output = Command::new(binary_path())
+        .args(["check", "--json", "--gate", "test"])
+        .current_dir(temp.path())
+        .output()
+        .expect("Failed to execute");
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json["gates"].as_array().unwrap().len(), 1);
+    assert_eq!(json["gates"][0]["status"], "cached");
+}
+
+#[test]
+fn e2e_check_failure_exit_code() {
+    let temp = project("quality:\n  lint: \"echo broken >&2; exit 2\"\n");
+
+    let output = Command::new(binary_path())
+        .arg("check")
+        .current_dir(temp.path())
+        .output()
+        .expect("Failed to execute");
+    assert!(!output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("exit 2"));
+    assert!(stdout.contains("broken"));
+}
+
+#[test]
+fn e2e_check_unknown_gate() {
+    let temp = project("quality:\n  lint: \"true\"\n");
+
+    let output = Command::new(binary_path())
+        .args(["check", "--gate", "links"])
+        .current_dir(temp.path())
+        .output()
+        .expect("Failed to execute");
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("'links'"));
+}
@@ -0,0 +1,120 @@
+//! Dependency audit e2e tests (v12.4.0)
+
+use super::binary_path;
+use std::fs;
+use std::process::Command;
+use tempfile::TempDir;
+
+const GO_SUM: &str = "github.com/gin-gonic/gin v1.9.0 h1:a=\ngithub.com/gin-gonic/gin v1.9.0/go.mod h1:b=\ngolang.org/x/net v0.7.0 h1:c=\n";
+const GO_MOD: &str =
+    "module example.com/app\n\nrequire (\n\tgithub.com/gin-gonic/gin v1.9.0\n\tgolang.org/x/net v0.7.0 // indirect\n)\n";
+
+#[test]
+fn e2e_deps_audit_json_offline() {
+    let temp = TempDir::new().unwrap();
+    fs::write(temp.path().join("go.sum"), GO_SUM).unwrap();
+    fs::write(temp.path().join("go.mod"), GO_MOD).unwrap();
+    fs::write(
+        temp.path().join("advisories.json"),
+        r#"{"advisories": [{"id": "GO-2023-1571", "ecosystem": "go", "package": "golang.org/x/net", "affected": ["<0.7.1"], "severity": "high"}]}"#,
+    )
+    .unwrap();
+
+    let output = Command::new(binary_path())
+        .args(["deps", "audit", "--json", "--advisories", "advisories.json"])
+        .current_dir(temp.path())
+        .output()
+        .expect("Failed to execute");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        !output.status.success(),
+        "vulnerable dep must fail: {stdout}"
+    );
+
+    let json: serde_json::Value = serde_json::from_str(&stdout).unwrap();
+    assert_eq!(json["total"], 2);
+    assert_eq!(json["direct"], 1);
+    assert_eq!(json["lockfiles"][0]["path"], "go.sum");
+    assert_eq!(json["vulnerabilities"][0]["advisory"]["id"], "GO-2023-1571");
+}
+
+#[test]
+fn e2e_deps_audit_without_lockfile_fails() {
+    let temp = TempDir::new().unwrap();
+    let output = Command::new(binary_path())
+        .args(["deps", "audit"])
+        .current_dir(temp.path())
+        .output()
+        .expect("Failed to execute");
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("No lockfile found"));
+}
+
+#[test]
+fn e2e_deps_audit_reports_bloat_from_project_yaml() {
+    let temp = TempDir::new().unwrap();
+    fs::write(temp.path().join("go.sum"), GO_SUM).unwrap();
+    fs::create_dir_all(temp.path().join(".asimov")).unwrap();
+    fs::write(
+        temp.path().join(".asimov/project.yaml"),
+        "identity:\n  name: x\n  type: go\ndeps:\n  max_dependencies: 1\n",
+    )
+    .unwrap();
+
+    let run = |strict: bool| {
+        let mut cmd = Command::new(binary_path());
+        cmd.args(["deps", "audit"]).current_dir(temp.path());
+        if strict {
+            cmd.arg("--strict");
+        }
+        cmd.output().expect("Failed to execute")
+    };
+
+    let output = run(false);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(output.status.success(), "stdout: {stdout}");
+    assert!(stdout.contains("2 locked packages (limit: 1)"));
+    assert!(!run(true).status.success());
+}
+
+#[test]
+fn e2e_licenses_denies_agpl_with_project_policy() {
+    let temp = TempDir::new().unwrap();
+    fs::write(
+        temp.path().join("package-lock.json"),
+        r#"{"lockfileVersion": 3, "packages": {
+  "": {"dependencies": {"copyleft": "^1", "fine": "^1"}},
+  "node_modules/copyleft": {"version": "1.0.0", "license": "AGPL-3.0-or-later"},
+  "node_modules/fine": {"version": "2.0.0"}
+}}"#,
+    )
+    .unwrap();
+    fs::create_dir_all(temp.path().join("node_modules/fine")).unwrap();
+    fs::write(
+        temp.path().join("node_modules/fine/package.json"),
+        r#"{"name": "fine", "license": "MIT"}"#,
+    )
+    .unwrap();
+    fs::create_dir_all(temp.path().join(".asimov")).unwrap();
+    fs::write(
+        temp.path().join(".asimov/project.yaml"),
+        "identity:\n  name: x\n  type: node\nlicenses:\n  deny: [AGPL-*]\n  unknown: deny\n",
+    )
+    .unwrap();
+
+    let output = Command::new(binary_path())
+        .args(["licenses", "--json"])
+        .current_dir(temp.path())
+        .output()
+        .expect("Failed to execute");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(!output.status.success(), "stdout: {stdout}");
+
+    let json: serde_json::Value = serde_json::from_str(&stdout).unwrap();
+    assert_eq!(json["policy_source"], "project.yaml");
+    assert_eq!(json["checked"], 2);
+    let violations = json["violations"].as_array().unwrap();
+    assert_eq!(violations.len(), 1);
+    assert_eq!(violations[0]["name"], "copyleft");
+    assert_eq!(violations[0]["reason"], "AGPL-3.0-or-later is denied");
+}
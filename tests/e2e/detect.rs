@@ -0,0 +1,109 @@
+//! Project type detection e2e tests (v12.4.0)
+
+use super::binary_path;
+use std::fs;
+use std::process::Command;
+use tempfile::TempDir;
+
+#[test]
+fn e2e_detect_json_ranks_candidates_with_evidence() {
+    let temp = TempDir::new().unwrap();
+    fs::write(
+        temp.path().join("pyproject.toml"),
+        "[project]\nname = \"x\"\n",
+    )
+    .unwrap();
+    fs::write(temp.path().join("poetry.lock"), "").unwrap();
+    fs::write(temp.path().join("package.json"), "{}").unwrap();
+    fs::create_dir_all(temp.path().join("app")).unwrap();
+    fs::write(temp.path().join("app/main.py"), "").unwrap();
+
+    let output = Command::new(binary_path())
+        .args(["detect", "--json"])
+        .arg(temp.path())
+        .output()
+        .expect("Failed to execute");
+    assert!(output.status.success());
+
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let candidates = json["candidates"].as_array().unwrap();
+    assert_eq!(candidates[0]["project_type"], "python");
+    assert_eq!(candidates[1]["project_type"], "node");
+    assert!(candidates[0]["confidence"].as_f64().unwrap() > 0.5);
+    assert!(candidates[0]["evidence"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .any(|e| e["kind"] == "lockfile" && e["detail"] == "poetry.lock"));
+    assert_eq!(json["file_counts"]["python"], 1);
+    assert_eq!(json["lockfiles"][0], "poetry.lock");
+}
+
+#[test]
+fn e2e_detect_human_output() {
+    let temp = TempDir::new().unwrap();
+    fs::write(temp.path().join("go.mod"), "module x\n").unwrap();
+
+    let output = Command::new(binary_path())
+        .arg("detect")
+        .arg(temp.path())
+        .output()
+        .expect("Failed to execute");
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("Project type:"));
+    assert!(stdout.contains("go.mod"));
+}
+
+#[test]
+fn e2e_init_without_type_uses_detected() {
+    let temp = TempDir::new().unwrap();
+    fs::write(temp.path().join("Cargo.toml"), "[package]\nname = \"x\"\n").unwrap();
+
+    // EOF on stdin accepts the top candidate
+    let output = Command::new(binary_path())
+        .args(["init", "-n", "demo", "-o"])
+        .arg(temp.path())
+        .stdin(std::process::Stdio::null())
+        .output()
+        .expect("Failed to execute");
+    assert!(output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("rust 100% - Cargo.toml"));
+
+    let project = fs::read_to_string(temp.path().join(".asimov/project.yaml")).unwrap();
+    assert!(project.contains("type: rust"));
+}
+
+#[test]
+fn e2e_init_without_type_does_not_wait_on_piped_stdin() {
+    // No terminal on stdin: the top candidate, even while the pipe stays open
+    let temp = TempDir::new().unwrap();
+    fs::write(temp.path().join("go.mod"), "module demo\n").unwrap();
+
+    let mut child = Command::new(binary_path())
+        .args(["init", "--name", "piped", "--output"])
+        .arg(temp.path())
+        .stdin(std::process::Stdio::piped())
+        .stdout(std::process::Stdio::null())
+        .stderr(std::process::Stdio::null())
+        .spawn()
+        .expect("Failed to execute");
+    let _stdin = child.stdin.take();
+
+    let deadline = std::time::Instant::now() + std::time::Duration::from_secs(30);
+    let status = loop {
+        if let Some(status) = child.try_wait().unwrap() {
+            break status;
+        }
+        if std::time::Instant::now() > deadline {
+            child.kill().ok();
+            panic!("init waited for input on a non-terminal stdin");
+        }
+        std::thread::sleep(std::time::Duration::from_millis(50));
+    };
+    assert!(status.success());
+
+    let project = fs::read_to_string(temp.path().join(".asimov/project.yaml")).unwrap();
+    assert!(project.contains("type: go"), "{}", project);
+}
@@ -0,0 +1,184 @@
+//! Hook installation tests (v12.4.0)
+
+use super::binary_path;
+use std::fs;
+use std::process::Command;
+use tempfile::TempDir;
+
+fn asimov(dir: &std::path::Path, args: &[&str]) -> std::process::Output {
+    Command::new(binary_path())
+        .args(args)
+        .current_dir(dir)
+        .env("ASIMOV_HOME", dir.join(".home"))
+        .output()
+        .expect("Failed to execute")
+}
+
+#[test]
+fn e2e_hooks_install_merges_claude_settings() {
+    let temp = TempDir::new().unwrap();
+    let dir = temp.path();
+    assert!(
+        asimov(dir, &["init", "--name", "hooked", "--type", "generic"])
+            .status
+            .success()
+    );
+
+    fs::create_dir_all(dir.join(".claude")).unwrap();
+    fs::write(
+        dir.join(".claude/settings.json"),
+        r#"{"model": "opus", "hooks": {"PreCompact": [{"matcher": "auto", "hooks": [{"type": "command", "command": "./notify.sh"}]}]}}"#,
+    )
+    .unwrap();
+
+    let output = asimov(dir, &["hooks", "install", "--ai", "claude"]);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(output.status.success(), "install failed: {:?}", output);
+    assert!(stdout.contains("Registered PreCompact"), "{}", stdout);
+
+    let settings: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(dir.join(".claude/settings.json")).unwrap())
+            .unwrap();
+    assert_eq!(settings["model"], "opus");
+    let pre_compact = settings["hooks"]["PreCompact"].as_array().unwrap();
+    assert_eq!(pre_compact.len(), 2, "user hook kept: {}", settings);
+    assert_eq!(pre_compact[0]["hooks"][0]["command"], "./notify.sh");
+    assert!(fs::read_to_string(dir.join(".asimov/hooks/pre-compact.sh"))
+        .unwrap()
+        .contains("asimov hooks install --ai claude"));
+
+    // Running again adds nothing
+    assert!(asimov(dir, &["hooks", "install", "--ai", "claude"])
+        .status
+        .success());
+    let settings: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(dir.join(".claude/settings.json")).unwrap())
+            .unwrap();
+    assert_eq!(settings["hooks"]["PreCompact"].as_array().unwrap().len(), 2);
+
+    let output = asimov(dir, &["hooks", "status"]);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(output.status.success());
+    assert!(
+        stdout.contains("PreCompact -> pre-compact, 1 other hook(s)"),
+        "{}",
+        stdout
+    );
+    assert!(
+        stdout.contains("asimov hooks install --ai gemini"),
+        "{}",
+        stdout
+    );
+}
+
+#[test]
+fn e2e_hooks_install_without_native_hooks() {
+    let temp = TempDir::new().unwrap();
+    let output = asimov(temp.path(), &["hooks", "install", "--ai", "codex"]);
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("--supervise"));
+}
+
+fn git(dir: &std::path::Path, args: &[&str]) -> std::process::Output {
+    Command::new("git")
+        .args(args)
+        .current_dir(dir)
+        .env("GIT_AUTHOR_NAME", "Test")
+        .env("GIT_AUTHOR_EMAIL", "test@example.com")
+        .env("GIT_COMMITTER_NAME", "Test")
+        .env("GIT_COMMITTER_EMAIL", "test@example.com")
+        .output()
+        .expect("Failed to execute git")
+}
+
+#[test]
+fn e2e_git_hooks_chain_and_uninstall_with_hooks_path() {
+    let temp = TempDir::new().unwrap();
+    let dir = temp.path();
+    if !git(dir, &["init", "-q"]).status.success() {
+        return; // git not available
+    }
+    git(dir, &["config", "core.hooksPath", ".husky"]);
+
+    // Another tool owns commit-msg
+    fs::create_dir_all(dir.join(".husky")).unwrap();
+    let husky = "#!/bin/sh\necho \"$1\" > husky-ran\n";
+    fs::write(dir.join(".husky/commit-msg"), husky).unwrap();
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        fs::set_permissions(
+            dir.join(".husky/commit-msg"),
+            fs::Permissions::from_mode(0o755),
+        )
+        .unwrap();
+    }
+
+    let output = asimov(dir, &["hooks", "install", "--hook", "commit-msg"]);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(output.status.success(), "{}", stdout);
+    assert!(stdout.contains("asimov-chained/commit-msg"), "{}", stdout);
+    assert!(!dir.join(".git/hooks/commit-msg").exists());
+    assert_eq!(
+        fs::read_to_string(dir.join(".husky/asimov-chained/commit-msg")).unwrap(),
+        husky
+    );
+
+    // A real commit still runs the chained hook
+    fs::write(dir.join("README.md"), "# test\n").unwrap();
+    git(dir, &["add", "README.md"]);
+    let commit = git(dir, &["commit", "-q", "-m", "docs: add readme"]);
+    assert!(
+        commit.status.success(),
+        "{}",
+        String::from_utf8_lossy(&commit.stderr)
+    );
+    assert!(dir.join("husky-ran").exists());
+
+    let output = asimov(dir, &["hooks", "status"]);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("commit-msg (chains to asimov-chained/commit-msg)"));
+    assert!(stdout.contains("pre-push (not installed)"), "{}", stdout);
+
+    let output = asimov(dir, &["hooks", "uninstall"]);
+    assert!(output.status.success());
+    assert_eq!(
+        fs::read_to_string(dir.join(".husky/commit-msg")).unwrap(),
+        husky
+    );
+    assert!(!dir.join(".husky/asimov-chained").exists());
+}
+
+#[test]
+fn e2e_lint_commit_message_file() {
+    let temp = TempDir::new().unwrap();
+    let dir = temp.path();
+    fs::create_dir_all(dir.join(".asimov")).unwrap();
+    fs::write(
+        dir.join(".asimov/project.yaml"),
+        "identity:\n  name: policy\ncommit_policy:\n  types: [feat, fix]\n  require_deliverable: true\n",
+    )
+    .unwrap();
+    fs::write(
+        dir.join(".asimov/roadmap.yaml"),
+        "current:\n  version: '3.1.0'\n  status: in_progress\n  summary: Policy\n  deliverables:\n    - id: lint\n      status: wip\n",
+    )
+    .unwrap();
+
+    fs::write(dir.join("MSG"), "feat: [lint] check messages\n").unwrap();
+    let output = asimov(dir, &["lint-commit", "MSG"]);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(output.status.success(), "{}", stdout);
+    assert!(stdout.contains("(v3.1.0)"), "{}", stdout);
+
+    fs::write(dir.join("MSG"), "docs: [nope] readme\n\n# comment\n").unwrap();
+    let output = asimov(dir, &["lint-commit", "MSG"]);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(!output.status.success());
+    assert!(stdout.contains("Type 'docs' is not allowed"), "{}", stdout);
+    assert!(
+        stdout.contains("Unknown deliverable [nope] (ids: lint)"),
+        "{}",
+        stdout
+    );
+}
@@ -589,3 +589,32 @@ fn e2e_init_rust_generated_files_pass_validation() {
         "Rust generated files should pass validation, stdout: {stdout}, stderr: {stderr}"
     );
 }
+
+#[test]
+fn e2e_init_records_workspace_packages() {
+    // v12.4.0: Rust root + nested web app = two packages
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(
+        temp_dir.path().join("Cargo.toml"),
+        "[package]\nname = \"api\"\nversion = \"0.1.0\"\n",
+    )
+    .unwrap();
+    fs::create_dir_all(temp_dir.path().join("web")).unwrap();
+    fs::write(temp_dir.path().join("web").join("package.json"), "{}").unwrap();
+    fs::create_dir_all(temp_dir.path().join(".git").join("hooks")).unwrap();
+
+    let output = Command::new(binary_path())
+        .args(["init", "--name", "mono", "--type", "rust", "--output"])
+        .arg(temp_dir.path())
+        .output()
+        .expect("Failed to execute");
+    assert!(output.status.success());
+
+    let project = fs::read_to_string(temp_dir.path().join(".asimov/project.yaml")).unwrap();
+    assert!(project.contains("packages:"));
+    assert!(project.contains("path: \"web\""));
+
+    let hook = fs::read_to_string(temp_dir.path().join(".git/hooks/pre-commit")).unwrap();
+    assert!(hook.contains("PACKAGE: web (node)"));
+    assert!(hook.contains("PACKAGE: . (rust)"));
+}
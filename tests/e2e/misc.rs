@@ -1072,3 +1072,169 @@ fn e2e_refresh_help_shows_new_flags() {
         "Should describe migration features"
     );
 }
+
+#[test]
+fn e2e_doctor_is_read_only_without_fix() {
+    // v12.4.0: Doctor reports repairs; only --fix applies them
+    let temp_dir = TempDir::new().unwrap();
+    fs::create_dir_all(temp_dir.path().join(".asimov")).unwrap();
+
+    let output = Command::new(binary_path())
+        .args(["doctor", "--json"])
+        .current_dir(temp_dir.path())
+        .output()
+        .expect("Failed to execute doctor");
+    assert!(!output.status.success(), "Missing roadmap should fail");
+    let report: serde_json::Value =
+        serde_json::from_slice(&output.stdout).expect("doctor --json should print JSON");
+    assert_eq!(report["fix"], false);
+    assert!(report["fixes_available"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .any(|f| f == "write roadmap.yaml template"));
+    assert!(!temp_dir.path().join(".asimov/roadmap.yaml").exists());
+    assert!(!temp_dir.path().join(".asimov/green.json").exists());
+
+    let output = Command::new(binary_path())
+        .args(["doctor", "--fix"])
+        .current_dir(temp_dir.path())
+        .output()
+        .expect("Failed to execute doctor --fix");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        output.status.success(),
+        "doctor --fix should repair the project, got: {stdout}"
+    );
+    assert!(
+        stdout.contains("Fixed"),
+        "Should report fixes, got: {stdout}"
+    );
+    assert!(temp_dir.path().join(".asimov/roadmap.yaml").exists());
+    assert!(temp_dir.path().join(".asimov/green.json").exists());
+}
+
+#[test]
+fn e2e_doctor_runs_project_checks() {
+    // v12.4.0: doctor.checks in project.yaml join the built-in report
+    let temp_dir = TempDir::new().unwrap();
+    let init_output = Command::new(binary_path())
+        .args([
+            "init",
+            "--name",
+            "test-project",
+            "--type",
+            "generic",
+            "--output",
+        ])
+        .arg(temp_dir.path())
+        .output()
+        .expect("Failed to execute init");
+    assert!(init_output.status.success(), "Init should succeed");
+
+    let project_yaml = temp_dir.path().join(".asimov/project.yaml");
+    let mut content = fs::read_to_string(&project_yaml).unwrap();
+    content.push_str(
+        r#"
+doctor:
+  checks:
+    - id: codeowners
+      name: CODEOWNERS exists
+      command: test -f CODEOWNERS
+      severity: error
+      hint: Add a CODEOWNERS file
+      fix: touch CODEOWNERS
+    - id: ci-validates
+      command: cat ci.yml
+      output: asimov validate
+"#,
+    );
+    fs::write(&project_yaml, content).unwrap();
+
+    let output = Command::new(binary_path())
+        .args(["doctor", "--json"])
+        .current_dir(temp_dir.path())
+        .output()
+        .expect("Failed to execute doctor");
+    assert!(!output.status.success(), "Error-level check should fail");
+    let report: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let checks = report["checks"].as_array().unwrap();
+    let codeowners = checks.iter().find(|c| c["id"] == "codeowners").unwrap();
+    assert_eq!(codeowners["category"], "custom");
+    assert_eq!(codeowners["severity"], "error");
+    assert_eq!(codeowners["passed"], false);
+    let ci = checks.iter().find(|c| c["id"] == "ci-validates").unwrap();
+    assert_eq!(ci["severity"], "warn");
+    assert!(report["issues"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .any(|i| i == "CODEOWNERS exists: Add a CODEOWNERS file"));
+
+    // The warn-level check alone does not fail doctor
+    let output = Command::new(binary_path())
+        .args(["doctor", "--fix"])
+        .current_dir(temp_dir.path())
+        .output()
+        .expect("Failed to execute doctor --fix");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(output.status.success(), "got: {stdout}");
+    assert!(temp_dir.path().join("CODEOWNERS").exists());
+    assert!(stdout.contains("Fixed: touch CODEOWNERS"), "got: {stdout}");
+}
+
+#[test]
+fn e2e_offline_mode_blocks_network() {
+    // v12.4.0: --offline / ASIMOV_OFFLINE=1 gate every network call
+    let temp_dir = TempDir::new().unwrap();
+    let output = Command::new(binary_path())
+        .args(["update", "--check"])
+        .env("ASIMOV_OFFLINE", "1")
+        .env("ASIMOV_HOME", temp_dir.path())
+        .output()
+        .expect("Failed to execute update");
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(!output.status.success());
+    assert!(stderr.contains("Network access disabled"), "got: {stderr}");
+
+    let init_output = Command::new(binary_path())
+        .args([
+            "init",
+            "--name",
+            "test-project",
+            "--type",
+            "generic",
+            "--output",
+        ])
+        .arg(temp_dir.path())
+        .output()
+        .expect("Failed to execute init");
+    assert!(init_output.status.success(), "Init should succeed");
+
+    let output = Command::new(binary_path())
+        .args(["--offline", "warmup"])
+        .current_dir(temp_dir.path())
+        .env_remove("ASIMOV_OFFLINE")
+        .output()
+        .expect("Failed to execute warmup");
+    assert!(output.status.success());
+    let warmup: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert!(warmup["offline"]["directive"]
+        .as_str()
+        .unwrap()
+        .contains("no network access"));
+
+    // No cache and no network: doctor skips the version check
+    let output = Command::new(binary_path())
+        .args(["doctor", "--offline", "--json"])
+        .current_dir(temp_dir.path())
+        .env("ASIMOV_HOME", temp_dir.path())
+        .output()
+        .expect("Failed to execute doctor");
+    let report: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert!(!report["checks"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .any(|c| c["id"] == "version"));
+}
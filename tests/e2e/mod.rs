@@ -22,8 +22,18 @@ pub fn binary_path() -> PathBuf {
     path
 }
 
+mod changelog;
+mod check;
+mod deps;
+mod detect;
 mod help;
+mod hooks;
 mod init;
 mod lint;
 mod misc;
+mod replay;
+mod sessions;
+mod stats;
+mod supervise;
+mod template;
 mod validate;
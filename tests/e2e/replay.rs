@@ -0,0 +1,63 @@
+//! Replay tests (v12.4.0)
+
+use super::binary_path;
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+use tempfile::TempDir;
+
+fn commit(dir: &Path, author: &str, file: &str, content: &str, message: &str) {
+    fs::write(dir.join(file), content).unwrap();
+    for args in [vec!["add", "."], vec!["commit", "-q", "-m", message]] {
+        let status = Command::new("git")
+            .args(&args)
+            .current_dir(dir)
+            .env("GIT_AUTHOR_NAME", author)
+            .env("GIT_AUTHOR_EMAIL", "dev@test.com")
+            .env("GIT_COMMITTER_NAME", author)
+            .env("GIT_COMMITTER_EMAIL", "dev@test.com")
+            .status()
+            .unwrap();
+        assert!(status.success());
+    }
+}
+
+#[test]
+fn e2e_replay_json_with_filters() {
+    let temp = TempDir::new().unwrap();
+    let dir = temp.path();
+    Command::new("git")
+        .args(["init", "-q"])
+        .current_dir(dir)
+        .status()
+        .unwrap();
+    commit(dir, "Ada", "a.txt", "1\n2\n", "feat: a");
+    commit(dir, "Bob", "b.txt", "1\n2\n3\n", "feat: b");
+    commit(dir, "Ada", "a.txt", "1\n", "fix: a");
+
+    let output = Command::new(binary_path())
+        .args(["replay", "-n", "10", "--author", "Ada", "--json"])
+        .current_dir(dir)
+        .output()
+        .expect("Failed to execute");
+    assert!(output.status.success());
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json["commits"].as_array().unwrap().len(), 2);
+    assert_eq!(json["commits"][0]["message"], "fix: a");
+    assert_eq!(json["commits"][0]["deletions"], 1);
+    assert_eq!(json["commits"][1]["files"][0]["path"], "a.txt");
+    assert_eq!(json["total_files_changed"], 1);
+    assert_eq!(json["total_insertions"], 2);
+    assert!(json["commits"][0].get("diff").is_none());
+
+    let output = Command::new(binary_path())
+        .args(["replay", "-n", "10", "--path", "b.txt", "--verbose"])
+        .current_dir(dir)
+        .output()
+        .expect("Failed to execute");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(output.status.success(), "{}", stdout);
+    assert!(stdout.contains("Commits: 1"), "{}", stdout);
+    assert!(stdout.contains("diff --git a/b.txt b/b.txt"), "{}", stdout);
+    assert!(!stdout.contains("a.txt"), "{}", stdout);
+}
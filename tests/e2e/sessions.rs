@@ -0,0 +1,172 @@
+//! Session journal tests (v12.4.0)
+
+use super::binary_path;
+use std::fs;
+use std::path::Path;
+use std::process::{Command, Output, Stdio};
+use tempfile::TempDir;
+
+/// asimov in `dir` with git identity set and no AI CLI detected around it
+fn asimov(dir: &Path, args: &[&str]) -> Output {
+    Command::new(binary_path())
+        .args(args)
+        .current_dir(dir)
+        .env("ASIMOV_HOME", dir)
+        .env("ASIMOV_OFFLINE", "1")
+        .env("GIT_AUTHOR_NAME", "Test")
+        .env("GIT_AUTHOR_EMAIL", "test@example.com")
+        .env("GIT_COMMITTER_NAME", "Test")
+        .env("GIT_COMMITTER_EMAIL", "test@example.com")
+        .env_remove("ASIMOV_SESSION")
+        .env_remove("CLAUDECODE")
+        .env_remove("CLAUDE_CODE_ENTRYPOINT")
+        .env_remove("GEMINI_CLI")
+        .env_remove("CODEX_CLI")
+        .stdin(Stdio::null())
+        .output()
+        .expect("Failed to execute")
+}
+
+fn json(output: &Output) -> serde_json::Value {
+    assert!(output.status.success(), "failed: {:?}", output);
+    serde_json::from_slice(&output.stdout).expect("valid JSON")
+}
+
+fn project() -> TempDir {
+    let temp = TempDir::new().unwrap();
+    let dir = temp.path();
+    let init = asimov(
+        dir,
+        &[
+            "init", "--name", "journal", "--type", "generic", "--output", ".",
+        ],
+    );
+    assert!(init.status.success(), "init failed: {:?}", init);
+    Command::new("git")
+        .args(["init", "-q"])
+        .current_dir(dir)
+        .status()
+        .unwrap();
+    temp
+}
+
+/// A launched fake AI warms up, commits and exits 3: the record holds it all
+#[cfg(unix)]
+#[test]
+fn e2e_launch_records_session() {
+    use std::os::unix::fs::PermissionsExt;
+
+    let temp = project();
+    let dir = temp.path();
+    let fake_ai = dir.join("fake-ai");
+    fs::write(
+        &fake_ai,
+        format!(
+            "#!/bin/sh\n\
+             '{0}' warmup > /dev/null\n\
+             echo change > work.txt\n\
+             git add work.txt && git commit -q --no-verify -m 'Add work'\n\
+             '{0}' check > /dev/null 2>&1\n\
+             exit 3\n",
+            binary_path().display()
+        ),
+    )
+    .unwrap();
+    fs::set_permissions(&fake_ai, fs::Permissions::from_mode(0o755)).unwrap();
+
+    let project_yaml = dir.join(".asimov/project.yaml");
+    let mut yaml = fs::read_to_string(&project_yaml).unwrap();
+    yaml.push_str(&format!(
+        "\nai_profiles:\n  - id: fake\n    binary: {}\n    prompt: none\n",
+        fake_ai.display()
+    ));
+    fs::write(&project_yaml, yaml).unwrap();
+    // ASIMOV_HOME points here: the user config allow-lists the project's binary
+    fs::write(
+        dir.join("config.yaml"),
+        format!("allowed_binaries: ['{}']\n", fake_ai.display()),
+    )
+    .unwrap();
+
+    let launch = Command::new(binary_path())
+        .args(["--ai", "fake"])
+        .current_dir(dir)
+        .env("ASIMOV_ROLE", "eng")
+        .env("ASIMOV_HOME", dir)
+        .env("GIT_AUTHOR_NAME", "Test")
+        .env("GIT_AUTHOR_EMAIL", "test@example.com")
+        .env("GIT_COMMITTER_NAME", "Test")
+        .env("GIT_COMMITTER_EMAIL", "test@example.com")
+        .env_remove("ASIMOV_SESSION")
+        .env_remove("CLAUDECODE")
+        .env_remove("CLAUDE_CODE_ENTRYPOINT")
+        .env_remove("GEMINI_CLI")
+        .env_remove("CODEX_CLI")
+        .stdin(Stdio::null())
+        .output()
+        .expect("Failed to execute");
+    assert_eq!(launch.status.code(), Some(3), "{:?}", launch);
+    assert!(String::from_utf8_lossy(&launch.stdout).contains("recorded: 1 commit(s)"));
+
+    let sessions = json(&asimov(dir, &["sessions", "list", "--json"]));
+    assert_eq!(sessions.as_array().unwrap().len(), 1, "{}", sessions);
+
+    let session = json(&asimov(dir, &["sessions", "show", "last", "--json"]));
+    assert_eq!(session["start"]["source"], "launch");
+    assert_eq!(session["start"]["ai"], "fake");
+    assert_eq!(session["start"]["role"], "eng");
+    assert_eq!(session["start"]["head"], serde_json::Value::Null);
+    assert!(session["start"]["protocol_hash"]
+        .as_str()
+        .unwrap()
+        .starts_with("sha256:"));
+    assert_eq!(session["warmups"], 1);
+    assert_eq!(session["end"]["exit_code"], 3);
+    assert_eq!(session["end"]["commits"][0]["subject"], "Add work");
+    // The AI's own check run, not one started on exit
+    assert!(session["last_check"].is_array(), "{}", session);
+    assert_eq!(session["end"]["gates"], session["last_check"]);
+
+    let status = Command::new("git")
+        .args(["status", "--porcelain", ".asimov/sessions"])
+        .current_dir(dir)
+        .output()
+        .unwrap();
+    assert!(status.stdout.is_empty(), "sessions are not ignored");
+}
+
+/// Warmup outside a launch opens a record; the next warmup closes it
+#[test]
+fn e2e_warmup_sessions_list_show_close() {
+    let temp = project();
+    let dir = temp.path();
+
+    let empty = asimov(dir, &["sessions", "list"]);
+    assert!(String::from_utf8_lossy(&empty.stdout).contains("No sessions recorded"));
+
+    assert!(asimov(dir, &["warmup"]).status.success());
+    // Session ids have one-second resolution
+    std::thread::sleep(std::time::Duration::from_millis(1100));
+    assert!(asimov(dir, &["warmup"]).status.success());
+
+    let sessions = json(&asimov(dir, &["sessions", "list", "--json"]));
+    let sessions = sessions.as_array().unwrap();
+    assert_eq!(sessions.len(), 2);
+    assert_eq!(sessions[0]["start"]["source"], "warmup");
+    assert_eq!(sessions[0]["end"], serde_json::Value::Null);
+    assert!(sessions[1]["end"].is_object(), "previous warmup closed");
+    assert_eq!(sessions[1]["end"]["exit_code"], serde_json::Value::Null);
+
+    let newest = sessions[0]["start"]["id"].as_str().unwrap();
+    let show = asimov(dir, &["sessions", "show", newest]);
+    assert!(show.status.success());
+    assert!(String::from_utf8_lossy(&show.stdout).contains("Open"));
+
+    assert!(asimov(dir, &["sessions", "close"]).status.success());
+    let again = asimov(dir, &["sessions", "close"]);
+    assert!(!again.status.success());
+    assert!(String::from_utf8_lossy(&again.stderr).contains("already closed"));
+
+    let unknown = asimov(dir, &["sessions", "show", "1999"]);
+    assert!(!unknown.status.success());
+}
@@ -0,0 +1,84 @@
+//! Stats history tests (v12.4.0)
+
+use super::binary_path;
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+use tempfile::TempDir;
+
+fn commit_roadmap(dir: &Path, date: &str, roadmap: &str) {
+    fs::write(dir.join(".asimov/roadmap.yaml"), roadmap).unwrap();
+    for args in [&["add", "-A"][..], &["commit", "-q", "-m", "roadmap"][..]] {
+        let status = Command::new("git")
+            .args(args)
+            .current_dir(dir)
+            .env("GIT_AUTHOR_NAME", "Test")
+            .env("GIT_AUTHOR_EMAIL", "test@example.com")
+            .env("GIT_COMMITTER_NAME", "Test")
+            .env("GIT_COMMITTER_EMAIL", "test@example.com")
+            .env("GIT_AUTHOR_DATE", format!("{}T12:00:00", date))
+            .env("GIT_COMMITTER_DATE", format!("{}T12:00:00", date))
+            .status()
+            .unwrap();
+        assert!(status.success());
+    }
+}
+
+#[test]
+fn e2e_stats_history_json_and_burndown() {
+    let temp = TempDir::new().unwrap();
+    let dir = temp.path();
+    Command::new("git")
+        .args(["init", "-q"])
+        .current_dir(dir)
+        .status()
+        .unwrap();
+    fs::create_dir_all(dir.join(".asimov")).unwrap();
+    let roadmap = |status: &str, done: &str| {
+        format!(
+            "current:\n  version: '3.0.0'\n  status: {}\n  summary: Three\n  deliverables:\n    - \"[{}] Ship it\"\n    - \"[ ] Tell people\"\n",
+            status, done
+        )
+    };
+    commit_roadmap(dir, "2026-03-02", &roadmap("in_progress", " "));
+    commit_roadmap(dir, "2026-03-04", &roadmap("in_progress", "x"));
+
+    let output = Command::new(binary_path())
+        .args(["stats", "--json", "--since", "2026-03-01"])
+        .current_dir(dir)
+        .output()
+        .expect("Failed to execute");
+    assert!(output.status.success(), "{:?}", output);
+    let stats: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(stats["since"], "2026-03-01");
+    assert_eq!(stats["history"]["milestones"][0]["started"], "2026-03-02");
+    assert_eq!(stats["history"]["weekly"][0]["week"], "2026-W09");
+    assert_eq!(stats["history"]["weekly"][1]["week"], "2026-W10");
+    assert_eq!(stats["history"]["weekly"][1]["completed"], 1);
+    let burndown = stats["history"]["burndown"].as_array().unwrap();
+    assert_eq!(burndown.len(), 2);
+    assert_eq!(burndown[1]["remaining"], 1);
+
+    let output = Command::new(binary_path())
+        .args(["stats", "--since", "2026-03-01"])
+        .current_dir(dir)
+        .output()
+        .expect("Failed to execute");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        stdout.contains("MILESTONE CYCLES since 2026-03-01"),
+        "{}",
+        stdout
+    );
+    assert!(stdout.contains("BURNDOWN v3.0.0"), "{}", stdout);
+    assert!(stdout.contains("1/2 remaining"), "{}", stdout);
+
+    let output = Command::new(binary_path())
+        .args(["stats", "--since", "a while"])
+        .current_dir(temp.path().join(".asimov"))
+        .env("GIT_DIR", "/nonexistent")
+        .output()
+        .expect("Failed to execute");
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("Cannot resolve --since"));
+}
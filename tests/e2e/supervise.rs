@@ -0,0 +1,106 @@
+//! Session supervisor tests (v12.4.0), driven by a scripted fake AI CLI
+
+use super::binary_path;
+use std::fs;
+use std::process::{Command, Stdio};
+use std::time::{Duration, Instant};
+use tempfile::TempDir;
+
+/// A fake AI that compacts twice: once announced on its output, once via
+/// the real pre-compact hook template, reading the re-injected context each time
+#[cfg(unix)]
+#[test]
+fn e2e_supervise_reinjects_context_on_compaction() {
+    use std::os::unix::fs::PermissionsExt;
+
+    let temp = TempDir::new().unwrap();
+    let project = temp.path();
+    let init = Command::new(binary_path())
+        .args([
+            "init",
+            "--name",
+            "supervised",
+            "--type",
+            "generic",
+            "--output",
+        ])
+        .arg(project)
+        .output()
+        .expect("Failed to execute");
+    assert!(init.status.success(), "init failed: {:?}", init);
+
+    let hook = std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join(".asimov")
+        .join("hooks")
+        .join("pre-compact.sh.tpl");
+    let fake_ai = project.join("fake-ai");
+    fs::write(
+        &fake_ai,
+        format!(
+            "#!/bin/sh\n\
+             echo 'fake-ai ready'\n\
+             printf '\\033[33m* Compacting conversation...\\033[0m\\n'\n\
+             IFS= read -r line\n\
+             printf '%s\\n' \"$line\" > from-marker.txt\n\
+             bash '{}' > /dev/null\n\
+             IFS= read -r line\n\
+             printf '%s\\n' \"$line\" > from-hook.txt\n\
+             exit 3\n",
+            hook.display()
+        ),
+    )
+    .unwrap();
+    fs::set_permissions(&fake_ai, fs::Permissions::from_mode(0o755)).unwrap();
+
+    let project_yaml = project.join(".asimov").join("project.yaml");
+    let mut yaml = fs::read_to_string(&project_yaml).unwrap_or_default();
+    yaml.push_str(&format!(
+        "\nai_profiles:\n  - id: fake\n    binary: {}\n    prompt: none\n    compact_markers: [\"Compacting conversation\"]\n",
+        fake_ai.display()
+    ));
+    fs::write(&project_yaml, yaml).unwrap();
+    // ASIMOV_HOME points here: the user config allow-lists the project's binary
+    fs::write(
+        project.join("config.yaml"),
+        format!("allowed_binaries: ['{}']\n", fake_ai.display()),
+    )
+    .unwrap();
+
+    let mut child = Command::new(binary_path())
+        .args(["--ai", "fake", "--supervise"])
+        .current_dir(project)
+        .env("ASIMOV_HOME", project)
+        .env("ASIMOV_OFFLINE", "1")
+        .env_remove("CLAUDECODE")
+        .env_remove("CLAUDE_CODE_ENTRYPOINT")
+        .env_remove("GEMINI_CLI")
+        .env_remove("CODEX_CLI")
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .expect("Failed to execute");
+
+    let deadline = Instant::now() + Duration::from_secs(30);
+    let status = loop {
+        if let Some(status) = child.try_wait().unwrap() {
+            break status;
+        }
+        if Instant::now() > deadline {
+            child.kill().ok();
+            panic!("supervised session did not finish");
+        }
+        std::thread::sleep(Duration::from_millis(100));
+    };
+    let output = child.wait_with_output().unwrap();
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    assert_eq!(status.code(), Some(3), "exit code passes through");
+    assert!(stdout.contains("fake-ai ready"), "output passes through");
+    for file in ["from-marker.txt", "from-hook.txt"] {
+        let received = fs::read_to_string(project.join(file)).unwrap_or_default();
+        let context: serde_json::Value = serde_json::from_str(received.trim())
+            .unwrap_or_else(|e| panic!("{} is not JSON ({}): {:?}", file, e, received));
+        assert_eq!(context["action"], "SELF_HEAL", "{}", file);
+    }
+}
@@ -0,0 +1,108 @@
+//! Template pack e2e tests - install, list, remove, init from pack (v12.4.0)
+
+use super::binary_path;
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+use tempfile::TempDir;
+
+fn write_pack(dir: &Path) {
+    fs::create_dir_all(dir).unwrap();
+    fs::write(
+        dir.join("manifest.yaml"),
+        "name: acme-service\nversion: \"2.0.0\"\ndescription: ACME microservice\nbase: rust\n",
+    )
+    .unwrap();
+    fs::write(
+        dir.join("template.yaml"),
+        "identity:\n  name: \"{PROJECT_NAME}\"\n  type: rust\n  tagline: \"ACME {PROJECT_TAGLINE}\"\n",
+    )
+    .unwrap();
+}
+
+fn asimov(home: &Path, cwd: &Path, args: &[&str]) -> std::process::Output {
+    Command::new(binary_path())
+        .args(args)
+        .env("ASIMOV_HOME", home)
+        .current_dir(cwd)
+        .output()
+        .expect("Failed to execute")
+}
+
+#[test]
+fn e2e_template_install_list_remove() {
+    let temp = TempDir::new().unwrap();
+    let home = temp.path().join("home");
+    let pack = temp.path().join("pack");
+    write_pack(&pack);
+
+    let output = asimov(
+        &home,
+        temp.path(),
+        &["template", "install", pack.to_str().unwrap()],
+    );
+    assert!(output.status.success(), "install failed: {:?}", output);
+    assert!(home.join("templates/acme-service/manifest.yaml").exists());
+
+    // Second install without --force is refused
+    let output = asimov(
+        &home,
+        temp.path(),
+        &["template", "install", pack.to_str().unwrap()],
+    );
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("already installed"));
+
+    let output = asimov(&home, temp.path(), &["template", "list"]);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("acme-service"), "list: {stdout}");
+    assert!(stdout.contains("2.0.0"));
+
+    let output = asimov(&home, temp.path(), &["template", "remove", "acme-service"]);
+    assert!(output.status.success());
+    assert!(!home.join("templates/acme-service").exists());
+
+    let output = asimov(&home, temp.path(), &["template", "remove", "acme-service"]);
+    assert!(!output.status.success());
+}
+
+#[test]
+fn e2e_init_uses_installed_pack() {
+    let temp = TempDir::new().unwrap();
+    let home = temp.path().join("home");
+    let pack = temp.path().join("pack");
+    let project = temp.path().join("project");
+    write_pack(&pack);
+    fs::create_dir_all(&project).unwrap();
+
+    let output = asimov(
+        &home,
+        temp.path(),
+        &["template", "install", pack.to_str().unwrap()],
+    );
+    assert!(output.status.success());
+
+    let output = asimov(
+        &home,
+        &project,
+        &["init", "-n", "billing", "-t", "acme-service"],
+    );
+    assert!(output.status.success(), "init failed: {:?}", output);
+
+    let content = fs::read_to_string(project.join(".asimov/project.yaml")).unwrap();
+    assert!(content.contains("name: \"billing\""));
+    assert!(content.contains("ACME"));
+}
+
+#[test]
+fn e2e_init_unknown_template_without_pack() {
+    let temp = TempDir::new().unwrap();
+    let home = temp.path().join("home");
+
+    let output = asimov(
+        &home,
+        temp.path(),
+        &["init", "-n", "x", "-t", "acme-service"],
+    );
+    assert!(!output.status.success());
+}
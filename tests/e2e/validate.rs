@@ -59,3 +59,114 @@ fn e2e_validate_directory_no_protocol_files() {
         "Should mention roadmap, got: {stdout}"
     );
 }
+
+#[test]
+fn e2e_validate_sizes_uses_project_globs() {
+    let temp_dir = TempDir::new().unwrap();
+    let asimov_dir = temp_dir.path().join(".asimov");
+    fs::create_dir_all(&asimov_dir).unwrap();
+    fs::write(
+        asimov_dir.join("project.yaml"),
+        r#"identity:
+  name: sizes
+  type: python
+coding_standards:
+  code:
+    file_size:
+      soft_limit: 5
+      hard_limit: 10
+      limits:
+        - glob: "tests/**/*.py"
+          hard_limit: 50
+      exempt:
+        - "generated/**"
+"#,
+    )
+    .unwrap();
+    fs::create_dir_all(temp_dir.path().join("tests/unit")).unwrap();
+    fs::create_dir_all(temp_dir.path().join("generated")).unwrap();
+    fs::write(temp_dir.path().join("app.py"), "x = 1\n".repeat(8)).unwrap();
+    fs::write(
+        temp_dir.path().join("tests/unit/test_app.py"),
+        "x = 1\n".repeat(40),
+    )
+    .unwrap();
+    fs::write(
+        temp_dir.path().join("generated/schema.py"),
+        "x = 1\n".repeat(500),
+    )
+    .unwrap();
+
+    let run = || {
+        Command::new(binary_path())
+            .args(["validate", "--sizes"])
+            .current_dir(temp_dir.path())
+            .output()
+            .expect("Failed to execute")
+    };
+
+    let output = run();
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(output.status.success(), "stdout: {stdout}");
+    assert!(stdout.contains("app.py has 8 lines (soft limit: 5)"));
+    assert!(!stdout.contains("schema.py"));
+
+    fs::write(temp_dir.path().join("app.py"), "x = 1\n".repeat(11)).unwrap();
+    let output = run();
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(!output.status.success(), "stdout: {stdout}");
+    assert!(stdout.contains("app.py has 11 lines (hard limit: 10)"));
+}
+
+#[test]
+fn e2e_min_asimov_version_enforced() {
+    // v12.4.0: project.yaml min_asimov_version fails validate and warmup on older binaries
+    let temp_dir = TempDir::new().unwrap();
+    let asimov_dir = temp_dir.path().join(".asimov");
+    fs::create_dir_all(&asimov_dir).unwrap();
+    fs::write(
+        asimov_dir.join("roadmap.yaml"),
+        "current:\n  version: '1.0.0'\n  status: planned\n  summary: Test milestone",
+    )
+    .unwrap();
+    let project = |min: &str| {
+        fs::write(
+            asimov_dir.join("project.yaml"),
+            format!("identity:\n  name: test\n  type: generic\nmin_asimov_version: \"{min}\"\n"),
+        )
+        .unwrap();
+    };
+    let run = |cmd: &str| {
+        Command::new(binary_path())
+            .arg(cmd)
+            .current_dir(temp_dir.path())
+            .output()
+            .expect("Failed to execute")
+    };
+
+    project("999.0.0");
+    let output = run("validate");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(!output.status.success());
+    assert!(
+        stdout.contains("requires asimov 999.0.0 or newer"),
+        "got: {stdout}"
+    );
+
+    let output = run("warmup");
+    assert!(!output.status.success());
+    let combined = format!(
+        "{}{}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert!(combined.contains("Run: asimov update"), "got: {combined}");
+
+    project("1.0.0");
+    let output = run("validate");
+    assert!(
+        output.status.success(),
+        "got: {}",
+        String::from_utf8_lossy(&output.stdout)
+    );
+}